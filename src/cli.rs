@@ -2,9 +2,12 @@
 
 use clap::{Args, Parser, Subcommand};
 
+use crate::render::dep_graph::GraphFormat;
+use crate::services::forecast::DEFAULT_WEEKS;
+
 use crate::models::{
-    Difficulty, DueFilter, Priority, Recurrence, RecurrenceFilter, ResourceType, SortBy,
-    StatusFilter,
+    Difficulty, DueFilter, Priority, ProjectStatus, Recurrence, RecurrenceFilter, ResourceType,
+    SortBy, StatusFilter, TrashType,
 };
 
 #[derive(Parser)]
@@ -16,16 +19,20 @@ use crate::models::{
 #[command(after_help = "\
 COMMANDS:
   Task Management:
-    add (a), list (ls), done, undone, edit (e), remove (rm), clear, recur, clear-recur
+    add (a), list (ls), done, undone, start, wait, cancel, edit (e), remove (rm),
+    clear, recur, clear-recur, dedupe, merge
 
   Viewing & Planning:
-    next (n), calendar (cal), stats, search (find), context (ctx), deps, tags
+    next (n), urgency, calendar (cal), stats, search (find), context (ctx), deps,
+    plan, tags
 
   Organization:
-    project, note, resource
+    project, note, journal, resource, template, view, attach, attachments,
+    open-attachment
 
   System:
-    info, purge, holidays, backup, restore, backup-list, export, import
+    info, trash, purge, archive, unarchive, holidays, backup, restore,
+    backup-list, export, import
 
 Run 'todo <COMMAND> --help' for more information on a command.
 ")]
@@ -58,33 +65,60 @@ pub enum Commands {
         project: Option<String>,
         #[arg(long, short = 'r', value_enum)]
         recurrence: Option<RecurrenceFilter>,
+        /// Filter expression, e.g. "project:api and (tag:bug or priority:high)"
+        #[arg(long, short = 'f', value_name = "EXPR")]
+        filter: Option<String>,
     },
 
     /// Mark a task as completed
     #[command(visible_alias = "complete", hide = true)]
     Done {
-        #[arg(value_name = "ID")]
-        id: usize,
+        #[command(flatten)]
+        target: TaskTarget,
     },
 
-    /// Mark a completed task as pending
+    /// Reopen a task (done, cancelled, active or waiting) as pending
     #[command(visible_alias = "undo", hide = true)]
     Undone {
-        #[arg(value_name = "ID")]
-        id: usize,
+        #[command(flatten)]
+        target: TaskTarget,
+    },
+
+    /// Mark a task as in progress
+    #[command(hide = true)]
+    Start {
+        #[command(flatten)]
+        target: TaskTarget,
+    },
+
+    /// Mark a task as waiting on something external
+    #[command(hide = true)]
+    Wait {
+        #[command(flatten)]
+        target: TaskTarget,
+    },
+
+    /// Cancel a task without completing it
+    #[command(hide = true)]
+    Cancel {
+        #[command(flatten)]
+        target: TaskTarget,
     },
 
     /// Edit an existing task
     #[command(visible_alias = "e", hide = true)]
-    Edit(EditArgs),
+    Edit {
+        #[command(flatten)]
+        target: TaskTarget,
+        #[command(flatten)]
+        args: EditArgs,
+    },
 
     /// Remove a task permanently
     #[command(visible_aliases = ["rm", "delete"], hide = true)]
     Remove {
-        #[arg(value_name = "ID")]
-        id: usize,
-        #[arg(long, short = 'y')]
-        yes: bool,
+        #[command(flatten)]
+        target: TaskTarget,
     },
 
     /// Clear all tasks
@@ -97,17 +131,44 @@ pub enum Commands {
     /// Set or change recurrence pattern for a task
     #[command(hide = true)]
     Recur {
-        #[arg(value_name = "ID")]
-        id: usize,
+        // Not a flattened `TaskTarget`: PATTERN follows IDS, so IDS must be
+        // required. Use `all` with `--filter` to select by filter alone.
+        #[arg(value_name = "IDS")]
+        ids: String,
         #[arg(value_enum)]
         pattern: Recurrence,
+        #[arg(long, value_name = "EXPR")]
+        filter: Option<String>,
+        #[arg(long, short = 'y')]
+        yes: bool,
     },
 
     /// Remove recurrence pattern from a task
     #[command(visible_alias = "norecur", hide = true)]
     ClearRecur {
+        #[command(flatten)]
+        target: TaskTarget,
+    },
+
+    /// Find groups of similar pending tasks and offer to merge them
+    #[command(hide = true)]
+    Dedupe {
+        /// Merge every group without asking
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Merge duplicate tasks into one, combining tags, notes and dependencies
+    #[command(hide = true)]
+    Merge {
+        /// Task to keep
         #[arg(value_name = "ID")]
-        id: usize,
+        into: usize,
+        /// Tasks to fold into it, e.g. 4,7-9
+        #[arg(value_name = "IDS")]
+        ids: String,
+        #[arg(long, short = 'y')]
+        yes: bool,
     },
 
     // ── Viewing & Planning ────────────────────────────────────────────────────
@@ -118,6 +179,13 @@ pub enum Commands {
         limit: usize,
     },
 
+    /// Explain how a task's urgency score is computed
+    #[command(hide = true)]
+    Urgency {
+        #[arg(value_name = "ID")]
+        id: usize,
+    },
+
     /// Show a monthly calendar with due dates for tasks and projects
     #[command(visible_alias = "cal", hide = true)]
     Calendar {
@@ -134,7 +202,11 @@ pub enum Commands {
     /// Search for tasks by text content
     #[command(visible_alias = "find", hide = true)]
     Search {
-        #[arg(value_name = "QUERY")]
+        #[arg(
+            value_name = "QUERY",
+            default_value = "",
+            required_unless_present = "filter"
+        )]
         query: String,
         #[arg(long, short = 't', value_delimiter = ',')]
         tag: Vec<String>,
@@ -142,20 +214,58 @@ pub enum Commands {
         project: Option<String>,
         #[arg(long, value_enum, default_value_t = StatusFilter::All)]
         status: StatusFilter,
+        /// Only show tasks matching this filter expression
+        #[arg(long, short = 'f', value_name = "EXPR")]
+        filter: Option<String>,
+        /// Search the archive instead of live items
+        #[arg(long, conflicts_with = "filter")]
+        archived: bool,
     },
 
-    /// Show everything linked to a task: project, dependencies, notes, resources
-    #[command(visible_alias = "ctx", hide = true)]
+    /// Show everything linked to a task, or manage the active context
+    #[command(
+        visible_alias = "ctx",
+        hide = true,
+        args_conflicts_with_subcommands = true
+    )]
     Context {
+        #[command(subcommand)]
+        action: Option<ContextCommands>,
         #[arg(value_name = "ID")]
-        id: usize,
+        id: Option<usize>,
     },
 
-    /// Show dependency graph for a task
+    /// Show dependency graph for a task, or export the whole graph
     #[command(hide = true)]
     Deps {
-        #[arg(value_name = "ID")]
-        id: usize,
+        #[arg(
+            value_name = "ID",
+            required_unless_present = "graph",
+            conflicts_with = "graph"
+        )]
+        id: Option<usize>,
+        /// Emit the whole dependency graph as DOT or Mermaid
+        #[arg(long)]
+        graph: bool,
+        /// Only tasks in this project (and tasks linked to them)
+        #[arg(long, short = 'p', requires = "graph")]
+        project: Option<String>,
+        #[arg(long, value_enum, default_value_t = GraphFormat::Dot, requires = "graph")]
+        format: GraphFormat,
+        /// Write to a file instead of stdout
+        #[arg(long, short = 'o', value_name = "FILE", requires = "graph")]
+        output: Option<std::path::PathBuf>,
+        /// Hide done and cancelled tasks, linking their neighbours directly
+        #[arg(long, requires = "graph")]
+        collapse_done: bool,
+    },
+
+    /// Compute the critical path and earliest finish from dependencies and estimates
+    #[command(hide = true)]
+    Plan {
+        /// Only this project's tasks (and their prerequisites)
+        #[arg(long, short = 'p')]
+        project: Option<String>,
     },
 
     /// List all tags with counts, or show hub view for a specific tag
@@ -174,16 +284,78 @@ pub enum Commands {
     #[command(subcommand, hide = true)]
     Note(NoteCommands),
 
+    /// Open the daily journal entry for today or DATE, creating it on first use
+    #[command(hide = true, args_conflicts_with_subcommands = true)]
+    Journal {
+        #[command(subcommand)]
+        action: Option<JournalCommands>,
+        /// Day of the entry (default: today), e.g. yesterday, 2026-03-14
+        #[arg(value_name = "DATE")]
+        date: Option<String>,
+        /// Create the entry without opening the editor
+        #[arg(long)]
+        no_edit: bool,
+    },
+
     /// Manage resources (external references: links, docs, assets)
     #[command(subcommand, hide = true)]
     Resource(ResourceCommands),
 
+    /// Manage task templates (reusable multi-task checklists)
+    #[command(subcommand, hide = true)]
+    Template(TemplateCommands),
+
+    /// Save and run named task lists
+    #[command(subcommand, hide = true)]
+    View(ViewCommands),
+
+    /// Attach a file to a task (or a note with --note)
+    #[command(hide = true)]
+    Attach {
+        #[arg(value_name = "ID")]
+        id: usize,
+        #[arg(value_name = "FILE")]
+        file: std::path::PathBuf,
+        /// ID is a note ID
+        #[arg(long)]
+        note: bool,
+    },
+
+    /// List the files attached to a task (or a note with --note)
+    #[command(hide = true)]
+    Attachments {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// ID is a note ID
+        #[arg(long)]
+        note: bool,
+    },
+
+    /// Open a file attached to a task (or a note with --note)
+    #[command(name = "open-attachment", hide = true)]
+    OpenAttachment {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// Which attachment, as numbered by 'todo attachments' (needed when there are several)
+        #[arg(value_name = "N")]
+        n: Option<usize>,
+        /// ID is a note ID
+        #[arg(long)]
+        note: bool,
+        /// Print the path of the file instead of opening it
+        #[arg(long)]
+        path: bool,
+    },
+
     // ── System ────────────────────────────────────────────────────────────────
     /// Export all data to a JSON file
     #[command(hide = true)]
     Export {
         #[arg(value_name = "FILE")]
         file: Option<std::path::PathBuf>,
+        /// Export only tasks matching this filter expression
+        #[arg(long, short = 'f', value_name = "EXPR")]
+        filter: Option<String>,
     },
 
     /// Import data from a JSON export file
@@ -218,7 +390,17 @@ pub enum Commands {
     #[command(hide = true)]
     Info,
 
-    /// Permanently remove soft-deleted tombstones
+    /// List, restore or permanently remove deleted items
+    #[command(hide = true, args_conflicts_with_subcommands = true)]
+    Trash {
+        #[command(subcommand)]
+        action: Option<TrashCommands>,
+        /// Only show deleted items of this type
+        #[arg(long = "type", value_enum, value_name = "TYPE")]
+        kind: Option<TrashType>,
+    },
+
+    /// Permanently remove soft-deleted tombstones (same as 'trash empty')
     #[command(hide = true)]
     Purge {
         #[arg(long, default_value_t = 30)]
@@ -229,6 +411,33 @@ pub enum Commands {
         yes: bool,
     },
 
+    /// Move completed tasks and projects, with their notes, into the archive
+    #[command(hide = true)]
+    Archive {
+        /// Archive items closed at least this long ago (e.g. 90d, 12w)
+        #[arg(
+            long,
+            value_name = "AGE",
+            required_unless_present = "list",
+            conflicts_with = "list"
+        )]
+        older_than: Option<String>,
+        /// List archived items
+        #[arg(long)]
+        list: bool,
+        #[arg(long)]
+        dry_run: bool,
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+
+    /// Restore an archived item (see 'todo archive --list')
+    #[command(hide = true)]
+    Unarchive {
+        #[arg(value_name = "ID")]
+        id: usize,
+    },
+
     /// Manage holiday data from holidata.net
     #[command(subcommand, hide = true)]
     Holidays(HolidaysCommands),
@@ -272,7 +481,14 @@ pub enum ProjectCommands {
     /// Add a new project.
     Add(ProjectAddArgs),
     /// List all projects.
-    List,
+    List {
+        /// Only list projects in these states (comma-separated)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        status: Vec<ProjectStatus>,
+        /// Group projects under a heading per status
+        #[arg(long)]
+        group: bool,
+    },
     /// Show full details of a project.
     Show {
         #[arg(value_name = "ID")]
@@ -280,12 +496,20 @@ pub enum ProjectCommands {
     },
     /// Edit an existing project.
     Edit(ProjectEditArgs),
-    /// Mark a project as completed.
+    /// Chart open tasks over time and forecast when the project will finish.
+    Forecast {
+        #[arg(value_name = "ID")]
+        id: usize,
+        /// Weeks of history used for the chart and throughput
+        #[arg(long, short = 'w', default_value_t = DEFAULT_WEEKS)]
+        weeks: usize,
+    },
+    /// Mark a project as completed, warning about tasks still open.
     Done {
         #[arg(value_name = "ID")]
         id: usize,
     },
-    /// Mark a completed project as pending.
+    /// Reopen a done or abandoned project as active.
     Undone {
         #[arg(value_name = "ID")]
         id: usize,
@@ -308,6 +532,7 @@ pub enum ProjectCommands {
 
 #[derive(Args)]
 pub struct ProjectAddArgs {
+    /// Project name, or a dotted path such as `acme.api` to nest it
     #[arg(value_name = "NAME")]
     pub name: String,
     #[arg(long, value_enum)]
@@ -316,6 +541,21 @@ pub struct ProjectAddArgs {
     pub tech: Vec<String>,
     #[arg(long, value_name = "DATE|EXPRESSION")]
     pub due: Option<String>,
+    /// Lifecycle state (default: active)
+    #[arg(long, value_enum)]
+    pub status: Option<ProjectStatus>,
+    #[arg(long)]
+    pub description: Option<String>,
+    /// When work started or is planned to start
+    #[arg(long, value_name = "DATE|EXPRESSION")]
+    pub start: Option<String>,
+    #[arg(long)]
+    pub owner: Option<String>,
+    #[arg(long)]
+    pub url: Option<String>,
+    /// Color name (`blue`, `bright red`) or `#rrggbb` hex code
+    #[arg(long)]
+    pub color: Option<String>,
 }
 
 // ── ProjectEditArgs ───────────────────────────────────────────────────────────
@@ -328,10 +568,13 @@ pub struct ProjectEditArgs {
     pub name: Option<String>,
     #[arg(long, value_enum)]
     pub difficulty: Option<Difficulty>,
-    #[arg(long, conflicts_with = "undone")]
+    #[arg(long, conflicts_with_all = ["undone", "status"])]
     pub done: bool,
-    #[arg(long, conflicts_with = "done")]
+    #[arg(long, conflicts_with_all = ["done", "status"])]
     pub undone: bool,
+    /// Move the project to another lifecycle state
+    #[arg(long, value_enum)]
+    pub status: Option<ProjectStatus>,
     #[arg(long, value_delimiter = ',', conflicts_with = "clear_tech")]
     pub add_tech: Vec<String>,
     #[arg(long, value_delimiter = ',', conflicts_with = "clear_tech")]
@@ -342,6 +585,161 @@ pub struct ProjectEditArgs {
     pub due: Option<String>,
     #[arg(long, conflicts_with = "due")]
     pub clear_due: bool,
+    /// Nest the project under another one, given by name or dotted path
+    #[arg(long, value_name = "PROJECT", conflicts_with = "clear_parent")]
+    pub parent: Option<String>,
+    /// Move the project back to the top level
+    #[arg(long, conflicts_with = "parent")]
+    pub clear_parent: bool,
+    #[arg(long, conflicts_with = "clear_description")]
+    pub description: Option<String>,
+    #[arg(long, conflicts_with = "description")]
+    pub clear_description: bool,
+    #[arg(long, value_name = "DATE|EXPRESSION", conflicts_with = "clear_start")]
+    pub start: Option<String>,
+    #[arg(long, conflicts_with = "start")]
+    pub clear_start: bool,
+    #[arg(long, conflicts_with = "clear_owner")]
+    pub owner: Option<String>,
+    #[arg(long, conflicts_with = "owner")]
+    pub clear_owner: bool,
+    #[arg(long, conflicts_with = "clear_url")]
+    pub url: Option<String>,
+    #[arg(long, conflicts_with = "url")]
+    pub clear_url: bool,
+    /// Color name (`blue`, `bright red`) or `#rrggbb` hex code
+    #[arg(long, conflicts_with = "clear_color")]
+    pub color: Option<String>,
+    #[arg(long, conflicts_with = "color")]
+    pub clear_color: bool,
+}
+
+// ── Template subcommands ──────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum TemplateCommands {
+    /// Create tasks from a template.
+    Apply {
+        #[arg(value_name = "NAME")]
+        name: String,
+        /// Project for all generated tasks (overrides the template's project)
+        #[arg(long, short = 'p')]
+        project: Option<String>,
+        /// Fill a {{KEY}} placeholder (repeatable)
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+    },
+    /// Save existing tasks as a template.
+    Save {
+        #[arg(value_name = "NAME")]
+        name: String,
+        #[arg(value_name = "IDS", required = true, num_args = 1..)]
+        ids: Vec<usize>,
+        #[arg(long, short = 'd')]
+        description: Option<String>,
+        /// Overwrite an existing template with the same name
+        #[arg(long, short = 'f')]
+        force: bool,
+    },
+    /// List all templates.
+    List,
+    /// Show the steps of a template.
+    Show {
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+    /// Remove a saved template.
+    Remove {
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
+
+// ── View subcommands ──────────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum ViewCommands {
+    /// Save the given list filters under a name.
+    Save(ViewSaveArgs),
+    /// List tasks using a saved view.
+    Run {
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+    /// List all saved views.
+    List,
+    /// Remove a saved view.
+    Remove {
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
+
+// ── ViewSaveArgs ──────────────────────────────────────────────────────────────
+
+#[derive(Args)]
+pub struct ViewSaveArgs {
+    #[arg(value_name = "NAME")]
+    pub name: String,
+    #[arg(long, value_enum)]
+    pub status: Option<StatusFilter>,
+    #[arg(long, value_enum)]
+    pub priority: Option<Priority>,
+    #[arg(long, value_enum)]
+    pub due: Option<DueFilter>,
+    #[arg(long, short = 't', value_delimiter = ',')]
+    pub tag: Vec<String>,
+    #[arg(long, short = 'p')]
+    pub project: Option<String>,
+    #[arg(long, short = 'r', value_enum)]
+    pub recurrence: Option<RecurrenceFilter>,
+    /// Filter expression, combined with the other flags
+    #[arg(long, short = 'f', value_name = "EXPR")]
+    pub filter: Option<String>,
+    #[arg(long, short = 's', value_enum)]
+    pub sort: Option<SortBy>,
+    /// Overwrite an existing view with the same name
+    #[arg(long)]
+    pub force: bool,
+}
+
+// ── Context subcommands ───────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum ContextCommands {
+    /// Apply a saved view to list, next, calendar, stats and the TUI.
+    Set {
+        #[arg(value_name = "VIEW")]
+        name: String,
+    },
+    /// Stop applying the context.
+    Clear,
+    /// Show the active context.
+    Show,
+}
+
+// ── Trash subcommands ─────────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum TrashCommands {
+    /// Restore a deleted item by its trash ID.
+    Restore {
+        #[arg(value_name = "ID")]
+        id: usize,
+    },
+    /// Permanently remove deleted items.
+    Empty {
+        /// Only items deleted at least this long ago (e.g. 30d, 4w)
+        #[arg(long, value_name = "AGE")]
+        older_than: Option<String>,
+        /// Only items of this type
+        #[arg(long = "type", value_enum, value_name = "TYPE")]
+        kind: Option<TrashType>,
+        #[arg(long)]
+        dry_run: bool,
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
 }
 
 // ── Note subcommands ──────────────────────────────────────────────────────────
@@ -357,13 +755,49 @@ pub enum NoteCommands {
         #[arg(value_name = "ID")]
         id: usize,
     },
-    /// Preview note body with markdown rendering (requires bat).
+    /// Render a markdown note in the terminal.
     Preview {
         #[arg(value_name = "ID")]
         id: usize,
     },
     /// Edit an existing note.
     Edit(NoteEditArgs),
+    /// List the saved revisions of a note.
+    History {
+        #[arg(value_name = "ID")]
+        id: usize,
+    },
+    /// Show what changed since a revision (the latest by default).
+    Diff {
+        #[arg(value_name = "ID")]
+        id: usize,
+        #[arg(value_name = "REV")]
+        rev: Option<u32>,
+    },
+    /// Restore the title and body of a revision.
+    Revert {
+        #[arg(value_name = "ID")]
+        id: usize,
+        #[arg(value_name = "REV")]
+        rev: u32,
+    },
+    /// Write every note as a markdown file with YAML front matter.
+    Export {
+        #[arg(long, value_name = "DIR")]
+        dir: std::path::PathBuf,
+    },
+    /// Create or update notes from the markdown files in a directory.
+    Import {
+        #[arg(long, value_name = "DIR")]
+        dir: std::path::PathBuf,
+    },
+    /// Export or import each note, whichever side changed last.
+    Sync {
+        #[arg(long, value_name = "DIR")]
+        dir: std::path::PathBuf,
+    },
+    /// List the templates for `note add --template`.
+    Templates,
     /// Remove a note (soft delete).
     Remove {
         #[arg(value_name = "ID")]
@@ -388,16 +822,21 @@ pub struct NoteAddArgs {
     pub editor: bool,
     #[arg(long, value_name = "PATH", conflicts_with_all = ["editor"])]
     pub file: Option<std::path::PathBuf>,
+    /// Start from a named template and open it in $EDITOR.
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["body", "editor", "file"])]
+    pub template: Option<String>,
     #[arg(long)]
     pub title: Option<String>,
     #[arg(long, short = 't', value_delimiter = ',')]
     pub tag: Vec<String>,
     #[arg(long, short = 'l')]
     pub language: Option<String>,
-    #[arg(long, short = 'p')]
-    pub project: Option<String>,
-    #[arg(long)]
-    pub task: Option<usize>,
+    /// Projects to link, e.g. api,docs
+    #[arg(long, short = 'p', value_delimiter = ',')]
+    pub project: Vec<String>,
+    /// Tasks to link, e.g. 3,5,9
+    #[arg(long, value_name = "IDS", value_delimiter = ',')]
+    pub task: Vec<usize>,
 }
 
 // ── NoteListArgs ──────────────────────────────────────────────────────────────
@@ -436,12 +875,28 @@ pub struct NoteEditArgs {
     pub remove_tag: Vec<String>,
     #[arg(long, conflicts_with_all = ["add_tag", "remove_tag"])]
     pub clear_tags: bool,
-    #[arg(long, short = 'p', conflicts_with = "clear_project")]
-    pub project: Option<String>,
+    /// Replace the linked projects, e.g. api,docs
+    #[arg(
+        long,
+        short = 'p',
+        value_delimiter = ',',
+        conflicts_with = "clear_project"
+    )]
+    pub project: Vec<String>,
     #[arg(long, conflicts_with = "project")]
     pub clear_project: bool,
-    #[arg(long, conflicts_with = "clear_task")]
-    pub task: Option<usize>,
+    /// Replace the linked tasks, e.g. 3,5,9
+    #[arg(
+        long,
+        value_name = "IDS",
+        value_delimiter = ',',
+        conflicts_with = "clear_task"
+    )]
+    pub task: Vec<usize>,
+    #[arg(long, value_name = "IDS", value_delimiter = ',', conflicts_with_all = ["task", "clear_task"])]
+    pub add_task: Vec<usize>,
+    #[arg(long, value_name = "IDS", value_delimiter = ',', conflicts_with_all = ["task", "clear_task"])]
+    pub remove_task: Vec<usize>,
     #[arg(long, conflicts_with = "task")]
     pub clear_task: bool,
     #[arg(long, value_name = "ID", conflicts_with = "clear_resources")]
@@ -452,6 +907,18 @@ pub struct NoteEditArgs {
     pub clear_resources: bool,
 }
 
+// ── Journal subcommands ───────────────────────────────────────────────────────
+
+#[derive(Subcommand)]
+pub enum JournalCommands {
+    /// List journal entries
+    List {
+        /// Only entries of this month (default: the current one)
+        #[arg(long, value_name = "YYYY-MM", num_args = 0..=1, default_missing_value = "")]
+        month: Option<String>,
+    },
+}
+
 // ── Resource subcommands ──────────────────────────────────────────────────────
 
 #[derive(Subcommand)]
@@ -479,6 +946,8 @@ pub enum ResourceCommands {
         #[arg(long, short = 'y')]
         yes: bool,
     },
+    /// Check resource URLs and report dead or redirected links.
+    Check(ResourceCheckArgs),
 }
 
 // ── ResourceAddArgs ───────────────────────────────────────────────────────────
@@ -507,6 +976,21 @@ pub struct ResourceListArgs {
     pub r#type: Option<ResourceType>,
 }
 
+// ── ResourceCheckArgs ─────────────────────────────────────────────────────────
+
+#[derive(Args)]
+pub struct ResourceCheckArgs {
+    /// Check only this resource
+    #[arg(value_name = "ID", conflicts_with = "all")]
+    pub id: Option<usize>,
+    /// Recheck links checked in the last week too
+    #[arg(long)]
+    pub all: bool,
+    /// Point redirected resources at their redirect target
+    #[arg(long)]
+    pub update_urls: bool,
+}
+
 // ── ResourceEditArgs ──────────────────────────────────────────────────────────
 
 #[derive(Args)]
@@ -552,6 +1036,7 @@ pub enum SyncCommands {
 
 #[derive(Args)]
 pub struct AddArgs {
+    /// Task text; may include `+project #tag !high due:fri every:week after:3`
     #[arg(value_name = "DESCRIPTION")]
     pub text: String,
     #[arg(long, value_enum, default_value_t = Priority::Medium)]
@@ -566,13 +1051,46 @@ pub struct AddArgs {
     pub recurrence: Option<Recurrence>,
     #[arg(long, value_name = "ID")]
     pub depends_on: Vec<usize>,
+    /// Estimated duration, e.g. 3 or 3d (days) or 2w (weeks)
+    #[arg(long, value_name = "DURATION")]
+    pub estimate: Option<String>,
+}
+
+// ── TaskTarget ────────────────────────────────────────────────────────────────
+
+/// Task selection shared by every mutating task command.
+///
+/// `IDS` accepts a single ID, a list with ranges (`1-5,8,12`) or `all`.
+/// `--filter` narrows the selection with `key:value` terms; given together,
+/// only tasks matching both are selected.
+#[derive(Args, Debug, Clone, Default)]
+pub struct TaskTarget {
+    #[arg(value_name = "IDS", required_unless_present = "filter")]
+    pub ids: Option<String>,
+    /// Select tasks by `project:`, `tag:`, `status:`, `priority:`, `due:` or text
+    #[arg(long, value_name = "EXPR")]
+    pub filter: Option<String>,
+    /// Skip the confirmation prompt
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+}
+
+impl TaskTarget {
+    /// Returns the ID when the selection is one plain ID with no filter.
+    pub fn single_id(&self) -> Option<usize> {
+        if self.filter.is_some() {
+            return None;
+        }
+        self.ids.as_deref()?.trim().parse().ok()
+    }
 }
 
 // ── EditArgs ──────────────────────────────────────────────────────────────────
 
-#[derive(Args)]
+#[derive(Args, Clone)]
 pub struct EditArgs {
-    #[arg(value_name = "ID")]
+    /// Filled in from [`TaskTarget`] for each selected task.
+    #[arg(skip)]
     pub id: usize,
     #[arg(long)]
     pub text: Option<String>,
@@ -598,6 +1116,11 @@ pub struct EditArgs {
     pub remove_dep: Vec<usize>,
     #[arg(long, conflicts_with_all = ["add_dep", "remove_dep"])]
     pub clear_deps: bool,
+    /// Estimated duration, e.g. 3 or 3d (days) or 2w (weeks)
+    #[arg(long, value_name = "DURATION", conflicts_with = "clear_estimate")]
+    pub estimate: Option<String>,
+    #[arg(long)]
+    pub clear_estimate: bool,
 }
 
 // ── Holidays subcommands ──────────────────────────────────────────────────────
@@ -607,3 +1130,14 @@ pub enum HolidaysCommands {
     /// Download or refresh holiday data from holidata.net for the configured locale.
     Refresh,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+}
@@ -0,0 +1,584 @@
+//! Handlers for `todo archive`, `todo unarchive` and `todo search --archived`.
+//!
+//! Archiving moves closed work out of the live tables into a separate store:
+//! - tasks that are done or cancelled, closed on or before the cutoff
+//! - done or abandoned projects closed on or before the cutoff, once every task in
+//!   them is being archived too
+//! - notes attached to any archived task or project
+//!
+//! Live tasks that depended on an archived task lose that dependency, and
+//! anything pointing at an archived project or parent loses the link.
+//! `todo unarchive` restores an item together with what it owns (a
+//! project's tasks and notes, a task's notes) and whatever it belongs to.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use chrono::{Local, NaiveDate, Utc};
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::error::TodoError;
+use crate::models::{ArchiveEntry, Archived, Note, Project, StatusFilter, Task};
+use crate::render::archive_table::display_archive;
+use crate::storage::Storage;
+use crate::utils::confirm;
+use crate::utils::date_parser::parse_days;
+
+pub fn execute(storage: &impl Storage, older_than: &str, dry_run: bool, yes: bool) -> Result<()> {
+    let days = parse_days(older_than)?;
+    let cutoff = Local::now()
+        .date_naive()
+        .checked_sub_signed(chrono::Duration::days(days as i64))
+        .ok_or(TodoError::DurationOutOfRange(days))?;
+    let (tasks, projects, notes) = storage.load_all()?;
+    let entries = select(&tasks, &projects, &notes, cutoff);
+
+    if entries.is_empty() {
+        println!(
+            "{}",
+            format!("\nNothing closed on or before {} to archive.\n", cutoff).dimmed()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{} item{} closed on or before {} would be archived:\n",
+        entries.len().to_string().yellow(),
+        if entries.len() == 1 { "" } else { "s" },
+        cutoff,
+    );
+    for kind in ["project", "task", "note"] {
+        let labels: Vec<String> = entries
+            .iter()
+            .filter(|e| e.kind() == kind)
+            .map(|e| e.label())
+            .collect();
+        if !labels.is_empty() {
+            println!("  {}:", format!("{}s", kind).dimmed());
+            for label in labels {
+                println!("    {} {}", "→".dimmed(), label);
+            }
+        }
+    }
+    println!();
+
+    if dry_run {
+        println!("{}", "Dry run — nothing was archived.".dimmed());
+        return Ok(());
+    }
+
+    if !yes && !confirm("Archive these items? [y/N]:")? {
+        println!("{}", "Archive cancelled.".dimmed());
+        return Ok(());
+    }
+
+    storage.archive(&entries)?;
+    println!(
+        "{} Archived {} item{}. Restore with 'todo unarchive <ID>'.",
+        "✓".green(),
+        entries.len().to_string().green(),
+        if entries.len() == 1 { "" } else { "s" },
+    );
+    Ok(())
+}
+
+pub fn list(storage: &impl Storage) -> Result<()> {
+    let archive = storage.load_archive()?;
+    if archive.is_empty() {
+        println!("{}", "\nThe archive is empty.\n".dimmed());
+        return Ok(());
+    }
+
+    let projects = storage.load_projects()?;
+    let entries: Vec<(usize, &ArchiveEntry)> = archive
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (i + 1, e))
+        .collect();
+    display_archive(
+        &entries,
+        &projects,
+        &format!("Archive  ({})", archive.len()),
+    );
+    Ok(())
+}
+
+pub fn unarchive(storage: &impl Storage, id: usize) -> Result<()> {
+    let archive = storage.load_archive()?;
+    if id == 0 || id > archive.len() {
+        return Err(TodoError::ArchivedItemNotFound(id).into());
+    }
+    let restoring = restore_set(&archive, id - 1);
+    let restored_uuids: HashSet<Uuid> = restoring.iter().map(|&i| archive[i].uuid()).collect();
+
+    let (mut tasks, mut projects, mut notes, resources) = storage.load_all_with_resources()?;
+    let mut known: HashSet<Uuid> = restored_uuids.clone();
+    known.extend(tasks.iter().map(|t| t.uuid));
+    known.extend(projects.iter().map(|p| p.uuid));
+    known.extend(notes.iter().map(|n| n.uuid));
+    let keep = |uuid: &Option<Uuid>| uuid.filter(|u| known.contains(u));
+
+    // Links to things that were archived separately (or purged since) are
+    // dropped rather than left dangling.
+    let mut restored_tasks = Vec::new();
+    for &i in &restoring {
+        match archive[i].item.clone() {
+            Archived::Project(mut p) => {
+                p.parent_id = keep(&p.parent_id);
+                projects.push(p);
+            }
+            Archived::Task(mut t) => {
+                t.project_id = keep(&t.project_id);
+                t.parent_id = keep(&t.parent_id);
+                t.depends_on.retain(|d| known.contains(d));
+                restored_tasks.push(t);
+            }
+            Archived::Note(mut n) => {
+                n.project_ids.retain(|p| known.contains(p));
+                n.task_ids.retain(|t| known.contains(t));
+                n.resource_ids
+                    .retain(|r| resources.iter().any(|res| res.uuid == *r));
+                notes.push(n);
+            }
+        }
+    }
+
+    // Restored tasks may reference each other, so they are saved once without
+    // links to satisfy foreign keys, then again in full.
+    storage.save_projects(&projects)?;
+    let first_pass: Vec<Task> = tasks
+        .iter()
+        .cloned()
+        .chain(restored_tasks.iter().cloned().map(|mut t| {
+            t.parent_id = None;
+            t.depends_on.clear();
+            t
+        }))
+        .collect();
+    storage.save(&first_pass)?;
+    tasks.extend(restored_tasks);
+    storage.save(&tasks)?;
+    storage.save_notes(&notes)?;
+    for revision in restoring.iter().flat_map(|&i| &archive[i].revisions) {
+        storage.save_note_revision(revision)?;
+    }
+    storage.delete_archived(&restored_uuids.into_iter().collect::<Vec<_>>())?;
+
+    let visible: Vec<&Task> = tasks.iter().filter(|t| !t.is_deleted()).collect();
+    println!(
+        "{} Restored {} item{} from the archive:",
+        "✓".green(),
+        restoring.len().to_string().green(),
+        if restoring.len() == 1 { "" } else { "s" },
+    );
+    for &i in &restoring {
+        let entry = &archive[i];
+        let id = match &entry.item {
+            Archived::Task(t) => visible
+                .iter()
+                .position(|v| v.uuid == t.uuid)
+                .map(|p| format!(" (#{})", p + 1))
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+        println!(
+            "    {} {} {}{}",
+            "←".dimmed(),
+            entry.kind().dimmed(),
+            entry.label(),
+            id.dimmed()
+        );
+    }
+    Ok(())
+}
+
+/// `todo search --archived`: the same text and filters as `todo search`,
+/// applied to the archive.
+pub fn search(
+    storage: &impl Storage,
+    query: &str,
+    tags: &[String],
+    project: Option<&str>,
+    status: StatusFilter,
+) -> Result<()> {
+    let archive = storage.load_archive()?;
+    let projects = storage.load_projects()?;
+
+    let proj_uuid = match project {
+        Some(name) => {
+            let live = Project::find(&projects, name).map(|p| p.uuid);
+            let archived = archive.iter().find_map(|e| match &e.item {
+                Archived::Project(p) if p.name.eq_ignore_ascii_case(name) => Some(p.uuid),
+                _ => None,
+            });
+            Some(
+                live.or(archived)
+                    .ok_or_else(|| TodoError::ProjectNotFound(name.to_string()))?,
+            )
+        }
+        None => None,
+    };
+
+    let results: Vec<(usize, &ArchiveEntry)> = archive
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| e.matches_text(query))
+        .filter(|(_, e)| {
+            let entry_tags = e.tags();
+            tags.is_empty()
+                || (!matches!(e.item, Archived::Project(_))
+                    && tags
+                        .iter()
+                        .all(|t| entry_tags.iter().any(|et| et.eq_ignore_ascii_case(t))))
+        })
+        .filter(|(_, e)| proj_uuid.is_none_or(|p| e.project_ids().contains(&p)))
+        .filter(|(_, e)| match &e.item {
+            Archived::Task(t) => t.matches_status(status),
+            _ => status == StatusFilter::All,
+        })
+        .map(|(i, e)| (i + 1, e))
+        .collect();
+
+    if results.is_empty() {
+        return Err(TodoError::NoSearchResults(query.to_string()).into());
+    }
+
+    display_archive(
+        &results,
+        &projects,
+        &format!(
+            "Archived results for \"{}\"  ({} found)",
+            query,
+            results.len()
+        ),
+    );
+    Ok(())
+}
+
+/// Picks what `todo archive` moves for a given cutoff date: projects first,
+/// then tasks, then notes, all stamped with the same time.
+pub(crate) fn select(
+    tasks: &[Task],
+    projects: &[Project],
+    notes: &[Note],
+    cutoff: NaiveDate,
+) -> Vec<ArchiveEntry> {
+    let closed_by = |completed: Option<NaiveDate>, updated: Option<chrono::DateTime<Utc>>| {
+        completed
+            .or(updated.map(|u| u.with_timezone(&Local).date_naive()))
+            .is_some_and(|d| d <= cutoff)
+    };
+
+    let task_set: HashSet<Uuid> = tasks
+        .iter()
+        .filter(|t| !t.is_deleted() && !t.is_open() && closed_by(t.completed_at, t.updated_at))
+        .map(|t| t.uuid)
+        .collect();
+
+    // A note goes with its tasks once all of them go; a note without tasks
+    // goes once all of its projects do.
+    let note_goes = |n: &Note, project_set: &HashSet<Uuid>| {
+        !n.is_deleted()
+            && if n.task_ids.is_empty() {
+                !n.project_ids.is_empty() && n.project_ids.iter().all(|p| project_set.contains(p))
+            } else {
+                n.task_ids.iter().all(|t| task_set.contains(t))
+            }
+    };
+
+    // A project only goes once nothing live would be left pointing at it.
+    let mut project_set: HashSet<Uuid> = projects
+        .iter()
+        .filter(|p| !p.is_deleted() && !p.is_open() && closed_by(p.completed_at, p.updated_at))
+        .filter(|p| {
+            tasks
+                .iter()
+                .filter(|t| !t.is_deleted() && t.project_id == Some(p.uuid))
+                .all(|t| task_set.contains(&t.uuid))
+        })
+        .map(|p| p.uuid)
+        .collect();
+    // ...and that includes sub-projects and notes, which may in turn be kept
+    // back.
+    loop {
+        let keep_live: Vec<Uuid> = project_set
+            .iter()
+            .copied()
+            .filter(|&uuid| {
+                projects.iter().any(|c| {
+                    !c.is_deleted() && c.parent_id == Some(uuid) && !project_set.contains(&c.uuid)
+                }) || notes.iter().any(|n| {
+                    !n.is_deleted() && n.belongs_to_project(uuid) && !note_goes(n, &project_set)
+                })
+            })
+            .collect();
+        if keep_live.is_empty() {
+            break;
+        }
+        for uuid in keep_live {
+            project_set.remove(&uuid);
+        }
+    }
+
+    let archived_at = Utc::now();
+    let entry = |item| ArchiveEntry {
+        item,
+        archived_at,
+        revisions: Vec::new(),
+    };
+    let mut entries: Vec<ArchiveEntry> = projects
+        .iter()
+        .filter(|p| project_set.contains(&p.uuid))
+        .map(|p| entry(Archived::Project(p.clone())))
+        .collect();
+    entries.extend(
+        tasks
+            .iter()
+            .filter(|t| task_set.contains(&t.uuid))
+            .map(|t| entry(Archived::Task(t.clone()))),
+    );
+    entries.extend(
+        notes
+            .iter()
+            .filter(|n| note_goes(n, &project_set))
+            .map(|n| entry(Archived::Note(n.clone()))),
+    );
+    entries
+}
+
+/// Indices of the archive entries restored by `todo unarchive` for the entry
+/// at `target`: the entry, what it owns, and the archived project or task
+/// each of those belongs to.
+pub(crate) fn restore_set(archive: &[ArchiveEntry], target: usize) -> Vec<usize> {
+    let mut set: HashSet<usize> = HashSet::from([target]);
+
+    // Downward: a project brings its sub-projects, and each of those its
+    // tasks and notes; a task brings its notes.
+    let target_uuid = archive[target].uuid();
+    let mut owners: HashSet<Uuid> = HashSet::from([target_uuid]);
+    if let Archived::Project(_) = archive[target].item {
+        loop {
+            let before = owners.len();
+            for entry in archive {
+                if let Archived::Project(p) = &entry.item
+                    && p.parent_id.is_some_and(|u| owners.contains(&u))
+                {
+                    owners.insert(p.uuid);
+                }
+            }
+            if owners.len() == before {
+                break;
+            }
+        }
+    }
+    for (i, entry) in archive.iter().enumerate() {
+        let owned = match (&archive[target].item, &entry.item) {
+            (Archived::Project(_), Archived::Project(p)) => owners.contains(&p.uuid),
+            (Archived::Project(_), Archived::Task(t)) => {
+                t.project_id.is_some_and(|u| owners.contains(&u))
+            }
+            (Archived::Project(_), Archived::Note(n)) => {
+                n.project_ids.iter().any(|u| owners.contains(u))
+            }
+            (Archived::Task(_), Archived::Note(n)) => n.belongs_to_task(target_uuid),
+            _ => false,
+        };
+        if owned {
+            set.insert(i);
+        }
+    }
+
+    // Upward: whatever a restored item belongs to comes back with it.
+    loop {
+        let parents: Vec<Uuid> = set
+            .iter()
+            .flat_map(|&i| match &archive[i].item {
+                Archived::Task(t) => t.project_id.into_iter().collect(),
+                Archived::Note(n) => [n.task_ids.as_slice(), n.project_ids.as_slice()].concat(),
+                Archived::Project(p) => p.parent_id.into_iter().collect(),
+            })
+            .collect();
+        let before = set.len();
+        for (i, entry) in archive.iter().enumerate() {
+            if parents.contains(&entry.uuid()) {
+                set.insert(i);
+            }
+        }
+        if set.len() == before {
+            break;
+        }
+    }
+
+    let mut indices: Vec<usize> = set.into_iter().collect();
+    indices.sort_unstable();
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{NoteRevision, Priority};
+    use crate::storage::InMemoryStorage;
+
+    fn done_task(text: &str, project: Option<Uuid>) -> Task {
+        let mut t = Task::new(text.into(), Priority::Medium, vec![], project, None, None);
+        t.mark_done();
+        t.completed_at = NaiveDate::from_ymd_opt(2020, 1, 1);
+        t
+    }
+
+    fn old_project(name: &str) -> Project {
+        let mut p = Project::new(name.into());
+        p.mark_done();
+        p.completed_at = NaiveDate::from_ymd_opt(2020, 1, 1);
+        p
+    }
+
+    fn today() -> NaiveDate {
+        Local::now().date_naive()
+    }
+
+    #[test]
+    fn test_select_skips_open_and_recent_tasks() {
+        let open = Task::new("Open".into(), Priority::Medium, vec![], None, None, None);
+        let mut recent = done_task("Recent", None);
+        recent.completed_at = Some(today());
+        let old = done_task("Old", None);
+        let cutoff = today() - chrono::Duration::days(90);
+
+        let entries = select(&[open, recent, old.clone()], &[], &[], cutoff);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].uuid(), old.uuid);
+    }
+
+    #[test]
+    fn test_select_keeps_project_with_open_tasks() {
+        let project = old_project("Website");
+        let done = done_task("Ship", Some(project.uuid));
+        let open = Task::new(
+            "Follow-up".into(),
+            Priority::Medium,
+            vec![],
+            Some(project.uuid),
+            None,
+            None,
+        );
+        let entries = select(
+            &[done.clone(), open],
+            std::slice::from_ref(&project),
+            &[],
+            today(),
+        );
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].uuid(), done.uuid);
+
+        let entries = select(&[done], &[project], &[], today());
+        assert_eq!(
+            entries.iter().map(|e| e.kind()).collect::<Vec<_>>(),
+            vec!["project", "task"]
+        );
+    }
+
+    #[test]
+    fn test_restore_set_follows_ownership_both_ways() {
+        let project = old_project("Website");
+        let task = done_task("Ship", Some(project.uuid));
+        let mut note = Note::new("Release notes".into());
+        note.task_ids = vec![task.uuid];
+        let entries = select(&[task], &[project], &[note], today());
+        assert_eq!(entries.len(), 3);
+
+        // Restoring the note brings back its task and the task's project.
+        assert_eq!(restore_set(&entries, 2), vec![0, 1, 2]);
+        // Restoring the project brings back everything in it.
+        assert_eq!(restore_set(&entries, 0), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_select_keeps_note_linked_to_an_open_task() {
+        let done = done_task("Ship", None);
+        let open = Task::new(
+            "Follow-up".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            None,
+            None,
+        );
+        let mut note = Note::new("Retro".into());
+        note.task_ids = vec![done.uuid, open.uuid];
+
+        let entries = select(&[done.clone(), open.clone()], &[], &[note.clone()], today());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].uuid(), done.uuid);
+
+        let mut closed = open;
+        closed.mark_done();
+        closed.completed_at = done.completed_at;
+        let entries = select(&[done, closed], &[], &[note], today());
+        assert_eq!(entries.len(), 3, "the note goes once all its tasks do");
+    }
+
+    #[test]
+    fn test_unarchive_restores_dependencies_between_restored_tasks() {
+        let storage = InMemoryStorage::default();
+        let first = done_task("First", None);
+        let mut second = done_task("Second", None);
+        second.depends_on = vec![first.uuid];
+        storage.save(&[first.clone(), second]).unwrap();
+        execute(&storage, "90d", false, true).unwrap();
+        assert!(storage.load().unwrap().is_empty());
+        assert_eq!(storage.load_archive().unwrap().len(), 2);
+
+        unarchive(&storage, 2).unwrap();
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks.len(), 1);
+        // First is still archived, so the dependency is dropped.
+        assert!(tasks[0].depends_on.is_empty());
+
+        unarchive(&storage, 1).unwrap();
+        assert_eq!(storage.load().unwrap().len(), 2);
+        assert!(storage.load_archive().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unarchive_restores_note_revisions() {
+        let storage = InMemoryStorage::default();
+        let note = Note::new("Retro".into());
+        storage.save_notes(std::slice::from_ref(&note)).unwrap();
+        storage
+            .save_note_revision(&NoteRevision::of(&note, 1))
+            .unwrap();
+        storage
+            .archive(&[ArchiveEntry::new(Archived::Note(note.clone()))])
+            .unwrap();
+        assert!(storage.load_note_revisions(note.uuid).unwrap().is_empty());
+        assert_eq!(storage.load_archive().unwrap()[0].revisions.len(), 1);
+
+        unarchive(&storage, 1).unwrap();
+        let revisions = storage.load_note_revisions(note.uuid).unwrap();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].body, "Retro");
+    }
+
+    #[test]
+    fn test_unarchive_unknown_id_fails() {
+        let storage = InMemoryStorage::default();
+        let err = unarchive(&storage, 1).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<TodoError>(),
+            Some(TodoError::ArchivedItemNotFound(1))
+        ));
+    }
+
+    #[test]
+    fn test_out_of_range_age_fails() {
+        let storage = InMemoryStorage::default();
+        let err = execute(&storage, "99999999d", true, true).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<TodoError>(),
+            Some(TodoError::DurationOutOfRange(99999999))
+        ));
+    }
+}
@@ -0,0 +1,307 @@
+//! Handlers for `todo attach`, `todo attachments` and `todo open-attachment`.
+//!
+//! Attached files are copied into the blob store next to the database (see
+//! [`crate::storage::attachments`]), so the original can be moved or
+//! deleted afterwards. IDs are task IDs, or note IDs with `--note`.
+//! Attachments are numbered per item, oldest first, as listed by
+//! `todo attachments`.
+
+use anyhow::{Context, Result, anyhow, bail};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::models::{Attachment, AttachmentOwner};
+use crate::render::formatting::format_size;
+use crate::storage::{Storage, attachments};
+use crate::utils::validation::resolve_visible;
+
+// ── attach ────────────────────────────────────────────────────────────────────
+
+/// `todo attach <ID> <FILE> [--note]` — copies a file into the store.
+pub fn execute_attach(storage: &impl Storage, id: usize, file: PathBuf, note: bool) -> Result<()> {
+    if !file.is_file() {
+        bail!("File not found: {}", file.display());
+    }
+    let owner = resolve_owner(storage, id, note)?;
+    let name = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or_else(|| anyhow!("Not a file: {}", file.display()))?;
+
+    let (hash, size) = attachments::store(&storage.attachments_dir()?, &file)
+        .context("Failed to store attachment")?;
+
+    let existing = storage.load_attachments()?;
+    if existing
+        .iter()
+        .any(|a| a.owner == owner && a.hash == hash && a.name == name)
+    {
+        println!(
+            "{} {} is already attached to {}.",
+            "".blue(),
+            name.bright_white(),
+            label(owner, id)
+        );
+        return Ok(());
+    }
+
+    storage.save_attachment(&Attachment::new(owner, name.clone(), hash, size))?;
+    println!(
+        "{} Attached {} ({}) to {}",
+        "✓".green(),
+        name.bright_white(),
+        format_size(size).dimmed(),
+        label(owner, id)
+    );
+    Ok(())
+}
+
+// ── list ──────────────────────────────────────────────────────────────────────
+
+/// `todo attachments <ID> [--note]` — lists the files attached to an item.
+pub fn execute_list(storage: &impl Storage, id: usize, note: bool) -> Result<()> {
+    let owner = resolve_owner(storage, id, note)?;
+    let files = attachments_of(storage, owner)?;
+
+    if files.is_empty() {
+        println!(
+            "{}",
+            format!("\nNo attachments on {}.\n", label(owner, id)).dimmed()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\n{}\n",
+        format!("Attachments of {}:", label(owner, id))
+            .bright_white()
+            .bold()
+    );
+    for (i, file) in files.iter().enumerate() {
+        println!(
+            "  {}  {}  {}  {}",
+            format!("{:>2}.", i + 1).dimmed(),
+            file.name.bright_white(),
+            format_size(file.size).dimmed(),
+            file.created_at
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d")
+                .to_string()
+                .dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+// ── open ──────────────────────────────────────────────────────────────────────
+
+/// `todo open-attachment <ID> [N] [--note] [--path]` — opens an attached
+/// file with the system's default application.
+///
+/// The file is opened from a copy in the temp directory, under its original
+/// name: the application then sees the right extension, and editing the
+/// copy cannot corrupt the stored contents. With `--path` the copy's path
+/// is printed instead.
+pub fn execute_open(
+    storage: &impl Storage,
+    id: usize,
+    n: Option<usize>,
+    note: bool,
+    print_path: bool,
+) -> Result<()> {
+    let owner = resolve_owner(storage, id, note)?;
+    let files = attachments_of(storage, owner)?;
+
+    let file = match (n, files.len()) {
+        (_, 0) => bail!("No attachments on {}", label(owner, id)),
+        (None, 1) => &files[0],
+        (None, count) => bail!(
+            "{} has {} attachments — pick one: todo open-attachment {} <N>{}",
+            label(owner, id),
+            count,
+            id,
+            if note { " --note" } else { "" }
+        ),
+        (Some(n), count) if n == 0 || n > count => {
+            bail!("Attachment #{} not found on {}", n, label(owner, id))
+        }
+        (Some(n), _) => &files[n - 1],
+    };
+
+    let blob = attachments::blob_path(&storage.attachments_dir()?, &file.hash)?;
+    if !blob.is_file() {
+        bail!(
+            "The contents of {} are missing from {}",
+            file.name,
+            blob.display()
+        );
+    }
+    let copy = std::env::temp_dir()
+        .join("rustodo-attachments")
+        .join(file.uuid.to_string())
+        .join(file.file_name());
+    if let Some(parent) = copy.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(&blob, &copy).context("Failed to copy attachment")?;
+
+    if print_path {
+        println!("{}", copy.display());
+        return Ok(());
+    }
+    open_with_default_app(&copy)?;
+    println!("{} Opened {}", "✓".green(), file.name.bright_white());
+    Ok(())
+}
+
+// ── helpers ───────────────────────────────────────────────────────────────────
+
+fn resolve_owner(storage: &impl Storage, id: usize, note: bool) -> Result<AttachmentOwner> {
+    if note {
+        let notes = storage.load_notes()?;
+        let note = resolve_visible(&notes, id, |n| n.is_deleted())
+            .map_err(|_| anyhow!("Note #{} not found", id))?;
+        Ok(AttachmentOwner::Note(note.uuid))
+    } else {
+        let tasks = storage.load()?;
+        let task = resolve_visible(&tasks, id, |t| t.is_deleted())
+            .map_err(|_| anyhow!("Task #{} not found", id))?;
+        Ok(AttachmentOwner::Task(task.uuid))
+    }
+}
+
+/// The attachments of one item, oldest first.
+pub(crate) fn attachments_of(
+    storage: &impl Storage,
+    owner: AttachmentOwner,
+) -> Result<Vec<Attachment>> {
+    let mut files = storage.load_attachments()?;
+    files.retain(|a| a.owner == owner);
+    files.sort_by_key(|a| a.created_at);
+    Ok(files)
+}
+
+fn label(owner: AttachmentOwner, id: usize) -> String {
+    format!("{} #{}", owner.kind(), id)
+}
+
+fn open_with_default_app(path: &Path) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    let status = command
+        .arg(path)
+        .status()
+        .with_context(|| format!("Could not open {}", path.display()))?;
+    if !status.success() {
+        bail!("Could not open {} ({})", path.display(), status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Note, Priority, Task};
+    use crate::storage::InMemoryStorage;
+    use tempfile::TempDir;
+
+    fn setup() -> (InMemoryStorage, TempDir) {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[Task::new(
+                "Draw diagram".into(),
+                Priority::Medium,
+                vec![],
+                None,
+                None,
+                None,
+            )])
+            .unwrap();
+        storage.save_notes(&[Note::new("Design".into())]).unwrap();
+        (storage, TempDir::new().unwrap())
+    }
+
+    fn write(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = dir.path().join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_attach_records_and_stores_file() {
+        let (storage, tmp) = setup();
+        let file = write(&tmp, "diagram.png", "png bytes");
+
+        execute_attach(&storage, 1, file.clone(), false).unwrap();
+        execute_attach(&storage, 1, file, false).unwrap();
+
+        let files = storage.load_attachments().unwrap();
+        assert_eq!(files.len(), 1, "attaching the same file twice is a no-op");
+        assert_eq!(files[0].name, "diagram.png");
+        assert_eq!(files[0].size, 9);
+        let blob =
+            attachments::blob_path(&storage.attachments_dir().unwrap(), &files[0].hash).unwrap();
+        assert_eq!(std::fs::read_to_string(blob).unwrap(), "png bytes");
+    }
+
+    #[test]
+    fn test_attach_to_note_and_missing_targets() {
+        let (storage, tmp) = setup();
+        let file = write(&tmp, "spec.pdf", "pdf");
+
+        execute_attach(&storage, 1, file.clone(), true).unwrap();
+        let files = storage.load_attachments().unwrap();
+        assert!(matches!(files[0].owner, AttachmentOwner::Note(_)));
+
+        assert!(execute_attach(&storage, 9, file, false).is_err());
+        assert!(execute_attach(&storage, 1, tmp.path().join("nope"), false).is_err());
+    }
+
+    #[test]
+    fn test_open_requires_a_choice_between_several() {
+        let (storage, tmp) = setup();
+        execute_attach(&storage, 1, write(&tmp, "a.txt", "a"), false).unwrap();
+        assert!(execute_open(&storage, 1, None, false, true).is_ok());
+
+        execute_attach(&storage, 1, write(&tmp, "b.txt", "b"), false).unwrap();
+        assert!(execute_open(&storage, 1, None, false, true).is_err());
+        assert!(execute_open(&storage, 1, Some(2), false, true).is_ok());
+        assert!(execute_open(&storage, 1, Some(3), false, true).is_err());
+    }
+
+    #[test]
+    fn test_open_keeps_copy_inside_temp_dir() {
+        let (storage, tmp) = setup();
+        execute_attach(&storage, 1, write(&tmp, "a.txt", "a"), false).unwrap();
+        let mut file = storage.load_attachments().unwrap().remove(0);
+        file.name = "../../../escaped.txt".into();
+        assert_eq!(file.file_name(), "escaped.txt");
+        storage.save_attachment(&file).unwrap();
+
+        execute_open(&storage, 1, None, false, true).unwrap();
+        let copy = std::env::temp_dir()
+            .join("rustodo-attachments")
+            .join(file.uuid.to_string())
+            .join("escaped.txt");
+        assert_eq!(std::fs::read_to_string(copy).unwrap(), "a");
+    }
+
+    #[test]
+    fn test_deleting_owner_drops_attachments() {
+        let (storage, tmp) = setup();
+        execute_attach(&storage, 1, write(&tmp, "a.txt", "a"), false).unwrap();
+        let task = storage.load().unwrap()[0].uuid;
+
+        storage.delete_tasks(&[task]).unwrap();
+        assert!(storage.load_attachments().unwrap().is_empty());
+    }
+}
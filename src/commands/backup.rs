@@ -4,7 +4,8 @@ use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use std::path::PathBuf;
 
-use crate::storage::{backup, get_db_path};
+use crate::render::formatting::format_size;
+use crate::storage::{attachments, backup, get_db_path};
 
 // ── backup ────────────────────────────────────────────────────────────────────
 
@@ -74,6 +75,14 @@ pub fn execute_restore(file: Option<PathBuf>, yes: bool) -> Result<()> {
 
     std::fs::copy(&backup_path, &db_path).context("Failed to restore backup")?;
 
+    // Blobs are only ever added, so restoring them never loses anything the
+    // current database still needs.
+    let blobs = backup::attachments_of(&backup_path);
+    if blobs.is_dir() {
+        attachments::copy_all(&blobs, &attachments::dir_for(&db_path), None, false)
+            .context("Failed to restore attachments")?;
+    }
+
     println!(
         "{} Restored from: {}",
         "✓".green(),
@@ -189,13 +198,3 @@ fn prompt_number(max: usize) -> Result<usize> {
         }
     }
 }
-
-fn format_size(bytes: u64) -> String {
-    if bytes < 1024 {
-        format!("{} B", bytes)
-    } else if bytes < 1024 * 1024 {
-        format!("{:.1} KB", bytes as f64 / 1024.0)
-    } else {
-        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
-    }
-}
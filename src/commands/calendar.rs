@@ -5,9 +5,11 @@ use std::collections::HashMap;
 use anyhow::Result;
 use chrono::{Datelike, Local, NaiveDate};
 
+use crate::commands::view::active_context;
 use crate::config::Config;
 use crate::render::calendar::{DayInfo, display_calendar};
 use crate::services::holidays::HolidayCache;
+use crate::services::journal;
 use crate::storage::Storage;
 
 pub fn execute(storage: &impl Storage, month: Option<u32>, year: Option<i32>) -> Result<()> {
@@ -30,7 +32,16 @@ pub fn execute(storage: &impl Storage, month: Option<u32>, year: Option<i32>) ->
 
     let all_tasks = storage.load()?;
     let all_projects = storage.load_projects()?;
-    let tasks: Vec<_> = all_tasks.iter().filter(|t| !t.is_deleted()).collect();
+    let context = active_context(storage)?;
+    let tasks: Vec<_> = all_tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .filter(|t| {
+            context
+                .as_ref()
+                .is_none_or(|ctx| ctx.matches(t, &all_tasks, &all_projects))
+        })
+        .collect();
     let projects: Vec<_> = all_projects.iter().filter(|p| !p.is_deleted()).collect();
 
     // ── Density map ───────────────────────────────────────────────────────────
@@ -40,7 +51,7 @@ pub fn execute(storage: &impl Storage, month: Option<u32>, year: Option<i32>) ->
         if let Some(due) = task.due_date {
             let e = density.entry(due).or_default();
             e.count += 1;
-            if due < today && !task.completed {
+            if due < today && task.is_open() {
                 e.overdue = true;
             }
         }
@@ -49,12 +60,19 @@ pub fn execute(storage: &impl Storage, month: Option<u32>, year: Option<i32>) ->
         if let Some(due) = project.due_date {
             let e = density.entry(due).or_default();
             e.count += 1;
-            if due < today && !project.completed {
+            if due < today && project.is_open() {
                 e.overdue = true;
             }
         }
     }
 
+    // ── Journal entries ───────────────────────────────────────────────────────
+    for note in storage.load_notes()?.iter().filter(|n| !n.is_deleted()) {
+        if let Some(date) = journal::entry_date(note) {
+            density.entry(date).or_default().journal = true;
+        }
+    }
+
     // ── Holiday map ───────────────────────────────────────────────────────────
     // Mark all days in the three visible months that are holidays
     for offset_month in [-1i32, 0, 1] {
@@ -70,6 +88,9 @@ pub fn execute(storage: &impl Storage, month: Option<u32>, year: Option<i32>) ->
     }
 
     display_calendar(today, target_month, target_year, &density);
+    if let Some(ref ctx) = context {
+        ctx.print_notice();
+    }
 
     Ok(())
 }
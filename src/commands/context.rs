@@ -1,12 +1,17 @@
 //! Handler for `todo context <ID>`.
 //!
-//! Shows everything linked to a task: project, dependencies, notes, and
-//! resources (via notes). A "knowledge hub" centred on a single task.
+//! Shows everything linked to a task: project, dependencies, notes, notes
+//! mentioning it with `[[task:N]]`, attached files, and resources (via
+//! notes). A "knowledge hub" centred on a single task.
 
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::render::formatting::{note_preview, truncate};
+use crate::commands::attachment;
+use crate::config::Config;
+use crate::models::{AttachmentOwner, LinkTarget};
+use crate::render::formatting::{format_size, note_preview, status_colored, truncate};
+use crate::services::links;
 use crate::storage::Storage;
 use crate::utils::validation::{resolve_visible_index, visible_indices};
 
@@ -20,7 +25,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     let vis = visible_indices(&tasks, |t| t.is_deleted());
 
     let all_visible: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
-    let is_blocked = !task.completed && task.is_blocked(&all_visible);
+    let is_blocked = task.is_open() && task.is_blocked(&all_visible);
 
     // ── Header ────────────────────────────────────────────────────────────────
     println!();
@@ -31,16 +36,11 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     println!("  {}", "─".repeat(50).dimmed());
 
     // ── Details ───────────────────────────────────────────────────────────────
-    let status = if task.completed {
-        "done".green().to_string()
-    } else if is_blocked {
-        "blocked".red().to_string()
-    } else {
-        "pending".yellow().to_string()
-    };
+    let status = status_colored(task, is_blocked, false);
     println!("  {}  {}", "Status".dimmed(), status);
     println!("  {}  {}", "Priority".dimmed(), task.priority.letter());
-    let urgency = task.urgency_score(&all_visible);
+    let urgency_cfg = Config::load().unwrap_or_default().urgency;
+    let urgency = task.urgency_score_with(&all_visible, &projects, &urgency_cfg);
     let urgency_colored = {
         let s = format!("{:.1}", urgency);
         if urgency >= 10.0 {
@@ -65,6 +65,10 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         println!("  {}  {}", "Due".dimmed(), due);
     }
 
+    if let Some(days) = task.estimate {
+        println!("  {}  {}d", "Estimate".dimmed(), days);
+    }
+
     if !task.tags.is_empty() {
         println!(
             "  {}  {}",
@@ -110,7 +114,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     // ── Notes linked to this task ─────────────────────────────────────────────
     let task_notes: Vec<_> = notes
         .iter()
-        .filter(|n| !n.is_deleted() && n.task_id == Some(task.uuid))
+        .filter(|n| !n.is_deleted() && n.belongs_to_task(task.uuid))
         .collect();
 
     let all_visible_notes: Vec<_> = notes.iter().filter(|n| !n.is_deleted()).collect();
@@ -134,6 +138,37 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         }
     }
 
+    // ── Notes mentioning this task with [[task:N]] ────────────────────────────
+    let mentions = links::backlinks(&notes, LinkTarget::Task(task.uuid));
+
+    if !mentions.is_empty() {
+        println!();
+        println!("  {}", "Mentioned in".dimmed());
+        for (note_vis_id, note) in &mentions {
+            println!(
+                "    {}  {}",
+                format!("#{}", note_vis_id).dimmed(),
+                truncate(&note_preview(note), 50).bright_white()
+            );
+        }
+    }
+
+    // ── Attachments ───────────────────────────────────────────────────────────
+    let files = attachment::attachments_of(storage, AttachmentOwner::Task(task.uuid))?;
+
+    if !files.is_empty() {
+        println!();
+        println!("  {}", "Attachments".dimmed());
+        for (i, file) in files.iter().enumerate() {
+            println!(
+                "    {}  {}  {}",
+                format!("{}.", i + 1).dimmed(),
+                file.name.bright_white(),
+                format_size(file.size).dimmed()
+            );
+        }
+    }
+
     // ── Resources (via notes linked to this task) ─────────────────────────────
     let all_visible_resources: Vec<_> = resources.iter().filter(|r| !r.is_deleted()).collect();
 
@@ -174,7 +209,11 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         }
     }
 
-    if task_notes.is_empty() && linked_resources.is_empty() && task.depends_on.is_empty() {
+    if task_notes.is_empty()
+        && mentions.is_empty()
+        && linked_resources.is_empty()
+        && task.depends_on.is_empty()
+    {
         println!();
         println!(
             "  {}",
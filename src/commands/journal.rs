@@ -0,0 +1,243 @@
+//! Handlers for `todo journal [DATE]` and `todo journal list`.
+//!
+//! Each day has at most one entry: a markdown note tagged `journal` and
+//! titled with the date (see [`crate::services::journal`]). `todo journal`
+//! opens the day's entry in `$EDITOR`, creating it from the
+//! `[notes] journal_template` on first use. Entries are ordinary notes
+//! otherwise — `todo note show`, `todo note history` and friends work on
+//! them too.
+
+use anyhow::{Result, anyhow, bail};
+use chrono::{Datelike, Local, NaiveDate};
+use colored::Colorize;
+
+use crate::commands::note::history;
+use crate::config::Config;
+use crate::models::{Note, Task};
+use crate::render::formatting::truncate;
+use crate::services::{journal, links};
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::date_parser::parse_date;
+
+// ── open ──────────────────────────────────────────────────────────────────────
+
+/// `todo journal [DATE] [--no-edit]` — opens the entry for a day (today
+/// by default), creating it first if needed.
+///
+/// With `--no-edit` the entry is only created, or its note ID printed.
+pub fn execute_open(storage: &impl Storage, date: Option<String>, no_edit: bool) -> Result<()> {
+    let date = match date {
+        Some(ref input) => parse_date(input)?,
+        None => Local::now().date_naive(),
+    };
+    let (tasks, _projects, mut notes, resources) = storage.load_all_with_resources()?;
+
+    let existing = journal::find(&notes, date);
+    if let Some(i) = existing
+        && no_edit
+    {
+        println!(
+            "{} Journal entry for {} is note #{}",
+            "".blue(),
+            journal::title(date).bright_white(),
+            visible_id(&notes, i)
+        );
+        return Ok(());
+    }
+    let (mut note, created) = match existing {
+        Some(i) => (notes[i].clone(), false),
+        None => (new_entry(storage, date, &tasks)?, true),
+    };
+
+    if !no_edit {
+        let body = edit::edit_with_builder(&note.body, edit::Builder::new().suffix(".md"))?;
+        let body = body.trim_end().to_string();
+        if body.trim().is_empty() {
+            bail!("Aborted: journal entry is empty.");
+        }
+        if body != note.body {
+            if let Some(i) = existing {
+                history::record_revision(storage, &notes[i])?;
+                note.touch();
+            }
+            note.body = body;
+        } else if !created {
+            println!("{}", "No changes.".dimmed());
+            return Ok(());
+        }
+    }
+
+    let index = match existing {
+        Some(i) => {
+            notes[i] = note;
+            i
+        }
+        None => {
+            notes.push(note);
+            notes.len() - 1
+        }
+    };
+    let mut changed = links::refresh(&mut notes, index, &tasks, &resources);
+    if !changed.contains(&index) {
+        changed.push(index);
+    }
+    for i in changed {
+        storage.upsert_note(&notes[i])?;
+    }
+
+    let id = visible_id(&notes, index);
+    if created {
+        storage.record_event(EntityType::Note, notes[index].uuid, EventType::Created)?;
+        println!(
+            "{} Created journal entry for {} (note #{})",
+            "✓".green(),
+            journal::title(date).bright_white(),
+            id
+        );
+    } else {
+        println!(
+            "{} Updated journal entry for {} (note #{})",
+            "✓".green(),
+            journal::title(date).bright_white(),
+            id
+        );
+    }
+    Ok(())
+}
+
+fn visible_id(notes: &[Note], index: usize) -> usize {
+    notes[..index].iter().filter(|n| !n.is_deleted()).count() + 1
+}
+
+/// Builds the entry for `date` from the configured template.
+fn new_entry(storage: &impl Storage, date: NaiveDate, tasks: &[Task]) -> Result<Note> {
+    let config = Config::load().unwrap_or_default();
+    let uuids: Vec<_> = tasks.iter().map(|t| t.uuid).collect();
+    let events = storage.load_events(EntityType::Task, &uuids)?;
+
+    let done = journal::completed_on(date, tasks, &events);
+    let due = journal::due_on(date, tasks);
+    let body = journal::render(config.notes.journal_template(), date, tasks, &done, &due).map_err(
+        |name| {
+            anyhow!(
+                "Unknown placeholder {{{{{}}}}} in [notes] journal_template",
+                name
+            )
+        },
+    )?;
+
+    let mut note = Note::new_markdown(body);
+    note.title = Some(journal::title(date));
+    note.tags = vec![journal::TAG.to_string()];
+    Ok(note)
+}
+
+// ── list ──────────────────────────────────────────────────────────────────────
+
+/// `todo journal list [--month [YYYY-MM]]` — lists journal entries, oldest
+/// first. `--month` alone means the current month.
+pub fn execute_list(storage: &impl Storage, month: Option<String>) -> Result<()> {
+    let month = month.map(|m| parse_month(&m)).transpose()?;
+    let notes = storage.load_notes()?;
+
+    let mut entries: Vec<(usize, NaiveDate, &Note)> = notes
+        .iter()
+        .filter(|n| !n.is_deleted())
+        .enumerate()
+        .filter_map(|(i, n)| Some((i + 1, journal::entry_date(n)?, n)))
+        .filter(|(_, date, _)| {
+            month.is_none_or(|m| date.year() == m.year() && date.month() == m.month())
+        })
+        .collect();
+    entries.sort_by_key(|(_, date, _)| *date);
+
+    let heading = match month {
+        Some(m) => format!("Journal — {}", m.format("%B %Y")),
+        None => "Journal".to_string(),
+    };
+    if entries.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "\nNo journal entries{}.\n",
+                month.map_or(String::new(), |m| { format!(" in {}", m.format("%B %Y")) })
+            )
+            .dimmed()
+        );
+        return Ok(());
+    }
+
+    println!("\n{}\n", heading.bright_white().bold());
+    for (id, date, note) in &entries {
+        println!(
+            "  {}  {}  {}  {}",
+            format!("#{:<3}", id).dimmed(),
+            journal::title(*date).bright_white(),
+            format!("{:<9}", date.format("%A")).dimmed(),
+            truncate(&summary(note), 50)
+        );
+    }
+    println!(
+        "\n  {}\n",
+        format!(
+            "{} entr{} — open one with 'todo journal DATE'",
+            entries.len(),
+            if entries.len() == 1 { "y" } else { "ies" }
+        )
+        .dimmed()
+    );
+    Ok(())
+}
+
+/// The first line of an entry that is neither blank nor a heading.
+fn summary(note: &Note) -> String {
+    note.body
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty() && !l.starts_with('#'))
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Parses `YYYY-MM` into the first day of that month; empty means the
+/// current month.
+fn parse_month(input: &str) -> Result<NaiveDate> {
+    let input = input.trim();
+    if input.is_empty() {
+        let today = Local::now().date_naive();
+        return Ok(today.with_day(1).unwrap_or(today));
+    }
+    NaiveDate::parse_from_str(&format!("{}-01", input), "%Y-%m-%d")
+        .map_err(|_| anyhow!("Invalid month '{}' (expected YYYY-MM)", input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_parse_month() {
+        assert_eq!(
+            parse_month("2026-03").unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+        assert_eq!(parse_month("").unwrap().day(), 1);
+        assert!(parse_month("March").is_err());
+    }
+
+    #[test]
+    fn test_open_creates_one_entry_per_day() {
+        let storage = InMemoryStorage::default();
+        execute_open(&storage, Some("2026-03-14".into()), true).unwrap();
+        execute_open(&storage, Some("2026-03-14".into()), true).unwrap();
+        execute_open(&storage, Some("2026-03-15".into()), true).unwrap();
+
+        let notes = storage.load_notes().unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].title.as_deref(), Some("2026-03-14"));
+        assert_eq!(notes[0].tags, vec!["journal".to_string()]);
+        assert!(notes[0].format.is_markdown());
+        assert!(execute_list(&storage, Some("2026-03".into())).is_ok());
+    }
+}
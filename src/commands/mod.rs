@@ -3,45 +3,73 @@
 //! | Submodule          | Subcommand                      |
 //! |--------------------|---------------------------------|
 //! | [`task::add`]      | `todo add`                      |
+//! | [`task::bulk`]     | ID lists / `--filter` on mutating commands |
 //! | [`task::clear`]    | `todo clear`                    |
 //! | [`task::clear_recur`] | `todo norecur <ID>`          |
+//! | [`task::dedupe`]   | `todo dedupe`                   |
 //! | [`task::deps`]     | `todo deps <ID>`                |
 //! | [`task::done`]     | `todo done <ID>`                |
 //! | [`task::edit`]     | `todo edit <ID>`                |
 //! | [`task::info`]     | `todo info`                     |
 //! | [`task::list`]     | `todo list`                     |
+//! | [`task::merge`]    | `todo merge <ID> <IDS>`         |
 //! | [`task::recur`]    | `todo recur <ID>`               |
 //! | [`task::remove`]   | `todo remove <ID>`              |
+//! | [`task::status`]   | `todo start/wait/cancel <ID>`   |
 //! | [`task::undone`]   | `todo undone <ID>`              |
 //! | [`note::add`]      | `todo note add`                 |
 //! | [`note::clear`]    | `todo note clear`               |
+//! | [`note::diff`]     | `todo note diff <ID> [REV]`     |
 //! | [`note::edit`]     | `todo note edit <ID>`           |
+//! | [`note::history`]  | `todo note history <ID>`        |
 //! | [`note::list`]     | `todo note list`                |
 //! | [`note::remove`]   | `todo note remove <ID>`         |
+//! | [`note::revert`]   | `todo note revert <ID> <REV>`   |
+//! | [`note::preview`]  | `todo note preview <ID>`        |
 //! | [`note::show`]     | `todo note show <ID>`           |
+//! | [`note::templates`] | `todo note templates`          |
+//! | [`note::vault`]    | `todo note export \| import \| sync` |
 //! | [`project::add`]   | `todo project add`              |
 //! | [`project::clear`] | `todo project clear`            |
 //! | [`project::done`]  | `todo project done <ID>`        |
 //! | [`project::undone`]| `todo project undone <ID>`      |
 //! | [`project::edit`]  | `todo project edit <ID>`        |
+//! | [`project::forecast`] | `todo project forecast <ID>` |
 //! | [`project::list`]  | `todo project list`             |
 //! | [`project::remove`]| `todo project remove <ID>`      |
 //! | [`project::show`]  | `todo project show <ID>`        |
 //! | [`resource::add`]  | `todo resource add`             |
+//! | [`resource::check`]| `todo resource check [ID]`      |
 //! | [`resource::clear`]| `todo resource clear`           |
 //! | [`resource::edit`] | `todo resource edit <ID>`       |
 //! | [`resource::list`] | `todo resource list`            |
 //! | [`resource::remove`]| `todo resource remove <ID>`    |
 //! | [`resource::show`] | `todo resource show <ID>`       |
+//! | [`template::apply`] | `todo template apply <NAME>`  |
+//! | [`template::list`] | `todo template list`            |
+//! | [`template::remove`] | `todo template remove <NAME>` |
+//! | [`template::save`] | `todo template save <NAME> <IDs>` |
+//! | [`template::show`] | `todo template show <NAME>`    |
+//! | [`view::context`]  | `todo ctx set <VIEW>` / `todo ctx clear` |
+//! | [`view::list`]     | `todo view list`                |
+//! | [`view::remove`]   | `todo view remove <NAME>`       |
+//! | [`view::run`]      | `todo view run <NAME>`          |
+//! | [`view::save`]     | `todo view save <NAME>`         |
+//! | [`archive`]        | `todo archive` / `todo unarchive <ID>` |
+//! | [`attachment`]     | `todo attach \| attachments \| open-attachment <ID>` |
 //! | [`calendar`]       | `todo calendar [MONTH] [YEAR]`  |
 //! | [`context`]        | `todo context <ID>`             |
 //! | [`holidays_cmd`]   | `todo holidays`                 |
+//! | [`journal`]        | `todo journal [DATE]` / `todo journal list` |
 //! | [`next`]           | `todo next`                     |
+//! | [`plan`]           | `todo plan`                     |
 //! | [`purge`]          | `todo purge`                    |
 //! | [`search`]         | `todo search <QUERY>`           |
 //! | [`stats`]          | `todo stats`                    |
 //! | [`stats_history`]  | `todo stats history`            |
 //! | [`tags`]           | `todo tags`                     |
+//! | [`trash`]          | `todo trash [restore <ID> \| empty]` |
+//! | [`urgency`]        | `todo urgency <ID>`             |
 //! | [`backup`]         | `todo backup`                   |
 //! | [`portability`]    | `todo portability`              |
 
@@ -49,15 +77,23 @@ pub mod note;
 pub mod project;
 pub mod resource;
 pub mod task;
+pub mod template;
+pub mod view;
 
+pub mod archive;
+pub mod attachment;
 pub mod backup;
 pub mod calendar;
 pub mod context;
 pub mod holidays_cmd;
+pub mod journal;
 pub mod next;
+pub mod plan;
 pub mod portability;
 pub mod purge;
 pub mod search;
 pub mod stats;
 pub mod stats_history;
 pub mod tags;
+pub mod trash;
+pub mod urgency;
@@ -1,14 +1,19 @@
 //! Handler for `todo next`.
 //!
-//! Shows the top N pending tasks sorted by urgency score, excluding tasks
-//! blocked by unresolved dependencies — only "ready to work" tasks.
+//! Shows the top N pending or active tasks sorted by urgency score, excluding
+//! tasks blocked by unresolved dependencies or parked with `todo wait` — only
+//! "ready to work" tasks.
+//!
+//! Only tasks inside the active context (`todo ctx set`) are considered.
 //!
 //! Inspired by Taskwarrior's `task next` command.
 
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::models::Task;
+use crate::commands::view::active_context;
+use crate::config::Config;
+use crate::models::{Task, TaskStatus};
 use crate::render::next_table::display_next;
 use crate::storage::Storage;
 
@@ -18,10 +23,20 @@ pub fn execute(storage: &impl Storage, limit: Option<usize>) -> Result<()> {
     let all_tasks = storage.load()?;
     let projects = storage.load_projects()?;
     let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let urgency = Config::load().unwrap_or_default().urgency;
+    let context = active_context(storage)?;
+    if let Some(ref ctx) = context {
+        ctx.print_notice();
+    }
 
     let pending: Vec<&Task> = all_tasks
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
+        .filter(|t| !t.is_deleted() && matches!(t.status, TaskStatus::Pending | TaskStatus::Active))
+        .filter(|t| {
+            context
+                .as_ref()
+                .is_none_or(|ctx| ctx.matches(t, &all_tasks, &projects))
+        })
         .collect();
 
     let blocked_count = pending.iter().filter(|t| t.is_blocked(&all_tasks)).count();
@@ -50,8 +65,8 @@ pub fn execute(storage: &impl Storage, limit: Option<usize>) -> Result<()> {
     }
 
     ready.sort_by(|a, b| {
-        b.urgency_score(&all_tasks)
-            .partial_cmp(&a.urgency_score(&all_tasks))
+        b.urgency_score_with(&all_tasks, &projects, &urgency)
+            .partial_cmp(&a.urgency_score_with(&all_tasks, &projects, &urgency))
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
@@ -70,7 +85,14 @@ pub fn execute(storage: &impl Storage, limit: Option<usize>) -> Result<()> {
         })
         .collect();
 
-    display_next(&shown, &all_tasks, &projects, ready_count, blocked_count);
+    display_next(
+        &shown,
+        &all_tasks,
+        &projects,
+        &urgency,
+        ready_count,
+        blocked_count,
+    );
 
     Ok(())
 }
@@ -1,77 +1,138 @@
 //! Handler for `todo note add`.
+//!
+//! With `--template NAME` the body starts from a note template (see
+//! [`crate::services::note_template`]) and is opened in `$EDITOR`; the
+//! template's front matter supplies the format, language, tags, title and
+//! project unless they are given on the command line.
 
 use anyhow::{Result, anyhow};
+use chrono::Local;
 use colored::Colorize;
 
 use crate::cli::NoteAddArgs;
-use crate::models::{Note, Project};
+use crate::config::Config;
+use crate::models::{Note, NoteFormat, Project, Task};
+use crate::services::links;
+use crate::services::note_template::{self, NoteTemplate};
 use crate::services::tag_service::collect_all_tag_names;
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::tag_normalizer::normalize_tags;
 use crate::utils::validation::resolve_visible;
 
 pub fn execute(storage: &impl Storage, args: NoteAddArgs) -> Result<()> {
-    let (tasks, projects, notes) = storage.load_all()?;
+    let (tasks, projects, mut notes) = storage.load_all()?;
     let resources = storage.load_resources()?;
 
-    let (body, is_markdown) = match (args.body, args.editor, args.file) {
-        (Some(text), false, None) => (text, false),
-        (None, true, None) => {
+    let template = match args.template {
+        Some(ref name) => Some(note_template::find(&Config::note_templates_dir()?, name)?),
+        None => None,
+    };
+
+    let mut linked: Vec<(usize, &Task)> = Vec::new();
+    for &task_num in &args.task {
+        let task = resolve_visible(&tasks, task_num, |t| t.is_deleted())
+            .map_err(|_| anyhow!("Task #{} not found", task_num))?;
+        if !linked.iter().any(|(_, t)| t.uuid == task.uuid) {
+            linked.push((task_num, task));
+        }
+    }
+
+    // A templated note goes to the template's projects, or else its tasks'.
+    let mut project_names = args.project.clone();
+    if project_names.is_empty()
+        && let Some(ref template) = template
+    {
+        project_names = template.front.projects.clone();
+        if project_names.is_empty() {
+            for (_, task) in &linked {
+                if let Some(project) = task
+                    .project_id
+                    .and_then(|id| projects.iter().find(|p| p.uuid == id))
+                {
+                    let path = project.path(&projects);
+                    if !project_names.contains(&path) {
+                        project_names.push(path);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut title = args.title;
+    let mut language = args.language;
+    let mut tags = args.tag;
+    let (body, format) = match (template, args.body, args.editor, args.file) {
+        (Some(template), None, false, None) => {
+            let project = project_names.join(", ");
+            let (template_title, draft) = render(
+                &template,
+                title.as_deref(),
+                (!project.is_empty()).then_some(project.as_str()),
+                linked.first().copied(),
+            )?;
+            let content = edit::edit_with_builder(&draft, edit::Builder::new().suffix(".md"))?;
+            let trimmed = content.trim().to_string();
+            if trimmed.is_empty() {
+                return Err(anyhow!("Aborted: note body is empty."));
+            }
+            title = title.or(template_title);
+            language = language.or(template.front.language.clone());
+            tags.extend(template.front.tags.iter().cloned());
+            (trimmed, template.format())
+        }
+        (None, Some(text), false, None) => (text, NoteFormat::Plain),
+        (None, None, true, None) => {
             let content = edit::edit_with_builder("", edit::Builder::new().suffix(".md"))?;
             let trimmed = content.trim().to_string();
             if trimmed.is_empty() {
                 return Err(anyhow!("Aborted: note body is empty."));
             }
-            (trimmed, true)
+            (trimmed, NoteFormat::Markdown)
         }
-        (None, false, Some(path)) => {
+        (None, None, false, Some(path)) => {
             let content = std::fs::read_to_string(&path)
                 .map_err(|e| anyhow!("Failed to read file {}: {}", path.display(), e))?;
-            (content, true)
+            (content, NoteFormat::Markdown)
         }
-        (None, false, None) => {
+        (None, None, false, None) => {
             return Err(anyhow!(
-                "Provide a note body: <BODY>, --editor, or --file <PATH>"
+                "Provide a note body: <BODY>, --editor, --file <PATH> or --template <NAME>"
             ));
         }
         _ => {
             return Err(anyhow!(
-                "Only one input source allowed: <BODY>, --editor, or --file <PATH>"
+                "Only one input source allowed: <BODY>, --editor, --file <PATH> or --template <NAME>"
             ));
         }
     };
 
-    let project_id = if let Some(ref name) = args.project {
-        Some(Project::resolve_or_create(storage, &projects, name)?)
-    } else {
-        None
-    };
-
-    let task_id = if let Some(task_num) = args.task {
-        let task = resolve_visible(&tasks, task_num, |t| t.is_deleted())
-            .map_err(|_| anyhow!("Task #{} not found", task_num))?;
-        Some(task.uuid)
-    } else {
-        None
-    };
+    let project_ids = Project::resolve_or_create_all(storage, &project_names)?;
 
     let existing_tags = collect_all_tag_names(&tasks, &notes, &resources);
-    let (normalized_tags, normalization_messages) = normalize_tags(args.tag, &existing_tags);
+    let (normalized_tags, normalization_messages) = normalize_tags(tags, &existing_tags);
 
-    let mut note = if is_markdown {
+    let mut note = if format.is_markdown() {
         Note::new_markdown(body)
     } else {
         Note::new(body)
     };
-    note.title = args.title;
+    note.title = title;
     note.tags = normalized_tags;
-    note.language = args.language;
-    note.project_id = project_id;
-    note.task_id = task_id;
+    note.language = language;
+    note.project_ids = project_ids;
+    note.task_ids = linked.iter().map(|(_, t)| t.uuid).collect();
 
     let note_uuid = note.uuid;
     let id = notes.iter().filter(|n| !n.is_deleted()).count() + 1;
-    storage.upsert_note(&note)?;
+    notes.push(note);
+    let index = notes.len() - 1;
+    let mut changed = links::refresh(&mut notes, index, &tasks, &resources);
+    if !changed.contains(&index) {
+        changed.push(index);
+    }
+    for i in changed {
+        storage.upsert_note(&notes[i])?;
+    }
     storage.record_event(EntityType::Note, note_uuid, EventType::Created)?;
 
     for msg in &normalization_messages {
@@ -81,6 +142,28 @@ pub fn execute(storage: &impl Storage, args: NoteAddArgs) -> Result<()> {
     Ok(())
 }
 
+/// Fills in a template's title and body for the note being added.
+fn render(
+    template: &NoteTemplate,
+    title: Option<&str>,
+    project: Option<&str>,
+    task: Option<(usize, &Task)>,
+) -> Result<(Option<String>, String)> {
+    let subject = note_template::Subject {
+        title,
+        project,
+        task,
+    };
+    let vars = note_template::variables(Local::now(), &subject);
+    template.render(&vars).map_err(|name| {
+        anyhow!(
+            "Unknown placeholder {{{{{}}}}} in note template '{}'",
+            name,
+            template.name
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,11 +175,12 @@ mod tests {
             body: Some(body.into()),
             editor: false,
             file: None,
+            template: None,
             title: None,
             tag: vec![],
             language: None,
-            project: None,
-            task: None,
+            project: vec![],
+            task: vec![],
         }
     }
 
@@ -121,11 +205,12 @@ mod tests {
                 body: None,
                 editor: false,
                 file: None,
+                template: None,
                 title: None,
                 tag: vec![],
                 language: None,
-                project: None,
-                task: None,
+                project: vec![],
+                task: vec![],
             },
         )
         .unwrap_err();
@@ -207,13 +292,16 @@ mod tests {
         execute(
             &storage,
             NoteAddArgs {
-                project: Some("Rustodo".into()),
+                project: vec!["Rustodo".into()],
                 ..args("Body")
             },
         )
         .unwrap();
 
-        assert_eq!(storage.load_notes().unwrap()[0].project_id, Some(proj_uuid));
+        assert_eq!(
+            storage.load_notes().unwrap()[0].project_ids,
+            vec![proj_uuid]
+        );
     }
 
     #[test]
@@ -223,7 +311,7 @@ mod tests {
         execute(
             &storage,
             NoteAddArgs {
-                project: Some("NewProject".into()),
+                project: vec!["NewProject".into()],
                 ..args("Body")
             },
         )
@@ -234,7 +322,7 @@ mod tests {
         assert_eq!(projects[0].name, "NewProject");
 
         let note = &storage.load_notes().unwrap()[0];
-        assert_eq!(note.project_id, Some(projects[0].uuid));
+        assert_eq!(note.project_ids, vec![projects[0].uuid]);
     }
 
     // ── task link ─────────────────────────────────────────────────────────────
@@ -249,13 +337,39 @@ mod tests {
         execute(
             &storage,
             NoteAddArgs {
-                task: Some(1),
+                task: vec![1],
                 ..args("Body")
             },
         )
         .unwrap();
 
-        assert_eq!(storage.load_notes().unwrap()[0].task_id, Some(task_uuid));
+        assert_eq!(storage.load_notes().unwrap()[0].task_ids, vec![task_uuid]);
+    }
+
+    #[test]
+    fn test_note_add_linked_to_several_tasks_and_projects() {
+        let storage = InMemoryStorage::default();
+        let tasks: Vec<Task> = ["One", "Two", "Three"]
+            .iter()
+            .map(|t| Task::new(t.to_string(), Priority::Medium, vec![], None, None, None))
+            .collect();
+        storage.save(&tasks).unwrap();
+
+        execute(
+            &storage,
+            NoteAddArgs {
+                task: vec![3, 1, 3],
+                project: vec!["api".into(), "docs".into()],
+                ..args("Meeting")
+            },
+        )
+        .unwrap();
+
+        let note = &storage.load_notes().unwrap()[0];
+        assert_eq!(note.task_ids, vec![tasks[2].uuid, tasks[0].uuid]);
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects.len(), 2, "both new projects are kept");
+        assert_eq!(note.project_ids, vec![projects[0].uuid, projects[1].uuid]);
     }
 
     #[test]
@@ -275,7 +389,7 @@ mod tests {
         let err = execute(
             &storage,
             NoteAddArgs {
-                task: Some(99),
+                task: vec![99],
                 ..args("Body")
             },
         )
@@ -310,4 +424,44 @@ mod tests {
         let notes = storage.load_notes().unwrap();
         assert_eq!(notes[1].tags[0], "rust");
     }
+
+    // ── templates ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_render_template_for_task() {
+        let template = NoteTemplate {
+            name: "adr".into(),
+            front: crate::services::vault::parse("---\ntitle: ADR {{task.id}}\n---\n").0,
+            body: "# {{title}}\n\nProject: {{project}}".into(),
+            path: None,
+        };
+        let task = Task::new(
+            "Pick a database".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            None,
+            None,
+        );
+
+        let (title, body) = render(&template, None, Some("acme"), Some((12, &task))).unwrap();
+        assert_eq!(title.as_deref(), Some("ADR 12"));
+        assert_eq!(body, "# Pick a database\n\nProject: acme");
+
+        let (_, body) = render(&template, Some("Use SQLite"), None, None).unwrap();
+        assert_eq!(body, "# Use SQLite\n\nProject: ");
+    }
+
+    #[test]
+    fn test_render_template_unknown_placeholder() {
+        let template = NoteTemplate {
+            name: "broken".into(),
+            front: Default::default(),
+            body: "{{attendees}}".into(),
+            path: None,
+        };
+        let err = render(&template, None, None, None).unwrap_err().to_string();
+        assert!(err.contains("{{attendees}}"));
+        assert!(err.contains("'broken'"));
+    }
 }
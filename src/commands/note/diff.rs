@@ -0,0 +1,85 @@
+//! Handler for `todo note diff <ID> [REV]`.
+//!
+//! Shows a line diff from a revision (the latest by default) to the current
+//! version of the note.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::render::pager;
+use crate::services::diff::{self, Change};
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible;
+
+/// Unchanged lines shown around each change.
+const CONTEXT: usize = 3;
+
+pub fn execute(storage: &impl Storage, id: usize, rev: Option<u32>) -> Result<()> {
+    let notes = storage.load_notes()?;
+    let note = resolve_visible(&notes, id, |n| n.is_deleted())
+        .map_err(|_| anyhow::anyhow!("Note #{} not found", id))?;
+    let revisions = storage.load_note_revisions(note.uuid)?;
+
+    let revision = match rev {
+        Some(number) => revisions
+            .iter()
+            .find(|r| r.number == number)
+            .ok_or_else(|| anyhow::anyhow!("Revision #{} of note #{} not found", number, id))?,
+        None => match revisions.last() {
+            Some(latest) => latest,
+            None => {
+                println!("{}", format!("\nNote #{} has no revisions.\n", id).dimmed());
+                return Ok(());
+            }
+        },
+    };
+
+    let mut out = vec![
+        String::new(),
+        format!(
+            "  {} {}",
+            format!("--- revision #{}", revision.number).red(),
+            revision
+                .created_at
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+                .dimmed()
+        ),
+        format!("  {}", "+++ current".green()),
+    ];
+
+    if revision.title != note.title {
+        out.push(String::new());
+        let show = |t: &Option<String>| t.clone().unwrap_or_else(|| "(no title)".into());
+        out.push(format!(
+            "  {}",
+            format!("-title: {}", show(&revision.title)).red()
+        ));
+        out.push(format!(
+            "  {}",
+            format!("+title: {}", show(&note.title)).green()
+        ));
+    }
+
+    let lines = diff::diff_lines(&revision.body, &note.body);
+    let hunks = diff::hunks(&lines, CONTEXT);
+    if hunks.is_empty() && revision.title == note.title {
+        out.push(String::new());
+        out.push(format!("  {}", "No changes.".dimmed()));
+    }
+    for hunk in hunks {
+        out.push(format!("  {}", "⋯".dimmed()));
+        for line in &lines[hunk] {
+            out.push(match line.change {
+                Change::Same => format!("   {}", line.text),
+                Change::Added => format!("  {}", format!("+{}", line.text).green()),
+                Change::Removed => format!("  {}", format!("-{}", line.text).red()),
+            });
+        }
+    }
+    out.push(String::new());
+
+    let config = Config::load().unwrap_or_default();
+    pager::page(&out, config.notes.pager.as_deref())
+}
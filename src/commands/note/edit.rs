@@ -1,22 +1,26 @@
 //! Handler for `todo note edit <ID>`.
 //!
 //! Applies partial updates to an existing note. Only fields explicitly
-//! provided are changed; everything else is preserved.
+//! provided are changed; everything else is preserved. The previous title
+//! and body are kept as a revision (see `todo note history`).
 
 use anyhow::Result;
 use colored::Colorize;
 
 use crate::cli::NoteEditArgs;
+use crate::commands::note::history;
 use crate::models::{NoteFormat, Project};
+use crate::services::links;
 use crate::storage::Storage;
 use crate::utils::validation::{resolve_visible, resolve_visible_index};
 
 pub fn execute(storage: &impl Storage, args: NoteEditArgs) -> Result<()> {
-    let (tasks, projects, mut notes, resources) = storage.load_all_with_resources()?;
+    let (tasks, _projects, mut notes, resources) = storage.load_all_with_resources()?;
 
     let real_index = resolve_visible_index(&notes, args.id, |n| n.is_deleted())
         .map_err(|_| anyhow::anyhow!("Note #{} not found", args.id))?;
 
+    let before = notes[real_index].clone();
     let note = &mut notes[real_index];
     let mut changes = Vec::new();
 
@@ -105,32 +109,66 @@ pub fn execute(storage: &impl Storage, args: NoteEditArgs) -> Result<()> {
         }
     }
 
-    // ── project ───────────────────────────────────────────────────────────────
+    // ── projects ──────────────────────────────────────────────────────────────
     if args.clear_project {
-        if note.project_id.is_some() {
-            note.project_id = None;
-            changes.push("project → cleared".dimmed().to_string());
+        if !note.project_ids.is_empty() {
+            note.project_ids.clear();
+            changes.push("projects → cleared".dimmed().to_string());
         }
-    } else if let Some(ref proj_name) = args.project {
-        let new_uuid = Project::resolve_or_create(storage, &projects, proj_name)?;
-        if note.project_id != Some(new_uuid) {
-            note.project_id = Some(new_uuid);
-            changes.push(format!("project → {}", proj_name.cyan()));
+    } else if !args.project.is_empty() {
+        let new_ids = Project::resolve_or_create_all(storage, &args.project)?;
+        if note.project_ids != new_ids {
+            note.project_ids = new_ids;
+            changes.push(format!("projects → {}", args.project.join(", ").cyan()));
         }
     }
 
-    // ── task link ─────────────────────────────────────────────────────────────
+    // ── task links ────────────────────────────────────────────────────────────
+    let find_task = |task_num: usize| {
+        resolve_visible(&tasks, task_num, |t| t.is_deleted())
+            .map_err(|_| anyhow::anyhow!("Task #{} not found", task_num))
+    };
     if args.clear_task {
-        if note.task_id.is_some() {
-            note.task_id = None;
-            changes.push("task → cleared".dimmed().to_string());
+        if !note.task_ids.is_empty() {
+            note.task_ids.clear();
+            changes.push("tasks → cleared".dimmed().to_string());
         }
-    } else if let Some(task_num) = args.task {
-        let task = resolve_visible(&tasks, task_num, |t| t.is_deleted())
-            .map_err(|_| anyhow::anyhow!("Task #{} not found", task_num))?;
-        if note.task_id != Some(task.uuid) {
-            note.task_id = Some(task.uuid);
-            changes.push(format!("task → #{} {}", task_num, task.text.cyan()));
+    } else if !args.task.is_empty() {
+        let mut new_ids = Vec::new();
+        let mut labels = Vec::new();
+        for &task_num in &args.task {
+            let task = find_task(task_num)?;
+            if !new_ids.contains(&task.uuid) {
+                new_ids.push(task.uuid);
+                labels.push(format!("#{} {}", task_num, task.text));
+            }
+        }
+        if note.task_ids != new_ids {
+            note.task_ids = new_ids;
+            changes.push(format!("tasks → {}", labels.join(", ").cyan()));
+        }
+    } else {
+        let mut removed = Vec::new();
+        for &task_num in &args.remove_task {
+            let task = find_task(task_num)?;
+            if note.belongs_to_task(task.uuid) {
+                note.remove_task(task.uuid);
+                removed.push(format!("#{} {}", task_num, task.text));
+            }
+        }
+        if !removed.is_empty() {
+            changes.push(format!("removed tasks → [{}]", removed.join(", ").red()));
+        }
+        let mut added = Vec::new();
+        for &task_num in &args.add_task {
+            let task = find_task(task_num)?;
+            if !note.belongs_to_task(task.uuid) {
+                note.add_task(task.uuid);
+                added.push(format!("#{} {}", task_num, task.text));
+            }
+        }
+        if !added.is_empty() {
+            changes.push(format!("added tasks → [{}]", added.join(", ").cyan()));
         }
     }
 
@@ -187,13 +225,32 @@ pub fn execute(storage: &impl Storage, args: NoteEditArgs) -> Result<()> {
         return Ok(());
     }
 
+    if notes[real_index].body != before.body || notes[real_index].title != before.title {
+        history::record_revision(storage, &before)?;
+    }
     notes[real_index].touch();
+
+    // ── wiki-links ────────────────────────────────────────────────────────────
+    let mut renamed = 0;
+    if let (Some(old), Some(new)) = (before.title, notes[real_index].title.clone())
+        && !old.eq_ignore_ascii_case(&new)
+    {
+        renamed = history::rename_links(storage, &mut notes, real_index, &old, &new)?;
+    }
+    links::refresh(&mut notes, real_index, &tasks, &resources);
     storage.save_notes(&notes)?;
 
     println!("{} Note #{} updated:", "✓".green(), args.id);
     for change in &changes {
         println!("  • {}", change);
     }
+    if renamed > 0 {
+        println!(
+            "  • updated links in {} note{}",
+            renamed,
+            if renamed == 1 { "" } else { "s" }
+        );
+    }
 
     Ok(())
 }
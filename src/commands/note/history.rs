@@ -0,0 +1,128 @@
+//! Handler for `todo note history <ID>`.
+//!
+//! Lists the saved revisions of a note, newest first, with what the edit
+//! after each one changed.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::models::{Note, NoteRevision};
+use crate::services::{diff, links};
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible;
+
+pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
+    let notes = storage.load_notes()?;
+    let note = resolve_visible(&notes, id, |n| n.is_deleted())
+        .map_err(|_| anyhow::anyhow!("Note #{} not found", id))?;
+    let revisions = storage.load_note_revisions(note.uuid)?;
+
+    if revisions.is_empty() {
+        println!("{}", format!("\nNote #{} has no revisions.\n", id).dimmed());
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "  {} {}",
+        format!("Note #{}", id).bold(),
+        format!(
+            "— {} revision{}",
+            revisions.len(),
+            if revisions.len() == 1 { "" } else { "s" }
+        )
+        .dimmed()
+    );
+    println!("  {}", "─".repeat(50).dimmed());
+    println!(
+        "  {:<7} {:<16}  {}",
+        "current".cyan(),
+        note.updated_at
+            .unwrap_or(note.created_at)
+            .format("%Y-%m-%d %H:%M")
+            .to_string()
+            .dimmed(),
+        lines(&note.body)
+    );
+
+    // Each revision is compared with the version that replaced it.
+    for (i, revision) in revisions.iter().enumerate().rev() {
+        let (next_title, next_body) = match revisions.get(i + 1) {
+            Some(next) => (next.title.as_deref(), next.body.as_str()),
+            None => (note.title.as_deref(), note.body.as_str()),
+        };
+        let (added, removed) = diff::stats(&diff::diff_lines(&revision.body, next_body));
+        let mut change = Vec::new();
+        if added > 0 {
+            change.push(format!("+{}", added).green().to_string());
+        }
+        if removed > 0 {
+            change.push(format!("−{}", removed).red().to_string());
+        }
+        if revision.title.as_deref() != next_title {
+            let title = revision.title.as_deref().unwrap_or("no title");
+            change.push(format!("title was \"{}\"", title).dimmed().to_string());
+        }
+
+        println!(
+            "  {:<7} {:<16}  {:<10} {}",
+            format!("#{}", revision.number).cyan(),
+            revision
+                .created_at
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+                .dimmed(),
+            lines(&revision.body),
+            change.join("  ")
+        );
+    }
+    println!();
+    println!(
+        "  {} {}",
+        "Compare with".dimmed(),
+        format!("todo note diff {} <REV>", id).cyan()
+    );
+    println!();
+    Ok(())
+}
+
+fn lines(body: &str) -> String {
+    let n = body.lines().count();
+    format!("{} line{}", n, if n == 1 { "" } else { "s" })
+}
+
+/// Saves the title and body of `note`, as they are before an edit, as its
+/// next revision, then prunes to the `[notes] keep_revisions` setting.
+pub(crate) fn record_revision(storage: &impl Storage, note: &Note) -> Result<()> {
+    let keep = Config::load().unwrap_or_default().notes.keep_revisions();
+    if keep > 0 {
+        let number = storage
+            .load_note_revisions(note.uuid)?
+            .last()
+            .map_or(1, |r| r.number + 1);
+        storage.save_note_revision(&NoteRevision::of(note, number))?;
+    }
+    storage.prune_note_revisions(keep)?;
+    Ok(())
+}
+
+/// Rewrites `[[old]]` links to the note at `index` as `[[new]]` in the other
+/// notes, recording a revision of each note whose body changes. Returns how
+/// many notes were rewritten.
+pub(crate) fn rename_links(
+    storage: &impl Storage,
+    notes: &mut [Note],
+    index: usize,
+    old: &str,
+    new: &str,
+) -> Result<usize> {
+    let before = notes.to_vec();
+    let changed = links::rename_note(notes, notes[index].uuid, old, new);
+    for &i in &changed {
+        if i != index && notes[i].body != before[i].body {
+            record_revision(storage, &before[i])?;
+        }
+    }
+    Ok(changed.len())
+}
@@ -4,6 +4,7 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::cli::NoteListArgs;
+use crate::models::Project;
 use crate::render::display_notes;
 use crate::storage::Storage;
 use crate::utils::tag_normalizer::has_tag;
@@ -15,11 +16,10 @@ pub fn execute(storage: &impl Storage, args: NoteListArgs) -> Result<()> {
     let mut visible: Vec<_> = notes.iter().filter(|n| !n.is_deleted()).collect();
 
     if let Some(ref proj_name) = args.project {
-        let proj_uuid = projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == proj_name.to_lowercase() && !p.is_deleted())
-            .map(|p| p.uuid);
-        visible.retain(|n| proj_uuid.is_some() && n.project_id == proj_uuid);
+        let subtree = Project::find(&projects, proj_name)
+            .map(|p| Project::subtree(&projects, p.uuid))
+            .unwrap_or_default();
+        visible.retain(|n| n.project_ids.iter().any(|u| subtree.contains(u)));
     }
 
     if let Some(ref tag) = args.tag {
@@ -1,7 +1,12 @@
 pub mod add;
 pub mod clear;
+pub mod diff;
 pub mod edit;
+pub mod history;
 pub mod list;
 pub mod preview;
 pub mod remove;
+pub mod revert;
 pub mod show;
+pub mod templates;
+pub mod vault;
@@ -1,10 +1,15 @@
 //! Handler for `todo note preview <ID>`.
+//!
+//! Renders markdown notes, and highlights code notes (notes with a
+//! `language`), without the metadata `todo note show` prints around them.
 
 use anyhow::Result;
 use colored::Colorize;
 use std::io::Write;
 use std::process::{Command, Stdio};
 
+use crate::config::Config;
+use crate::render::{markdown, pager};
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible;
 
@@ -15,39 +20,62 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         .map_err(|_| anyhow::anyhow!("Note #{} not found", id))?;
 
     // ── Check note format ─────────────────────────────────────────────────────
-    if !note.is_markdown() {
-        eprintln!("{} Note #{} is plain text, not markdown.", "✗".red(), id);
+    if !note.is_markdown() && note.language.is_none() {
+        eprintln!(
+            "{} Note #{} is plain text, not markdown or code.",
+            "✗".red(),
+            id
+        );
         eprintln!("  Use {} to view it.", "todo note show".cyan());
         return Ok(());
     }
 
-    // ── Check glow is available ───────────────────────────────────────────────
-    if Command::new("glow").arg("--version").output().is_err() {
-        eprintln!(
-            "{} {} is not installed — showing raw markdown.",
-            "".yellow(),
-            "glow".yellow()
-        );
-        eprintln!(
-            "  Install it from: {}\n",
-            "https://github.com/charmbracelet/glow".cyan()
-        );
-        return Ok(());
+    let config = Config::load().unwrap_or_default();
+
+    // ── External renderer, when configured ────────────────────────────────────
+    if note.is_markdown()
+        && let Some(ref command) = config.notes.renderer
+    {
+        match pipe_to(command, &note.body) {
+            Ok(()) => return Ok(()),
+            Err(e) => eprintln!(
+                "{} Could not run renderer '{}' ({}) — using the built-in one.",
+                "!".yellow(),
+                command,
+                e
+            ),
+        }
     }
 
-    // ── Pipe note body into glow via stdin ────────────────────────────────────
-    let mut child = Command::new("glow")
-        .arg("-")
+    // ── Built-in renderer ─────────────────────────────────────────────────────
+    let width = pager::terminal_size().0.min(markdown::MAX_WIDTH);
+    let lines = markdown::to_ansi(&markdown::render_note(note, width), |kind| {
+        config.theme.syntax(kind)
+    });
+    pager::page(&lines, config.notes.pager.as_deref())
+}
+
+/// Runs `command` with `body` on stdin, e.g. `renderer = "glow -"`.
+fn pipe_to(command: &str, body: &str) -> Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("empty command"))?;
+
+    let mut child = Command::new(program)
+        .args(parts)
         .stdin(Stdio::piped())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()?;
 
     if let Some(stdin) = child.stdin.as_mut() {
-        stdin.write_all(note.body.as_bytes())?;
+        stdin.write_all(body.as_bytes())?;
     }
 
-    child.wait()?;
-
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("exited with {}", status);
+    }
     Ok(())
 }
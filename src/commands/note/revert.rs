@@ -0,0 +1,52 @@
+//! Handler for `todo note revert <ID> <REV>`.
+//!
+//! Restores the title and body of a revision. The version being replaced is
+//! saved as a new revision first, so a revert can itself be reverted.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::commands::note::history;
+use crate::services::links;
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible_index;
+
+pub fn execute(storage: &impl Storage, id: usize, rev: u32) -> Result<()> {
+    let (tasks, _, mut notes, resources) = storage.load_all_with_resources()?;
+    let real_index = resolve_visible_index(&notes, id, |n| n.is_deleted())
+        .map_err(|_| anyhow::anyhow!("Note #{} not found", id))?;
+
+    let revision = storage
+        .load_note_revisions(notes[real_index].uuid)?
+        .into_iter()
+        .find(|r| r.number == rev)
+        .ok_or_else(|| anyhow::anyhow!("Revision #{} of note #{} not found", rev, id))?;
+
+    let before = notes[real_index].clone();
+    if before.body == revision.body && before.title == revision.title {
+        println!(
+            "{} Note #{} already matches revision #{}.",
+            "".blue(),
+            id,
+            rev
+        );
+        return Ok(());
+    }
+
+    history::record_revision(storage, &before)?;
+    let note = &mut notes[real_index];
+    note.title = revision.title;
+    note.body = revision.body;
+    note.touch();
+
+    if let (Some(old), Some(new)) = (before.title, notes[real_index].title.clone())
+        && !old.eq_ignore_ascii_case(&new)
+    {
+        history::rename_links(storage, &mut notes, real_index, &old, &new)?;
+    }
+    links::refresh(&mut notes, real_index, &tasks, &resources);
+    storage.save_notes(&notes)?;
+
+    println!("{} Note #{} reverted to revision #{}", "✓".green(), id, rev);
+    Ok(())
+}
@@ -3,8 +3,12 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::models::NoteFormat;
-use crate::render::formatting::note_preview;
+use crate::commands::attachment;
+use crate::config::Config;
+use crate::models::{AttachmentOwner, LinkTarget};
+use crate::render::formatting::{format_size, note_preview, truncate};
+use crate::render::{markdown, pager};
+use crate::services::links;
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible;
 
@@ -16,43 +20,43 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
 
     let visible_resources: Vec<_> = resources.iter().filter(|r| !r.is_deleted()).collect();
 
+    let config = Config::load().unwrap_or_default();
+    let mut out = Vec::new();
+
     // ── Header ────────────────────────────────────────────────────────────────
-    println!();
+    out.push(String::new());
     if let Some(ref title) = note.title {
-        println!("  {} {}", "Note:".dimmed(), title.bold());
+        out.push(format!("  {} {}", "Note:".dimmed(), title.bold()));
     } else {
-        println!("  {}", format!("Note #{}", id).bold());
+        out.push(format!("  {}", format!("Note #{}", id).bold()));
     }
 
-    println!("  {}", "─".repeat(50).dimmed());
+    out.push(format!("  {}", "─".repeat(50).dimmed()));
 
     // ── Body ──────────────────────────────────────────────────────────────────
-    println!();
-    if note.format == NoteFormat::Markdown {
-        let preview = note_preview(note);
-        println!("  {}", preview.bold());
-        println!();
-        println!(
-            "  {} {}",
-            "tip:".dimmed(),
-            format!("run `todo note preview {}` to render markdown", id).dimmed()
-        );
-    } else {
-        for line in note.body.lines() {
-            println!("  {}", line);
-        }
+    out.push(String::new());
+    let width = pager::terminal_size().0.min(markdown::MAX_WIDTH) - 2;
+    let lines = markdown::to_ansi(&markdown::render_note(note, width), |kind| {
+        config.theme.syntax(kind)
+    });
+    for line in lines {
+        out.push(if line.is_empty() {
+            line
+        } else {
+            format!("  {}", line)
+        });
     }
-    println!();
+    out.push(String::new());
 
     // ── Metadata ──────────────────────────────────────────────────────────────
-    println!("  {}", "─".repeat(50).dimmed());
+    out.push(format!("  {}", "─".repeat(50).dimmed()));
 
     if let Some(ref lang) = note.language {
-        println!("  {} {}", "Language:".dimmed(), lang.yellow());
+        out.push(format!("  {} {}", "Language:".dimmed(), lang.yellow()));
     }
 
     if !note.tags.is_empty() {
-        println!(
+        out.push(format!(
             "  {} {}",
             "Tags:".dimmed(),
             note.tags
@@ -61,24 +65,50 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
                 .collect::<Vec<_>>()
                 .join("  ")
                 .cyan()
-        );
+        ));
     }
 
-    if let Some(pid) = note.project_id
-        && let Some(project) = projects.iter().find(|p| p.uuid == pid)
-    {
-        println!("  {} {}", "Project:".dimmed(), project.name.cyan());
+    let linked_projects: Vec<&str> = note
+        .project_ids
+        .iter()
+        .filter_map(|pid| projects.iter().find(|p| p.uuid == *pid))
+        .map(|p| p.name.as_str())
+        .collect();
+    if !linked_projects.is_empty() {
+        let label = if linked_projects.len() == 1 {
+            "Project:"
+        } else {
+            "Projects:"
+        };
+        out.push(format!(
+            "  {} {}",
+            label.dimmed(),
+            linked_projects.join(", ").cyan()
+        ));
     }
 
-    if let Some(tid) = note.task_id
-        && let Some(task) = tasks.iter().find(|t| t.uuid == tid)
-    {
-        println!("  {} {}", "Task:".dimmed(), task.text.cyan());
+    let linked_tasks: Vec<&str> = note
+        .task_ids
+        .iter()
+        .filter_map(|tid| tasks.iter().find(|t| t.uuid == *tid))
+        .map(|t| t.text.as_str())
+        .collect();
+    if !linked_tasks.is_empty() {
+        let label = if linked_tasks.len() == 1 {
+            "Task:"
+        } else {
+            "Tasks:"
+        };
+        out.push(format!(
+            "  {} {}",
+            label.dimmed(),
+            linked_tasks.join(", ").cyan()
+        ));
     }
 
     // ── Resources ─────────────────────────────────────────────────────────────
     if !note.resource_ids.is_empty() {
-        println!("  {}", "Resources:".dimmed());
+        out.push(format!("  {}", "Resources:".dimmed()));
         for rid in &note.resource_ids {
             let found = visible_resources
                 .iter()
@@ -92,23 +122,77 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
                     .as_deref()
                     .map(|u| format!(" — {}", u.dimmed()))
                     .unwrap_or_default();
-                println!(
+                out.push(format!(
                     "    {} {} {}{}",
                     "·".dimmed(),
                     format!("#{}", resource_id).dimmed(),
                     resource.title.cyan(),
                     url_part
-                );
+                ));
             }
         }
     }
 
-    println!(
+    // ── Wiki-links ────────────────────────────────────────────────────────────
+    let references = links::references(&note.body);
+    if !references.is_empty() {
+        out.push(format!("  {}", "Links:".dimmed()));
+        for reference in references {
+            let target = note
+                .links
+                .iter()
+                .find(|l| l.reference == reference)
+                .map(|l| links::describe(l.target, &notes, &tasks, &resources));
+            let resolved = match target {
+                Some(Some((kind, id, label))) => {
+                    format!("{} {}", format!("{} #{}", kind, id).dimmed(), label.cyan())
+                }
+                Some(None) => "deleted".red().to_string(),
+                None => "unresolved".dimmed().italic().to_string(),
+            };
+            out.push(format!(
+                "    {} [[{}]] {} {}",
+                "·".dimmed(),
+                reference,
+                "→".dimmed(),
+                resolved
+            ));
+        }
+    }
+
+    let backlinks = links::backlinks(&notes, LinkTarget::Note(note.uuid));
+    if !backlinks.is_empty() {
+        out.push(format!("  {}", "Backlinks:".dimmed()));
+        for (backlink_id, source) in backlinks {
+            out.push(format!(
+                "    {} {} {}",
+                "·".dimmed(),
+                format!("#{}", backlink_id).dimmed(),
+                truncate(&note_preview(source), 50).cyan()
+            ));
+        }
+    }
+
+    let files = attachment::attachments_of(storage, AttachmentOwner::Note(note.uuid))?;
+    if !files.is_empty() {
+        out.push(format!("  {}", "Attachments:".dimmed()));
+        for (i, file) in files.iter().enumerate() {
+            out.push(format!(
+                "    {} {} {} {}",
+                "·".dimmed(),
+                format!("{}.", i + 1).dimmed(),
+                file.name.cyan(),
+                format_size(file.size).dimmed()
+            ));
+        }
+    }
+
+    out.push(format!(
         "  {} {}",
         "Created:".dimmed(),
         note.created_at.format("%Y-%m-%d")
-    );
-    println!();
+    ));
+    out.push(String::new());
 
-    Ok(())
+    pager::page(&out, config.notes.pager.as_deref())
 }
@@ -0,0 +1,50 @@
+//! Handler for `todo note templates`.
+//!
+//! Lists the templates `todo note add --template` accepts: the built-in
+//! ones and the `.md` files in the note templates directory.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::services::note_template;
+
+pub fn execute() -> Result<()> {
+    let dir = Config::note_templates_dir()?;
+    let templates = note_template::load_all(&dir)?;
+
+    println!("\n{}\n", "Note templates".bright_white().bold());
+    for template in &templates {
+        let source = match &template.path {
+            Some(path) => path.display().to_string(),
+            None => "built-in".to_string(),
+        };
+        let format = if template.format().is_markdown() {
+            "markdown"
+        } else {
+            "plain"
+        };
+        let mut details = vec![format.to_string()];
+        if !template.front.tags.is_empty() {
+            details.push(format!("#{}", template.front.tags.join(" #")));
+        }
+        if !template.front.projects.is_empty() {
+            details.push(format!("project {}", template.front.projects.join(", ")));
+        }
+        println!(
+            "  {} {:<28} {}",
+            format!("{:<12}", template.name).cyan(),
+            details.join(", "),
+            source.dimmed()
+        );
+    }
+    println!(
+        "\n  {}\n",
+        format!(
+            "Add or override templates as NAME.md files in {}",
+            dir.display()
+        )
+        .dimmed()
+    );
+    Ok(())
+}
@@ -0,0 +1,395 @@
+//! Handlers for `todo note export`, `todo note import` and `todo note sync`.
+//!
+//! A vault is a directory of markdown files, one per note, with the note's
+//! metadata in YAML front matter (see [`crate::services::vault`]). Files
+//! are matched to notes by the `id` in their front matter, whatever their
+//! name, so notes can be renamed and moved into folders in the editor.
+//!
+//! - export writes every note, overwriting its file
+//! - import upserts every file into the notes, by UUID
+//! - sync does whichever is needed per note: the newer of the note's
+//!   `updated_at` and the file's modification time wins
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::commands::note::history;
+use crate::models::{Note, NoteFormat, Project, Resource, Task};
+use crate::services::links;
+use crate::services::vault::{self, VaultFile};
+use crate::storage::{EntityType, EventType, Storage};
+
+/// Timestamps closer than this are treated as equal: file systems differ in
+/// the precision of modification times.
+const CLOCK_SLACK_SECS: i64 = 1;
+
+// ── export ────────────────────────────────────────────────────────────────────
+
+/// `todo note export --dir <DIR>` — writes every note as a markdown file.
+pub fn execute_export(storage: &impl Storage, dir: PathBuf) -> Result<()> {
+    let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+    let existing = if dir.exists() {
+        paths_by_id(&vault::scan(&dir)?)
+    } else {
+        HashMap::new()
+    };
+
+    let mut count = 0;
+    for note in notes.iter().filter(|n| !n.is_deleted()) {
+        let path = existing
+            .get(&note.uuid)
+            .cloned()
+            .unwrap_or_else(|| dir.join(vault::file_name(note)));
+        let content = vault::render(note, &projects, &tasks, &resources);
+        vault::write(&path, &content, modified_at(note))?;
+        count += 1;
+    }
+
+    println!(
+        "{} Exported {} note{} to {}",
+        "✓".green(),
+        count,
+        if count == 1 { "" } else { "s" },
+        dir.display().to_string().cyan()
+    );
+    Ok(())
+}
+
+// ── import ────────────────────────────────────────────────────────────────────
+
+/// `todo note import --dir <DIR>` — upserts every markdown file as a note.
+pub fn execute_import(storage: &impl Storage, dir: PathBuf) -> Result<()> {
+    let files = scan_existing(&dir)?;
+    let mut state = Vault::load(storage)?;
+
+    let mut summary = Summary::default();
+    for file in &files {
+        match state.import(storage, file)? {
+            Imported::Created(_) => summary.created += 1,
+            Imported::Updated(_) => summary.updated += 1,
+            Imported::Unchanged(_) => summary.unchanged += 1,
+            Imported::Skipped => summary.skipped += 1,
+        }
+    }
+    state.save(storage)?;
+
+    println!(
+        "{} Imported {}: {} new, {} updated, {} unchanged",
+        "✓".green(),
+        dir.display().to_string().cyan(),
+        summary.created,
+        summary.updated,
+        summary.unchanged
+    );
+    state.print_warnings(summary.skipped);
+    if files.iter().any(|f| f.front.id.is_none()) {
+        println!(
+            "  {} Files without an id are imported as new notes each time; use {} to write ids back.",
+            "".blue(),
+            "todo note sync".cyan()
+        );
+    }
+    Ok(())
+}
+
+// ── sync ──────────────────────────────────────────────────────────────────────
+
+/// `todo note sync --dir <DIR>` — reconciles notes and files both ways.
+pub fn execute_sync(storage: &impl Storage, dir: PathBuf) -> Result<()> {
+    let files = if dir.exists() {
+        vault::scan(&dir)?
+    } else {
+        Vec::new()
+    };
+    let mut state = Vault::load(storage)?;
+
+    let mut summary = Summary::default();
+    // Files to (re)write once every import is in: (path, note index).
+    let mut to_write: Vec<(PathBuf, usize)> = Vec::new();
+    let mut seen: Vec<Uuid> = Vec::new();
+
+    for file in &files {
+        let known = file
+            .front
+            .id
+            .and_then(|id| state.notes.iter().position(|n| n.uuid == id));
+
+        match known {
+            Some(i) if state.notes[i].is_deleted() => {
+                state.warnings.push(format!(
+                    "{}: note is in the trash, skipped",
+                    file.path.display()
+                ));
+                summary.skipped += 1;
+            }
+            Some(i) => {
+                seen.push(state.notes[i].uuid);
+                let note_time = modified_at(&state.notes[i]);
+                let delta = (file.modified - note_time).num_seconds();
+                if delta > CLOCK_SLACK_SECS {
+                    match state.import(storage, file)? {
+                        Imported::Updated(i) => {
+                            summary.updated += 1;
+                            to_write.push((file.path.clone(), i));
+                        }
+                        Imported::Unchanged(i) => {
+                            summary.unchanged += 1;
+                            to_write.push((file.path.clone(), i));
+                        }
+                        _ => summary.skipped += 1,
+                    }
+                } else if delta < -CLOCK_SLACK_SECS {
+                    summary.exported += 1;
+                    to_write.push((file.path.clone(), i));
+                } else {
+                    summary.unchanged += 1;
+                }
+            }
+            None => match state.import(storage, file)? {
+                Imported::Created(i) => {
+                    seen.push(state.notes[i].uuid);
+                    summary.created += 1;
+                    to_write.push((file.path.clone(), i));
+                }
+                _ => summary.skipped += 1,
+            },
+        }
+    }
+
+    for (i, note) in state.notes.iter().enumerate() {
+        if !note.is_deleted() && !seen.contains(&note.uuid) {
+            summary.exported += 1;
+            to_write.push((dir.join(vault::file_name(note)), i));
+        }
+    }
+
+    state.save(storage)?;
+    for (path, i) in to_write {
+        let note = &state.notes[i];
+        let content = vault::render(note, &state.projects, &state.tasks, &state.resources);
+        vault::write(&path, &content, modified_at(note))?;
+    }
+
+    println!(
+        "{} Synced {}: {} exported, {} imported ({} new), {} unchanged",
+        "✓".green(),
+        dir.display().to_string().cyan(),
+        summary.exported,
+        summary.created + summary.updated,
+        summary.created,
+        summary.unchanged
+    );
+    state.print_warnings(summary.skipped);
+    Ok(())
+}
+
+// ── shared ────────────────────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct Summary {
+    created: usize,
+    updated: usize,
+    unchanged: usize,
+    exported: usize,
+    skipped: usize,
+}
+
+/// What importing one file did, with the index of the note it landed in.
+enum Imported {
+    Created(usize),
+    Updated(usize),
+    Unchanged(usize),
+    Skipped,
+}
+
+/// Everything an import reads and writes, loaded once per command.
+struct Vault {
+    tasks: Vec<Task>,
+    projects: Vec<Project>,
+    notes: Vec<Note>,
+    resources: Vec<Resource>,
+    changed: Vec<usize>,
+    warnings: Vec<String>,
+}
+
+impl Vault {
+    fn load(storage: &impl Storage) -> Result<Self> {
+        let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+        Ok(Vault {
+            tasks,
+            projects,
+            notes,
+            resources,
+            changed: Vec::new(),
+            warnings: Vec::new(),
+        })
+    }
+
+    /// Creates or updates the note a file describes.
+    fn import(&mut self, storage: &impl Storage, file: &VaultFile) -> Result<Imported> {
+        if file.body.trim().is_empty() {
+            self.warnings
+                .push(format!("{}: empty body, skipped", file.path.display()));
+            return Ok(Imported::Skipped);
+        }
+        let index = file
+            .front
+            .id
+            .and_then(|id| self.notes.iter().position(|n| n.uuid == id));
+        if index.is_some_and(|i| self.notes[i].is_deleted()) {
+            self.warnings.push(format!(
+                "{}: note is in the trash, skipped",
+                file.path.display()
+            ));
+            return Ok(Imported::Skipped);
+        }
+
+        let mut note = match index {
+            Some(i) => self.notes[i].clone(),
+            None => {
+                let mut note = Note::new_markdown(String::new());
+                if let Some(id) = file.front.id {
+                    note.uuid = id;
+                }
+                if let Some(created) = file.front.created {
+                    note.created_at = created;
+                }
+                note
+            }
+        };
+        let front = &file.front;
+        note.body = file.body.clone();
+        // A file name only stands in for a missing title on new notes.
+        note.title = match index {
+            Some(_) => front.title.clone(),
+            None => file.title(),
+        };
+        note.tags = front.tags.clone();
+        note.language = front.language.clone();
+        note.format = front.format.unwrap_or(NoteFormat::Markdown);
+        note.project_ids = Project::resolve_or_create_all(storage, &front.projects)?;
+        if !front.projects.is_empty() {
+            self.projects = storage.load_projects()?;
+        }
+        note.task_ids = front
+            .tasks
+            .iter()
+            .copied()
+            .filter(|id| {
+                let found = self.tasks.iter().any(|t| t.uuid == *id && !t.is_deleted());
+                if !found {
+                    self.warnings.push(format!(
+                        "{}: task {} not found, link dropped",
+                        file.path.display(),
+                        id
+                    ));
+                }
+                found
+            })
+            .collect();
+        note.resource_ids = front
+            .resources
+            .iter()
+            .copied()
+            .filter(|id| {
+                let found = self
+                    .resources
+                    .iter()
+                    .any(|r| r.uuid == *id && !r.is_deleted());
+                if !found {
+                    self.warnings.push(format!(
+                        "{}: resource {} not found, link dropped",
+                        file.path.display(),
+                        id
+                    ));
+                }
+                found
+            })
+            .collect();
+
+        let outcome = match index {
+            Some(i) => {
+                let before = &self.notes[i];
+                if same_content(before, &note) {
+                    return Ok(Imported::Unchanged(i));
+                }
+                if vault::clean_body(&before.body) != note.body || before.title != note.title {
+                    history::record_revision(storage, before)?;
+                }
+                note.touch();
+                self.notes[i] = note;
+                Imported::Updated(i)
+            }
+            None => {
+                storage.record_event(EntityType::Note, note.uuid, EventType::Created)?;
+                self.notes.push(note);
+                Imported::Created(self.notes.len() - 1)
+            }
+        };
+        if let Imported::Created(i) | Imported::Updated(i) = outcome {
+            self.changed.push(i);
+        }
+        Ok(outcome)
+    }
+
+    /// Resolves wiki-links in the imported notes and saves everything.
+    fn save(&mut self, storage: &impl Storage) -> Result<()> {
+        if self.changed.is_empty() {
+            return Ok(());
+        }
+        for &i in &self.changed {
+            links::refresh(&mut self.notes, i, &self.tasks, &self.resources);
+        }
+        storage.save_notes(&self.notes)
+    }
+
+    fn print_warnings(&self, skipped: usize) {
+        for warning in &self.warnings {
+            println!("  {} {}", "!".yellow(), warning);
+        }
+        if skipped > 0 {
+            println!(
+                "  {} {} file{} skipped",
+                "!".yellow(),
+                skipped,
+                if skipped == 1 { "" } else { "s" }
+            );
+        }
+    }
+}
+
+/// Whether a note read back from a file matches the stored one. Bodies are
+/// compared normalized, since writing a file drops surrounding blank lines.
+fn same_content(a: &Note, b: &Note) -> bool {
+    vault::clean_body(&a.body) == vault::clean_body(&b.body)
+        && a.title == b.title
+        && a.tags == b.tags
+        && a.language == b.language
+        && a.format == b.format
+        && a.project_ids == b.project_ids
+        && a.task_ids == b.task_ids
+        && a.resource_ids == b.resource_ids
+}
+
+fn modified_at(note: &Note) -> DateTime<Utc> {
+    note.updated_at.unwrap_or(note.created_at)
+}
+
+fn paths_by_id(files: &[VaultFile]) -> HashMap<Uuid, PathBuf> {
+    files
+        .iter()
+        .filter_map(|f| Some((f.front.id?, f.path.clone())))
+        .collect()
+}
+
+fn scan_existing(dir: &Path) -> Result<Vec<VaultFile>> {
+    if !dir.is_dir() {
+        bail!("Directory not found: {}", dir.display());
+    }
+    vault::scan(dir)
+}
@@ -0,0 +1,174 @@
+//! Handler for `todo plan`.
+//!
+//! Schedules a project's open tasks from today using their dependencies and
+//! estimates (see [`crate::services::schedule`]), then prints:
+//! - each task's earliest start and finish, and its slack
+//! - the critical path and the earliest feasible finish date
+//! - tasks due before one of their prerequisites, or before they can finish
+//! - the tasks with slack, most first
+
+use anyhow::Result;
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+
+use crate::error::TodoError;
+use crate::models::{Project, Task};
+use crate::services::schedule::{self, DEFAULT_ESTIMATE, Plan};
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, project: Option<&str>) -> Result<()> {
+    let tasks = storage.load()?;
+    let (project_id, title) = match project {
+        Some(name) => {
+            let projects = storage.load_projects()?;
+            let project = Project::find(&projects, name)
+                .ok_or_else(|| TodoError::ProjectNotFound(name.to_string()))?;
+            (Some(project.uuid), project.path(&projects))
+        }
+        None => (None, "all tasks".to_string()),
+    };
+
+    let plan = schedule::plan(&tasks, project_id)?;
+    if plan.tasks.is_empty() {
+        println!("{}", "No open tasks to plan.".dimmed());
+        return Ok(());
+    }
+
+    let today = Local::now().date_naive();
+    let id = |index: usize| tasks[..index].iter().filter(|t| !t.is_deleted()).count() + 1;
+
+    println!("\n{} {}\n", "Plan:".dimmed(), title.bright_white().bold());
+    print_schedule(&tasks, &plan, today, &id);
+
+    // ── Summary ───────────────────────────────────────────────────────────────
+    let path = plan
+        .critical_path
+        .iter()
+        .map(|&i| format!("#{}", id(i)))
+        .collect::<Vec<_>>()
+        .join(" → ");
+    println!();
+    println!(
+        "  {}  {} ({} day(s))",
+        "Critical path".dimmed(),
+        path.red().bold(),
+        plan.total_days
+    );
+    println!(
+        "  {}  {}",
+        "Earliest finish".dimmed(),
+        plan.finish_date(today).to_string().cyan()
+    );
+
+    // ── Warnings ──────────────────────────────────────────────────────────────
+    let mut warnings = Vec::new();
+    for conflict in &plan.conflicts {
+        let (t, p) = (&tasks[conflict.task], &tasks[conflict.prerequisite]);
+        warnings.push(format!(
+            "#{} \"{}\" is due {} but its prerequisite #{} \"{}\" is due {}",
+            id(conflict.task),
+            t.text,
+            t.due_date.unwrap(),
+            id(conflict.prerequisite),
+            p.text,
+            p.due_date.unwrap()
+        ));
+    }
+    for planned in &plan.tasks {
+        let task = &tasks[planned.index];
+        if let Some(due) = task.due_date
+            && planned.finish_date(today) > due
+        {
+            warnings.push(format!(
+                "#{} \"{}\" cannot finish before {} (due {})",
+                id(planned.index),
+                task.text,
+                planned.finish_date(today),
+                due
+            ));
+        }
+    }
+    if !warnings.is_empty() {
+        println!();
+        for warning in &warnings {
+            println!("  {} {}", "⚠".yellow(), warning.yellow());
+        }
+    }
+
+    // ── Slack ─────────────────────────────────────────────────────────────────
+    let mut slack: Vec<_> = plan.tasks.iter().filter(|p| !p.is_critical()).collect();
+    slack.sort_by(|a, b| b.slack.cmp(&a.slack).then(a.index.cmp(&b.index)));
+    if !slack.is_empty() {
+        println!();
+        println!("  {}", "Tasks with slack".dimmed());
+        for planned in slack {
+            println!(
+                "    #{} {} — can slip {} day(s)",
+                id(planned.index),
+                tasks[planned.index].text,
+                planned.slack.to_string().green()
+            );
+        }
+    }
+
+    if plan.tasks.iter().any(|p| !p.estimated) {
+        println!();
+        println!(
+            "  {}",
+            format!(
+                "* no estimate, {} day(s) assumed — set one with `todo edit <ID> --estimate 3d`",
+                DEFAULT_ESTIMATE
+            )
+            .dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+fn print_schedule(tasks: &[Task], plan: &Plan, today: NaiveDate, id: &dyn Fn(usize) -> usize) {
+    let width = plan
+        .tasks
+        .iter()
+        .map(|p| tasks[p.index].text.chars().count())
+        .max()
+        .unwrap_or(4)
+        .clamp(4, 40);
+
+    println!(
+        "  {:>4}  {:<width$}  {:>4}  {:<10}  {:<10}  {:>5}",
+        "ID".dimmed(),
+        "Task".dimmed(),
+        "Est".dimmed(),
+        "Start".dimmed(),
+        "Finish".dimmed(),
+        "Slack".dimmed(),
+    );
+    for planned in &plan.tasks {
+        let task = &tasks[planned.index];
+        let text: String = task.text.chars().take(width).collect();
+        let estimate = format!(
+            "{}d{}",
+            planned.duration,
+            if planned.estimated { "" } else { "*" }
+        );
+        let line = format!(
+            "  {:>4}  {:<width$}  {:>4}  {:<10}  {:<10}  {:>5}",
+            format!("#{}", id(planned.index)),
+            text,
+            estimate,
+            planned.start_date(today),
+            planned.finish_date(today),
+            if planned.is_critical() {
+                "—".to_string()
+            } else {
+                format!("{}d", planned.slack)
+            },
+        );
+        if planned.is_critical() {
+            println!("{}", line.red());
+        } else {
+            println!("{}", line);
+        }
+    }
+}
@@ -7,23 +7,36 @@
 //! # Import integrity
 //!
 //! Before writing anything, the import validates referential integrity:
+//! - Projects whose parent is not in the file, or whose parents loop back
+//!   to them, have their `parent_id` cleared.
 //! - Tasks whose `project_id` points to a UUID not present in the file have
 //!   their `project_id` cleared (with a warning) rather than being silently
 //!   stored with a dangling reference.
-//! - Notes whose `task_id` or `project_id` are dangling are cleared the
-//!   same way.
-//! - Notes whose `resource_ids` contain unknown UUIDs have those entries
-//!   removed.
+//! - Task estimates longer than `todo add --estimate` accepts are cleared.
+//! - Notes whose `task_ids`, `project_ids` or `resource_ids` contain
+//!   unknown UUIDs have those entries removed.
+//! - Attachments whose task or note is not in the file, or whose contents
+//!   are missing, are dropped.
+//!
+//! # Attachments
+//!
+//! Attachment records go in the JSON file; their contents are copied to a
+//! directory next to it, named after the file with an `.attachments`
+//! extension (`export.json` → `export.attachments/`), laid out like the
+//! blob store (see [`attachments`]).
 
 use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
-use crate::models::{Note, Project, Resource, Task};
-use crate::storage::Storage;
+use crate::models::{Attachment, AttachmentOwner, Note, Project, Resource, Task, View};
+use crate::services::duplicates;
+use crate::services::query::{Query, QueryContext};
+use crate::storage::{Storage, attachments};
+use crate::utils::date_parser::MAX_ESTIMATE_DAYS;
 
 // ── envelope ──────────────────────────────────────────────────────────────────
 
@@ -38,19 +51,48 @@ struct Envelope {
     notes: Vec<Note>,
     #[serde(default)]
     resources: Vec<Resource>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    views: Vec<View>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<Attachment>,
 }
 
 // ── export ────────────────────────────────────────────────────────────────────
 
 /// `todo export [FILE]` — serializes all data to a JSON file.
-pub fn execute_export(storage: &impl Storage, file: Option<PathBuf>) -> Result<()> {
-    let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+///
+/// With `--filter`, only tasks matching the expression are written;
+/// projects, notes and resources are always exported in full.
+pub fn execute_export(
+    storage: &impl Storage,
+    file: Option<PathBuf>,
+    filter: Option<String>,
+) -> Result<()> {
+    let query = filter.as_deref().map(Query::parse).transpose()?;
+    let (mut tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+
+    if let Some(ref query) = query {
+        let all = tasks.clone();
+        let ctx = QueryContext {
+            tasks: &all,
+            projects: &projects,
+        };
+        tasks.retain(|t| !t.is_deleted() && query.matches(t, &ctx));
+    }
+
+    let mut files = storage.load_attachments()?;
+    files.retain(|a| match a.owner {
+        AttachmentOwner::Task(uuid) => tasks.iter().any(|t| t.uuid == uuid),
+        AttachmentOwner::Note(uuid) => notes.iter().any(|n| n.uuid == uuid),
+    });
 
     let envelope = Envelope {
         tasks,
         projects,
         notes,
         resources,
+        views: storage.load_views()?,
+        attachments: files,
     };
 
     let json = serde_json::to_string_pretty(&envelope).context("Failed to serialize data")?;
@@ -63,18 +105,41 @@ pub fn execute_export(storage: &impl Storage, file: Option<PathBuf>) -> Result<(
     std::fs::write(&path, &json)
         .context(format!("Failed to write export file: {}", path.display()))?;
 
+    if !envelope.attachments.is_empty() {
+        let hashes: HashSet<&str> = envelope
+            .attachments
+            .iter()
+            .map(|a| a.hash.as_str())
+            .collect();
+        attachments::copy_all(
+            &storage.attachments_dir()?,
+            &blobs_dir(&path),
+            Some(&hashes),
+            false,
+        )
+        .context("Failed to export attachments")?;
+    }
+
     println!(
         "{} Exported to: {}",
         "✓".green(),
         path.display().to_string().cyan()
     );
     println!(
-        "  {} tasks, {} projects, {} notes, {} resources",
+        "  {} tasks, {} projects, {} notes, {} resources, {} views",
         envelope.tasks.len().to_string().dimmed(),
         envelope.projects.len().to_string().dimmed(),
         envelope.notes.len().to_string().dimmed(),
         envelope.resources.len().to_string().dimmed(),
+        envelope.views.len().to_string().dimmed(),
     );
+    if !envelope.attachments.is_empty() {
+        println!(
+            "  {} attachments in {}",
+            envelope.attachments.len().to_string().dimmed(),
+            blobs_dir(&path).display().to_string().dimmed()
+        );
+    }
 
     Ok(())
 }
@@ -102,14 +167,27 @@ pub fn execute_import(
     let project_count = envelope.projects.len();
     let note_count = envelope.notes.len();
     let resource_count = envelope.resources.len();
+    let view_count = envelope.views.len();
 
-    if task_count + project_count + note_count + resource_count == 0 {
+    if task_count + project_count + note_count + resource_count + view_count == 0 {
         println!("{}", "\nNothing to import — file is empty.\n".dimmed());
         return Ok(());
     }
 
     // ── Referential integrity check ───────────────────────────────────────────
-    let warnings = validate_and_repair(&mut envelope);
+    let mut warnings = validate_and_repair(&mut envelope);
+    let blobs = blobs_dir(&file);
+    envelope.attachments.retain(|a| {
+        let found = attachments::blob_path(&blobs, &a.hash).is_ok_and(|p| p.exists());
+        if !found {
+            warnings.push(format!(
+                "Attachment \"{}\": contents not found in {} — dropped.",
+                a.name,
+                blobs.display()
+            ));
+        }
+        found
+    });
 
     println!(
         "\n{} Importing from: {}\n",
@@ -117,11 +195,12 @@ pub fn execute_import(
         file.display().to_string().cyan()
     );
     println!(
-        "  {} tasks, {} projects, {} notes, {} resources",
+        "  {} tasks, {} projects, {} notes, {} resources, {} views",
         task_count.to_string().bright_white(),
         project_count.to_string().bright_white(),
         note_count.to_string().bright_white(),
         resource_count.to_string().bright_white(),
+        view_count.to_string().bright_white(),
     );
 
     if !warnings.is_empty() {
@@ -131,6 +210,20 @@ pub fn execute_import(
         }
     }
 
+    if !replace {
+        let duplicates = find_duplicates(&envelope.tasks, &storage.load()?);
+        if !duplicates.is_empty() {
+            println!("\n  Possible duplicates of existing pending tasks:");
+            for d in &duplicates {
+                println!("  {} {}", "~".yellow(), d);
+            }
+            println!(
+                "  {}",
+                "Run 'todo dedupe' after importing to merge them.".dimmed()
+            );
+        }
+    }
+
     if replace {
         println!(
             "\n  {} {} All existing data will be replaced.\n",
@@ -154,6 +247,9 @@ pub fn execute_import(
         storage.save_projects(&envelope.projects)?;
         storage.save_notes(&envelope.notes)?;
         storage.save_resources(&envelope.resources)?;
+        for view in storage.load_views()? {
+            storage.delete_view(&view.name)?;
+        }
     } else {
         if !envelope.tasks.is_empty() {
             storage.save(&envelope.tasks)?;
@@ -168,19 +264,46 @@ pub fn execute_import(
             storage.save_resources(&envelope.resources)?;
         }
     }
+    for view in &envelope.views {
+        storage.save_view(view)?;
+    }
+    if !envelope.attachments.is_empty() {
+        let hashes: HashSet<&str> = envelope
+            .attachments
+            .iter()
+            .map(|a| a.hash.as_str())
+            .collect();
+        attachments::copy_all(&blobs, &storage.attachments_dir()?, Some(&hashes), false)
+            .context("Failed to import attachments")?;
+        for attachment in &envelope.attachments {
+            storage.save_attachment(attachment)?;
+        }
+    }
 
     println!(
-        "{} Import complete: {} tasks, {} projects, {} notes, {} resources",
+        "{} Import complete: {} tasks, {} projects, {} notes, {} resources, {} views",
         "✓".green(),
         task_count.to_string().green(),
         project_count.to_string().green(),
         note_count.to_string().green(),
         resource_count.to_string().green(),
+        view_count.to_string().green(),
     );
+    if !envelope.attachments.is_empty() {
+        println!(
+            "  {} attachments",
+            envelope.attachments.len().to_string().green()
+        );
+    }
 
     Ok(())
 }
 
+/// The directory holding the attachment contents of an export file.
+fn blobs_dir(file: &Path) -> PathBuf {
+    file.with_extension("attachments")
+}
+
 // ── integrity validation ──────────────────────────────────────────────────────
 
 /// Repairs dangling foreign-key references within the envelope and returns
@@ -191,12 +314,57 @@ pub fn execute_import(
 fn validate_and_repair(envelope: &mut Envelope) -> Vec<String> {
     let mut warnings = Vec::new();
 
+    // Projects exported before project states existed only carry `completed`.
+    for project in &mut envelope.projects {
+        project.normalize_status();
+    }
+
     let project_uuids: HashSet<Uuid> = envelope.projects.iter().map(|p| p.uuid).collect();
     let task_uuids: HashSet<Uuid> = envelope.tasks.iter().map(|t| t.uuid).collect();
+
+    // Projects: clear parents that are not in the envelope, then break
+    // parent loops, which would hide every project in them from the tree.
+    for project in &mut envelope.projects {
+        if let Some(parent) = project.parent_id
+            && !project_uuids.contains(&parent)
+        {
+            warnings.push(format!(
+                "Project \"{}\": parent_id {} not found — cleared.",
+                project.name, parent
+            ));
+            project.parent_id = None;
+        }
+    }
+    for i in 0..envelope.projects.len() {
+        let mut seen = HashSet::from([envelope.projects[i].uuid]);
+        let mut current = envelope.projects[i].parent_id;
+        while let Some(uuid) = current {
+            if !seen.insert(uuid) {
+                break;
+            }
+            current = envelope
+                .projects
+                .iter()
+                .find(|p| p.uuid == uuid)
+                .and_then(|p| p.parent_id);
+        }
+        if current == Some(envelope.projects[i].uuid) {
+            let project = &mut envelope.projects[i];
+            warnings.push(format!(
+                "Project \"{}\": parent loop — parent_id cleared.",
+                project.name
+            ));
+            project.parent_id = None;
+        }
+    }
+
     let resource_uuids: HashSet<Uuid> = envelope.resources.iter().map(|r| r.uuid).collect();
 
-    // Tasks: clear project_id if the project is not in the envelope
+    // Tasks: clear project_id if the project is not in the envelope, clear
+    // estimates `todo add --estimate` would reject, and derive `status` for
+    // exports written before task states existed.
     for task in &mut envelope.tasks {
+        task.normalize_status();
         if let Some(pid) = task.project_id
             && !project_uuids.contains(&pid)
         {
@@ -206,30 +374,39 @@ fn validate_and_repair(envelope: &mut Envelope) -> Vec<String> {
             ));
             task.project_id = None;
         }
+        if let Some(days) = task.estimate
+            && days > MAX_ESTIMATE_DAYS
+        {
+            warnings.push(format!(
+                "Task \"{}\": estimate of {} days exceeds {} — cleared.",
+                task.text, days, MAX_ESTIMATE_DAYS
+            ));
+            task.estimate = None;
+        }
     }
 
-    // Notes: clear project_id, task_id, and unknown resource_ids
+    // Notes: drop unknown project, task and resource links
     for note in &mut envelope.notes {
-        if let Some(pid) = note.project_id
-            && !project_uuids.contains(&pid)
-        {
+        let before = note.project_ids.len();
+        note.project_ids.retain(|pid| project_uuids.contains(pid));
+        let removed = before - note.project_ids.len();
+        if removed > 0 {
             let label = note.title.as_deref().unwrap_or("<untitled>");
             warnings.push(format!(
-                "Note \"{}\": project_id {} not found — cleared.",
-                label, pid
+                "Note \"{}\": {} unknown project link(s) removed.",
+                label, removed
             ));
-            note.project_id = None;
         }
 
-        if let Some(tid) = note.task_id
-            && !task_uuids.contains(&tid)
-        {
+        let before = note.task_ids.len();
+        note.task_ids.retain(|tid| task_uuids.contains(tid));
+        let removed = before - note.task_ids.len();
+        if removed > 0 {
             let label = note.title.as_deref().unwrap_or("<untitled>");
             warnings.push(format!(
-                "Note \"{}\": task_id {} not found — cleared.",
-                label, tid
+                "Note \"{}\": {} unknown task link(s) removed.",
+                label, removed
             ));
-            note.task_id = None;
         }
 
         let before = note.resource_ids.len();
@@ -244,9 +421,61 @@ fn validate_and_repair(envelope: &mut Envelope) -> Vec<String> {
         }
     }
 
+    // Attachment names and hashes become file paths: keep only the file
+    // name, and drop records whose hash is not a SHA-256.
+    envelope.attachments.retain_mut(|a| {
+        if !attachments::is_valid_hash(&a.hash) {
+            warnings.push(format!(
+                "Attachment \"{}\": invalid hash \"{}\" — dropped.",
+                a.name, a.hash
+            ));
+            return false;
+        }
+        if a.file_name() != a.name {
+            warnings.push(format!(
+                "Attachment \"{}\": renamed to \"{}\".",
+                a.name,
+                a.file_name()
+            ));
+            a.name = a.file_name().to_string();
+        }
+        let (kind, known) = match a.owner {
+            AttachmentOwner::Task(uuid) => ("task", task_uuids.contains(&uuid)),
+            AttachmentOwner::Note(uuid) => ("note", envelope.notes.iter().any(|n| n.uuid == uuid)),
+        };
+        if !known {
+            warnings.push(format!(
+                "Attachment \"{}\": {} {} not found — dropped.",
+                a.name,
+                kind,
+                a.owner.uuid()
+            ));
+        }
+        known
+    });
+
     warnings
 }
 
+/// Describes incoming open tasks that look like an existing open task.
+///
+/// Tasks whose UUID already exists are updates, not duplicates.
+fn find_duplicates(incoming: &[Task], existing: &[Task]) -> Vec<String> {
+    incoming
+        .iter()
+        .filter(|t| t.is_open() && !t.is_deleted())
+        .filter(|t| !existing.iter().any(|e| e.uuid == t.uuid))
+        .filter_map(|t| {
+            let (idx, _) = duplicates::find_similar(&t.text, existing, &[])?;
+            let id = existing[..idx].iter().filter(|e| !e.is_deleted()).count() + 1;
+            Some(format!(
+                "\"{}\" looks like #{} \"{}\"",
+                t.text, id, existing[idx].text
+            ))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,7 +490,7 @@ mod tests {
     fn export_and_read(storage: &InMemoryStorage) -> (TempDir, PathBuf) {
         let tmp = TempDir::new().unwrap();
         let path = tmp.path().join("export.json");
-        execute_export(storage, Some(path.clone())).unwrap();
+        execute_export(storage, Some(path.clone()), None).unwrap();
         (tmp, path)
     }
 
@@ -284,6 +513,28 @@ mod tests {
         assert!(content.contains("Buy milk"));
     }
 
+    #[test]
+    fn test_export_with_filter_keeps_only_matching_tasks() {
+        let storage = InMemoryStorage::default();
+        let mut bug = make_task("Fix crash");
+        bug.tags = vec!["bug".into()];
+        storage.save(&[bug, make_task("Write docs")]).unwrap();
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("export.json");
+        execute_export(
+            &storage,
+            Some(path.clone()),
+            Some("tag:bug or text:nothing".into()),
+        )
+        .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("Fix crash"));
+        assert!(!content.contains("Write docs"));
+        assert!(execute_export(&storage, Some(path), Some("tag:".into())).is_err());
+    }
+
     #[test]
     fn test_import_restores_tasks() {
         let storage = InMemoryStorage::default();
@@ -345,6 +596,35 @@ mod tests {
         assert!(storage2.load().unwrap()[0].project_id.is_none());
     }
 
+    #[test]
+    fn test_import_derives_status_from_legacy_completed() {
+        let mut task = make_task("Old done task");
+        task.completed = true;
+        let mut json = serde_json::to_value(Envelope {
+            tasks: vec![task],
+            projects: vec![],
+            notes: vec![],
+            resources: vec![],
+            views: vec![],
+            attachments: vec![],
+        })
+        .unwrap();
+        // Exports written before task states existed have no `status` field.
+        json["tasks"][0].as_object_mut().unwrap().remove("status");
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("legacy.json");
+        std::fs::write(&path, json.to_string()).unwrap();
+
+        let storage = InMemoryStorage::default();
+        execute_import(&storage, path, false, true).unwrap();
+
+        assert_eq!(
+            storage.load().unwrap()[0].status,
+            crate::models::TaskStatus::Done
+        );
+    }
+
     #[test]
     fn test_export_import_roundtrip_with_all_entities() {
         let storage = InMemoryStorage::default();
@@ -363,13 +643,21 @@ mod tests {
         storage.save(&[task]).unwrap();
 
         let mut note = Note::new("Note body".into());
-        note.project_id = Some(proj_uuid);
+        note.project_ids = vec![proj_uuid];
         storage.save_notes(&[note]).unwrap();
 
         let mut resource = Resource::new("Docs".into());
         resource.url = Some("https://docs.rs".into());
         storage.save_resources(&[resource]).unwrap();
 
+        storage
+            .save_view(&View {
+                name: "work".into(),
+                filter: "project:rustodo".into(),
+                sort: None,
+            })
+            .unwrap();
+
         let (_tmp, path) = export_and_read(&storage);
 
         let storage2 = InMemoryStorage::default();
@@ -379,5 +667,127 @@ mod tests {
         assert_eq!(storage2.load_projects().unwrap().len(), 1);
         assert_eq!(storage2.load_notes().unwrap().len(), 1);
         assert_eq!(storage2.load_resources().unwrap().len(), 1);
+        assert_eq!(storage2.load_views().unwrap()[0].filter, "project:rustodo");
+    }
+
+    #[test]
+    fn test_export_import_roundtrip_with_attachments() {
+        let storage = InMemoryStorage::default();
+        let task = make_task("Task");
+        let owner = AttachmentOwner::Task(task.uuid);
+        storage.save(&[task]).unwrap();
+
+        let tmp = TempDir::new().unwrap();
+        let file = tmp.path().join("diagram.png");
+        std::fs::write(&file, "png").unwrap();
+        let (hash, size) = attachments::store(&storage.attachments_dir().unwrap(), &file).unwrap();
+        storage
+            .save_attachment(&Attachment::new(
+                owner,
+                "diagram.png".into(),
+                hash.clone(),
+                size,
+            ))
+            .unwrap();
+
+        let (_tmp, path) = export_and_read(&storage);
+        assert!(
+            attachments::blob_path(&blobs_dir(&path), &hash)
+                .unwrap()
+                .exists()
+        );
+
+        let storage2 = InMemoryStorage::default();
+        execute_import(&storage2, path, false, true).unwrap();
+
+        let imported = storage2.load_attachments().unwrap();
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].owner, owner);
+        let blob = attachments::blob_path(&storage2.attachments_dir().unwrap(), &hash).unwrap();
+        assert_eq!(std::fs::read_to_string(blob).unwrap(), "png");
+    }
+
+    #[test]
+    fn test_import_rejects_unsafe_attachment_paths() {
+        let task = make_task("Task");
+        let owner = AttachmentOwner::Task(task.uuid);
+        let hash = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        let mut envelope = Envelope {
+            tasks: vec![task],
+            attachments: vec![
+                Attachment::new(owner, "../../../escaped.txt".into(), hash.into(), 5),
+                Attachment::new(owner, "evil".into(), "../..".into(), 5),
+            ],
+            ..Default::default()
+        };
+
+        let warnings = validate_and_repair(&mut envelope);
+
+        assert_eq!(envelope.attachments.len(), 1);
+        assert_eq!(envelope.attachments[0].name, "escaped.txt");
+        assert_eq!(warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_import_breaks_parent_loops_and_clears_unknown_parents() {
+        let mut a = Project::new("a".into());
+        let mut b = Project::new("b".into());
+        let mut c = Project::new("c".into());
+        let mut orphan = Project::new("orphan".into());
+        a.parent_id = Some(b.uuid);
+        b.parent_id = Some(a.uuid);
+        c.parent_id = Some(b.uuid);
+        orphan.parent_id = Some(Uuid::new_v4());
+        let mut envelope = Envelope {
+            projects: vec![a, b, c, orphan],
+            ..Default::default()
+        };
+
+        let warnings = validate_and_repair(&mut envelope);
+
+        let p = &envelope.projects;
+        assert_eq!(p[0].parent_id, None, "the loop is broken once");
+        assert_eq!(p[1].parent_id, Some(p[0].uuid));
+        assert_eq!(p[2].parent_id, Some(p[1].uuid));
+        assert_eq!(p[3].parent_id, None);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(Project::tree_order(p).len(), 4);
+    }
+
+    #[test]
+    fn test_import_clears_out_of_range_estimates() {
+        let mut huge = make_task("Huge");
+        huge.estimate = Some(4_000_000_000);
+        let mut fine = make_task("Fine");
+        fine.estimate = Some(MAX_ESTIMATE_DAYS);
+        let mut envelope = Envelope {
+            tasks: vec![huge, fine],
+            ..Default::default()
+        };
+
+        let warnings = validate_and_repair(&mut envelope);
+
+        assert_eq!(envelope.tasks[0].estimate, None);
+        assert_eq!(envelope.tasks[1].estimate, Some(MAX_ESTIMATE_DAYS));
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_find_duplicates_ignores_same_uuid_and_closed_tasks() {
+        let existing = vec![make_task("Write report"), make_task("Fix login bug")];
+        let mut done = make_task("Fix login bug");
+        done.mark_done();
+        let incoming = vec![
+            existing[1].clone(),
+            make_task("Fix the login bug"),
+            make_task("Buy milk"),
+            done,
+        ];
+
+        let found = find_duplicates(&incoming, &existing);
+        assert_eq!(
+            found,
+            vec!["\"Fix the login bug\" looks like #2 \"Fix login bug\""]
+        );
     }
 }
@@ -3,16 +3,14 @@ use colored::Colorize;
 
 use crate::cli::ProjectAddArgs;
 use crate::models::Project;
+use crate::render::formatting::parse_color;
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::date_parser;
 
 pub fn execute(storage: &impl Storage, args: ProjectAddArgs) -> Result<()> {
     let projects = storage.load_projects()?;
 
-    if projects
-        .iter()
-        .any(|p| p.name.to_lowercase() == args.name.to_lowercase() && !p.is_deleted())
-    {
+    if Project::find_by_path(&projects, &args.name).is_some() {
         return Err(anyhow::anyhow!("Project \"{}\" already exists", args.name));
     }
 
@@ -22,7 +20,27 @@ pub fn execute(storage: &impl Storage, args: ProjectAddArgs) -> Result<()> {
         None
     };
 
-    let mut project = Project::new(args.name.clone());
+    let start = match args.start {
+        Some(ref start_str) => Some(date_parser::parse_date(start_str)?),
+        None => None,
+    };
+
+    if let Some(ref color) = args.color
+        && parse_color(color).is_none()
+    {
+        return Err(anyhow::anyhow!(
+            "Unknown color '{}' (use a name such as blue or bright red, or #rrggbb)",
+            color
+        ));
+    }
+
+    // A dotted name nests the project, creating any missing parents on the way.
+    let mut all = projects.clone();
+    let project_uuid = Project::create_path(&mut all, &args.name)?;
+    let project = all
+        .iter_mut()
+        .find(|p| p.uuid == project_uuid)
+        .expect("create_path returns a project in the list");
     if let Some(difficulty) = args.difficulty {
         project.difficulty = difficulty;
     }
@@ -37,17 +55,36 @@ pub fn execute(storage: &impl Storage, args: ProjectAddArgs) -> Result<()> {
     if let Some(d) = due {
         project.due_date = Some(d);
     }
+    if let Some(status) = args.status {
+        project.set_status(status);
+    }
+    project.start_date = start;
+    let text = |v: Option<String>| v.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    project.description = text(args.description);
+    project.owner = text(args.owner);
+    project.url = text(args.url);
+    project.color = text(args.color);
+
+    storage.save_projects(&all)?;
+    for created in &all[projects.len()..] {
+        storage.record_event(EntityType::Project, created.uuid, EventType::Created)?;
+    }
 
-    let project_uuid = project.uuid;
-    let visible_id = projects.iter().filter(|p| !p.is_deleted()).count() + 1;
-    storage.upsert_project(&project)?;
-    storage.record_event(EntityType::Project, project_uuid, EventType::Created)?;
-
+    let visible_id = all
+        .iter()
+        .filter(|p| !p.is_deleted())
+        .position(|p| p.uuid == project_uuid)
+        .map_or(0, |i| i + 1);
+    let path = all
+        .iter()
+        .find(|p| p.uuid == project_uuid)
+        .map(|p| p.path(&all))
+        .unwrap_or_default();
     println!(
         "{} Added project #{}: {}",
         "✓".green(),
         visible_id,
-        args.name.cyan()
+        path.cyan()
     );
     Ok(())
 }
@@ -56,7 +93,7 @@ pub fn execute(storage: &impl Storage, args: ProjectAddArgs) -> Result<()> {
 mod tests {
     use super::*;
     use crate::cli::ProjectAddArgs;
-    use crate::models::Difficulty;
+    use crate::models::{Difficulty, ProjectStatus};
     use crate::storage::InMemoryStorage;
 
     fn args(name: &str) -> ProjectAddArgs {
@@ -65,6 +102,12 @@ mod tests {
             difficulty: None,
             tech: vec![],
             due: None,
+            status: None,
+            description: None,
+            start: None,
+            owner: None,
+            url: None,
+            color: None,
         }
     }
 
@@ -106,6 +149,12 @@ mod tests {
                 difficulty: Some(Difficulty::Hard),
                 tech: vec![],
                 due: None,
+                status: None,
+                description: None,
+                start: None,
+                owner: None,
+                url: None,
+                color: None,
             },
         )
         .unwrap();
@@ -124,6 +173,12 @@ mod tests {
                 difficulty: None,
                 tech: vec!["rust".into(), "sqlite".into()],
                 due: None,
+                status: None,
+                description: None,
+                start: None,
+                owner: None,
+                url: None,
+                color: None,
             },
         )
         .unwrap();
@@ -149,4 +204,49 @@ mod tests {
             .collect();
         assert_eq!(active.len(), 1);
     }
+
+    #[test]
+    fn test_project_add_dotted_path_nests_under_existing_parent() {
+        let storage = InMemoryStorage::default();
+        execute(&storage, args("Acme")).unwrap();
+        execute(&storage, args("acme.api.auth")).unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects.len(), 3);
+        assert_eq!(projects[1].parent_id, Some(projects[0].uuid));
+        assert_eq!(projects[2].path(&projects), "Acme.api.auth");
+
+        let err = execute(&storage, args("Acme.API")).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_project_add_with_status_and_details() {
+        let storage = InMemoryStorage::default();
+        execute(
+            &storage,
+            ProjectAddArgs {
+                status: Some(ProjectStatus::Idea),
+                owner: Some(" sam ".into()),
+                description: Some("".into()),
+                ..args("Lab")
+            },
+        )
+        .unwrap();
+
+        let project = &storage.load_projects().unwrap()[0];
+        assert_eq!(project.status, ProjectStatus::Idea);
+        assert_eq!(project.owner.as_deref(), Some("sam"));
+        assert!(project.description.is_none());
+        assert!(
+            execute(
+                &storage,
+                ProjectAddArgs {
+                    color: Some("plaid".into()),
+                    ..args("Other")
+                }
+            )
+            .is_err()
+        );
+    }
 }
@@ -1,7 +1,7 @@
 //! Handler for `todo project clear`.
 //!
-//! Soft-deletes all visible projects. Tasks and notes that were linked
-//! to deleted projects have their `project_id` cleared automatically.
+//! Soft-deletes all visible projects. Tasks linked to deleted projects have
+//! their `project_id` cleared, and notes lose the link automatically.
 
 use anyhow::Result;
 use colored::Colorize;
@@ -42,7 +42,7 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
         project.soft_delete();
     }
 
-    // Clear project_id from tasks and notes linked to deleted projects
+    // Unlink tasks and notes from the deleted projects
     let mut tasks_updated = 0;
     for task in tasks.iter_mut().filter(|t| !t.is_deleted()) {
         if let Some(pid) = task.project_id
@@ -56,10 +56,8 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
 
     let mut notes_updated = 0;
     for note in notes.iter_mut().filter(|n| !n.is_deleted()) {
-        if let Some(pid) = note.project_id
-            && deleted_uuids.contains(&pid)
-        {
-            note.project_id = None;
+        if note.project_ids.iter().any(|p| deleted_uuids.contains(p)) {
+            note.project_ids.retain(|p| !deleted_uuids.contains(p));
             note.touch();
             notes_updated += 1;
         }
@@ -1,10 +1,13 @@
 //! Handler for `todo project done <ID>`.
+//!
+//! Completing a project does not touch its tasks, but warns when some of them
+//! (including tasks in sub-projects) are still open.
 
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::models::{Project, ProjectStatus};
 use crate::storage::{EntityType, EventType, Storage};
-use crate::utils::validation::resolve_visible_index;
 
 pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     execute_inner(storage, id, false)?;
@@ -18,12 +21,12 @@ pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
 fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<String> {
     let mut projects = storage.load_projects()?;
 
-    let real_index = resolve_visible_index(&projects, id, |p| p.is_deleted())
-        .map_err(|_| anyhow::anyhow!("Project #{} not found", id))?;
+    let real_index = Project::index_of_id(&projects, id)
+        .ok_or_else(|| anyhow::anyhow!("Project #{} not found", id))?;
 
     let project = &mut projects[real_index];
 
-    if project.completed {
+    if project.status == ProjectStatus::Done {
         let msg = format!("Project {} is already done.", format!("#{}", id).green());
         if !silent {
             println!("{}", msg);
@@ -36,17 +39,46 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
     storage.upsert_project(&projects[real_index])?;
     storage.record_event(EntityType::Project, project_uuid, EventType::Completed)?;
 
-    let msg = format!("Project {} marked as done.", format!("#{}", id).green());
+    let mut msg = format!("Project {} marked as done.", format!("#{}", id).green());
     if !silent {
         println!("{}", msg);
     }
+
+    let open = open_task_count(storage, &projects, project_uuid)?;
+    if open > 0 {
+        let warning = format!(
+            "{} task{} in this project {} still open.",
+            open,
+            if open == 1 { "" } else { "s" },
+            if open == 1 { "is" } else { "are" }
+        );
+        if !silent {
+            println!("{} {}", "⚠".yellow(), warning.yellow());
+        }
+        msg = format!("{} {}", msg, warning);
+    }
     Ok(msg)
 }
 
+/// Open tasks in the project `uuid` and its sub-projects.
+fn open_task_count(
+    storage: &impl Storage,
+    projects: &[Project],
+    uuid: uuid::Uuid,
+) -> Result<usize> {
+    let subtree = Project::subtree(projects, uuid);
+    Ok(storage
+        .load()?
+        .iter()
+        .filter(|t| !t.is_deleted() && t.is_open())
+        .filter(|t| t.project_id.is_some_and(|p| subtree.contains(&p)))
+        .count())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Project;
+    use crate::models::{Priority, Task};
     use crate::storage::InMemoryStorage;
 
     #[test]
@@ -100,4 +132,33 @@ mod tests {
         assert!(projects[0].completed);
         assert!(!projects[1].completed);
     }
+
+    #[test]
+    fn test_project_done_warns_about_open_tasks() {
+        let storage = InMemoryStorage::default();
+        let parent = Project::new("acme".into());
+        let mut child = Project::new("api".into());
+        child.parent_id = Some(parent.uuid);
+        let task = |text: &str, project: &Project| {
+            Task::new(
+                text.into(),
+                Priority::Medium,
+                vec![],
+                Some(project.uuid),
+                None,
+                None,
+            )
+        };
+        let mut finished = task("Finished", &parent);
+        finished.mark_done();
+        let tasks = vec![finished, task("Open", &parent), task("Nested", &child)];
+        storage.save(&tasks).unwrap();
+        storage.save_projects(&[parent, child]).unwrap();
+
+        let result = execute_silent(&storage, 1).unwrap();
+
+        assert!(result.contains("2 tasks in this project are still open"));
+        assert!(storage.load_projects().unwrap()[0].completed);
+        assert!(execute_silent(&storage, 2).unwrap().contains("1 task in"));
+    }
 }
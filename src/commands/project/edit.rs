@@ -1,18 +1,22 @@
 //! Handler for `todo project edit <ID>`.
+//!
+//! Status changes (`--status`, `--done`, `--undone`) are recorded in the
+//! event log, using the same event types as task transitions.
 
 use anyhow::Result;
 use colored::Colorize;
 
 use crate::cli::ProjectEditArgs;
-use crate::storage::Storage;
+use crate::models::{PATH_SEPARATOR, Project, ProjectStatus};
+use crate::render::formatting::{parse_color, project_status_colored};
+use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::date_parser;
-use crate::utils::validation::resolve_visible_index;
 
 pub fn execute(storage: &impl Storage, args: ProjectEditArgs) -> Result<()> {
     let mut projects = storage.load_projects()?;
 
-    let real_index = resolve_visible_index(&projects, args.id, |p| p.is_deleted())
-        .map_err(|_| anyhow::anyhow!("Project #{} not found", args.id))?;
+    let real_index = Project::index_of_id(&projects, args.id)
+        .ok_or_else(|| anyhow::anyhow!("Project #{} not found", args.id))?;
 
     let due = if let Some(ref due_str) = args.due {
         Some(date_parser::parse_date_not_in_past(due_str)?)
@@ -20,6 +24,35 @@ pub fn execute(storage: &impl Storage, args: ProjectEditArgs) -> Result<()> {
         None
     };
 
+    let start = match args.start {
+        Some(ref start_str) => Some(date_parser::parse_date(start_str)?),
+        None => None,
+    };
+
+    if let Some(ref color) = args.color
+        && parse_color(color).is_none()
+    {
+        return Err(anyhow::anyhow!(
+            "Unknown color '{}' (use a name such as blue or bright red, or #rrggbb)",
+            color
+        ));
+    }
+
+    let new_parent = match args.parent {
+        Some(ref path) => {
+            let parent = Project::find(&projects, path)
+                .ok_or_else(|| anyhow::anyhow!("Project \"{}\" not found", path))?;
+            if Project::subtree(&projects, projects[real_index].uuid).contains(&parent.uuid) {
+                return Err(anyhow::anyhow!(
+                    "Cannot nest project #{} under itself or one of its sub-projects",
+                    args.id
+                ));
+            }
+            Some((parent.uuid, parent.path(&projects)))
+        }
+        None => None,
+    };
+
     let project = &mut projects[real_index];
     let mut changes = Vec::new();
 
@@ -27,18 +60,35 @@ pub fn execute(storage: &impl Storage, args: ProjectEditArgs) -> Result<()> {
         if new_name.trim().is_empty() {
             return Err(anyhow::anyhow!("Project name cannot be empty"));
         }
+        if new_name.contains(PATH_SEPARATOR) {
+            return Err(anyhow::anyhow!(
+                "Project name cannot contain '{}' (use --parent to nest it)",
+                PATH_SEPARATOR
+            ));
+        }
         if &project.name != new_name {
             project.name = new_name.clone();
             changes.push(format!("name → {}", new_name.bright_white()));
         }
     }
 
-    if args.done && !project.completed {
-        project.mark_done();
-        changes.push(format!("status → {}", "done".green()));
-    } else if args.undone && project.completed {
-        project.mark_undone();
-        changes.push(format!("status → {}", "pending".yellow()));
+    let target = if args.done {
+        Some(ProjectStatus::Done)
+    } else if args.undone && !project.is_open() {
+        Some(ProjectStatus::Active)
+    } else {
+        args.status
+    };
+    let mut transition = None;
+    if let Some(target) = target
+        && project.status != target
+    {
+        transition = Some(transition_event(project.status, target));
+        project.set_status(target);
+        changes.push(format!(
+            "status → {}",
+            project_status_colored(target, false)
+        ));
     }
 
     if let Some(new_diff) = args.difficulty
@@ -108,6 +158,58 @@ pub fn execute(storage: &impl Storage, args: ProjectEditArgs) -> Result<()> {
         changes.push(format!("due date → {}", new_due.to_string().cyan()));
     }
 
+    if args.clear_parent {
+        if project.parent_id.is_some() {
+            project.parent_id = None;
+            changes.push("parent → cleared".dimmed().to_string());
+        }
+    } else if let Some((parent_uuid, parent_path)) = new_parent
+        && project.parent_id != Some(parent_uuid)
+    {
+        project.parent_id = Some(parent_uuid);
+        changes.push(format!("parent → {}", parent_path.cyan()));
+    }
+
+    if let Some(start) = start
+        && args.start.is_some()
+        && project.start_date != Some(start)
+    {
+        project.start_date = Some(start);
+        changes.push(format!("start date → {}", start.to_string().cyan()));
+    } else if args.clear_start && project.start_date.is_some() {
+        project.start_date = None;
+        changes.push("start date → cleared".dimmed().to_string());
+    }
+
+    edit_text(
+        &mut project.description,
+        args.description.as_deref(),
+        args.clear_description,
+        "description",
+        &mut changes,
+    );
+    edit_text(
+        &mut project.owner,
+        args.owner.as_deref(),
+        args.clear_owner,
+        "owner",
+        &mut changes,
+    );
+    edit_text(
+        &mut project.url,
+        args.url.as_deref(),
+        args.clear_url,
+        "url",
+        &mut changes,
+    );
+    edit_text(
+        &mut project.color,
+        args.color.as_deref(),
+        args.clear_color,
+        "color",
+        &mut changes,
+    );
+
     if changes.is_empty() {
         println!(
             "{} No changes made (values are already set to the specified values).",
@@ -116,8 +218,22 @@ pub fn execute(storage: &impl Storage, args: ProjectEditArgs) -> Result<()> {
         return Ok(());
     }
 
+    // Renaming or moving must not collide with a sibling, as in `project add`.
+    if args.name.is_some() || args.parent.is_some() || args.clear_parent {
+        let edited = &projects[real_index];
+        if Project::path_taken(&projects, edited.uuid) {
+            return Err(anyhow::anyhow!(
+                "Project \"{}\" already exists",
+                edited.path(&projects)
+            ));
+        }
+    }
+
     projects[real_index].touch();
     storage.upsert_project(&projects[real_index])?;
+    if let Some(event) = transition {
+        storage.record_event(EntityType::Project, projects[real_index].uuid, event)?;
+    }
 
     println!("{} Project #{} updated:", "✓".green(), args.id);
     for change in &changes {
@@ -127,6 +243,47 @@ pub fn execute(storage: &impl Storage, args: ProjectEditArgs) -> Result<()> {
     Ok(())
 }
 
+/// Event logged when a project moves from `from` to `to`.
+///
+/// Idea and planning have no dedicated event type and are logged as edits.
+fn transition_event(from: ProjectStatus, to: ProjectStatus) -> EventType {
+    match to {
+        ProjectStatus::Done => EventType::Completed,
+        ProjectStatus::Abandoned => EventType::Cancelled,
+        _ if from.is_closed() => EventType::Uncompleted,
+        ProjectStatus::Active => EventType::Started,
+        ProjectStatus::OnHold => EventType::Waiting,
+        ProjectStatus::Idea | ProjectStatus::Planning => EventType::Edited,
+    }
+}
+
+/// Applies `--<field>` or `--clear-<field>` to an optional text field.
+/// Blank values clear the field.
+fn edit_text(
+    field: &mut Option<String>,
+    value: Option<&str>,
+    clear: bool,
+    label: &str,
+    changes: &mut Vec<String>,
+) {
+    let new = if clear {
+        None
+    } else {
+        match value {
+            Some(v) => Some(v.trim()).filter(|v| !v.is_empty()).map(String::from),
+            None => return,
+        }
+    };
+    if *field == new {
+        return;
+    }
+    *field = new;
+    match field {
+        Some(v) => changes.push(format!("{} → {}", label, v.bright_white())),
+        None => changes.push(format!("{} → cleared", label).dimmed().to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,6 +302,19 @@ mod tests {
             clear_tech: false,
             due: None,
             clear_due: false,
+            parent: None,
+            clear_parent: false,
+            status: None,
+            description: None,
+            clear_description: false,
+            start: None,
+            clear_start: false,
+            owner: None,
+            clear_owner: false,
+            url: None,
+            clear_url: false,
+            color: None,
+            clear_color: false,
         }
     }
 
@@ -328,4 +498,225 @@ mod tests {
 
         assert!(execute(&storage, args(99)).is_err());
     }
+
+    #[test]
+    fn test_project_edit_parent_and_clear_parent() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("Acme".into()), Project::new("Api".into())])
+            .unwrap();
+
+        execute(
+            &storage,
+            ProjectEditArgs {
+                parent: Some("acme".into()),
+                ..args(2)
+            },
+        )
+        .unwrap();
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects[1].parent_id, Some(projects[0].uuid));
+
+        execute(
+            &storage,
+            ProjectEditArgs {
+                clear_parent: true,
+                ..args(2)
+            },
+        )
+        .unwrap();
+        assert!(storage.load_projects().unwrap()[1].parent_id.is_none());
+    }
+
+    #[test]
+    fn test_project_edit_parent_rejects_cycle() {
+        let storage = InMemoryStorage::default();
+        let acme = Project::new("Acme".into());
+        let mut api = Project::new("Api".into());
+        api.parent_id = Some(acme.uuid);
+        storage.save_projects(&[acme, api]).unwrap();
+
+        let err = execute(
+            &storage,
+            ProjectEditArgs {
+                parent: Some("Acme.Api".into()),
+                ..args(1)
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("under itself"));
+    }
+
+    #[test]
+    fn test_project_edit_rejects_duplicate_sibling() {
+        let storage = InMemoryStorage::default();
+        let acme = Project::new("Acme".into());
+        let mut api = Project::new("Api".into());
+        api.parent_id = Some(acme.uuid);
+        storage
+            .save_projects(&[
+                acme,
+                api,
+                Project::new("Web".into()),
+                Project::new("api".into()),
+            ])
+            .unwrap();
+
+        let err = execute(
+            &storage,
+            ProjectEditArgs {
+                name: Some("acme".into()),
+                ..args(3)
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let err = execute(
+            &storage,
+            ProjectEditArgs {
+                parent: Some("Acme".into()),
+                ..args(4)
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        let err = execute(
+            &storage,
+            ProjectEditArgs {
+                name: Some("Api".into()),
+                ..args(3)
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+
+        // Acme has no child called Web, so moving it there is fine.
+        execute(
+            &storage,
+            ProjectEditArgs {
+                parent: Some("Acme".into()),
+                ..args(3)
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_project_edit_name_with_separator_fails() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("Project".into())])
+            .unwrap();
+
+        assert!(
+            execute(
+                &storage,
+                ProjectEditArgs {
+                    name: Some("a.b".into()),
+                    ..args(1)
+                }
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_project_edit_status_records_transition_events() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("Project".into())])
+            .unwrap();
+        let status = |s| ProjectEditArgs {
+            status: Some(s),
+            ..args(1)
+        };
+
+        execute(&storage, status(ProjectStatus::OnHold)).unwrap();
+        let project = &storage.load_projects().unwrap()[0];
+        assert_eq!(project.status, ProjectStatus::OnHold);
+        assert!(!project.completed);
+
+        execute(&storage, status(ProjectStatus::Abandoned)).unwrap();
+        let project = &storage.load_projects().unwrap()[0];
+        assert!(!project.completed);
+        assert!(project.completed_at.is_some());
+
+        execute(&storage, status(ProjectStatus::Done)).unwrap();
+        assert!(storage.load_projects().unwrap()[0].completed);
+
+        let uuid = storage.load_projects().unwrap()[0].uuid;
+        let events: Vec<EventType> = storage
+            .load_events(EntityType::Project, &[uuid])
+            .unwrap()
+            .iter()
+            .map(|e| e.event_type)
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                EventType::Waiting,
+                EventType::Cancelled,
+                EventType::Completed
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_edit_metadata_and_clear() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("Project".into())])
+            .unwrap();
+
+        execute(
+            &storage,
+            ProjectEditArgs {
+                description: Some("  Public API  ".into()),
+                start: Some("2026-01-05".into()),
+                owner: Some("sam".into()),
+                url: Some("https://example.com".into()),
+                color: Some("#ff8800".into()),
+                ..args(1)
+            },
+        )
+        .unwrap();
+        let project = &storage.load_projects().unwrap()[0];
+        assert_eq!(project.description.as_deref(), Some("Public API"));
+        assert_eq!(project.start_date.unwrap().to_string(), "2026-01-05");
+        assert_eq!(project.owner.as_deref(), Some("sam"));
+        assert_eq!(project.color.as_deref(), Some("#ff8800"));
+
+        execute(
+            &storage,
+            ProjectEditArgs {
+                clear_owner: true,
+                clear_start: true,
+                ..args(1)
+            },
+        )
+        .unwrap();
+        let project = &storage.load_projects().unwrap()[0];
+        assert!(project.owner.is_none());
+        assert!(project.start_date.is_none());
+        assert_eq!(project.url.as_deref(), Some("https://example.com"));
+    }
+
+    #[test]
+    fn test_project_edit_rejects_unknown_color() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("Project".into())])
+            .unwrap();
+
+        let result = execute(
+            &storage,
+            ProjectEditArgs {
+                color: Some("plaid".into()),
+                ..args(1)
+            },
+        );
+        assert!(result.is_err());
+    }
 }
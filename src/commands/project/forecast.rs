@@ -0,0 +1,259 @@
+//! Handler for `todo project forecast <ID>`.
+//!
+//! Charts a project's open tasks week by week and projects a finish date from
+//! recent throughput. Tasks in sub-projects count towards the parent. See
+//! [`crate::services::forecast`] for how the numbers are derived.
+
+use anyhow::Result;
+use chrono::{Local, NaiveDate};
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::models::{Project, Task};
+use crate::services::forecast::{Forecast, MAX_WEEKS, Outlook, forecast};
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible;
+
+const BAR_WIDTH: usize = 24;
+
+pub fn execute(storage: &impl Storage, id: usize, weeks: usize) -> Result<()> {
+    if weeks == 0 {
+        return Err(anyhow::anyhow!("--weeks must be at least 1"));
+    }
+    if weeks > MAX_WEEKS {
+        return Err(anyhow::anyhow!("--weeks must be at most {}", MAX_WEEKS));
+    }
+
+    let (tasks, projects, _) = storage.load_all()?;
+    let visible_projects = Project::tree_order(&projects);
+    let project = resolve_visible(&visible_projects, id, |p| p.is_deleted())
+        .map_err(|_| anyhow::anyhow!("Project #{} not found", id))?;
+
+    let f = project_forecast(storage, &tasks, &projects, project.uuid, weeks)?;
+
+    // ── Header ────────────────────────────────────────────────────────────────
+    println!();
+    println!(
+        "  {}",
+        format!("Forecast for project #{}: {}", id, project.path(&projects))
+            .bold()
+            .cyan()
+    );
+    println!("  {}", "─".repeat(50).dimmed());
+    println!(
+        "  {}        {}",
+        "Open".dimmed(),
+        pluralize(f.remaining, "task")
+    );
+    println!(
+        "  {}  {:.1} / week  {}",
+        "Throughput".dimmed(),
+        f.throughput,
+        format!("(±{:.1}, last {} weeks)", f.spread, weeks).dimmed()
+    );
+    if let Some(due) = project.due_date {
+        println!("  {}         {}", "Due".dimmed(), due);
+    }
+
+    // ── Burndown ──────────────────────────────────────────────────────────────
+    println!();
+    println!("  {}", "Week ending   Open".dimmed());
+    let max = f
+        .weeks
+        .iter()
+        .map(|w| w.remaining)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for week in &f.weeks {
+        let filled = week.remaining * BAR_WIDTH / max;
+        let done = if week.completed > 0 {
+            format!("✓{}", week.completed).green().to_string()
+        } else {
+            String::new()
+        };
+        println!(
+            "    {}  {}{}  {:>3}  {}",
+            week.end.format("%Y-%m-%d").to_string().dimmed(),
+            "█".repeat(filled).cyan(),
+            "░".repeat(BAR_WIDTH - filled).dimmed(),
+            week.remaining,
+            done
+        );
+    }
+
+    // ── Forecast ──────────────────────────────────────────────────────────────
+    println!();
+    match f.estimate {
+        Some(ref estimate) => println!(
+            "  {}      ~{}  {}",
+            "Finish".dimmed(),
+            estimate.expected.to_string().bold(),
+            range(estimate.earliest, estimate.latest).dimmed()
+        ),
+        None if f.remaining == 0 => {
+            println!("  {}      {}", "Finish".dimmed(), "all tasks done".green())
+        }
+        None => println!(
+            "  {}      {}",
+            "Finish".dimmed(),
+            format!("no tasks completed in the last {} weeks", weeks).dimmed()
+        ),
+    }
+
+    if let Some(due) = project.due_date
+        && f.remaining > 0
+    {
+        match f.outlook(due) {
+            Some(Outlook::OnTrack) => {
+                println!("  {} On track to finish by the due date.", "✓".green())
+            }
+            Some(Outlook::AtRisk) => println!(
+                "  {} At risk: the slowest estimate finishes after the due date.",
+                "!".yellow()
+            ),
+            Some(Outlook::Late) => {
+                let expected = f.estimate.as_ref().map_or(due, |e| e.expected);
+                println!(
+                    "  {} On track to be late: expected {} after the due date.",
+                    "⚠".red(),
+                    pluralize((expected - due).num_days() as usize, "day")
+                );
+            }
+            None => println!(
+                "  {} Not enough recent throughput to compare with the due date.",
+                "!".yellow()
+            ),
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+/// Forecast for the project `uuid` and its sub-projects, as of today.
+pub(crate) fn project_forecast(
+    storage: &impl Storage,
+    tasks: &[Task],
+    projects: &[Project],
+    uuid: Uuid,
+    weeks: usize,
+) -> Result<Forecast> {
+    let subtree = Project::subtree(projects, uuid);
+    let members: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| t.project_id.is_some_and(|p| subtree.contains(&p)))
+        .collect();
+    let uuids: Vec<Uuid> = members.iter().map(|t| t.uuid).collect();
+    let events = storage.load_task_events(&uuids)?;
+    Ok(forecast(
+        &members,
+        &events,
+        weeks,
+        Local::now().date_naive(),
+    ))
+}
+
+/// One-line summary used by `todo project show`.
+pub(crate) fn summary(f: &Forecast, due: Option<NaiveDate>, weeks: usize) -> String {
+    let Some(ref estimate) = f.estimate else {
+        return if f.remaining == 0 {
+            "all tasks done".green().to_string()
+        } else {
+            format!("no tasks completed in the last {} weeks", weeks)
+                .dimmed()
+                .to_string()
+        };
+    };
+
+    let outlook = match due.and_then(|d| f.outlook(d)) {
+        Some(Outlook::OnTrack) => format!("  {}", "on track".green()),
+        Some(Outlook::AtRisk) => format!("  {}", "at risk".yellow()),
+        Some(Outlook::Late) => {
+            let late_by = (estimate.expected - due.unwrap_or(estimate.expected)).num_days();
+            format!(
+                "  {}",
+                format!("late by {}", pluralize(late_by as usize, "day")).red()
+            )
+        }
+        None => String::new(),
+    };
+    format!(
+        "~{}  {}{}",
+        estimate.expected,
+        range(estimate.earliest, estimate.latest).dimmed(),
+        outlook
+    )
+}
+
+fn range(earliest: NaiveDate, latest: Option<NaiveDate>) -> String {
+    match latest {
+        Some(latest) => format!("({} – {})", earliest, latest),
+        None => format!("({} – ?)", earliest),
+    }
+}
+
+fn pluralize(n: usize, word: &str) -> String {
+    if n == 1 {
+        format!("{} {}", n, word)
+    } else {
+        format!("{} {}s", n, word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::{EntityType, EventType, InMemoryStorage};
+
+    #[test]
+    fn test_forecast_counts_sub_project_tasks() {
+        let storage = InMemoryStorage::default();
+        let parent = Project::new("acme".into());
+        let mut child = Project::new("api".into());
+        child.parent_id = Some(parent.uuid);
+        let mut done = Task::new(
+            "Done".into(),
+            Priority::Medium,
+            vec![],
+            Some(child.uuid),
+            None,
+            None,
+        );
+        done.mark_done();
+        let open = Task::new(
+            "Open".into(),
+            Priority::Medium,
+            vec![],
+            Some(parent.uuid),
+            None,
+            None,
+        );
+        storage
+            .record_event(EntityType::Task, done.uuid, EventType::Completed)
+            .unwrap();
+        storage.save(&[done, open]).unwrap();
+        storage.save_projects(&[parent, child]).unwrap();
+
+        let (tasks, projects, _) = storage.load_all().unwrap();
+        let f = project_forecast(&storage, &tasks, &projects, projects[0].uuid, 4).unwrap();
+
+        assert_eq!(f.remaining, 1);
+        assert_eq!(f.weeks.last().unwrap().completed, 1);
+        assert!(f.estimate.is_some());
+        assert!(execute(&storage, 1, 4).is_ok());
+    }
+
+    #[test]
+    fn test_forecast_rejects_out_of_range_weeks_and_unknown_id() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("acme".into())])
+            .unwrap();
+        assert!(execute(&storage, 1, 0).is_err());
+        assert!(execute(&storage, 1, MAX_WEEKS + 1).is_err());
+        assert!(execute(&storage, 1, 100_000_000).is_err());
+        assert!(execute(&storage, 2, 4).is_err());
+    }
+}
@@ -1,22 +1,37 @@
 //! Handler for `todo project list`.
+//!
+//! `--status` narrows the list to some lifecycle states and `--group` prints
+//! one table per state. Projects keep the IDs they have in the full list.
 
 use anyhow::Result;
 
 use crate::error::TodoError;
-use crate::render::display_projects;
+use crate::models::{Project, ProjectStatus};
+use crate::render::project_table::{display_project_rows, filter_by_status, group_by_status};
 use crate::storage::Storage;
 
-pub fn execute(storage: &impl Storage) -> Result<()> {
+pub fn execute(storage: &impl Storage, statuses: &[ProjectStatus], group: bool) -> Result<()> {
     let (tasks, projects, notes) = storage.load_all()?;
 
-    let mut visible: Vec<_> = projects.iter().filter(|p| !p.is_deleted()).collect();
+    let visible = Project::tree_order(&projects);
+    let rows: Vec<(usize, &Project)> = visible
+        .into_iter()
+        .enumerate()
+        .map(|(i, p)| (i + 1, p))
+        .collect();
+    let rows = filter_by_status(&rows, statuses);
 
-    if visible.is_empty() {
+    if rows.is_empty() {
         return Err(TodoError::NoProjectsFound.into());
     }
 
-    visible.sort_by(|a, b| a.name.cmp(&b.name));
-
-    display_projects(&visible, &tasks, &notes);
+    if group {
+        for (status, group) in group_by_status(&rows) {
+            let title = format!("{}  ({})", status.label(), group.len());
+            display_project_rows(&group, &title, &projects, &tasks, &notes);
+        }
+    } else {
+        display_project_rows(&rows, "Projects", &projects, &tasks, &notes);
+    }
     Ok(())
 }
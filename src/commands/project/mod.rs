@@ -2,6 +2,7 @@ pub mod add;
 pub mod clear;
 pub mod done;
 pub mod edit;
+pub mod forecast;
 pub mod list;
 pub mod remove;
 pub mod show;
@@ -3,8 +3,8 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::models::Project;
 use crate::storage::{EntityType, EventType, Storage};
-use crate::utils::validation::resolve_visible_index;
 
 pub fn execute(storage: &impl Storage, id: usize, yes: bool) -> Result<()> {
     execute_inner(storage, id, yes, false)?;
@@ -18,12 +18,35 @@ pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
 fn execute_inner(storage: &impl Storage, id: usize, yes: bool, silent: bool) -> Result<String> {
     let (mut tasks, mut projects, mut notes) = storage.load_all()?;
 
-    let real_index = resolve_visible_index(&projects, id, |p| p.is_deleted())
-        .map_err(|_| anyhow::anyhow!("Project #{} not found", id))?;
+    let real_index = Project::index_of_id(&projects, id)
+        .ok_or_else(|| anyhow::anyhow!("Project #{} not found", id))?;
 
     let project_uuid = projects[real_index].uuid;
     let name = projects[real_index].name.clone();
 
+    let grandparent = projects[real_index].parent_id;
+    projects[real_index].soft_delete();
+
+    // Sub-projects move up a level instead of losing their place in the tree,
+    // unless one would then share its path with a project already there.
+    let children: Vec<usize> = (0..projects.len())
+        .filter(|&i| !projects[i].is_deleted() && projects[i].parent_id == Some(project_uuid))
+        .collect();
+    for &i in &children {
+        projects[i].parent_id = grandparent;
+        projects[i].touch();
+    }
+    if let Some(&i) = children
+        .iter()
+        .find(|&&i| Project::path_taken(&projects, projects[i].uuid))
+    {
+        return Err(anyhow::anyhow!(
+            "Cannot remove project #{}: its sub-project would become \"{}\", which already exists (rename one first)",
+            id,
+            projects[i].path(&projects)
+        ));
+    }
+
     if !yes && !silent {
         println!(
             "{} Remove project #{}: {}? [y/N] ",
@@ -39,8 +62,6 @@ fn execute_inner(storage: &impl Storage, id: usize, yes: bool, silent: bool) ->
         }
     }
 
-    projects[real_index].soft_delete();
-
     for task in tasks.iter_mut().filter(|t| !t.is_deleted()) {
         if task.project_id == Some(project_uuid) {
             task.project_id = None;
@@ -48,8 +69,8 @@ fn execute_inner(storage: &impl Storage, id: usize, yes: bool, silent: bool) ->
         }
     }
     for note in notes.iter_mut().filter(|n| !n.is_deleted()) {
-        if note.project_id == Some(project_uuid) {
-            note.project_id = None;
+        if note.belongs_to_project(project_uuid) {
+            note.remove_project(project_uuid);
             note.touch();
         }
     }
@@ -134,11 +155,70 @@ mod tests {
         let uuid = p.uuid;
         storage.save_projects(&[p]).unwrap();
         let mut note = Note::new("Body".into());
-        note.project_id = Some(uuid);
+        note.project_ids = vec![uuid];
         storage.save_notes(&[note]).unwrap();
 
         execute_silent(&storage, 1).unwrap();
 
-        assert!(storage.load_notes().unwrap()[0].project_id.is_none());
+        assert!(storage.load_notes().unwrap()[0].project_ids.is_empty());
+    }
+
+    #[test]
+    fn test_project_remove_moves_children_up() {
+        let storage = InMemoryStorage::default();
+        let acme = make_project("Acme");
+        let mut api = make_project("Api");
+        api.parent_id = Some(acme.uuid);
+        let mut auth = make_project("Auth");
+        auth.parent_id = Some(api.uuid);
+        storage.save_projects(&[acme.clone(), api, auth]).unwrap();
+
+        execute_silent(&storage, 2).unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects[2].parent_id, Some(acme.uuid));
+        assert_eq!(projects[2].path(&projects), "Acme.Auth");
+    }
+
+    #[test]
+    fn test_project_remove_uses_list_numbering() {
+        let storage = InMemoryStorage::default();
+        let acme = make_project("Acme");
+        let mut web = make_project("Web");
+        web.parent_id = Some(acme.uuid);
+        let mut api = make_project("Api");
+        api.parent_id = Some(acme.uuid);
+        storage.save_projects(&[acme, web, api]).unwrap();
+
+        // `project list` shows #1 Acme, #2 Api, #3 Web.
+        execute_silent(&storage, 2).unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        assert!(projects[2].is_deleted());
+        assert!(!projects[1].is_deleted());
+    }
+
+    #[test]
+    fn test_project_remove_refuses_to_create_duplicate_siblings() {
+        let storage = InMemoryStorage::default();
+        let acme = make_project("Acme");
+        let mut auth = make_project("auth");
+        auth.parent_id = Some(acme.uuid);
+        let mut api = make_project("api");
+        api.parent_id = Some(acme.uuid);
+        let mut api_auth = make_project("Auth");
+        api_auth.parent_id = Some(api.uuid);
+        storage.save_projects(&[acme, auth, api, api_auth]).unwrap();
+
+        // #2 is api; removing it would leave two acme.auth projects.
+        let err = execute_silent(&storage, 2).unwrap_err();
+        assert!(err.to_string().contains("Acme.Auth"));
+        assert!(
+            storage
+                .load_projects()
+                .unwrap()
+                .iter()
+                .all(|p| !p.is_deleted())
+        );
     }
 }
@@ -3,22 +3,35 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::models::count_by_project;
-use crate::render::formatting::{note_preview, truncate};
+use super::forecast;
+use crate::models::{Project, ProjectStatus, count_by_project, count_by_project_tree};
+use crate::render::formatting::{
+    note_preview, parse_color, project_status_colored, status_colored, truncate,
+};
+use crate::services::forecast::DEFAULT_WEEKS;
 use crate::storage::Storage;
 use crate::utils::validation::resolve_visible;
 
 pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     let (tasks, projects, notes) = storage.load_all()?;
 
-    let mut visible_projects: Vec<_> = projects.iter().filter(|p| !p.is_deleted()).collect();
-    visible_projects.sort_by(|a, b| a.name.cmp(&b.name));
+    let visible_projects = Project::tree_order(&projects);
 
     let project = resolve_visible(&visible_projects, id, |p| p.is_deleted())
         .map_err(|_| anyhow::anyhow!("Project #{} not found", id))?;
 
     let (total, done) = count_by_project(&tasks, project.uuid);
 
+    let sub_projects: Vec<(usize, &Project)> = visible_projects
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| {
+            p.parent(&projects)
+                .is_some_and(|parent| parent.uuid == project.uuid)
+        })
+        .map(|(i, p)| (i + 1, *p))
+        .collect();
+
     let all_visible: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
 
     let visible_tasks: Vec<_> = all_visible
@@ -28,7 +41,7 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
 
     let blocked = visible_tasks
         .iter()
-        .filter(|t| !t.completed && t.is_blocked(&all_visible))
+        .filter(|t| t.is_open() && t.is_blocked(&all_visible))
         .count();
     let pending = total - done - blocked;
 
@@ -36,26 +49,56 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     let project_notes: Vec<(usize, _)> = visible_notes
         .iter()
         .enumerate()
-        .filter(|(_, n)| n.project_id == Some(project.uuid))
+        .filter(|(_, n)| n.belongs_to_project(project.uuid))
         .map(|(i, n)| (i + 1, n))
         .collect();
 
-    let status_label = if project.completed {
-        "done".green()
-    } else {
-        "pending".yellow()
-    };
-
     // ── Header ────────────────────────────────────────────────────────────────
     println!();
     println!(
         "  {}",
-        format!("Project #{}: {}", id, project.name).bold().cyan()
+        format!("Project #{}: {}", id, project.path(&projects))
+            .bold()
+            .cyan()
     );
     println!("  {}", "─".repeat(50).dimmed());
 
     // ── Details ───────────────────────────────────────────────────────────────
-    println!("  {}  {}", "Status".dimmed(), status_label);
+    println!(
+        "  {}  {}",
+        "Status".dimmed(),
+        project_status_colored(project.status, false)
+    );
+    if let Some(ref description) = project.description {
+        println!("  {}  {}", "Description".dimmed(), description);
+    }
+    if let Some(ref owner) = project.owner {
+        println!("  {}  {}", "Owner".dimmed(), owner.bright_white());
+    }
+    if let Some(ref url) = project.url {
+        println!("  {}  {}", "URL".dimmed(), url.underline());
+    }
+    if let Some(ref color) = project.color {
+        let swatch = match parse_color(color) {
+            Some(c) => "■".color(c).to_string(),
+            None => String::new(),
+        };
+        println!("  {}  {} {}", "Color".dimmed(), swatch, color);
+    }
+    if let Some(parent) = project.parent(&projects) {
+        println!("  {}  {}", "Parent".dimmed(), parent.path(&projects));
+    }
+    if !sub_projects.is_empty() {
+        let (tree_total, tree_done) = count_by_project_tree(&tasks, &projects, project.uuid);
+        let percent = (tree_done * 100).checked_div(tree_total).unwrap_or(0);
+        println!(
+            "  {}  {}/{} done ({}%) including sub-projects",
+            "Progress".dimmed(),
+            tree_done,
+            tree_total,
+            percent
+        );
+    }
     println!(
         "  {}  {}",
         "Difficulty".dimmed(),
@@ -79,8 +122,25 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         println!("  {}  {}{}", "Due".dimmed(), due, overdue);
     }
 
+    if let Some(start) = project.start_date {
+        println!("  {}  {}", "Started".dimmed(), start);
+    }
+
     if let Some(completed_at) = project.completed_at {
-        println!("  {}  {}", "Completed".dimmed(), completed_at);
+        let label = if project.status == ProjectStatus::Abandoned {
+            "Abandoned"
+        } else {
+            "Completed"
+        };
+        println!("  {}  {}", label.dimmed(), completed_at);
+    } else {
+        let f =
+            forecast::project_forecast(storage, &tasks, &projects, project.uuid, DEFAULT_WEEKS)?;
+        println!(
+            "  {}  {}",
+            "Forecast".dimmed(),
+            forecast::summary(&f, project.due_date, DEFAULT_WEEKS)
+        );
     }
 
     // ── Tasks ─────────────────────────────────────────────────────────────────
@@ -112,18 +172,12 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
                 .map(|i| i + 1)
                 .unwrap_or(0);
 
-            let is_blocked = !task.completed && task.is_blocked(&all_visible);
+            let is_blocked = task.is_open() && task.is_blocked(&all_visible);
 
-            let status = if task.completed {
-                "D".green()
-            } else if is_blocked {
-                "B".red()
-            } else {
-                "P".yellow()
-            };
+            let status = status_colored(task, is_blocked, true);
 
             let text = truncate(&task.text, 40);
-            let text_colored = if task.completed {
+            let text_colored = if !task.is_open() {
                 text.dimmed()
             } else if is_blocked {
                 text.truecolor(150, 150, 150)
@@ -140,6 +194,21 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         }
     }
 
+    // ── Sub-projects ──────────────────────────────────────────────────────────
+    if !sub_projects.is_empty() {
+        println!();
+        println!("  {}", "Sub-projects".dimmed());
+        for (sub_id, sub) in &sub_projects {
+            let (sub_total, sub_done) = count_by_project_tree(&tasks, &projects, sub.uuid);
+            println!(
+                "    {}  {}  {}",
+                format!("#{}", sub_id).dimmed(),
+                sub.name.bright_white(),
+                format!("{}/{}", sub_done, sub_total).dimmed()
+            );
+        }
+    }
+
     // ── Notes ─────────────────────────────────────────────────────────────────
     if !project_notes.is_empty() {
         println!();
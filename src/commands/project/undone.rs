@@ -3,8 +3,8 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::storage::Storage;
-use crate::utils::validation::resolve_visible_index;
+use crate::models::Project;
+use crate::storage::{EntityType, EventType, Storage};
 
 pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     execute_inner(storage, id, false)?;
@@ -19,15 +19,16 @@ pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
 fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<String> {
     let mut projects = storage.load_projects()?;
 
-    let real_index = resolve_visible_index(&projects, id, |p| p.is_deleted())
-        .map_err(|_| anyhow::anyhow!("Project #{} not found", id))?;
+    let real_index = Project::index_of_id(&projects, id)
+        .ok_or_else(|| anyhow::anyhow!("Project #{} not found", id))?;
 
     let project = &mut projects[real_index];
 
-    if !project.completed {
+    if project.is_open() {
         let msg = format!(
-            "Project {} is already pending.",
-            format!("#{}", id).yellow()
+            "Project {} is already {}.",
+            format!("#{}", id).yellow(),
+            project.status
         );
         if !silent {
             println!("{}", msg);
@@ -35,10 +36,12 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
         return Ok(msg);
     }
 
+    let project_uuid = project.uuid;
     project.mark_undone();
     storage.upsert_project(&projects[real_index])?;
+    storage.record_event(EntityType::Project, project_uuid, EventType::Uncompleted)?;
 
-    let msg = format!("Project {} marked as pending.", format!("#{}", id).yellow());
+    let msg = format!("Project {} marked as active.", format!("#{}", id).yellow());
     if !silent {
         println!("{}", msg);
     }
@@ -90,6 +93,6 @@ mod tests {
             .unwrap();
 
         let result = execute_silent(&storage, 1).unwrap();
-        assert!(result.contains("already pending"));
+        assert!(result.contains("already active"));
     }
 }
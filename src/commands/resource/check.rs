@@ -0,0 +1,156 @@
+//! Handler for `todo resource check [ID]`.
+//!
+//! Requests every resource URL (see [`crate::services::link_check`]), stores
+//! the outcome on the resource and reports dead and redirected links. Links
+//! checked in the last [`RECHECK_AFTER_DAYS`] days are skipped unless `--all`
+//! or an ID is given; `--update-urls` replaces redirected URLs with their
+//! target.
+
+use anyhow::{Result, anyhow};
+use chrono::{Duration, Utc};
+use colored::Colorize;
+
+use crate::cli::ResourceCheckArgs;
+use crate::services::link_check::LinkChecker;
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible_index;
+
+/// How long a link check counts as recent.
+pub const RECHECK_AFTER_DAYS: i64 = 7;
+
+pub fn execute(storage: &impl Storage, args: ResourceCheckArgs) -> Result<()> {
+    let mut resources = storage.load_resources()?;
+
+    // (visible ID, index into `resources`)
+    let selected: Vec<(usize, usize)> = if let Some(id) = args.id {
+        let index = resolve_visible_index(&resources, id, |r| r.is_deleted())
+            .map_err(|_| anyhow!("Resource #{} not found", id))?;
+        if resources[index].web_url().is_none() {
+            return Err(anyhow!("Resource #{} has no http(s) URL to check", id));
+        }
+        vec![(id, index)]
+    } else {
+        let recent = Utc::now() - Duration::days(RECHECK_AFTER_DAYS);
+        resources
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| !r.is_deleted())
+            .enumerate()
+            .filter(|(_, (_, r))| {
+                r.web_url().is_some()
+                    && (args.all || r.last_check.as_ref().is_none_or(|c| c.checked_at < recent))
+            })
+            .map(|(i, (index, _))| (i + 1, index))
+            .collect()
+    };
+
+    if selected.is_empty() {
+        println!(
+            "{} No links to check (links checked in the last {} days are skipped without --all).",
+            "".blue(),
+            RECHECK_AFTER_DAYS
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{} Checking {} link{}…",
+        "".blue(),
+        selected.len(),
+        if selected.len() == 1 { "" } else { "s" }
+    );
+
+    let checker = LinkChecker::default();
+    let mut dead = Vec::new();
+    let mut redirected = Vec::new();
+    for &(id, index) in &selected {
+        let resource = &mut resources[index];
+        let Some(url) = resource.web_url().map(str::to_string) else {
+            continue;
+        };
+        let check = checker.check(&url);
+        if check.is_dead() {
+            dead.push((id, index));
+        } else if check.redirect().is_some() {
+            redirected.push((id, index));
+        }
+        resource.last_check = Some(check);
+    }
+
+    // ── report ────────────────────────────────────────────────────────────────
+    if !dead.is_empty() {
+        println!("\n  {}", "Dead".red().bold());
+        for &(id, index) in &dead {
+            let resource = &resources[index];
+            let summary = resource
+                .last_check
+                .as_ref()
+                .map(|c| c.summary())
+                .unwrap_or_default();
+            println!(
+                "  {} {}  {}",
+                format!("#{:<3}", id).dimmed(),
+                resource.title.bright_white(),
+                summary.red()
+            );
+            println!(
+                "        {}",
+                resource.url.as_deref().unwrap_or_default().dimmed()
+            );
+        }
+    }
+
+    if !redirected.is_empty() {
+        println!("\n  {}", "Redirected".yellow().bold());
+        for &(id, index) in &redirected {
+            let resource = &mut resources[index];
+            let Some(check) = resource.last_check.as_mut() else {
+                continue;
+            };
+            let target = check.final_url.clone().unwrap_or_default();
+            println!(
+                "  {} {}",
+                format!("#{:<3}", id).dimmed(),
+                resource.title.bright_white()
+            );
+            println!(
+                "        {}\n      → {}",
+                resource.url.as_deref().unwrap_or_default().dimmed(),
+                target.cyan()
+            );
+            if args.update_urls {
+                check.final_url = None;
+                resource.url = Some(target);
+                resource.touch();
+            }
+        }
+    }
+
+    storage.save_resources(&resources)?;
+
+    let ok = selected.len() - dead.len() - redirected.len();
+    println!(
+        "\n  {} ok · {} redirected · {} dead",
+        ok.to_string().green(),
+        redirected.len().to_string().yellow(),
+        dead.len().to_string().red()
+    );
+    if !redirected.is_empty() {
+        if args.update_urls {
+            println!(
+                "{} Updated {} URL{} to the redirect target.",
+                "✓".green(),
+                redirected.len(),
+                if redirected.len() == 1 { "" } else { "s" }
+            );
+        } else {
+            println!(
+                "  {}",
+                "Run with --update-urls to point redirected resources at their target.".dimmed()
+            );
+        }
+    }
+    println!();
+
+    Ok(())
+}
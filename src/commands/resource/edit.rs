@@ -47,12 +47,14 @@ pub fn execute(storage: &impl Storage, args: ResourceEditArgs) -> Result<()> {
     if args.clear_url {
         if resource.url.is_some() {
             resource.url = None;
+            resource.last_check = None;
             changes.push("url → cleared".dimmed().to_string());
         }
     } else if let Some(new_url) = args.url
         && resource.url.as_deref() != Some(new_url.as_str())
     {
         resource.url = Some(new_url.clone());
+        resource.last_check = None;
         changes.push(format!("url → {}", new_url.cyan()));
     }
 
@@ -1,4 +1,5 @@
 pub mod add;
+pub mod check;
 pub mod clear;
 pub mod edit;
 pub mod list;
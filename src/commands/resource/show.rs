@@ -48,6 +48,22 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         println!("  {}  {}", "URL".dimmed(), url.cyan().underline());
     }
 
+    if let Some(ref check) = resource.last_check {
+        let outcome = if check.is_dead() {
+            format!("dead ({})", check.summary()).red()
+        } else if let Some(target) = check.redirect() {
+            format!("{} → {}", check.summary(), target).yellow()
+        } else {
+            check.summary().green()
+        };
+        println!(
+            "  {}  {}  {}",
+            "Checked".dimmed(),
+            check.checked_at.format("%Y-%m-%d"),
+            outcome
+        );
+    }
+
     if let Some(ref desc) = resource.description {
         println!();
         for line in desc.lines() {
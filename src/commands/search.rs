@@ -4,20 +4,26 @@
 //! Searches across: task text, note title/body, project name/tech, resource title/url/description.
 //!
 //! Filter behaviour:
-//! - `--project` filters tasks, notes, and projects (resources have no project_id).
+//! - `--project` filters tasks, notes, and projects (resources have no project_id),
+//!   including everything in the project's sub-projects.
 //! - `--tag`     filters tasks, notes, and resources (projects have no tags — hidden when --tag is passed).
 //! - `--status`  filters tasks only.
+//! - `--filter`  filters tasks only, using the query language from
+//!   [`crate::services::query`]. With an empty QUERY only tasks are searched.
+
+use std::collections::HashSet;
 
 use anyhow::Result;
 use colored::Colorize;
 use uuid::Uuid;
 
 use crate::error::TodoError;
-use crate::models::StatusFilter;
+use crate::models::{Project, StatusFilter};
 use crate::render::display_lists;
 use crate::render::note_table::display_notes;
 use crate::render::project_table::display_projects;
 use crate::render::resource_table::display_resources;
+use crate::services::query::{Query, QueryContext};
 use crate::storage::Storage;
 
 pub fn execute(
@@ -26,43 +32,69 @@ pub fn execute(
     tags: Vec<String>,
     project: Option<String>,
     status: StatusFilter,
+    filter: Option<String>,
 ) -> Result<()> {
-    // ── Resolve project UUID ───────────────────────────────────────────────────
-    let proj_uuid: Option<Uuid> = if let Some(ref project_name) = project {
-        let projects = storage.load_projects()?;
-        let uuid = projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == project_name.to_lowercase() && !p.is_deleted())
-            .map(|p| p.uuid);
+    let filter = filter.as_deref().map(Query::parse).transpose()?;
+    // `todo search --filter EXPR` with no text is a pure task query.
+    let tasks_only = filter.is_some() && query.trim().is_empty();
+    let label = match filter {
+        Some(ref f) if tasks_only => f.source().to_string(),
+        _ => query.clone(),
+    };
 
-        if uuid.is_none() {
-            return Err(TodoError::ProjectNotFound(project_name.clone()).into());
-        }
-        uuid
+    // ── Resolve project and its sub-projects ──────────────────────────────────
+    let subtree: Option<HashSet<Uuid>> = if let Some(ref project_name) = project {
+        let projects = storage.load_projects()?;
+        let root = Project::find(&projects, project_name)
+            .ok_or_else(|| TodoError::ProjectNotFound(project_name.clone()))?;
+        Some(Project::subtree(&projects, root.uuid))
     } else {
         None
     };
+    let in_scope = |project_id: Option<Uuid>| match subtree {
+        Some(ref set) => project_id.is_some_and(|u| set.contains(&u)),
+        None => true,
+    };
 
     // ── Search each entity via storage (SQLite uses WHERE LIKE) ───────────────
-    let task_results = storage.search_tasks(&query, &tags, proj_uuid, status)?;
-    let note_results = storage.search_notes(&query, &tags, proj_uuid)?;
-    let project_results = if tags.is_empty() {
+    let mut task_results = storage.search_tasks(&query, &tags, None, status)?;
+    task_results.retain(|t| in_scope(t.project_id));
+    if let Some(ref filter) = filter {
+        let (all_tasks, all_projects, _) = storage.load_all()?;
+        let ctx = QueryContext {
+            tasks: &all_tasks,
+            projects: &all_projects,
+        };
+        task_results.retain(|t| filter.matches(t, &ctx));
+    }
+    let note_results = if tasks_only {
+        vec![]
+    } else {
+        let mut notes = storage.search_notes(&query, &tags, None)?;
+        notes.retain(|n| subtree.is_none() || n.project_ids.iter().any(|&p| in_scope(Some(p))));
+        notes
+    };
+    let project_results = if tags.is_empty() && !tasks_only {
         storage
             .search_projects(&query)?
             .into_iter()
-            .filter(|p| proj_uuid.is_none_or(|uuid| p.uuid == uuid))
+            .filter(|p| in_scope(Some(p.uuid)))
             .collect()
     } else {
         vec![]
     };
-    let resource_results = storage.search_resources(&query, &tags)?;
+    let resource_results = if tasks_only {
+        vec![]
+    } else {
+        storage.search_resources(&query, &tags)?
+    };
 
     if task_results.is_empty()
         && note_results.is_empty()
         && project_results.is_empty()
         && resource_results.is_empty()
     {
-        return Err(TodoError::NoSearchResults(query).into());
+        return Err(TodoError::NoSearchResults(label).into());
     }
 
     // ── Render ────────────────────────────────────────────────────────────────
@@ -71,7 +103,7 @@ pub fn execute(
 
     println!(
         "\nSearch results for \"{}\"  ({})\n",
-        query,
+        label,
         format!("{} found", found_total).dimmed()
     );
 
@@ -110,6 +142,7 @@ pub fn execute(
         if !project_results.is_empty() {
             display_projects(
                 &project_results.iter().collect::<Vec<_>>(),
+                &all_projects,
                 &all_tasks,
                 &all_notes,
             );
@@ -128,9 +161,11 @@ pub fn execute(
         // No tasks — load only what's needed for each render section
         if !project_results.is_empty() {
             let all_tasks = storage.load()?;
+            let all_projects = storage.load_projects()?;
             let all_notes = storage.load_notes()?;
             display_projects(
                 &project_results.iter().collect::<Vec<_>>(),
+                &all_projects,
                 &all_tasks,
                 &all_notes,
             );
@@ -165,7 +200,7 @@ mod tests {
     }
 
     fn search(storage: &InMemoryStorage, query: &str) -> Result<()> {
-        execute(storage, query.into(), vec![], None, StatusFilter::All)
+        execute(storage, query.into(), vec![], None, StatusFilter::All, None)
     }
 
     #[test]
@@ -235,6 +270,7 @@ mod tests {
             vec!["rust".into()],
             None,
             StatusFilter::All,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -250,6 +286,7 @@ mod tests {
             vec!["nonexistent".into()],
             None,
             StatusFilter::All,
+            None,
         );
         assert!(result.is_err());
     }
@@ -261,7 +298,14 @@ mod tests {
         done.mark_done();
         storage.save(&[done]).unwrap();
 
-        let result = execute(&storage, "task".into(), vec![], None, StatusFilter::Pending);
+        let result = execute(
+            &storage,
+            "task".into(),
+            vec![],
+            None,
+            StatusFilter::Pending,
+            None,
+        );
         assert!(result.is_err());
     }
 
@@ -281,6 +325,7 @@ mod tests {
             vec![],
             Some("Rustodo".into()),
             StatusFilter::All,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -296,6 +341,7 @@ mod tests {
             vec![],
             Some("NonExistent".into()),
             StatusFilter::All,
+            None,
         );
         assert!(result.is_err());
     }
@@ -4,13 +4,23 @@ use anyhow::Result;
 use chrono::{Duration, Local};
 use colored::Colorize;
 
-use crate::models::{Priority, count_by_project};
+use crate::commands::view::active_context;
+use crate::config::Config;
+use crate::models::{Priority, Project, Task, TaskStatus, count_by_project_tree};
 use crate::storage::Storage;
 
 pub fn execute(storage: &impl Storage) -> Result<()> {
     let (all_tasks, projects, all_notes, all_resources) = storage.load_all_with_resources()?;
 
-    let tasks: Vec<_> = all_tasks.into_iter().filter(|t| !t.is_deleted()).collect();
+    let context = active_context(storage)?;
+    let tasks: Vec<_> = match context {
+        Some(ref ctx) => all_tasks
+            .iter()
+            .filter(|t| !t.is_deleted() && ctx.matches(t, &all_tasks, &projects))
+            .cloned()
+            .collect(),
+        None => all_tasks.into_iter().filter(|t| !t.is_deleted()).collect(),
+    };
     let notes: Vec<_> = all_notes.into_iter().filter(|n| !n.is_deleted()).collect();
     let resources: Vec<_> = all_resources
         .into_iter()
@@ -25,18 +35,23 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
     let today = Local::now().naive_local().date();
 
     // ── Task metrics ──────────────────────────────────────────────────────────
+    // Cancelled tasks are listed but never count towards completion rates.
     let total = tasks.len();
-    let completed = tasks.iter().filter(|t| t.completed).count();
-    let pending = total - completed;
+    let count_status = |s: TaskStatus| tasks.iter().filter(|t| t.status == s).count();
+    let completed = count_status(TaskStatus::Done);
+    let active = count_status(TaskStatus::Active);
+    let waiting = count_status(TaskStatus::Waiting);
+    let cancelled = count_status(TaskStatus::Cancelled);
+    let pending = tasks.iter().filter(|t| t.is_open()).count();
     let overdue = tasks.iter().filter(|t| t.is_overdue()).count();
     let due_soon = tasks.iter().filter(|t| t.is_due_soon(7)).count();
     let blocked = tasks
         .iter()
-        .filter(|t| !t.completed && t.is_blocked(&tasks))
+        .filter(|t| t.is_open() && t.is_blocked(&tasks))
         .count();
     let recurring = tasks.iter().filter(|t| t.recurrence.is_some()).count();
     let with_deps = tasks.iter().filter(|t| !t.depends_on.is_empty()).count();
-    let pct = percent(completed, total);
+    let pct = percent(completed, total - cancelled);
 
     // ── Tag metrics ───────────────────────────────────────────────────────────
     let mut tag_counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
@@ -56,15 +71,21 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         }
     }
     let mut top_tags: Vec<(String, usize)> = tag_counts.into_iter().collect();
-    top_tags.sort_by(|a, b| b.1.cmp(&a.1));
+    top_tags.sort_by_key(|t| std::cmp::Reverse(t.1));
 
     // ── Urgency buckets ───────────────────────────────────────────────────────
+    let urgency = Config::load().unwrap_or_default().urgency;
+    let score = |t: &Task| t.urgency_score_with(&tasks, &projects, &urgency);
     let urgent: Vec<_> = tasks
         .iter()
-        .filter(|t| !t.completed && t.urgency_score(&tasks) >= 10.0)
+        .filter(|t| t.is_open() && score(t) >= 10.0)
         .collect();
 
     println!("\n{}\n", "Todo Statistics".bright_white().bold());
+    if let Some(ref ctx) = context {
+        ctx.print_notice();
+        println!();
+    }
 
     // ── Overview ──────────────────────────────────────────────────────────────
     section("Overview");
@@ -76,6 +97,15 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
             Some(completion_color(pct)),
         );
         stat_line("Pending", &pending.to_string(), None);
+        if active > 0 {
+            stat_line("Active", &active.to_string(), None);
+        }
+        if waiting > 0 {
+            stat_line("Waiting", &waiting.to_string(), None);
+        }
+        if cancelled > 0 {
+            stat_line("Cancelled", &cancelled.to_string(), None);
+        }
         stat_line(
             "Overdue",
             &overdue.to_string(),
@@ -86,7 +116,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         }
         let no_due = tasks
             .iter()
-            .filter(|t| !t.completed && t.due_date.is_none())
+            .filter(|t| t.is_open() && t.due_date.is_none())
             .count();
         if no_due > 0 {
             stat_line("No due date", &no_due.to_string(), None);
@@ -104,7 +134,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
     if !notes.is_empty() {
         let orphan_notes = notes
             .iter()
-            .filter(|n| n.project_id.is_none() && n.task_id.is_none())
+            .filter(|n| n.project_ids.is_empty() && n.task_ids.is_empty())
             .count();
         let linked_notes = notes.len() - orphan_notes;
         stat_line(
@@ -149,7 +179,10 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
             ("Medium", Priority::Medium),
             ("Low", Priority::Low),
         ] {
-            let t: Vec<_> = tasks.iter().filter(|t| t.priority == *priority).collect();
+            let t: Vec<_> = tasks
+                .iter()
+                .filter(|t| t.priority == *priority && t.status != TaskStatus::Cancelled)
+                .collect();
             if !t.is_empty() {
                 let d = t.iter().filter(|t| t.completed).count();
                 let p = t.len() - d;
@@ -168,15 +201,18 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
     }
 
     // ── By Project ────────────────────────────────────────────────────────────
-    let visible_projects: Vec<_> = projects.iter().filter(|p| !p.is_deleted()).collect();
+    // Sub-projects are indented under their parent, whose numbers include them.
+    let visible_projects = Project::tree_order(&projects);
     if !visible_projects.is_empty() {
         section("By Project");
         for project in &visible_projects {
-            let (total_p, done_p) = count_by_project(&tasks, project.uuid);
+            let (total_p, done_p) = count_by_project_tree(&tasks, &projects, project.uuid);
+            let subtree = Project::subtree(&projects, project.uuid);
             let note_count = notes
                 .iter()
-                .filter(|n| n.project_id == Some(project.uuid))
+                .filter(|n| n.project_ids.iter().any(|u| subtree.contains(u)))
                 .count();
+            let label = format!("{}{}", "  ".repeat(project.depth(&projects)), project.name);
             let note_str = if note_count > 0 {
                 format!("  {} {}", note_count.to_string().dimmed(), "notes".dimmed())
             } else {
@@ -185,7 +221,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
             if total_p == 0 {
                 println!(
                     "  {:<24} {}{}",
-                    project.name.bright_white(),
+                    label.bright_white(),
                     "no tasks".dimmed(),
                     note_str,
                 );
@@ -195,7 +231,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
                 let task_str = pluralize(total_p, "task");
                 println!(
                     "  {:<24} {:<10}  {}  {}%{}",
-                    project.name.bright_white(),
+                    label.bright_white(),
                     task_str.cyan(),
                     bar,
                     pct_p,
@@ -220,8 +256,8 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         let all_vis: Vec<_> = tasks.iter().collect();
         let mut urgent_sorted: Vec<_> = urgent.iter().collect();
         urgent_sorted.sort_by(|a, b| {
-            b.urgency_score(&tasks)
-                .partial_cmp(&a.urgency_score(&tasks))
+            score(b)
+                .partial_cmp(&score(a))
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
         for task in urgent_sorted.iter().take(5) {
@@ -230,8 +266,7 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
                 .position(|t| t.uuid == task.uuid)
                 .map(|i| i + 1)
                 .unwrap_or(0);
-            let score = task.urgency_score(&tasks);
-            let score_str = format!("{:.1}", score).red().bold();
+            let score_str = format!("{:.1}", score(task)).red().bold();
             println!(
                 "  {}  {}  {}",
                 format!("#{}", vis_id).dimmed(),
@@ -261,11 +296,11 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         let cutoff = today - Duration::days(7);
         let done = tasks
             .iter()
-            .filter(|t| t.completed_at.map(|d| d >= cutoff).unwrap_or(false))
+            .filter(|t| t.completed && t.completed_at.map(|d| d >= cutoff).unwrap_or(false))
             .count();
         let created = tasks
             .iter()
-            .filter(|t| t.created_at.date_naive() >= cutoff)
+            .filter(|t| t.status != TaskStatus::Cancelled && t.created_at.date_naive() >= cutoff)
             .count();
         percent(done, created.max(1))
     };
@@ -273,11 +308,11 @@ pub fn execute(storage: &impl Storage) -> Result<()> {
         let cutoff = today - Duration::days(30);
         let done = tasks
             .iter()
-            .filter(|t| t.completed_at.map(|d| d >= cutoff).unwrap_or(false))
+            .filter(|t| t.completed && t.completed_at.map(|d| d >= cutoff).unwrap_or(false))
             .count();
         let created = tasks
             .iter()
-            .filter(|t| t.created_at.date_naive() >= cutoff)
+            .filter(|t| t.status != TaskStatus::Cancelled && t.created_at.date_naive() >= cutoff)
             .count();
         percent(done, created.max(1))
     };
@@ -313,11 +348,7 @@ fn stat_line(label: &str, value: &str, color: Option<&str>) {
 }
 
 fn progress_bar(done: usize, total: usize, width: usize) -> String {
-    let filled = if total == 0 {
-        0
-    } else {
-        (done * width) / total
-    };
+    let filled = (done * width).checked_div(total).unwrap_or(0);
     let empty = width - filled;
     let bar = format!("{}{}", "█".repeat(filled), "░".repeat(empty));
     if done == total && total > 0 {
@@ -338,7 +369,7 @@ fn pluralize(count: usize, word: &str) -> String {
 }
 
 fn percent(part: usize, total: usize) -> usize {
-    if total == 0 { 0 } else { (part * 100) / total }
+    (part * 100).checked_div(total).unwrap_or(0)
 }
 
 fn completion_color(pct: usize) -> &'static str {
@@ -1,9 +1,11 @@
 //! Handler for `todo stats history`.
 //!
-//! Shows a monthly history chart of tasks created, completed, and deleted.
+//! Shows a monthly history chart of tasks created, completed, cancelled, and
+//! deleted.
 //! Data is read from the `events` table, which is append-only and survives
-//! `todo purge` — unlike the previous approach of inferring history from
-//! `created_at` / `deleted_at` fields on live task rows.
+//! `todo purge` and `todo archive` — unlike the previous approach of
+//! inferring history from `created_at` / `deleted_at` fields on live task
+//! rows.
 
 use anyhow::Result;
 use colored::Colorize;
@@ -16,7 +18,7 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
     // Drop leading months that have zero activity
     let first_non_empty = rows
         .iter()
-        .position(|r| r.created > 0 || r.completed > 0 || r.deleted > 0);
+        .position(|r| r.created > 0 || r.completed > 0 || r.cancelled > 0 || r.deleted > 0);
 
     let rows = match first_non_empty {
         Some(idx) => &rows[idx..],
@@ -28,7 +30,7 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
 
     let max_count = rows
         .iter()
-        .map(|r| r.created.max(r.completed).max(r.deleted))
+        .map(|r| r.created.max(r.completed).max(r.cancelled).max(r.deleted))
         .max()
         .unwrap_or(1)
         .max(1);
@@ -39,7 +41,7 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
     println!(
         "  {:<10}  {:<width$}  {}",
         "Month".dimmed(),
-        "Added / Completed / Deleted".dimmed(),
+        "Added / Completed / Cancelled / Deleted".dimmed(),
         "Count".dimmed(),
         width = bar_width + 2,
     );
@@ -48,22 +50,25 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
     for row in rows {
         let filled_a = (row.created * bar_width) / max_count;
         let filled_c = (row.completed * bar_width) / max_count;
+        let filled_x = (row.cancelled * bar_width) / max_count;
         let filled_d = (row.deleted * bar_width) / max_count;
-        let total_filled = (filled_a + filled_c + filled_d).min(bar_width);
+        let total_filled = (filled_a + filled_c + filled_x + filled_d).min(bar_width);
         let empty = bar_width.saturating_sub(total_filled);
 
         let bar = format!(
-            "{}{}{}{}",
+            "{}{}{}{}{}",
             "█".repeat(filled_a).green(),
             "█".repeat(filled_c).yellow(),
+            "█".repeat(filled_x).magenta(),
             "█".repeat(filled_d).red(),
             "░".repeat(empty).dimmed(),
         );
 
         let detail = format!(
-            "+{}  ✓{}  -{}",
+            "+{}  ✓{}  ✗{}  -{}",
             row.created.to_string().green(),
             row.completed.to_string().yellow(),
+            row.cancelled.to_string().magenta(),
             row.deleted.to_string().red(),
         );
 
@@ -73,11 +78,13 @@ pub fn execute(storage: &impl Storage, months: usize) -> Result<()> {
 
     println!("{}", "─".repeat(bar_width + 32).dimmed());
     println!(
-        "\n  {}  {}  {}  {}  {}  {}  {}\n",
+        "\n  {}  {}  {}  {}  {}  {}  {}  {}  {}\n",
         "█".green(),
         "Added".dimmed(),
         "█".yellow(),
         "Completed".dimmed(),
+        "█".magenta(),
+        "Cancelled".dimmed(),
         "█".red(),
         "Deleted".dimmed(),
         format!("(last {} months)", months).dimmed(),
@@ -118,6 +118,8 @@ fn show_tag_hub(tasks: &[Task], notes: &[Note], resources: &[Resource], tag: &st
 
             let status = if task.completed {
                 "✓".green()
+            } else if !task.is_open() {
+                "✗".dimmed()
             } else {
                 "·".yellow()
             };
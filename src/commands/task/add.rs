@@ -1,13 +1,19 @@
 //! Handler for `todo add`.
+//!
+//! The description may carry inline fields (`+project #tag !high due:fri
+//! every:week after:12`, see [`crate::utils::quick_capture`]). They fill the
+//! same fields as the flags; an explicit flag wins over an inline value.
 
 use anyhow::Result;
 use colored::Colorize;
 
 use crate::cli::AddArgs;
 use crate::error::TodoError;
-use crate::models::{Project, Task};
+use crate::models::{Priority, Project, Task};
+use crate::services::duplicates;
 use crate::services::tag_service::collect_all_tag_names;
 use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::quick_capture;
 use crate::utils::tag_normalizer::normalize_tags;
 use crate::utils::validation::{self, resolve_uuid_visible, visible_indices};
 use crate::{utils::date_parser, utils::validation::validate_task_id};
@@ -22,7 +28,8 @@ pub fn execute_silent(storage: &impl Storage, args: AddArgs) -> Result<()> {
     Ok(())
 }
 
-fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<usize> {
+fn execute_inner(storage: &impl Storage, mut args: AddArgs, silent: bool) -> Result<usize> {
+    apply_quick_capture(&mut args)?;
     validation::validate_task_text(&args.text)?;
     validation::validate_tags(&args.tag)?;
     if let Some(ref p) = args.project {
@@ -37,26 +44,38 @@ fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<
 
     validation::validate_due_date(due, false)?;
     validation::validate_recurrence(args.recurrence, due)?;
+    let estimate = args
+        .estimate
+        .as_deref()
+        .map(date_parser::parse_estimate)
+        .transpose()?;
 
     let mut tasks = storage.load()?;
 
     // ── Duplicate check ───────────────────────────────────────────────────────
-    if !silent && args.recurrence.is_none() {
-        let vis = visible_indices(&tasks, |t| t.is_deleted());
-        let duplicate = vis.iter().enumerate().find(|&(_, &real_idx)| {
-            tasks[real_idx].text.to_lowercase() == args.text.to_lowercase()
-        });
-        if let Some((vis_pos, _)) = duplicate {
-            let vis_id = vis_pos + 1;
-            eprintln!(
-                "{} Task \"{}\" already exists (#{}). Add anyway? [y/N] ",
-                "".yellow(),
-                args.text,
-                vis_id,
-            );
-            let mut input = String::new();
-            std::io::stdin().read_line(&mut input)?;
-            if input.trim().to_lowercase() != "y" {
+    let mut merge_into: Option<usize> = None;
+    if !silent
+        && args.recurrence.is_none()
+        && let Some((idx, score)) = duplicates::find_similar(&args.text, &tasks, &[])
+    {
+        let vis_id = tasks[..idx].iter().filter(|t| !t.is_deleted()).count() + 1;
+        eprintln!(
+            "{} Similar task already exists: #{} \"{}\" ({:.0}% similar)",
+            "~".yellow(),
+            vis_id,
+            tasks[idx].text,
+            score * 100.0,
+        );
+        eprint!(
+            "  [a]dd anyway, [m]erge into #{}, or cancel? [a/m/N] ",
+            vis_id
+        );
+        let mut input = String::new();
+        std::io::stdin().read_line(&mut input)?;
+        match input.trim().to_lowercase().as_str() {
+            "a" | "add" | "y" => {}
+            "m" | "merge" => merge_into = Some(idx),
+            _ => {
                 println!("{}", "Cancelled.".dimmed());
                 return Ok(0);
             }
@@ -107,6 +126,19 @@ fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<
         args.recurrence,
     );
     task.depends_on = dep_uuids;
+    task.estimate = estimate;
+
+    if let Some(idx) = merge_into {
+        duplicates::absorb(&mut tasks[idx], &task);
+        let existing_uuid = tasks[idx].uuid;
+        duplicates::check_no_cycle(&tasks, existing_uuid)?;
+        storage.save(&tasks)?;
+        storage.record_event(EntityType::Task, existing_uuid, EventType::Edited)?;
+        let id = tasks[..idx].iter().filter(|t| !t.is_deleted()).count() + 1;
+        println!("{} Merged into task #{}", "✓".green(), id);
+        return Ok(id);
+    }
+
     let task_uuid = task.uuid;
     tasks.push(task);
 
@@ -129,6 +161,34 @@ fn execute_inner(storage: &impl Storage, args: AddArgs, silent: bool) -> Result<
     Ok(id)
 }
 
+/// Moves inline quick-capture fields out of `args.text` into `args`.
+///
+/// Single-value flags (`--project`, `--due`, `--recurrence`, a non-default
+/// `--priority`) take precedence; tags and dependencies are merged.
+fn apply_quick_capture(args: &mut AddArgs) -> Result<(), TodoError> {
+    let qc = quick_capture::parse(&args.text)?;
+    args.text = qc.text;
+    if args.priority == Priority::Medium
+        && let Some(priority) = qc.priority
+    {
+        args.priority = priority;
+    }
+    for tag in qc.tags {
+        if !args.tag.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            args.tag.push(tag);
+        }
+    }
+    args.project = args.project.take().or(qc.project);
+    args.due = args.due.take().or(qc.due);
+    args.recurrence = args.recurrence.or(qc.recurrence);
+    for id in qc.depends_on {
+        if !args.depends_on.contains(&id) {
+            args.depends_on.push(id);
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -144,6 +204,7 @@ mod tests {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         }
     }
 
@@ -294,4 +355,58 @@ mod tests {
         let tasks = storage.load().unwrap();
         assert_eq!(tasks[1].tags[0], "rust");
     }
+
+    #[test]
+    fn test_add_quick_capture_fills_fields() {
+        let storage = InMemoryStorage::default();
+        execute_silent(&storage, args("Setup CI")).unwrap();
+        execute_silent(
+            &storage,
+            args("Fix login bug +api #bug #Bug2 !high due:2099-12-31 every:week after:1"),
+        )
+        .unwrap();
+
+        let tasks = storage.load().unwrap();
+        let task = &tasks[1];
+        assert_eq!(task.text, "Fix login bug");
+        assert_eq!(task.priority, Priority::High);
+        assert_eq!(task.tags, vec!["bug", "Bug2"]);
+        assert_eq!(task.recurrence, Some(Recurrence::Weekly));
+        assert_eq!(task.due_date.unwrap().to_string(), "2099-12-31");
+        assert_eq!(task.depends_on, vec![tasks[0].uuid]);
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(task.project_id, Some(projects[0].uuid));
+        assert_eq!(projects[0].name, "api");
+    }
+
+    #[test]
+    fn test_add_flags_win_over_quick_capture() {
+        let storage = InMemoryStorage::default();
+        execute_silent(
+            &storage,
+            AddArgs {
+                priority: Priority::Low,
+                project: Some("web".into()),
+                tag: vec!["bug".into()],
+                ..args("Task +api !high #bug #ui")
+            },
+        )
+        .unwrap();
+
+        let task = &storage.load().unwrap()[0];
+        assert_eq!(task.text, "Task");
+        assert_eq!(task.priority, Priority::Low);
+        assert_eq!(task.tags, vec!["bug", "ui"]);
+        assert_eq!(storage.load_projects().unwrap()[0].name, "web");
+    }
+
+    #[test]
+    fn test_add_quick_capture_is_validated() {
+        let storage = InMemoryStorage::default();
+        assert!(execute_silent(&storage, args("#bug !high")).is_err());
+        assert!(execute_silent(&storage, args("Task #bad.tag")).is_err());
+        assert!(execute_silent(&storage, args("Task every:week")).is_err());
+        assert!(execute_silent(&storage, args("Task after:3")).is_err());
+        assert!(storage.load().unwrap().is_empty());
+    }
 }
@@ -0,0 +1,357 @@
+//! Shared driver for commands that act on several tasks at once.
+//!
+//! Every mutating task command (`done`, `undone`, `start`, `wait`, `cancel`,
+//! `edit`, `remove`, `recur`, `norecur`) accepts a [`TaskTarget`]:
+//!
+//! ```text
+//! todo done 4                          # single ID — unchanged behaviour
+//! todo done 1-5,8,12                   # list and ranges
+//! todo done --filter "project:api and (tag:bug or priority:high)"
+//! todo remove 10-20 --filter "status:done" --yes
+//! ```
+//!
+//! `--filter` takes the query language from [`crate::services::query`].
+//! A single plain ID runs the command exactly as before. Anything else is
+//! resolved to task UUIDs up front (so IDs shifting after a removal cannot
+//! hit the wrong task), previewed, confirmed once, and then applied task by
+//! task through the command's `execute_silent`, which records one event per
+//! task. Tasks that fail — e.g. `done` on a task blocked by another selected
+//! task — are retried after the others until no further progress is made.
+
+use anyhow::Result;
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::cli::TaskTarget;
+use crate::error::TodoError;
+use crate::models::{Project, Task};
+use crate::services::query::{Query, QueryContext};
+use crate::storage::Storage;
+use crate::utils::confirm;
+use crate::utils::id_list::parse_id_list;
+use crate::utils::validation::{resolve_uuid_visible, visible_indices};
+
+/// How many selected tasks the preview lists before summarising the rest.
+const PREVIEW_LIMIT: usize = 15;
+
+/// Runs `single` for a lone plain ID, otherwise previews the selection and
+/// applies `each` to every selected task.
+///
+/// `verb` is used in the preview prompt, e.g. `"complete"`.
+pub fn dispatch<S: Storage>(
+    storage: &S,
+    target: &TaskTarget,
+    verb: &str,
+    single: impl FnOnce(&S, usize) -> Result<()>,
+    each: impl FnMut(&S, usize) -> Result<String>,
+) -> Result<()> {
+    if let Some(id) = target.single_id() {
+        return single(storage, id);
+    }
+    run(storage, target, verb, each)
+}
+
+/// Resolves `target`, prints a preview, asks for confirmation (unless
+/// `--yes`), and applies `each` to every selected task by its current ID.
+pub fn run<S: Storage>(
+    storage: &S,
+    target: &TaskTarget,
+    verb: &str,
+    mut each: impl FnMut(&S, usize) -> Result<String>,
+) -> Result<()> {
+    let (tasks, projects, _) = storage.load_all()?;
+    let selected = resolve(&tasks, &projects, target)?;
+
+    preview(&tasks, &selected, verb);
+    if !target.yes
+        && !confirm(&format!(
+            "{} {} task(s)? [y/N]:",
+            capitalize(verb),
+            selected.len()
+        ))?
+    {
+        println!("{} Cancelled.", "".yellow());
+        return Ok(());
+    }
+
+    let outcome = apply(storage, &selected, &mut each)?;
+
+    for msg in &outcome.done {
+        println!("{} {}", "✓".green(), msg);
+    }
+    for (label, err) in &outcome.failed {
+        println!("{} {}: {}", "✗".red(), label, err);
+    }
+    if outcome.failed.is_empty() {
+        println!("\n{} {} task(s) updated.", "✓".green(), outcome.done.len());
+        Ok(())
+    } else {
+        anyhow::bail!(
+            "{} of {} task(s) failed",
+            outcome.failed.len(),
+            selected.len()
+        )
+    }
+}
+
+/// Result of applying an operation to a selection.
+#[derive(Debug, Default)]
+pub struct BulkOutcome {
+    /// Messages from successful operations, in the order they ran.
+    pub done: Vec<String>,
+    /// `(#ID text, error)` for tasks that still failed after retrying.
+    pub failed: Vec<(String, String)>,
+}
+
+/// Applies `each` to every task in `selected`, re-resolving its visible ID
+/// before every call. Failed tasks are retried while other tasks keep
+/// succeeding, so a selection can satisfy its own dependencies.
+pub fn apply<S: Storage>(
+    storage: &S,
+    selected: &[Uuid],
+    each: &mut impl FnMut(&S, usize) -> Result<String>,
+) -> Result<BulkOutcome> {
+    let mut outcome = BulkOutcome::default();
+    let mut pending: Vec<Uuid> = selected.to_vec();
+
+    loop {
+        let mut failed = Vec::new();
+        let before = pending.len();
+        for uuid in pending {
+            let tasks = storage.load()?;
+            let Some(id) = visible_id(&tasks, uuid) else {
+                failed.push((
+                    uuid,
+                    "#? (task)".to_string(),
+                    "no longer exists".to_string(),
+                ));
+                continue;
+            };
+            match each(storage, id) {
+                Ok(msg) => outcome.done.push(msg),
+                Err(e) => {
+                    let text = tasks
+                        .iter()
+                        .find(|t| t.uuid == uuid)
+                        .map(|t| t.text.as_str());
+                    failed.push((
+                        uuid,
+                        format!("#{} {}", id, text.unwrap_or("")),
+                        e.to_string(),
+                    ));
+                }
+            }
+        }
+        if failed.is_empty() || failed.len() == before {
+            outcome.failed = failed.into_iter().map(|(_, l, e)| (l, e)).collect();
+            return Ok(outcome);
+        }
+        pending = failed.into_iter().map(|(u, _, _)| u).collect();
+    }
+}
+
+/// Resolves IDs and/or `--filter` to task UUIDs, in ID order.
+///
+/// When both are given, only tasks matching both are selected.
+pub fn resolve(tasks: &[Task], projects: &[Project], target: &TaskTarget) -> Result<Vec<Uuid>> {
+    let vis = visible_indices(tasks, |t| t.is_deleted());
+
+    let mut selected: Vec<Uuid> = match target.ids.as_deref().map(str::trim) {
+        None | Some("all") => vis.iter().map(|&i| tasks[i].uuid).collect(),
+        Some(spec) => parse_id_list(spec)?
+            .into_iter()
+            .map(|id| resolve_uuid_visible(id, tasks))
+            .collect::<Result<_, _>>()?,
+    };
+
+    if let Some(ref expr) = target.filter {
+        let query = Query::parse(expr)?;
+        let ctx = QueryContext { tasks, projects };
+        selected.retain(|uuid| {
+            tasks
+                .iter()
+                .find(|t| t.uuid == *uuid)
+                .is_some_and(|t| query.matches(t, &ctx))
+        });
+    }
+
+    if selected.is_empty() {
+        return Err(TodoError::NoTasksFound.into());
+    }
+    Ok(selected)
+}
+
+fn visible_id(tasks: &[Task], uuid: Uuid) -> Option<usize> {
+    tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .position(|t| t.uuid == uuid)
+        .map(|p| p + 1)
+}
+
+fn preview(tasks: &[Task], selected: &[Uuid], verb: &str) {
+    println!(
+        "\nAbout to {} {} task(s):\n",
+        verb,
+        selected.len().to_string().bold()
+    );
+    for uuid in selected.iter().take(PREVIEW_LIMIT) {
+        if let (Some(id), Some(task)) = (
+            visible_id(tasks, *uuid),
+            tasks.iter().find(|t| t.uuid == *uuid),
+        ) {
+            println!(
+                "  {:>4}  {}  {}",
+                format!("#{}", id).dimmed(),
+                task.status.letter(),
+                task.text
+            );
+        }
+    }
+    if selected.len() > PREVIEW_LIMIT {
+        println!(
+            "  {}",
+            format!("… and {} more", selected.len() - PREVIEW_LIMIT).dimmed()
+        );
+    }
+    println!();
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::task::{done, remove};
+    use crate::models::Priority;
+    use crate::storage::{InMemoryStorage, Storage};
+
+    fn make_task(text: &str, tags: &[&str]) -> Task {
+        Task::new(
+            text.into(),
+            Priority::Medium,
+            tags.iter().map(|t| t.to_string()).collect(),
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn target(ids: Option<&str>, filter: Option<&str>) -> TaskTarget {
+        TaskTarget {
+            ids: ids.map(String::from),
+            filter: filter.map(String::from),
+            yes: true,
+        }
+    }
+
+    #[test]
+    fn test_resolve_ids_and_filter_intersect() {
+        let tasks = vec![
+            make_task("A", &["bug"]),
+            make_task("B", &[]),
+            make_task("C", &["bug"]),
+        ];
+        let all = resolve(&tasks, &[], &target(Some("1-2"), Some("tag:bug"))).unwrap();
+        assert_eq!(all, vec![tasks[0].uuid]);
+
+        let by_filter = resolve(&tasks, &[], &target(None, Some("tag:BUG"))).unwrap();
+        assert_eq!(by_filter, vec![tasks[0].uuid, tasks[2].uuid]);
+    }
+
+    #[test]
+    fn test_resolve_filter_by_project_and_text() {
+        let project = Project::new("api".into());
+        let mut a = make_task("Fix login", &[]);
+        a.project_id = Some(project.uuid);
+        let b = make_task("Fix logout", &[]);
+        let tasks = vec![a, b];
+
+        let sel = resolve(&tasks, &[project], &target(None, Some("project:API fix"))).unwrap();
+        assert_eq!(sel, vec![tasks[0].uuid]);
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        let tasks = vec![make_task("A", &[])];
+        assert!(resolve(&tasks, &[], &target(Some("5"), None)).is_err());
+        assert!(resolve(&tasks, &[], &target(None, Some("colour:red"))).is_err());
+        assert!(resolve(&tasks, &[], &target(None, Some("status:bogus"))).is_err());
+        assert!(resolve(&tasks, &[], &target(None, Some("tag:missing"))).is_err());
+    }
+
+    #[test]
+    fn test_apply_remove_tracks_shifting_ids() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[
+                make_task("A", &[]),
+                make_task("B", &[]),
+                make_task("C", &[]),
+            ])
+            .unwrap();
+        let tasks = storage.load().unwrap();
+        let selected = resolve(&tasks, &[], &target(Some("1,3"), None)).unwrap();
+
+        let outcome = apply(&storage, &selected, &mut |s, id| {
+            remove::execute_silent(s, id)
+        })
+        .unwrap();
+
+        assert_eq!(outcome.done.len(), 2);
+        let remaining: Vec<_> = storage
+            .load()
+            .unwrap()
+            .into_iter()
+            .filter(|t| !t.is_deleted())
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(remaining, vec!["B"]);
+    }
+
+    #[test]
+    fn test_apply_done_retries_tasks_blocked_by_selection() {
+        let storage = InMemoryStorage::default();
+        let dep = make_task("Dep", &[]);
+        let mut task = make_task("Task", &[]);
+        task.depends_on = vec![dep.uuid];
+        storage.save(&[task, dep]).unwrap();
+        let tasks = storage.load().unwrap();
+        let selected = resolve(&tasks, &[], &target(Some("1-2"), None)).unwrap();
+
+        let outcome = apply(&storage, &selected, &mut |s, id| {
+            done::execute_silent(s, id)
+        })
+        .unwrap();
+
+        assert!(outcome.failed.is_empty());
+        assert!(storage.load().unwrap().iter().all(|t| t.completed));
+        let stats = storage.load_event_stats(1).unwrap();
+        assert_eq!(stats.last().unwrap().completed, 2);
+    }
+
+    #[test]
+    fn test_apply_reports_persistent_failures() {
+        let storage = InMemoryStorage::default();
+        let mut closed = make_task("Closed", &[]);
+        closed.mark_done();
+        storage.save(&[closed, make_task("Open", &[])]).unwrap();
+        let tasks = storage.load().unwrap();
+        let selected = resolve(&tasks, &[], &target(Some("1-2"), None)).unwrap();
+
+        let outcome = apply(&storage, &selected, &mut |s, id| {
+            done::execute_silent(s, id)
+        })
+        .unwrap();
+
+        assert_eq!(outcome.done.len(), 1);
+        assert_eq!(outcome.failed.len(), 1);
+        assert!(outcome.failed[0].0.contains("Closed"));
+    }
+}
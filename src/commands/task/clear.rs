@@ -41,10 +41,8 @@ pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
 
     let mut notes_updated = 0;
     for note in notes.iter_mut().filter(|n| !n.is_deleted()) {
-        if let Some(tid) = note.task_id
-            && deleted_uuids.contains(&tid)
-        {
-            note.task_id = None;
+        if note.task_ids.iter().any(|t| deleted_uuids.contains(t)) {
+            note.task_ids.retain(|t| !deleted_uuids.contains(t));
             note.touch();
             notes_updated += 1;
         }
@@ -130,12 +128,12 @@ mod tests {
         storage.save(&[task]).unwrap();
 
         let mut note = Note::new("Note body".into());
-        note.task_id = Some(task_uuid);
+        note.task_ids = vec![task_uuid];
         storage.save_notes(&[note]).unwrap();
 
         execute(&storage, true).unwrap();
 
-        assert!(storage.load_notes().unwrap()[0].task_id.is_none());
+        assert!(storage.load_notes().unwrap()[0].task_ids.is_empty());
     }
 
     #[test]
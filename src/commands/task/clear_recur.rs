@@ -6,10 +6,19 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::storage::Storage;
+use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::validation::resolve_visible_index;
 
 pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
+    execute_inner(storage, id, false)?;
+    Ok(())
+}
+
+pub fn execute_silent(storage: &impl Storage, id: usize) -> Result<String> {
+    execute_inner(storage, id, true)
+}
+
+fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<String> {
     let mut tasks = storage.load()?;
 
     let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
@@ -18,20 +27,28 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     let task = &mut tasks[index];
 
     let Some(old_pattern) = task.recurrence.take() else {
-        println!("{} Task #{} has no recurrence", "".yellow(), id);
-        return Ok(());
+        if !silent {
+            println!("{} Task #{} has no recurrence", "".yellow(), id);
+        }
+        return Ok(format!("Task #{} has no recurrence", id));
     };
 
     task.touch();
 
-    storage.save(&tasks)?;
-
-    println!(
-        "{} Removed {} recurrence from task #{}",
-        "✓".green(),
-        old_pattern,
-        id,
-    );
-
-    Ok(())
+    storage.upsert_task(task)?;
+    storage.record_event(EntityType::Task, task.uuid, EventType::Edited)?;
+
+    if !silent {
+        println!(
+            "{} Removed {} recurrence from task #{}",
+            "✓".green(),
+            old_pattern,
+            id,
+        );
+    }
+
+    Ok(format!(
+        "Removed {} recurrence from task #{}",
+        old_pattern, id
+    ))
 }
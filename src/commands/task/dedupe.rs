@@ -0,0 +1,113 @@
+//! Handler for `todo dedupe`.
+//!
+//! Groups similar pending tasks (see [`crate::services::duplicates`]) and
+//! offers to merge each group into its oldest task with
+//! [`merge`](super::merge). With `--yes` every group is merged without
+//! asking.
+
+use anyhow::Result;
+use colored::Colorize;
+use uuid::Uuid;
+
+use super::merge::{merge_uuids, visible_id};
+use crate::services::duplicates;
+use crate::storage::Storage;
+use crate::utils::confirm;
+
+pub fn execute(storage: &impl Storage, yes: bool) -> Result<()> {
+    let tasks = storage.load()?;
+    let clusters = duplicates::clusters(&tasks);
+
+    if clusters.is_empty() {
+        println!("{}", "No duplicate tasks found.".dimmed());
+        return Ok(());
+    }
+
+    // Resolve to UUIDs up front: merging removes tasks and shifts IDs.
+    let groups: Vec<Vec<Uuid>> = clusters
+        .iter()
+        .map(|c| c.iter().map(|&i| tasks[i].uuid).collect())
+        .collect();
+
+    println!(
+        "\nFound {} group(s) of similar tasks:\n",
+        groups.len().to_string().bold()
+    );
+
+    let mut merged = 0;
+    for (n, group) in groups.iter().enumerate() {
+        let current = storage.load()?;
+        println!("{}", format!("Group {} of {}", n + 1, groups.len()).bold());
+        for uuid in group {
+            let Some(task) = current.iter().find(|t| t.uuid == *uuid) else {
+                continue;
+            };
+            let tags = if task.tags.is_empty() {
+                String::new()
+            } else {
+                format!("  [{}]", task.tags.join(", "))
+            };
+            let due = task
+                .due_date
+                .map(|d| format!("  due {}", d))
+                .unwrap_or_default();
+            println!(
+                "  {:>4}  {}{}",
+                format!("#{}", visible_id(&current, *uuid).unwrap_or(0)).dimmed(),
+                task.text,
+                format!("{}{}", tags, due).dimmed()
+            );
+        }
+
+        let keep = group[0];
+        let keep_id = visible_id(&current, keep).unwrap_or(0);
+        if yes || confirm(&format!("Merge into #{}? [y/N]:", keep_id))? {
+            let msg = merge_uuids(storage, keep, &group[1..])?;
+            println!("{} {}\n", "✓".green(), msg);
+            merged += 1;
+        } else {
+            println!("{}\n", "Skipped.".dimmed());
+        }
+    }
+
+    println!(
+        "{} {} of {} group(s) merged.",
+        "✓".green(),
+        merged,
+        groups.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Task};
+    use crate::storage::InMemoryStorage;
+
+    #[test]
+    fn test_dedupe_yes_merges_every_group() {
+        let storage = InMemoryStorage::default();
+        let task = |text: &str| Task::new(text.into(), Priority::Medium, vec![], None, None, None);
+        storage
+            .save(&[
+                task("Fix login bug"),
+                task("Buy milk"),
+                task("fix login bugs"),
+                task("Buy milk"),
+                task("Write report"),
+            ])
+            .unwrap();
+
+        execute(&storage, true).unwrap();
+
+        let left: Vec<String> = storage
+            .load()
+            .unwrap()
+            .into_iter()
+            .filter(|t| !t.is_deleted())
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(left, vec!["Fix login bug", "Buy milk", "Write report"]);
+    }
+}
@@ -4,10 +4,18 @@
 //! - Tasks it depends on, with their completion status
 //! - Tasks that depend on it (reverse edges)
 //! - Whether the task is currently blocked, and by which IDs
+//!
+//! `todo deps --graph` instead exports the whole dependency graph as DOT or
+//! Mermaid (see [`crate::render::dep_graph`]).
+
+use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
 
+use crate::error::TodoError;
+use crate::models::Project;
+use crate::render::dep_graph::{self, GraphFormat, GraphOptions};
 use crate::storage::Storage;
 use crate::utils::validation::{resolve_visible_index, visible_indices};
 
@@ -42,10 +50,12 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
                 let dep_vis_id = vis_id(real_pos);
                 let status = if dep.completed {
                     "✓".green()
+                } else if !dep.is_open() {
+                    "✗".dimmed()
                 } else {
                     "◦".red()
                 };
-                let label = if dep.completed {
+                let label = if !dep.is_open() {
                     dep.text.dimmed()
                 } else {
                     dep.text.bright_white()
@@ -78,6 +88,8 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
         for (dep_vis_id, dep_task) in &dependents {
             let status = if dep_task.completed {
                 "✓".green()
+            } else if !dep_task.is_open() {
+                "✗".dimmed()
             } else {
                 "◦".yellow()
             };
@@ -111,3 +123,45 @@ pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
     println!();
     Ok(())
 }
+
+/// Writes the dependency graph to `output`, or stdout when `None`.
+pub fn execute_graph(
+    storage: &impl Storage,
+    project: Option<&str>,
+    format: GraphFormat,
+    output: Option<PathBuf>,
+    collapse_done: bool,
+) -> Result<()> {
+    let tasks = storage.load()?;
+    let project = match project {
+        Some(name) => Some(
+            Project::find(&storage.load_projects()?, name)
+                .map(|p| p.uuid)
+                .ok_or_else(|| TodoError::ProjectNotFound(name.to_string()))?,
+        ),
+        None => None,
+    };
+
+    let graph = dep_graph::render(
+        &tasks,
+        format,
+        &GraphOptions {
+            project,
+            collapse_done,
+        },
+    );
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, &graph)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!(
+                "{} Dependency graph written to {}",
+                "✓".green(),
+                path.display().to_string().cyan()
+            );
+        }
+        None => print!("{}", graph),
+    }
+    Ok(())
+}
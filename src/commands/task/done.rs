@@ -4,6 +4,7 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::error::TodoError;
+use crate::models::{Task, TaskStatus};
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::validation::resolve_visible_index;
 
@@ -22,34 +23,25 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
     let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
         .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
 
-    if tasks[index].completed {
-        return Err(TodoError::TaskAlreadyInStatus {
-            id,
-            status: "completed".to_owned(),
+    match tasks[index].status {
+        TaskStatus::Done => {
+            return Err(TodoError::TaskAlreadyInStatus {
+                id,
+                status: "completed".to_owned(),
+            }
+            .into());
         }
-        .into());
+        TaskStatus::Cancelled => {
+            return Err(TodoError::TaskAlreadyInStatus {
+                id,
+                status: "cancelled".to_owned(),
+            }
+            .into());
+        }
+        _ => {}
     }
 
-    let blocking = tasks[index].blocking_deps(&tasks);
-    if !blocking.is_empty() {
-        let vis: Vec<usize> = tasks
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| !t.is_deleted())
-            .map(|(i, _)| i)
-            .collect();
-        let ids = blocking
-            .iter()
-            .filter_map(|uuid| {
-                let real_pos = tasks.iter().position(|t| t.uuid == *uuid)?;
-                let vis_id = vis.iter().position(|&i| i == real_pos).map(|p| p + 1)?;
-                let text = tasks[real_pos].text.clone();
-                Some(format!("#{} \"{}\"", vis_id, text))
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
-        return Err(TodoError::TaskBlocked(id, ids).into());
-    }
+    ensure_not_blocked(&tasks, index, id)?;
 
     tasks[index].mark_done();
     let task_uuid = tasks[index].uuid;
@@ -57,7 +49,7 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
     if let Some(next_task) = tasks[index].create_next_recurrence(task_uuid) {
         let next_due = next_task.due_date.unwrap();
         let already_exists = tasks.iter().any(|t| {
-            !t.completed
+            t.is_open()
                 && t.due_date == Some(next_due)
                 && (t.parent_id == Some(task_uuid) || t.text == next_task.text)
         });
@@ -107,10 +99,38 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
     }
 }
 
+/// Fails with [`TodoError::TaskBlocked`] when the task at `index` still has
+/// open dependencies, listing them by visible ID.
+///
+/// Shared by `done` and `start` — neither makes sense while blocked.
+pub(crate) fn ensure_not_blocked(tasks: &[Task], index: usize, id: usize) -> Result<()> {
+    let blocking = tasks[index].blocking_deps(tasks);
+    if blocking.is_empty() {
+        return Ok(());
+    }
+    let vis: Vec<usize> = tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| !t.is_deleted())
+        .map(|(i, _)| i)
+        .collect();
+    let ids = blocking
+        .iter()
+        .filter_map(|uuid| {
+            let real_pos = tasks.iter().position(|t| t.uuid == *uuid)?;
+            let vis_id = vis.iter().position(|&i| i == real_pos).map(|p| p + 1)?;
+            let text = tasks[real_pos].text.clone();
+            Some(format!("#{} \"{}\"", vis_id, text))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(TodoError::TaskBlocked(id, ids).into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Priority, Task};
+    use crate::models::Priority;
     use crate::storage::InMemoryStorage;
 
     fn make_task(text: &str) -> Task {
@@ -178,4 +198,27 @@ mod tests {
         assert!(!tasks[0].completed); // deleted stays untouched
         assert!(tasks[1].completed); // active gets marked done
     }
+
+    #[test]
+    fn test_done_cancelled_task_returns_error() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("Task");
+        task.set_status(TaskStatus::Cancelled);
+        storage.save(&[task]).unwrap();
+
+        let err = execute_silent(&storage, 1).unwrap_err();
+        assert!(err.to_string().contains("cancelled"));
+    }
+
+    #[test]
+    fn test_done_from_active_completes() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("Task");
+        task.set_status(TaskStatus::Active);
+        storage.save(&[task]).unwrap();
+
+        execute_silent(&storage, 1).unwrap();
+
+        assert_eq!(storage.load().unwrap()[0].status, TaskStatus::Done);
+    }
 }
@@ -26,6 +26,11 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
     } else {
         None
     };
+    let estimate = args
+        .estimate
+        .as_deref()
+        .map(date_parser::parse_estimate)
+        .transpose()?;
 
     let mut tasks = storage.load()?;
     let vis = visible_indices(&tasks, |t| t.is_deleted());
@@ -176,6 +181,18 @@ fn execute_inner(storage: &impl Storage, args: EditArgs, silent: bool) -> Result
         changes.push(format!("due date → {}", new_due.to_string().cyan()));
     }
 
+    if args.clear_estimate {
+        if task.estimate.is_some() {
+            task.estimate = None;
+            changes.push("estimate → cleared".dimmed().to_string());
+        }
+    } else if let Some(days) = estimate
+        && task.estimate != Some(days)
+    {
+        task.estimate = Some(days);
+        changes.push(format!("estimate → {}", format!("{}d", days).cyan()));
+    }
+
     if args.clear_deps {
         if !task.depends_on.is_empty() {
             task.depends_on.clear();
@@ -255,6 +272,8 @@ mod tests {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         }
     }
 
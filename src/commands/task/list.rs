@@ -1,10 +1,20 @@
 //! Handler for `todo list`.
+//!
+//! The individual flags (`--status`, `--tag`, …) and `--filter` combine with
+//! AND; `--filter` accepts the full query language from
+//! [`crate::services::query`], e.g. `tag:bug or tag:urgent`. The active
+//! context (`todo ctx set`) narrows the list further.
 
 use anyhow::Result;
 
+use crate::commands::view::active_context;
+use crate::config::Config;
 use crate::error::TodoError;
-use crate::models::{DueFilter, Priority, Recurrence, RecurrenceFilter, SortBy, StatusFilter};
+use crate::models::{
+    DueFilter, Priority, Project, Recurrence, RecurrenceFilter, SortBy, StatusFilter,
+};
 use crate::render::display_lists;
+use crate::services::query::{Query, QueryContext};
 use crate::storage::Storage;
 
 #[allow(clippy::too_many_arguments)]
@@ -17,9 +27,13 @@ pub fn execute(
     tags: Vec<String>,
     project: Option<String>,
     recur: Option<RecurrenceFilter>,
+    filter: Option<String>,
 ) -> Result<()> {
+    // Parse before loading so syntax errors surface even on an empty list.
+    let query = filter.as_deref().map(Query::parse).transpose()?;
     let (all_tasks, projects, notes) = storage.load_all()?;
     let resources = storage.load_resources()?;
+    let context = active_context(storage)?;
 
     let mut indexed_tasks: Vec<(usize, &_)> = all_tasks
         .iter()
@@ -28,6 +42,10 @@ pub fn execute(
         .map(|(i, task)| (i + 1, task))
         .collect();
 
+    if let Some(ref ctx) = context {
+        indexed_tasks.retain(|(_, t)| ctx.matches(t, &all_tasks, &projects));
+    }
+
     indexed_tasks.retain(|(_, t)| t.matches_status(status));
 
     if let Some(pri) = priority {
@@ -47,15 +65,15 @@ pub fn execute(
         }
     }
 
-    // Filter by project: resolve name → UUID, then filter by project_id
+    // Filter by project: resolve name → UUID, then keep tasks in the project
+    // or any of its sub-projects
     if let Some(ref project_name) = project {
         let count_before = indexed_tasks.len();
-        let proj_uuid = projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == project_name.to_lowercase() && !p.is_deleted())
-            .map(|p| p.uuid);
+        let subtree = Project::find(&projects, project_name)
+            .map(|p| Project::subtree(&projects, p.uuid))
+            .unwrap_or_default();
 
-        indexed_tasks.retain(|(_, t)| proj_uuid.is_some() && t.project_id == proj_uuid);
+        indexed_tasks.retain(|(_, t)| t.project_id.is_some_and(|u| subtree.contains(&u)));
 
         if indexed_tasks.is_empty() && count_before > 0 {
             return Err(TodoError::ProjectNotFound(project_name.to_owned()).into());
@@ -72,14 +90,25 @@ pub fn execute(
         });
     }
 
+    if let Some(ref query) = query {
+        let ctx = QueryContext {
+            tasks: &all_tasks,
+            projects: &projects,
+        };
+        indexed_tasks.retain(|(_, t)| query.matches(t, &ctx));
+    }
+
     if indexed_tasks.is_empty() {
+        if let Some(ref ctx) = context {
+            ctx.print_notice();
+        }
         return Err(TodoError::NoTasksFound.into());
     }
 
     if let Some(sort_by) = sort {
         match sort_by {
             SortBy::Priority => {
-                indexed_tasks.sort_by(|(_, a), (_, b)| a.priority.order().cmp(&b.priority.order()));
+                indexed_tasks.sort_by_key(|(_, t)| t.priority.order());
             }
             SortBy::Due => {
                 indexed_tasks.sort_by(|(_, a), (_, b)| match (a.due_date, b.due_date) {
@@ -90,25 +119,32 @@ pub fn execute(
                 });
             }
             SortBy::Created => {
-                indexed_tasks.sort_by(|(_, a), (_, b)| a.created_at.cmp(&b.created_at));
+                indexed_tasks.sort_by_key(|(_, t)| t.created_at);
             }
 
             SortBy::Urgency => {
+                let urgency = Config::load().unwrap_or_default().urgency;
                 indexed_tasks.sort_by(|(_, a), (_, b)| {
-                    b.urgency_score(&all_tasks)
-                        .partial_cmp(&a.urgency_score(&all_tasks))
+                    b.urgency_score_with(&all_tasks, &projects, &urgency)
+                        .partial_cmp(&a.urgency_score_with(&all_tasks, &projects, &urgency))
                         .unwrap_or(std::cmp::Ordering::Equal)
                 });
             }
         }
     }
 
-    let title = determine_title(status, priority, due, &tags, project.as_deref(), recur);
+    let title = match query {
+        Some(ref q) => format!("Tasks matching \"{}\"", q.source()),
+        None => determine_title(status, priority, due, &tags, project.as_deref(), recur),
+    };
     let visible: Vec<_> = all_tasks
         .iter()
         .filter(|t| !t.is_deleted())
         .cloned()
         .collect();
+    if let Some(ref ctx) = context {
+        ctx.print_notice();
+    }
     display_lists(
         &indexed_tasks,
         &title,
@@ -160,6 +196,9 @@ fn determine_title(
             (StatusFilter::All, RecurrenceFilter::Monthly) => "Monthly recurring tasks",
             (StatusFilter::All, RecurrenceFilter::Recurring) => "Recurring tasks",
             (StatusFilter::All, RecurrenceFilter::NonRecurring) => "Non-recurring tasks",
+            (StatusFilter::Active, _) => "Active recurring tasks",
+            (StatusFilter::Waiting, _) => "Waiting recurring tasks",
+            (StatusFilter::Cancelled, _) => "Cancelled recurring tasks",
         }
         .to_string();
     }
@@ -172,6 +211,9 @@ fn determine_title(
         (StatusFilter::Pending, None, Some(DueFilter::Soon)) => "Pending tasks due soon",
         (StatusFilter::Pending, None, _) => "Pending tasks",
         (StatusFilter::Done, _, _) => "Completed tasks",
+        (StatusFilter::Active, _, _) => "Active tasks",
+        (StatusFilter::Waiting, _, _) => "Waiting tasks",
+        (StatusFilter::Cancelled, _, _) => "Cancelled tasks",
         (StatusFilter::All, Some(Priority::High), _) => "High priority tasks",
         (StatusFilter::All, Some(Priority::Medium), _) => "Medium priority tasks",
         (StatusFilter::All, Some(Priority::Low), _) => "Low priority tasks",
@@ -208,6 +250,7 @@ mod tests {
             vec![],
             None,
             None,
+            None,
         )
     }
 
@@ -242,6 +285,7 @@ mod tests {
             vec![],
             None,
             None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -262,6 +306,7 @@ mod tests {
             vec![],
             None,
             None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -282,6 +327,7 @@ mod tests {
             vec![],
             None,
             None,
+            None,
         );
         assert!(result.is_err());
     }
@@ -305,6 +351,7 @@ mod tests {
             vec![],
             None,
             None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -325,6 +372,7 @@ mod tests {
             vec!["rust".into()],
             None,
             None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -343,6 +391,7 @@ mod tests {
             vec!["nonexistent".into()],
             None,
             None,
+            None,
         );
         assert!(result.is_err());
     }
@@ -367,6 +416,7 @@ mod tests {
             vec![],
             Some("Rustodo".into()),
             None,
+            None,
         );
         assert!(result.is_ok());
     }
@@ -400,6 +450,7 @@ mod tests {
             vec![],
             None,
             None,
+            None,
         );
         assert!(result.is_ok());
     }
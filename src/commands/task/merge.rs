@@ -0,0 +1,181 @@
+//! Handler for `todo merge <ID> <IDS>`.
+//!
+//! Folds duplicate tasks into the task `<ID>`: tags, dependencies and linked
+//! notes are combined, the earliest due date and highest priority are kept,
+//! and the duplicates are removed. Tasks that depended on a duplicate depend
+//! on the merged task instead. See [`crate::services::duplicates::merge`].
+
+use anyhow::Result;
+use colored::Colorize;
+use uuid::Uuid;
+
+use crate::models::Task;
+use crate::services::duplicates;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::confirm;
+use crate::utils::id_list::parse_id_list;
+use crate::utils::validation::resolve_uuid_visible;
+
+pub fn execute(storage: &impl Storage, into: usize, ids: &str, yes: bool) -> Result<()> {
+    let tasks = storage.load()?;
+    let keep = resolve_uuid_visible(into, &tasks)?;
+    let others = resolve_others(&tasks, keep, ids)?;
+
+    if !yes {
+        println!();
+        print_task(&tasks, keep, "keep ");
+        for uuid in &others {
+            print_task(&tasks, *uuid, "merge");
+        }
+        println!();
+        if !confirm(&format!(
+            "Merge {} task(s) into #{}? [y/N]:",
+            others.len(),
+            into
+        ))? {
+            println!("{} Merge cancelled.", "".yellow());
+            return Ok(());
+        }
+    }
+
+    let msg = merge_uuids(storage, keep, &others)?;
+    println!("{} {}", "✓".green(), msg);
+    Ok(())
+}
+
+pub fn execute_silent(storage: &impl Storage, into: usize, ids: &str) -> Result<String> {
+    let tasks = storage.load()?;
+    let keep = resolve_uuid_visible(into, &tasks)?;
+    let others = resolve_others(&tasks, keep, ids)?;
+    merge_uuids(storage, keep, &others)
+}
+
+/// Merges `others` into `keep`, saves, and records one event per task.
+pub(crate) fn merge_uuids(storage: &impl Storage, keep: Uuid, others: &[Uuid]) -> Result<String> {
+    let (mut tasks, projects, mut notes) = storage.load_all()?;
+    let moved = duplicates::merge(&mut tasks, &mut notes, keep, others)?;
+    storage.save_all(&tasks, &projects, &notes)?;
+
+    storage.record_event(EntityType::Task, keep, EventType::Edited)?;
+    for uuid in others {
+        storage.record_event(EntityType::Task, *uuid, EventType::Deleted)?;
+    }
+
+    let id = visible_id(&tasks, keep).unwrap_or(0);
+    let mut msg = format!("Merged {} task(s) into #{}", others.len(), id);
+    if !moved.is_empty() {
+        msg.push_str(&format!(" ({} note(s) moved)", moved.len()));
+    }
+    Ok(msg)
+}
+
+fn resolve_others(tasks: &[Task], keep: Uuid, ids: &str) -> Result<Vec<Uuid>> {
+    let mut others = Vec::new();
+    for id in parse_id_list(ids)? {
+        let uuid = resolve_uuid_visible(id, tasks)?;
+        if uuid != keep && !others.contains(&uuid) {
+            others.push(uuid);
+        }
+    }
+    if others.is_empty() {
+        anyhow::bail!("Nothing to merge: give at least one task other than the one to keep");
+    }
+    Ok(others)
+}
+
+pub(crate) fn visible_id(tasks: &[Task], uuid: Uuid) -> Option<usize> {
+    tasks
+        .iter()
+        .filter(|t| !t.is_deleted())
+        .position(|t| t.uuid == uuid)
+        .map(|p| p + 1)
+}
+
+fn print_task(tasks: &[Task], uuid: Uuid, label: &str) {
+    if let (Some(id), Some(task)) = (
+        visible_id(tasks, uuid),
+        tasks.iter().find(|t| t.uuid == uuid),
+    ) {
+        println!(
+            "  {}  {:>4}  {}",
+            label.dimmed(),
+            format!("#{}", id).dimmed(),
+            task.text
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Note, Priority};
+    use crate::storage::InMemoryStorage;
+
+    fn make_task(text: &str, tags: &[&str]) -> Task {
+        Task::new(
+            text.into(),
+            Priority::Medium,
+            tags.iter().map(|t| t.to_string()).collect(),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_merge_folds_duplicates_into_target() {
+        let storage = InMemoryStorage::default();
+        let dup = make_task("Fix the login bug", &["auth"]);
+        let mut note = Note::new("Repro steps".into());
+        note.task_ids = vec![dup.uuid];
+        storage
+            .save(&[
+                make_task("Fix login bug", &["bug"]),
+                make_task("Other", &[]),
+                dup,
+            ])
+            .unwrap();
+        storage.save_notes(&[note]).unwrap();
+
+        let msg = execute_silent(&storage, 1, "3").unwrap();
+        assert!(msg.contains("into #1"));
+        assert!(msg.contains("1 note(s) moved"));
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].tags, vec!["bug", "auth"]);
+        assert!(tasks[2].is_deleted());
+        assert_eq!(
+            storage.load_notes().unwrap()[0].task_ids,
+            vec![tasks[0].uuid]
+        );
+    }
+
+    #[test]
+    fn test_merge_requires_another_task() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("A", &[])]).unwrap();
+        assert!(execute_silent(&storage, 1, "1").is_err());
+        assert!(execute_silent(&storage, 1, "2").is_err());
+    }
+
+    #[test]
+    fn test_merge_refuses_to_create_a_cycle() {
+        // B; X depends on B; A depends on X. Merging B into A would make X
+        // depend on A, which depends on X.
+        let storage = InMemoryStorage::default();
+        let b = make_task("B", &[]);
+        let mut x = make_task("X", &[]);
+        x.depends_on = vec![b.uuid];
+        let mut a = make_task("A", &[]);
+        a.depends_on = vec![x.uuid];
+        storage.save(&[b, x, a]).unwrap();
+        let before = storage.load().unwrap();
+
+        let err = execute_silent(&storage, 3, "1").unwrap_err();
+        assert!(err.to_string().contains("cycle"), "{}", err);
+
+        let after = storage.load().unwrap();
+        assert!(!after[0].is_deleted(), "nothing is saved");
+        assert_eq!(after[1].depends_on, before[1].depends_on);
+    }
+}
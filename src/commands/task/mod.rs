@@ -1,11 +1,15 @@
 pub mod add;
+pub mod bulk;
 pub mod clear;
 pub mod clear_recur;
+pub mod dedupe;
 pub mod deps;
 pub mod done;
 pub mod edit;
 pub mod info;
 pub mod list;
+pub mod merge;
 pub mod recur;
 pub mod remove;
+pub mod status;
 pub mod undone;
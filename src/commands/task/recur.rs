@@ -8,10 +8,24 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::models::Recurrence;
-use crate::storage::Storage;
+use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::validation::resolve_visible_index;
 
 pub fn execute(storage: &impl Storage, id: usize, pattern: Recurrence) -> Result<()> {
+    execute_inner(storage, id, pattern, false)?;
+    Ok(())
+}
+
+pub fn execute_silent(storage: &impl Storage, id: usize, pattern: Recurrence) -> Result<String> {
+    execute_inner(storage, id, pattern, true)
+}
+
+fn execute_inner(
+    storage: &impl Storage,
+    id: usize,
+    pattern: Recurrence,
+    silent: bool,
+) -> Result<String> {
     let mut tasks = storage.load()?;
 
     let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
@@ -32,37 +46,46 @@ pub fn execute(storage: &impl Storage, id: usize, pattern: Recurrence) -> Result
 
     if old_recurrence != Some(pattern) {
         task.touch();
+        storage.upsert_task(task)?;
+        storage.record_event(EntityType::Task, task.uuid, EventType::Edited)?;
     }
 
-    storage.save(&tasks)?;
-
-    match old_recurrence {
+    let msg = match old_recurrence {
         Some(old) if old == pattern => {
-            println!(
-                "{} Recurrence already set to {} for task #{}",
-                "".yellow(),
-                pattern,
-                id,
-            );
+            if !silent {
+                println!(
+                    "{} Recurrence already set to {} for task #{}",
+                    "".yellow(),
+                    pattern,
+                    id,
+                );
+            }
+            format!("Recurrence already set to {} for task #{}", pattern, id)
         }
         Some(old) => {
-            println!(
-                "{} Updated recurrence for task #{}: {} → {}",
-                "✓".green(),
-                id,
-                old,
-                pattern
-            );
+            if !silent {
+                println!(
+                    "{} Updated recurrence for task #{}: {} → {}",
+                    "✓".green(),
+                    id,
+                    old,
+                    pattern
+                );
+            }
+            format!("Updated recurrence for task #{}: {} → {}", id, old, pattern)
         }
         None => {
-            println!(
-                "{} Set {} recurrence for task #{}",
-                "✓".green(),
-                id,
-                pattern,
-            );
+            if !silent {
+                println!(
+                    "{} Set {} recurrence for task #{}",
+                    "✓".green(),
+                    pattern,
+                    id,
+                );
+            }
+            format!("Set {} recurrence for task #{}", pattern, id)
         }
-    }
+    };
 
-    Ok(())
+    Ok(msg)
 }
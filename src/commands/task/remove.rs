@@ -34,8 +34,8 @@ fn execute_inner(storage: &impl Storage, id: usize, yes: bool, silent: bool) ->
     tasks[real_index].soft_delete();
 
     for note in notes.iter_mut().filter(|n| !n.is_deleted()) {
-        if note.task_id == Some(task_uuid) {
-            note.task_id = None;
+        if note.belongs_to_task(task_uuid) {
+            note.remove_task(task_uuid);
             note.touch();
         }
     }
@@ -101,13 +101,13 @@ mod tests {
         storage.save(&[task]).unwrap();
 
         let mut note = crate::models::Note::new("Note body".into());
-        note.task_id = Some(task_uuid);
+        note.task_ids = vec![task_uuid];
         storage.save_notes(&[note]).unwrap();
 
         execute_silent(&storage, 1).unwrap();
 
         let notes = storage.load_notes().unwrap();
-        assert!(notes[0].task_id.is_none());
+        assert!(notes[0].task_ids.is_empty());
     }
 
     #[test]
@@ -0,0 +1,188 @@
+//! Handlers for `todo start <ID>`, `todo wait <ID>` and `todo cancel <ID>`.
+//!
+//! Transitions between the open states follow a small table:
+//!
+//! | Command  | Allowed from        | Result      |
+//! |----------|---------------------|-------------|
+//! | `start`  | pending, waiting    | `active`    |
+//! | `wait`   | pending, active     | `waiting`   |
+//! | `cancel` | any open state      | `cancelled` |
+//!
+//! Closed tasks (done/cancelled) must be reopened with `todo undone` first.
+//! Completing a task stays with `todo done`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::done::ensure_not_blocked;
+use crate::error::TodoError;
+use crate::models::TaskStatus;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::validation::resolve_visible_index;
+
+pub fn execute(storage: &impl Storage, id: usize, target: TaskStatus) -> Result<()> {
+    execute_inner(storage, id, target, false)?;
+    Ok(())
+}
+
+pub fn execute_silent(storage: &impl Storage, id: usize, target: TaskStatus) -> Result<String> {
+    execute_inner(storage, id, target, true)
+}
+
+fn execute_inner(
+    storage: &impl Storage,
+    id: usize,
+    target: TaskStatus,
+    silent: bool,
+) -> Result<String> {
+    let event = match target {
+        TaskStatus::Active => EventType::Started,
+        TaskStatus::Waiting => EventType::Waiting,
+        TaskStatus::Cancelled => EventType::Cancelled,
+        TaskStatus::Pending | TaskStatus::Done => {
+            anyhow::bail!(
+                "use 'todo undone' or 'todo done' to set a task to {}",
+                target
+            )
+        }
+    };
+
+    let mut tasks = storage.load()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    let current = tasks[index].status;
+    if current == target {
+        return Err(TodoError::TaskAlreadyInStatus {
+            id,
+            status: target.to_string(),
+        }
+        .into());
+    }
+    if current.is_closed() {
+        return Err(TodoError::InvalidStatusTransition {
+            id,
+            from: current.to_string(),
+            to: target.to_string(),
+        }
+        .into());
+    }
+
+    if target == TaskStatus::Active {
+        ensure_not_blocked(&tasks, index, id)?;
+    }
+
+    let task_uuid = tasks[index].uuid;
+    tasks[index].set_status(target);
+    storage.upsert_task(&tasks[index])?;
+    storage.record_event(EntityType::Task, task_uuid, event)?;
+
+    let verb = match target {
+        TaskStatus::Active => "started",
+        TaskStatus::Waiting => "marked as waiting",
+        _ => "cancelled",
+    };
+    if !silent {
+        let label = format!("#{}", id);
+        let label = match target {
+            TaskStatus::Active => label.cyan(),
+            TaskStatus::Waiting => label.magenta(),
+            _ => label.dimmed(),
+        };
+        println!("Task {} {}.", label, verb);
+    }
+    Ok(format!("Task #{} {}.", id, verb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Task};
+    use crate::storage::InMemoryStorage;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_start_marks_task_active() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(&storage, 1, TaskStatus::Active).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].status, TaskStatus::Active);
+        assert!(!tasks[0].completed);
+    }
+
+    #[test]
+    fn test_start_blocked_task_fails() {
+        let storage = InMemoryStorage::default();
+        let dep = make_task("Dep");
+        let mut task = make_task("Task");
+        task.depends_on = vec![dep.uuid];
+        storage.save(&[dep, task]).unwrap();
+
+        let err = execute_silent(&storage, 2, TaskStatus::Active).unwrap_err();
+        assert!(err.to_string().contains("blocked"));
+    }
+
+    #[test]
+    fn test_wait_then_start() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(&storage, 1, TaskStatus::Waiting).unwrap();
+        assert_eq!(storage.load().unwrap()[0].status, TaskStatus::Waiting);
+
+        execute_silent(&storage, 1, TaskStatus::Active).unwrap();
+        assert_eq!(storage.load().unwrap()[0].status, TaskStatus::Active);
+    }
+
+    #[test]
+    fn test_cancel_sets_completed_at_but_not_completed() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(&storage, 1, TaskStatus::Cancelled).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].status, TaskStatus::Cancelled);
+        assert!(!tasks[0].completed);
+        assert!(tasks[0].completed_at.is_some());
+    }
+
+    #[test]
+    fn test_same_status_returns_error() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(&storage, 1, TaskStatus::Waiting).unwrap();
+        let err = execute_silent(&storage, 1, TaskStatus::Waiting).unwrap_err();
+        assert!(err.to_string().contains("already"));
+    }
+
+    #[test]
+    fn test_closed_task_cannot_be_started() {
+        let storage = InMemoryStorage::default();
+        let mut task = make_task("Task");
+        task.mark_done();
+        storage.save(&[task]).unwrap();
+
+        let err = execute_silent(&storage, 1, TaskStatus::Active).unwrap_err();
+        assert!(err.to_string().contains("undone"));
+    }
+
+    #[test]
+    fn test_status_change_records_event() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("Task")]).unwrap();
+
+        execute_silent(&storage, 1, TaskStatus::Cancelled).unwrap();
+
+        let stats = storage.load_event_stats(1).unwrap();
+        assert_eq!(stats.last().unwrap().cancelled, 1);
+    }
+}
@@ -1,9 +1,12 @@
 //! Handler for `todo undone <ID>`.
+//!
+//! Reopens a task from any other state (active, waiting, done or cancelled).
 
 use anyhow::Result;
 use colored::Colorize;
 
 use crate::error::TodoError;
+use crate::models::TaskStatus;
 use crate::storage::{EntityType, EventType, Storage};
 use crate::utils::validation::resolve_visible_index;
 
@@ -22,7 +25,7 @@ fn execute_inner(storage: &impl Storage, id: usize, silent: bool) -> Result<Stri
     let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
         .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
 
-    if !tasks[index].completed {
+    if tasks[index].status == TaskStatus::Pending {
         return Err(TodoError::TaskAlreadyInStatus {
             id,
             status: "pending".to_owned(),
@@ -96,4 +99,18 @@ mod tests {
         assert!(!tasks[0].completed);
         assert!(tasks[1].completed);
     }
+
+    #[test]
+    fn test_undone_reopens_cancelled_task() {
+        let storage = InMemoryStorage::default();
+        let mut task = Task::new("Task".into(), Priority::Medium, vec![], None, None, None);
+        task.set_status(TaskStatus::Cancelled);
+        storage.save(&[task]).unwrap();
+
+        execute_silent(&storage, 1).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].status, TaskStatus::Pending);
+        assert!(tasks[0].completed_at.is_none());
+    }
 }
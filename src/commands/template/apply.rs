@@ -0,0 +1,317 @@
+//! Handler for `todo template apply <NAME>`.
+//!
+//! Expands every step of a template into a new task. Each step goes through
+//! the same validation as `todo add` (text, tags, project name), due offsets
+//! are resolved against today, and `depends_on` positions are mapped to the
+//! UUIDs of the tasks generated in the same run.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use chrono::Local;
+use colored::Colorize;
+use uuid::Uuid;
+
+use super::find;
+use crate::config::Config;
+use crate::error::TodoError;
+use crate::models::{Project, Task, Template, parse_offset, substitute};
+use crate::services::tag_service::collect_all_tag_names;
+use crate::storage::{EntityType, EventType, Storage};
+use crate::utils::tag_normalizer::normalize_tags;
+use crate::utils::validation::{self, visible_indices};
+
+pub fn execute(
+    storage: &impl Storage,
+    name: &str,
+    project: Option<String>,
+    vars: Vec<String>,
+) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    execute_inner(storage, &config, name, project, &vars, false)?;
+    Ok(())
+}
+
+/// Applies a template without printing; returns the visible IDs of the new tasks.
+pub fn execute_silent(
+    storage: &impl Storage,
+    name: &str,
+    project: Option<String>,
+    vars: Vec<String>,
+) -> Result<Vec<usize>> {
+    let config = Config::load().unwrap_or_default();
+    execute_inner(storage, &config, name, project, &vars, true)
+}
+
+fn execute_inner(
+    storage: &impl Storage,
+    config: &Config,
+    name: &str,
+    project: Option<String>,
+    vars: &[String],
+    silent: bool,
+) -> Result<Vec<usize>> {
+    let (template, _) = find(storage, config, name)?;
+    let invalid = |reason: String| TodoError::InvalidTemplate {
+        name: template.name.clone(),
+        reason,
+    };
+    template.validate().map_err(invalid)?;
+
+    let vars = parse_vars(vars)?;
+    let missing: Vec<_> = template
+        .variables()
+        .into_iter()
+        .filter(|v| !vars.contains_key(v))
+        .collect();
+    if !missing.is_empty() {
+        return Err(TodoError::MissingTemplateVars {
+            name: template.name.clone(),
+            vars: missing.join(", "),
+        }
+        .into());
+    }
+    // Every placeholder has a value now, so substitution cannot fail.
+    let fill = |s: &str| substitute(s, &vars).unwrap_or_else(|_| s.to_string());
+
+    // ── Project ───────────────────────────────────────────────────────────────
+    let project_name = project.or_else(|| template.project.as_deref().map(fill));
+    if let Some(ref p) = project_name {
+        validation::validate_project_name(p)?;
+    }
+
+    // ── Validate every step before writing anything ───────────────────────────
+    let today = Local::now().date_naive();
+    let mut steps = Vec::with_capacity(template.tasks.len());
+    for step in &template.tasks {
+        let text = fill(&step.text);
+        validation::validate_task_text(&text)?;
+        let tags: Vec<String> = step.tags.iter().map(|t| fill(t)).collect();
+        validation::validate_tags(&tags)?;
+        let due = match step.due {
+            Some(ref offset) => Some(
+                parse_offset(offset)
+                    .map_err(&invalid)?
+                    .resolve(today)
+                    .ok_or_else(|| invalid(format!("due offset '{}' is out of range", offset)))?,
+            ),
+            None => None,
+        };
+        steps.push((text, step.priority, tags, due, &step.depends_on));
+    }
+
+    let mut tasks = storage.load()?;
+    let notes = storage.load_notes()?;
+    let resources = storage.load_resources()?;
+    let mut existing_tags = collect_all_tag_names(&tasks, &notes, &resources);
+
+    let project_id = match project_name {
+        Some(ref p) => {
+            let projects = storage.load_projects()?;
+            Some(Project::resolve_or_create(storage, &projects, p)?)
+        }
+        None => None,
+    };
+
+    // ── Build & persist ───────────────────────────────────────────────────────
+    let first_id = visible_indices(&tasks, |t| t.is_deleted()).len() + 1;
+    let mut created: Vec<Uuid> = Vec::with_capacity(steps.len());
+    for (text, priority, tags, due, deps) in steps {
+        let (tags, _) = normalize_tags(tags, &existing_tags);
+        for tag in &tags {
+            if !existing_tags.contains(tag) {
+                existing_tags.push(tag.clone());
+            }
+        }
+        let mut task = Task::new(text, priority, tags, project_id, due, None);
+        task.depends_on = deps.iter().map(|&pos| created[pos - 1]).collect();
+        created.push(task.uuid);
+        tasks.push(task);
+    }
+
+    storage.save(&tasks)?;
+    for &uuid in &created {
+        storage.record_event(EntityType::Task, uuid, EventType::Created)?;
+    }
+
+    let ids: Vec<usize> = (first_id..first_id + created.len()).collect();
+    if !silent {
+        print_summary(&template, &tasks[tasks.len() - created.len()..], &ids);
+    }
+    Ok(ids)
+}
+
+/// Parses `KEY=VALUE` pairs from `--var`.
+fn parse_vars(raw: &[String]) -> Result<BTreeMap<String, String>> {
+    raw.iter()
+        .map(|pair| {
+            let (k, v) = pair
+                .split_once('=')
+                .filter(|(k, _)| !k.trim().is_empty())
+                .ok_or_else(|| anyhow::anyhow!("invalid --var '{}' (expected KEY=VALUE)", pair))?;
+            Ok((k.trim().to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+fn print_summary(template: &Template, created: &[Task], ids: &[usize]) {
+    println!(
+        "{} Applied template '{}': added {} task(s)",
+        "✓".green(),
+        template.name.bold(),
+        created.len()
+    );
+    for (task, id) in created.iter().zip(ids) {
+        let due = task
+            .due_date
+            .map(|d| format!("  due {}", d).dimmed().to_string())
+            .unwrap_or_default();
+        println!(
+            "  {}  {}  {}{}",
+            format!("#{}", id).dimmed(),
+            task.priority.letter(),
+            task.text,
+            due
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, TemplateTask};
+    use crate::storage::InMemoryStorage;
+
+    fn step(text: &str, due: Option<&str>, deps: Vec<usize>) -> TemplateTask {
+        TemplateTask {
+            text: text.into(),
+            priority: Priority::Medium,
+            tags: vec!["release".into()],
+            due: due.map(String::from),
+            depends_on: deps,
+        }
+    }
+
+    fn release() -> Template {
+        let mut t = Template::new(
+            "release".into(),
+            vec![
+                step("Freeze v{{version}}", Some("+1d"), vec![]),
+                step("Test v{{version}}", Some("+2d"), vec![1]),
+                step("Publish v{{version}}", Some("+1w"), vec![1, 2]),
+            ],
+        );
+        t.project = Some("api".into());
+        t
+    }
+
+    fn apply(storage: &InMemoryStorage, config: &Config, vars: &[&str]) -> Result<Vec<usize>> {
+        let vars: Vec<String> = vars.iter().map(|s| s.to_string()).collect();
+        execute_inner(storage, config, "release", None, &vars, true)
+    }
+
+    #[test]
+    fn test_apply_creates_tasks_with_deps_dues_and_project() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save(&[Task::new(
+                "Existing".into(),
+                Priority::Low,
+                vec![],
+                None,
+                None,
+                None,
+            )])
+            .unwrap();
+        storage.save_template(&release()).unwrap();
+
+        let ids = apply(&storage, &Config::default(), &["version=2.4"]).unwrap();
+        assert_eq!(ids, vec![2, 3, 4]);
+
+        let tasks = storage.load().unwrap();
+        let today = Local::now().date_naive();
+        assert_eq!(tasks[1].text, "Freeze v2.4");
+        assert_eq!(tasks[1].due_date, Some(today + chrono::Duration::days(1)));
+        assert_eq!(tasks[3].due_date, Some(today + chrono::Duration::days(7)));
+        assert_eq!(tasks[2].depends_on, vec![tasks[1].uuid]);
+        assert_eq!(tasks[3].depends_on, vec![tasks[1].uuid, tasks[2].uuid]);
+
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects.len(), 1);
+        assert!(
+            tasks[1..]
+                .iter()
+                .all(|t| t.project_id == Some(projects[0].uuid))
+        );
+    }
+
+    #[test]
+    fn test_apply_missing_var_writes_nothing() {
+        let storage = InMemoryStorage::default();
+        storage.save_template(&release()).unwrap();
+
+        let err = apply(&storage, &Config::default(), &[]).unwrap_err();
+        assert!(err.to_string().contains("version"));
+        assert!(storage.load().unwrap().is_empty());
+        assert!(storage.load_projects().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_uses_config_template_and_project_override() {
+        let storage = InMemoryStorage::default();
+        let mut config = Config::default();
+        config.templates.insert("release".into(), release());
+
+        let vars = vec!["version=1.0".to_string()];
+        execute_inner(
+            &storage,
+            &config,
+            "RELEASE",
+            Some("web".into()),
+            &vars,
+            true,
+        )
+        .unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects[0].name, "web");
+        assert_eq!(storage.load().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_apply_rejects_invalid_step_text() {
+        let storage = InMemoryStorage::default();
+        let mut t = release();
+        t.tasks[1].text = "{{version}}".into();
+        storage.save_template(&t).unwrap();
+
+        assert!(apply(&storage, &Config::default(), &["version=  "]).is_err());
+        assert!(storage.load().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_rejects_out_of_range_due_offset() {
+        let storage = InMemoryStorage::default();
+        let mut t = release();
+        t.tasks[2].due = Some("+4000000000d".into());
+        storage.save_template(&t).unwrap();
+
+        let err = apply(&storage, &Config::default(), &["version=2.4"]).unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+        assert!(storage.load().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_unknown_template_fails() {
+        let storage = InMemoryStorage::default();
+        let err = apply(&storage, &Config::default(), &[]).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[test]
+    fn test_parse_vars_rejects_missing_equals() {
+        assert!(parse_vars(&["version".into()]).is_err());
+        let vars = parse_vars(&["a=b=c".into()]).unwrap();
+        assert_eq!(vars["a"], "b=c");
+    }
+}
@@ -0,0 +1,49 @@
+//! Handler for `todo template list`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::load_all;
+use crate::config::Config;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let templates = load_all(storage, &config)?;
+
+    if templates.is_empty() {
+        println!("\n{}", "No templates yet.".dimmed());
+        println!(
+            "  {}\n",
+            "Create one with: todo template save <NAME> <IDs>...".dimmed()
+        );
+        return Ok(());
+    }
+
+    let width = templates
+        .iter()
+        .map(|(t, _)| t.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    println!("\n{}\n", "Templates".bright_white().bold());
+    for (template, source) in &templates {
+        let vars = template.variables();
+        let vars = if vars.is_empty() {
+            String::new()
+        } else {
+            format!("  vars: {}", vars.join(", "))
+        };
+        println!(
+            "  {:<w$}  {:>3} step(s)  {:<6}  {}{}",
+            template.name.cyan(),
+            template.tasks.len(),
+            source.label().dimmed(),
+            template.description.as_deref().unwrap_or(""),
+            vars.dimmed(),
+            w = width
+        );
+    }
+    println!();
+    Ok(())
+}
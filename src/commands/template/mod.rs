@@ -0,0 +1,68 @@
+//! Handlers for `todo template …`.
+//!
+//! Templates are looked up in the database first and then in the
+//! `[templates]` section of `config.toml`.
+
+pub mod apply;
+pub mod list;
+pub mod remove;
+pub mod save;
+pub mod show;
+
+use anyhow::Result;
+
+use crate::config::Config;
+use crate::error::TodoError;
+use crate::models::Template;
+use crate::storage::Storage;
+
+/// Where a template was defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSource {
+    Database,
+    Config,
+}
+
+impl TemplateSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            TemplateSource::Database => "db",
+            TemplateSource::Config => "config",
+        }
+    }
+}
+
+/// All templates visible to the user, sorted by name. A database template
+/// hides a config template with the same name.
+pub(crate) fn load_all(
+    storage: &impl Storage,
+    config: &Config,
+) -> Result<Vec<(Template, TemplateSource)>> {
+    let mut all: Vec<_> = storage
+        .load_templates()?
+        .into_iter()
+        .map(|t| (t, TemplateSource::Database))
+        .collect();
+    for template in config.templates.values() {
+        if !all
+            .iter()
+            .any(|(t, _)| t.name.eq_ignore_ascii_case(&template.name))
+        {
+            all.push((template.clone(), TemplateSource::Config));
+        }
+    }
+    all.sort_by_key(|(t, _)| t.name.to_lowercase());
+    Ok(all)
+}
+
+/// Finds a template by name (case-insensitive).
+pub(crate) fn find(
+    storage: &impl Storage,
+    config: &Config,
+    name: &str,
+) -> Result<(Template, TemplateSource)> {
+    load_all(storage, config)?
+        .into_iter()
+        .find(|(t, _)| t.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| TodoError::TemplateNotFound(name.to_string()).into())
+}
@@ -0,0 +1,32 @@
+//! Handler for `todo template remove <NAME>`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::error::TodoError;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, name: &str) -> Result<()> {
+    execute_silent(storage, name)?;
+    println!("{} Removed template '{}'", "✓".green(), name.bold());
+    Ok(())
+}
+
+pub fn execute_silent(storage: &impl Storage, name: &str) -> Result<()> {
+    if storage.delete_template(name)? {
+        return Ok(());
+    }
+    let config = Config::load().unwrap_or_default();
+    if config
+        .templates
+        .keys()
+        .any(|k| k.eq_ignore_ascii_case(name))
+    {
+        anyhow::bail!(
+            "template '{}' is defined in config.toml; edit that file to remove it",
+            name
+        );
+    }
+    Err(TodoError::TemplateNotFound(name.to_string()).into())
+}
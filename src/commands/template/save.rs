@@ -0,0 +1,237 @@
+//! Handler for `todo template save <NAME> <IDs>...`.
+//!
+//! Captures existing tasks as a template. Due dates become offsets from
+//! today (past due dates are dropped with a warning), dependencies between
+//! the selected tasks are kept (steps are reordered so every step comes
+//! after the ones it depends on), and a project shared by all tasks becomes
+//! the template's default project.
+
+use anyhow::Result;
+use chrono::Local;
+use colored::Colorize;
+
+use crate::error::TodoError;
+use crate::models::{Task, Template, TemplateTask};
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible_index;
+
+pub fn execute(
+    storage: &impl Storage,
+    name: &str,
+    ids: &[usize],
+    description: Option<String>,
+    force: bool,
+) -> Result<()> {
+    let (template, past_due) = execute_silent(storage, name, ids, description, force)?;
+    if !past_due.is_empty() {
+        let steps: Vec<String> = past_due.iter().map(|n| n.to_string()).collect();
+        println!(
+            "{} Due date in the past, not saved for step{} {}",
+            "!".yellow(),
+            if past_due.len() == 1 { "" } else { "s" },
+            steps.join(", ")
+        );
+    }
+    println!(
+        "{} Saved template '{}' with {} step(s)",
+        "✓".green(),
+        template.name.bold(),
+        template.tasks.len()
+    );
+    println!(
+        "  {}",
+        format!("Apply it with: todo template apply {}", template.name).dimmed()
+    );
+    Ok(())
+}
+
+pub fn execute_silent(
+    storage: &impl Storage,
+    name: &str,
+    ids: &[usize],
+    description: Option<String>,
+    force: bool,
+) -> Result<(Template, Vec<usize>)> {
+    let name = name.trim();
+    validate_name(name)?;
+    if ids.is_empty() {
+        anyhow::bail!("no task IDs given");
+    }
+
+    if !force
+        && storage
+            .load_templates()?
+            .iter()
+            .any(|t| t.name.eq_ignore_ascii_case(name))
+    {
+        return Err(TodoError::TemplateAlreadyExists(name.to_string()).into());
+    }
+
+    let (tasks, projects, _) = storage.load_all()?;
+
+    let mut selected: Vec<&Task> = Vec::new();
+    for &id in ids {
+        let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+            .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+        if !selected.iter().any(|t| t.uuid == tasks[index].uuid) {
+            selected.push(&tasks[index]);
+        }
+    }
+
+    let ordered = order_by_dependencies(&selected);
+    let today = Local::now().date_naive();
+    // 1-based steps whose due date has passed; offsets cannot point back.
+    let past_due: Vec<usize> = ordered
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.due_date.is_some_and(|d| d < today))
+        .map(|(i, _)| i + 1)
+        .collect();
+    let steps = ordered
+        .iter()
+        .map(|task| TemplateTask {
+            text: task.text.clone(),
+            priority: task.priority,
+            tags: task.tags.clone(),
+            due: task
+                .due_date
+                .map(|d| (d - today).num_days())
+                .filter(|&days| days >= 0)
+                .map(|days| format!("+{}d", days)),
+            depends_on: task
+                .depends_on
+                .iter()
+                .filter_map(|dep| ordered.iter().position(|t| t.uuid == *dep))
+                .map(|pos| pos + 1)
+                .collect(),
+        })
+        .collect();
+
+    let mut template = Template::new(name.to_string(), steps);
+    template.description = description;
+
+    let first_project = ordered[0].project_id;
+    if first_project.is_some() && ordered.iter().all(|t| t.project_id == first_project) {
+        template.project = projects
+            .iter()
+            .find(|p| Some(p.uuid) == first_project)
+            .map(|p| p.name.clone());
+    }
+
+    storage.save_template(&template)?;
+    Ok((template, past_due))
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(TodoError::InvalidTemplate {
+            name: name.to_string(),
+            reason: "names may only contain letters, digits, '-' and '_'".into(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Stable topological sort: keeps the user's order except where a task must
+/// move after one of its (selected) dependencies.
+fn order_by_dependencies<'a>(selected: &[&'a Task]) -> Vec<&'a Task> {
+    let mut remaining: Vec<&Task> = selected.to_vec();
+    let mut ordered: Vec<&Task> = Vec::with_capacity(remaining.len());
+    while !remaining.is_empty() {
+        let ready = remaining
+            .iter()
+            .position(|t| {
+                t.depends_on
+                    .iter()
+                    .all(|dep| !remaining.iter().any(|r| r.uuid == *dep))
+            })
+            // Cycles are rejected when dependencies are added; fall back to
+            // input order rather than looping forever on corrupt data.
+            .unwrap_or(0);
+        ordered.push(remaining.remove(ready));
+    }
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Priority, Project};
+    use crate::storage::InMemoryStorage;
+
+    fn make_task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_save_orders_steps_after_their_dependencies() {
+        let storage = InMemoryStorage::default();
+        let a = make_task("Build");
+        let mut b = make_task("Deploy");
+        b.depends_on = vec![a.uuid];
+        b.due_date = Some(Local::now().date_naive() + chrono::Duration::days(3));
+        storage.save(&[a, b]).unwrap();
+
+        let (t, _) = execute_silent(&storage, "ship", &[2, 1], None, false).unwrap();
+
+        assert_eq!(t.tasks[0].text, "Build");
+        assert_eq!(t.tasks[1].text, "Deploy");
+        assert_eq!(t.tasks[1].depends_on, vec![1]);
+        assert_eq!(t.tasks[1].due.as_deref(), Some("+3d"));
+        assert!(t.validate().is_ok());
+        assert_eq!(storage.load_templates().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_save_drops_deps_outside_selection_and_keeps_shared_project() {
+        let storage = InMemoryStorage::default();
+        let project = Project::new("api".into());
+        let outside = make_task("Outside");
+        let mut task = make_task("Inside");
+        task.depends_on = vec![outside.uuid];
+        task.project_id = Some(project.uuid);
+        storage.save_projects(&[project]).unwrap();
+        storage.save(&[outside, task]).unwrap();
+
+        let (t, _) = execute_silent(&storage, "one", &[2], None, false).unwrap();
+        assert!(t.tasks[0].depends_on.is_empty());
+        assert_eq!(t.project.as_deref(), Some("api"));
+    }
+
+    #[test]
+    fn test_save_reports_past_due_steps() {
+        let storage = InMemoryStorage::default();
+        let mut late = make_task("Late");
+        late.due_date = Some(Local::now().date_naive() - chrono::Duration::days(2));
+        storage.save(&[make_task("Plain"), late]).unwrap();
+
+        let (t, past_due) = execute_silent(&storage, "review", &[1, 2], None, false).unwrap();
+        assert_eq!(t.tasks[1].due, None);
+        assert_eq!(past_due, vec![2]);
+    }
+
+    #[test]
+    fn test_save_existing_name_requires_force() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("A")]).unwrap();
+
+        execute_silent(&storage, "daily", &[1], None, false).unwrap();
+        assert!(execute_silent(&storage, "Daily", &[1], None, false).is_err());
+        execute_silent(&storage, "Daily", &[1], None, true).unwrap();
+        assert_eq!(storage.load_templates().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_save_rejects_bad_name_and_ids() {
+        let storage = InMemoryStorage::default();
+        storage.save(&[make_task("A")]).unwrap();
+
+        assert!(execute_silent(&storage, "has space", &[1], None, false).is_err());
+        assert!(execute_silent(&storage, "ok", &[5], None, false).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! Handler for `todo template show <NAME>`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::find;
+use crate::config::Config;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, name: &str) -> Result<()> {
+    let config = Config::load().unwrap_or_default();
+    let (template, source) = find(storage, &config, name)?;
+
+    println!();
+    println!(
+        "  {}  {}",
+        format!("Template: {}", template.name).bold().bright_white(),
+        format!("({})", source.label()).dimmed()
+    );
+    println!("  {}", "─".repeat(50).dimmed());
+    if let Some(ref description) = template.description {
+        println!("  {}  {}", "Description".dimmed(), description);
+    }
+    if let Some(ref project) = template.project {
+        println!("  {}  {}", "Project".dimmed(), project.magenta());
+    }
+    let vars = template.variables();
+    if !vars.is_empty() {
+        println!("  {}  {}", "Variables".dimmed(), vars.join(", "));
+    }
+    println!();
+
+    for (i, step) in template.tasks.iter().enumerate() {
+        let mut extra = Vec::new();
+        if let Some(ref due) = step.due {
+            extra.push(format!("due {}", due));
+        }
+        if !step.tags.is_empty() {
+            extra.push(format!("#{}", step.tags.join(" #")));
+        }
+        if !step.depends_on.is_empty() {
+            let deps: Vec<String> = step.depends_on.iter().map(|d| d.to_string()).collect();
+            extra.push(format!("after {}", deps.join(", ")));
+        }
+        println!(
+            "  {:>3}.  {}  {}  {}",
+            i + 1,
+            step.priority.letter(),
+            step.text,
+            extra.join("  ").dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
@@ -0,0 +1,601 @@
+//! Handlers for `todo trash`.
+//!
+//! Removing a task, project, note or resource only soft-deletes it: the row
+//! stays in storage with `deleted_at` set. The trash lists these tombstones,
+//! newest first, and numbers them across all entity types:
+//! - `todo trash` lists them with how long ago they were deleted
+//! - `todo trash restore <ID>` clears `deleted_at`
+//! - `todo trash empty` removes them permanently, prunes note revisions
+//!   beyond the `[notes] keep_revisions` setting, and deletes attachment
+//!   contents no attachment refers to any more
+//!
+//! Removal unlinks notes from the task or project they were attached to, and
+//! that link is not restored. A task's own dependencies, and the tasks that
+//! depended on it, are kept through the deletion and become active again on
+//! restore — except dependencies that would now close a cycle, which are
+//! dropped.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::error::TodoError;
+use crate::models::{Note, Project, Resource, Task, TrashType, detect_cycle};
+use crate::render::formatting::{format_size, truncate};
+use crate::storage::{EntityType, EventType, Storage, attachments};
+use crate::utils::confirm;
+use crate::utils::date_parser::parse_days;
+
+fn entity_type(kind: TrashType) -> EntityType {
+    match kind {
+        TrashType::Task => EntityType::Task,
+        TrashType::Project => EntityType::Project,
+        TrashType::Note => EntityType::Note,
+        TrashType::Resource => EntityType::Resource,
+    }
+}
+
+pub trait HasDeletedAt {
+    fn deleted_at(&self) -> Option<DateTime<Utc>>;
+    fn uuid(&self) -> Uuid;
+    fn label(&self) -> String;
+}
+
+impl HasDeletedAt for Task {
+    fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+    fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+    fn label(&self) -> String {
+        self.text.clone()
+    }
+}
+
+impl HasDeletedAt for Project {
+    fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+    fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+    fn label(&self) -> String {
+        self.name.clone()
+    }
+}
+
+impl HasDeletedAt for Note {
+    fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+    fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+    fn label(&self) -> String {
+        self.title.clone().unwrap_or_else(|| {
+            self.body
+                .lines()
+                .find(|l| !l.trim().is_empty())
+                .map(|l| l.trim_start_matches('#').trim().to_string())
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl HasDeletedAt for Resource {
+    fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+    fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+    fn label(&self) -> String {
+        self.title.clone()
+    }
+}
+
+/// A soft-deleted entity as shown in the trash.
+#[derive(Debug, Clone)]
+pub(crate) struct Tombstone {
+    pub kind: TrashType,
+    pub uuid: Uuid,
+    pub label: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Every tombstone, newest first. A tombstone's trash ID is its position
+/// in this list plus one.
+pub(crate) fn tombstones(
+    tasks: &[Task],
+    projects: &[Project],
+    notes: &[Note],
+    resources: &[Resource],
+) -> Vec<Tombstone> {
+    fn collect<T: HasDeletedAt>(kind: TrashType, items: &[T], out: &mut Vec<Tombstone>) {
+        out.extend(items.iter().filter_map(|item| {
+            item.deleted_at().map(|deleted_at| Tombstone {
+                kind,
+                uuid: item.uuid(),
+                label: item.label(),
+                deleted_at,
+            })
+        }));
+    }
+
+    let mut out = Vec::new();
+    collect(TrashType::Task, tasks, &mut out);
+    collect(TrashType::Project, projects, &mut out);
+    collect(TrashType::Note, notes, &mut out);
+    collect(TrashType::Resource, resources, &mut out);
+    out.sort_by_key(|t| std::cmp::Reverse(t.deleted_at));
+    out
+}
+
+pub fn list(storage: &impl Storage, kind: Option<TrashType>) -> Result<()> {
+    let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+    let trash = tombstones(&tasks, &projects, &notes, &resources);
+    let shown: Vec<(usize, &Tombstone)> = trash
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| kind.is_none_or(|k| t.kind == k))
+        .map(|(i, t)| (i + 1, t))
+        .collect();
+
+    if shown.is_empty() {
+        println!("{}", "\nThe trash is empty.\n".dimmed());
+        return Ok(());
+    }
+
+    let label_w = shown
+        .iter()
+        .map(|(_, t)| t.label.chars().count())
+        .max()
+        .unwrap_or(4)
+        .clamp(4, 50);
+    let now = Utc::now();
+
+    println!(
+        "\n{}\n",
+        format!("Trash  ({})", shown.len()).bright_white().bold()
+    );
+    println!(
+        "{:>4}  {:<8}  {:<label_w$}  {}",
+        "ID".dimmed(),
+        "Type".dimmed(),
+        "Item".dimmed(),
+        "Deleted".dimmed(),
+    );
+    println!("{}", "─".repeat(4 + 2 + 8 + 2 + label_w + 2 + 12).dimmed());
+    for (id, tomb) in shown {
+        println!(
+            "{:>4}  {:<8}  {:<label_w$}  {}",
+            format!("#{}", id).dimmed(),
+            tomb.kind.as_str(),
+            truncate(&tomb.label, label_w),
+            age(now - tomb.deleted_at).dimmed(),
+        );
+    }
+    println!(
+        "\n{}\n",
+        "Restore with 'todo trash restore <ID>', or remove for good with 'todo trash empty'."
+            .dimmed()
+    );
+    Ok(())
+}
+
+pub fn restore(storage: &impl Storage, id: usize) -> Result<()> {
+    let (mut tasks, mut projects, mut notes, mut resources) = storage.load_all_with_resources()?;
+    let trash = tombstones(&tasks, &projects, &notes, &resources);
+    let tomb = trash
+        .get(id.wrapping_sub(1))
+        .ok_or(TodoError::TrashItemNotFound(id))?;
+
+    let live_task = |uuid: Uuid| tasks.iter().any(|t| t.uuid == uuid && !t.is_deleted());
+    let live_project = |uuid: Uuid| projects.iter().any(|p| p.uuid == uuid && !p.is_deleted());
+
+    match tomb.kind {
+        TrashType::Task => {
+            let idx = tasks.iter().position(|t| t.uuid == tomb.uuid).unwrap();
+            let mut task = tasks[idx].clone();
+            task.restore();
+            task.project_id = task.project_id.filter(|&p| live_project(p));
+            task.parent_id = task.parent_id.filter(|&p| live_task(p));
+
+            // Re-link dependencies one at a time against the live graph, so
+            // any that would close a cycle created meanwhile are dropped.
+            let mut live: Vec<Task> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
+            let mut relinked = task.clone();
+            relinked.depends_on.clear();
+            live.push(relinked);
+            let mut dropped = Vec::new();
+            for dep in &task.depends_on {
+                let Some(dep_task) = tasks.iter().find(|t| t.uuid == *dep) else {
+                    continue;
+                };
+                if !dep_task.is_deleted() && detect_cycle(&live, task.uuid, *dep).is_err() {
+                    dropped.push(dep_task.text.clone());
+                    continue;
+                }
+                live.last_mut().unwrap().depends_on.push(*dep);
+            }
+            task.depends_on = live.pop().unwrap().depends_on;
+            tasks[idx] = task;
+            storage.save(&tasks)?;
+
+            let visible: Vec<&Task> = tasks.iter().filter(|t| !t.is_deleted()).collect();
+            let visible_id = |uuid: Uuid| visible.iter().position(|t| t.uuid == uuid).unwrap() + 1;
+            let restored = &tasks[idx];
+            let deps: Vec<String> = restored
+                .depends_on
+                .iter()
+                .filter(|d| visible.iter().any(|t| t.uuid == **d))
+                .map(|d| format!("#{}", visible_id(*d)))
+                .collect();
+            let dependents: Vec<String> = visible
+                .iter()
+                .filter(|t| t.depends_on.contains(&restored.uuid))
+                .map(|t| format!("#{}", visible_id(t.uuid)))
+                .collect();
+
+            println!(
+                "{} Restored task #{}: {}",
+                "✓".green(),
+                visible_id(restored.uuid),
+                restored.text
+            );
+            if !deps.is_empty() {
+                println!("  {} {}", "depends on".dimmed(), deps.join(", "));
+            }
+            if !dependents.is_empty() {
+                println!("  {} {}", "blocks".dimmed(), dependents.join(", "));
+            }
+            for text in dropped {
+                println!(
+                    "  {} dropped dependency on \"{}\" (would create a cycle)",
+                    "⚠".yellow(),
+                    text
+                );
+            }
+        }
+        TrashType::Project => {
+            let idx = projects.iter().position(|p| p.uuid == tomb.uuid).unwrap();
+            // A deleted parent has no place in the tree; come back at the top.
+            let parent_id = projects[idx].parent(&projects).map(|parent| parent.uuid);
+            projects[idx].parent_id = parent_id;
+            let name = projects[idx].path(&projects);
+            if Project::find_by_path(&projects, &name).is_some() {
+                return Err(anyhow::anyhow!(
+                    "Project \"{}\" already exists; rename it before restoring",
+                    name
+                ));
+            }
+            projects[idx].restore();
+            storage.save_projects(&projects)?;
+            println!("{} Restored project: {}", "✓".green(), name);
+        }
+        TrashType::Note => {
+            let idx = notes.iter().position(|n| n.uuid == tomb.uuid).unwrap();
+            let note = &mut notes[idx];
+            note.restore();
+            note.task_ids.retain(|&t| live_task(t));
+            note.project_ids.retain(|&p| live_project(p));
+            note.resource_ids.retain(|r| {
+                resources
+                    .iter()
+                    .any(|res| res.uuid == *r && !res.is_deleted())
+            });
+            storage.save_notes(&notes)?;
+            println!("{} Restored note: {}", "✓".green(), tomb.label);
+        }
+        TrashType::Resource => {
+            let idx = resources.iter().position(|r| r.uuid == tomb.uuid).unwrap();
+            resources[idx].restore();
+            storage.save_resources(&resources)?;
+            println!("{} Restored resource: {}", "✓".green(), tomb.label);
+        }
+    }
+
+    storage.record_event(entity_type(tomb.kind), tomb.uuid, EventType::Restored)?;
+    Ok(())
+}
+
+/// `todo trash empty`: permanently removes tombstones, optionally only those
+/// deleted at least `older_than` ago (e.g. `30d`).
+pub fn empty(
+    storage: &impl Storage,
+    older_than: Option<&str>,
+    kind: Option<TrashType>,
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    let days = older_than.map(parse_days).transpose()?;
+    empty_older_than(storage, days, kind, dry_run, yes)
+}
+
+/// Shared by `todo trash empty` and `todo purge`.
+pub(crate) fn empty_older_than(
+    storage: &impl Storage,
+    days: Option<u32>,
+    kind: Option<TrashType>,
+    dry_run: bool,
+    yes: bool,
+) -> Result<()> {
+    let (tasks, projects, notes, resources) = storage.load_all_with_resources()?;
+    let cutoff = days
+        .map(|d| {
+            Utc::now()
+                .checked_sub_signed(chrono::Duration::days(d as i64))
+                .ok_or(TodoError::DurationOutOfRange(d))
+        })
+        .transpose()?;
+    let selected: Vec<Tombstone> = tombstones(&tasks, &projects, &notes, &resources)
+        .into_iter()
+        .filter(|t| kind.is_none_or(|k| t.kind == k))
+        .filter(|t| cutoff.is_none_or(|c| t.deleted_at <= c))
+        .collect();
+
+    let age_note = match days {
+        Some(d) => format!(" older than {} day{}", d, if d == 1 { "" } else { "s" }),
+        None => String::new(),
+    };
+    let total = selected.len();
+
+    if total == 0 {
+        println!(
+            "{}",
+            format!("\nNo tombstones{} found.\n", age_note).dimmed()
+        );
+        if !dry_run {
+            prune_revisions(storage)?;
+            collect_attachments(storage)?;
+        }
+        return Ok(());
+    }
+
+    println!(
+        "\n{} tombstone{}{} would be permanently removed:\n",
+        total.to_string().yellow(),
+        if total == 1 { "" } else { "s" },
+        age_note,
+    );
+
+    let of_kind =
+        |k: TrashType| -> Vec<&Tombstone> { selected.iter().filter(|t| t.kind == k).collect() };
+    for (label, k) in [
+        ("tasks", TrashType::Task),
+        ("projects", TrashType::Project),
+        ("notes", TrashType::Note),
+        ("resources", TrashType::Resource),
+    ] {
+        let tombs = of_kind(k);
+        if !tombs.is_empty() {
+            println!("  {}:", label.dimmed());
+            for tomb in tombs {
+                println!("    {} {}", "✗".dimmed(), tomb.label.dimmed());
+            }
+        }
+    }
+    println!();
+
+    if dry_run {
+        println!("{}", "Dry run — nothing was removed.".dimmed());
+        return Ok(());
+    }
+
+    if !yes && !confirm("Permanently delete these tombstones? [y/N]:")? {
+        println!("{}", "Purge cancelled.".dimmed());
+        return Ok(());
+    }
+
+    // Record Purged events BEFORE physical delete so the event log captures
+    // what was removed even though the rows are about to disappear.
+    for tomb in &selected {
+        storage.record_event(entity_type(tomb.kind), tomb.uuid, EventType::Purged)?;
+    }
+
+    let uuids = |k: TrashType| -> Vec<Uuid> { of_kind(k).iter().map(|t| t.uuid).collect() };
+    let task_uuids = uuids(TrashType::Task);
+    let project_uuids = uuids(TrashType::Project);
+    let note_uuids = uuids(TrashType::Note);
+    let resource_uuids = uuids(TrashType::Resource);
+
+    if !task_uuids.is_empty() {
+        storage.delete_tasks(&task_uuids)?;
+    }
+    if !project_uuids.is_empty() {
+        storage.delete_projects(&project_uuids)?;
+    }
+    if !note_uuids.is_empty() {
+        storage.delete_notes(&note_uuids)?;
+    }
+    if !resource_uuids.is_empty() {
+        storage.delete_resources(&resource_uuids)?;
+    }
+
+    println!(
+        "{} Permanently removed {} tombstone{}.",
+        "✓".green(),
+        total.to_string().green(),
+        if total == 1 { "" } else { "s" },
+    );
+    prune_revisions(storage)?;
+    collect_attachments(storage)?;
+
+    Ok(())
+}
+
+/// Drops note revisions beyond the `[notes] keep_revisions` setting, along
+/// with those of notes that no longer exist.
+fn prune_revisions(storage: &impl Storage) -> Result<()> {
+    let keep = Config::load().unwrap_or_default().notes.keep_revisions();
+    let pruned = storage.prune_note_revisions(keep)?;
+    if pruned > 0 {
+        println!(
+            "{} Pruned {} old note revision{}.",
+            "✓".green(),
+            pruned.to_string().green(),
+            if pruned == 1 { "" } else { "s" },
+        );
+    }
+    Ok(())
+}
+
+/// Deletes blobs in the attachment store that no attachment refers to.
+fn collect_attachments(storage: &impl Storage) -> Result<()> {
+    let files = storage.load_attachments()?;
+    let referenced: HashSet<&str> = files.iter().map(|a| a.hash.as_str()).collect();
+    let (count, bytes) = attachments::collect_garbage(&storage.attachments_dir()?, &referenced)?;
+    if count > 0 {
+        println!(
+            "{} Removed {} unused attachment file{} ({}).",
+            "✓".green(),
+            count.to_string().green(),
+            if count == 1 { "" } else { "s" },
+            format_size(bytes),
+        );
+    }
+    Ok(())
+}
+
+/// "today", "1 day ago", "12 days ago".
+fn age(elapsed: chrono::Duration) -> String {
+    match elapsed.num_days() {
+        d if d <= 0 => "today".to_string(),
+        1 => "1 day ago".to_string(),
+        d => format!("{} days ago", d),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use crate::storage::InMemoryStorage;
+
+    fn task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_tombstones_are_newest_first_across_types() {
+        let mut old = task("Old");
+        old.deleted_at = Some(Utc::now() - chrono::Duration::days(10));
+        let mut note = Note::new("Fresh note".into());
+        note.soft_delete();
+        let live = task("Live");
+
+        let trash = tombstones(&[old, live], &[], &[note], &[]);
+        let labels: Vec<&str> = trash.iter().map(|t| t.label.as_str()).collect();
+        assert_eq!(labels, vec!["Fresh note", "Old"]);
+        assert_eq!(trash[0].kind, TrashType::Note);
+    }
+
+    #[test]
+    fn test_restore_task_keeps_dependencies_both_ways() {
+        let storage = InMemoryStorage::default();
+        let a = task("A");
+        let mut b = task("B");
+        b.depends_on = vec![a.uuid];
+        let mut c = task("C");
+        c.depends_on = vec![b.uuid];
+        b.soft_delete();
+        storage.save(&[a.clone(), b.clone(), c]).unwrap();
+
+        restore(&storage, 1).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert!(!tasks[1].is_deleted());
+        assert_eq!(tasks[1].depends_on, vec![a.uuid]);
+        assert_eq!(tasks[2].depends_on, vec![b.uuid]);
+    }
+
+    #[test]
+    fn test_restore_task_drops_dependency_that_would_close_a_cycle() {
+        let storage = InMemoryStorage::default();
+        let a = task("A");
+        let mut b = task("B");
+        b.depends_on = vec![a.uuid];
+        b.soft_delete();
+        let mut a = a;
+        // While B was in the trash, A came to depend on B's dependent chain.
+        let mut c = task("C");
+        c.depends_on = vec![b.uuid];
+        a.depends_on = vec![c.uuid];
+        storage.save(&[a, b, c]).unwrap();
+
+        restore(&storage, 1).unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert!(!tasks[1].is_deleted());
+        assert!(tasks[1].depends_on.is_empty());
+    }
+
+    #[test]
+    fn test_restore_clears_links_to_deleted_project() {
+        let storage = InMemoryStorage::default();
+        let mut project = Project::new("Gone".into());
+        let mut t = task("Orphan");
+        t.project_id = Some(project.uuid);
+        t.deleted_at = Some(Utc::now() - chrono::Duration::days(1));
+        project.soft_delete();
+        storage.save_projects(&[project]).unwrap();
+        storage.save(&[t]).unwrap();
+
+        // #1 is the project (deleted most recently), #2 the task.
+        restore(&storage, 2).unwrap();
+        let tasks = storage.load().unwrap();
+        assert!(!tasks[0].is_deleted());
+        assert_eq!(tasks[0].project_id, None);
+    }
+
+    #[test]
+    fn test_restore_project_with_taken_name_fails() {
+        let storage = InMemoryStorage::default();
+        let mut old = Project::new("Website".into());
+        old.soft_delete();
+        storage
+            .save_projects(&[old, Project::new("website".into())])
+            .unwrap();
+        assert!(restore(&storage, 1).is_err());
+    }
+
+    #[test]
+    fn test_restore_unknown_id_fails() {
+        let storage = InMemoryStorage::default();
+        let err = restore(&storage, 0).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<TodoError>(),
+            Some(TodoError::TrashItemNotFound(0))
+        ));
+    }
+
+    #[test]
+    fn test_empty_filters_by_type() {
+        let storage = InMemoryStorage::default();
+        let mut t = task("T");
+        t.soft_delete();
+        let mut note = Note::new("N".into());
+        note.soft_delete();
+        storage.save(&[t]).unwrap();
+        storage.save_notes(&[note]).unwrap();
+
+        empty(&storage, None, Some(TrashType::Note), false, true).unwrap();
+
+        assert_eq!(storage.load().unwrap().len(), 1);
+        assert!(storage.load_notes().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_empty_out_of_range_age_fails() {
+        let storage = InMemoryStorage::default();
+        let err = empty(&storage, Some("99999999d"), None, true, true).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<TodoError>(),
+            Some(TodoError::DurationOutOfRange(99999999))
+        ));
+    }
+}
@@ -0,0 +1,87 @@
+//! Handler for `todo urgency <ID>`.
+//!
+//! Prints each term that contributes to a task's urgency score, using the
+//! coefficients from the `[urgency]` section of `config.toml`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::Config;
+use crate::models::UrgencyBreakdown;
+use crate::storage::Storage;
+use crate::utils::validation::resolve_visible_index;
+
+pub fn execute(storage: &impl Storage, id: usize) -> Result<()> {
+    let (tasks, projects, _) = storage.load_all()?;
+
+    let index = resolve_visible_index(&tasks, id, |t| t.is_deleted())
+        .map_err(|_| anyhow::anyhow!("invalid task ID: {}", id))?;
+
+    let task = &tasks[index];
+    let visible: Vec<_> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
+    let cfg = Config::load().unwrap_or_default().urgency;
+    let breakdown = task.urgency_breakdown(&visible, &projects, &cfg);
+
+    println!();
+    println!(
+        "  {}",
+        format!("Urgency for task #{}: {}", id, task.text)
+            .bold()
+            .bright_white()
+    );
+    println!("  {}", "─".repeat(50).dimmed());
+
+    if !task.is_open() {
+        println!(
+            "  {}",
+            format!("Task is {} — closed tasks always score 0.", task.status).dimmed()
+        );
+        println!();
+        return Ok(());
+    }
+
+    print_breakdown(&breakdown);
+    println!();
+    Ok(())
+}
+
+fn print_breakdown(breakdown: &UrgencyBreakdown) {
+    let name_width = breakdown
+        .components
+        .iter()
+        .map(|c| c.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+
+    for c in &breakdown.components {
+        let value = format!("{:>+7.2}", c.value);
+        let value = if c.value < 0.0 {
+            value.red()
+        } else {
+            value.normal()
+        };
+        println!(
+            "  {:<w$}  {}  {}",
+            c.name.cyan(),
+            value,
+            c.detail.dimmed(),
+            w = name_width
+        );
+    }
+
+    println!("  {}", "─".repeat(50).dimmed());
+    let raw: f32 = breakdown.components.iter().map(|c| c.value).sum();
+    let total = format!("{:>7.2}", breakdown.total).bold();
+    if raw < 0.0 {
+        println!(
+            "  {:<w$}  {}  {}",
+            "total".bold(),
+            total,
+            "(negative sum floored at 0)".dimmed(),
+            w = name_width
+        );
+    } else {
+        println!("  {:<w$}  {}", "total".bold(), total, w = name_width);
+    }
+}
@@ -0,0 +1,103 @@
+//! Handlers for `todo ctx set <VIEW>`, `todo ctx clear` and `todo ctx`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::find;
+use crate::storage::Storage;
+
+pub fn set(storage: &impl Storage, name: &str) -> Result<()> {
+    let view = find(storage, name)?;
+    storage.save_context(Some(&view.name))?;
+    println!(
+        "{} Context set to '{}': {}",
+        "✓".green(),
+        view.name.bold(),
+        if view.filter.is_empty() {
+            "(all tasks)"
+        } else {
+            &view.filter
+        }
+    );
+    println!(
+        "  {}",
+        "Applies to list, next, calendar, stats and the TUI until 'todo ctx clear'.".dimmed()
+    );
+    Ok(())
+}
+
+pub fn clear(storage: &impl Storage) -> Result<()> {
+    match storage.load_context()? {
+        Some(name) => {
+            storage.save_context(None)?;
+            println!("{} Context '{}' cleared", "✓".green(), name);
+        }
+        None => println!("{}", "No context is set.".dimmed()),
+    }
+    Ok(())
+}
+
+pub fn show(storage: &impl Storage) -> Result<()> {
+    let Some(name) = storage.load_context()? else {
+        println!("{}", "No context is set.".dimmed());
+        println!(
+            "  {}",
+            "Set one with: todo ctx set <VIEW>  (see 'todo view list')".dimmed()
+        );
+        return Ok(());
+    };
+    match find(storage, &name) {
+        Ok(view) => println!(
+            "Context: {}  {}",
+            view.name.cyan().bold(),
+            if view.filter.is_empty() {
+                "(all tasks)"
+            } else {
+                &view.filter
+            }
+        ),
+        Err(_) => println!(
+            "Context '{}' refers to a view that no longer exists; it is ignored.",
+            name
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::view::{active_context, remove};
+    use crate::models::{Priority, Task, View};
+    use crate::storage::InMemoryStorage;
+
+    fn view(name: &str, filter: &str) -> View {
+        View {
+            name: name.into(),
+            filter: filter.into(),
+            sort: None,
+        }
+    }
+
+    #[test]
+    fn test_set_applies_and_remove_clears_context() {
+        let storage = InMemoryStorage::default();
+        storage.save_view(&view("work", "tag:work")).unwrap();
+
+        assert!(set(&storage, "nope").is_err());
+        set(&storage, "WORK").unwrap();
+        assert_eq!(storage.load_context().unwrap().as_deref(), Some("work"));
+
+        let mut tagged = Task::new("A".into(), Priority::Medium, vec![], None, None, None);
+        tagged.tags = vec!["work".into()];
+        let other = Task::new("B".into(), Priority::Medium, vec![], None, None, None);
+        let tasks = vec![tagged, other];
+        let ctx = active_context(&storage).unwrap().unwrap();
+        assert!(ctx.matches(&tasks[0], &tasks, &[]));
+        assert!(!ctx.matches(&tasks[1], &tasks, &[]));
+
+        assert!(remove::execute_silent(&storage, "work").unwrap());
+        assert!(storage.load_context().unwrap().is_none());
+        assert!(active_context(&storage).unwrap().is_none());
+    }
+}
@@ -0,0 +1,56 @@
+//! Handler for `todo view list`.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage) -> Result<()> {
+    let views = storage.load_views()?;
+    let context = storage.load_context()?;
+
+    if views.is_empty() {
+        println!("\n{}", "No saved views yet.".dimmed());
+        println!(
+            "  {}\n",
+            "Create one with: todo view save <NAME> --status pending --project work".dimmed()
+        );
+        return Ok(());
+    }
+
+    let width = views
+        .iter()
+        .map(|v| v.name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    println!("\n{}\n", "Views".bright_white().bold());
+    for view in &views {
+        let is_context = context
+            .as_deref()
+            .is_some_and(|c| c.eq_ignore_ascii_case(&view.name));
+        let marker = if is_context { "●" } else { " " };
+        let sort = view
+            .sort
+            .map(|s| format!("  sort: {:?}", s).to_lowercase())
+            .unwrap_or_default();
+        let filter = if view.filter.is_empty() {
+            "(all tasks)"
+        } else {
+            &view.filter
+        };
+        println!(
+            "  {} {:<w$}  {}{}",
+            marker.green(),
+            view.name.cyan(),
+            filter,
+            sort.dimmed(),
+            w = width
+        );
+    }
+    if context.is_some() {
+        println!("\n  {}", "● active context".dimmed());
+    }
+    println!();
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Handlers for `todo view …` and `todo ctx set/clear`.
+//!
+//! A view is a saved filter expression plus sort order. The view named by
+//! `todo ctx set` becomes the *context* and is applied implicitly to
+//! `list`, `next`, `calendar`, `stats` and the TUI until it is cleared.
+
+pub mod context;
+pub mod list;
+pub mod remove;
+pub mod run;
+pub mod save;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::error::TodoError;
+use crate::models::{Project, Task, View};
+use crate::services::query::{Query, QueryContext};
+use crate::storage::Storage;
+
+/// Finds a saved view by name (case-insensitive).
+pub(crate) fn find(storage: &impl Storage, name: &str) -> Result<View> {
+    storage
+        .load_views()?
+        .into_iter()
+        .find(|v| v.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| TodoError::ViewNotFound(name.trim().to_string()).into())
+}
+
+/// The view set with `todo ctx set`, parsed and ready to filter tasks.
+#[derive(Debug, Clone)]
+pub struct ActiveContext {
+    pub name: String,
+    query: Option<Query>,
+}
+
+impl ActiveContext {
+    /// Parses `view` into a context. An empty filter matches every task.
+    pub fn from_view(view: &View) -> Result<Self> {
+        let query = match view.filter.trim() {
+            "" => None,
+            expr => Some(Query::parse(expr)?),
+        };
+        Ok(Self {
+            name: view.name.clone(),
+            query,
+        })
+    }
+
+    /// Returns `true` when `task` belongs to the context.
+    pub fn matches(&self, task: &Task, tasks: &[Task], projects: &[Project]) -> bool {
+        self.query
+            .as_ref()
+            .is_none_or(|q| q.matches(task, &QueryContext { tasks, projects }))
+    }
+
+    /// Prints a one-line reminder that output is narrowed by the context.
+    pub fn print_notice(&self) {
+        println!(
+            "{}",
+            format!(
+                "Context: {}  (todo ctx clear to show everything)",
+                self.name
+            )
+            .dimmed()
+        );
+    }
+}
+
+/// Loads the active context, if one is set.
+///
+/// A context naming a view that no longer exists is ignored.
+pub fn active_context(storage: &impl Storage) -> Result<Option<ActiveContext>> {
+    let Some(name) = storage.load_context()? else {
+        return Ok(None);
+    };
+    match storage
+        .load_views()?
+        .iter()
+        .find(|v| v.name.eq_ignore_ascii_case(&name))
+    {
+        Some(view) => Ok(Some(ActiveContext::from_view(view)?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,33 @@
+//! Handler for `todo view remove <NAME>`.
+//!
+//! Removing the view that is the active context also clears the context.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::error::TodoError;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, name: &str) -> Result<()> {
+    let cleared = execute_silent(storage, name)?;
+    println!("{} Removed view '{}'", "✓".green(), name.trim());
+    if cleared {
+        println!("  {}", "The context was cleared.".dimmed());
+    }
+    Ok(())
+}
+
+/// Deletes the view; returns `true` if it was the active context.
+pub fn execute_silent(storage: &impl Storage, name: &str) -> Result<bool> {
+    let name = name.trim();
+    if !storage.delete_view(name)? {
+        return Err(TodoError::ViewNotFound(name.to_string()).into());
+    }
+    let was_context = storage
+        .load_context()?
+        .is_some_and(|c| c.eq_ignore_ascii_case(name));
+    if was_context {
+        storage.save_context(None)?;
+    }
+    Ok(was_context)
+}
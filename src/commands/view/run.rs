@@ -0,0 +1,27 @@
+//! Handler for `todo view run <NAME>`.
+//!
+//! Equivalent to `todo list --filter <expr> --sort <sort>` with the view's
+//! stored expression; the active context still applies on top.
+
+use anyhow::Result;
+
+use super::find;
+use crate::commands::task::list;
+use crate::models::StatusFilter;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, name: &str) -> Result<()> {
+    let view = find(storage, name)?;
+    let filter = (!view.filter.is_empty()).then_some(view.filter);
+    list::execute(
+        storage,
+        StatusFilter::All,
+        None,
+        None,
+        view.sort,
+        vec![],
+        None,
+        None,
+        filter,
+    )
+}
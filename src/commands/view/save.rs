@@ -0,0 +1,156 @@
+//! Handler for `todo view save <NAME> [list flags]`.
+//!
+//! The `list` flags are folded into one filter expression (see
+//! [`ViewFlags::to_expression`]) and validated before the view is stored.
+
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::cli::ViewSaveArgs;
+use crate::error::TodoError;
+use crate::models::{View, ViewFlags};
+use crate::services::query::Query;
+use crate::storage::Storage;
+
+pub fn execute(storage: &impl Storage, args: ViewSaveArgs) -> Result<()> {
+    let view = execute_silent(storage, args)?;
+    let filter = if view.filter.is_empty() {
+        "(all tasks)".to_string()
+    } else {
+        view.filter.clone()
+    };
+    println!(
+        "{} Saved view '{}': {}",
+        "✓".green(),
+        view.name.bold(),
+        filter
+    );
+    println!(
+        "  {}",
+        format!(
+            "Run it with: todo view run {}  ·  make it the context: todo ctx set {}",
+            view.name, view.name
+        )
+        .dimmed()
+    );
+    Ok(())
+}
+
+pub fn execute_silent(storage: &impl Storage, args: ViewSaveArgs) -> Result<View> {
+    let name = args.name.trim().to_string();
+    validate_name(&name)?;
+
+    if !args.force
+        && storage
+            .load_views()?
+            .iter()
+            .any(|v| v.name.eq_ignore_ascii_case(&name))
+    {
+        return Err(TodoError::ViewAlreadyExists(name).into());
+    }
+
+    let filter = ViewFlags {
+        status: args.status,
+        priority: args.priority,
+        due: args.due,
+        tags: args.tag,
+        project: args.project,
+        recurrence: args.recurrence,
+        filter: args.filter,
+    }
+    .to_expression();
+    if !filter.is_empty() {
+        Query::parse(&filter)?;
+    }
+
+    let view = View {
+        name,
+        filter,
+        sort: args.sort,
+    };
+    storage.save_view(&view)?;
+    Ok(view)
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        anyhow::bail!(
+            "invalid view name '{}': names may only contain letters, digits, '-' and '_'",
+            name
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{DueFilter, SortBy, StatusFilter};
+    use crate::storage::InMemoryStorage;
+
+    fn args(name: &str) -> ViewSaveArgs {
+        ViewSaveArgs {
+            name: name.into(),
+            status: None,
+            priority: None,
+            due: None,
+            tag: vec![],
+            project: None,
+            recurrence: None,
+            filter: None,
+            sort: None,
+            force: false,
+        }
+    }
+
+    #[test]
+    fn test_save_folds_flags_into_expression() {
+        let storage = InMemoryStorage::default();
+        let view = execute_silent(
+            &storage,
+            ViewSaveArgs {
+                status: Some(StatusFilter::Pending),
+                project: Some("work".into()),
+                due: Some(DueFilter::Overdue),
+                filter: Some("tag:bug or priority:high".into()),
+                sort: Some(SortBy::Urgency),
+                ..args("overdue-work")
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            view.filter,
+            "status:pending due:overdue project:work (tag:bug or priority:high)"
+        );
+        assert_eq!(storage.load_views().unwrap(), vec![view]);
+    }
+
+    #[test]
+    fn test_save_rejects_duplicates_bad_names_and_filters() {
+        let storage = InMemoryStorage::default();
+        execute_silent(&storage, args("work")).unwrap();
+
+        assert!(execute_silent(&storage, args("Work")).is_err());
+        execute_silent(
+            &storage,
+            ViewSaveArgs {
+                force: true,
+                ..args("Work")
+            },
+        )
+        .unwrap();
+        assert_eq!(storage.load_views().unwrap().len(), 1);
+
+        assert!(execute_silent(&storage, args("two words")).is_err());
+        let bad = ViewSaveArgs {
+            filter: Some("tag:bug or".into()),
+            ..args("bad")
+        };
+        assert!(execute_silent(&storage, bad).is_err());
+    }
+}
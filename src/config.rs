@@ -13,13 +13,73 @@
 //! medium         = "#ffff55"
 //! low            = "#55ff55"
 //! done           = "#555555"
+//! active         = "#55aaff"
+//! waiting        = "#c678dd"
+//! cancelled      = "#444444"
 //! blocked        = "#969696"
 //! selected_bg    = "#282840"
 //! search_bg      = "#1e1e32"
 //! focused_bg     = "#1e2840"
 //! focused_border = "#00ffff"
+//! syntax_keyword = "#c678dd"  # also syntax_type, _function, _string, _number, _comment
+//!
+//! [urgency]
+//! priority_high = 6.0
+//! overdue       = 12.0
+//! blocking      = 8.0
+//!
+//! [urgency.tag]
+//! urgent = 5.0
+//!
+//! [urgency.project]
+//! Backend = 2.0
+//! ```
+//!
+//! See [`UrgencyConfig`] for every urgency coefficient and its default.
+//!
+//! Task templates can also be defined here (see [`Template`]):
+//!
+//! ```toml
+//! [templates.release]
+//! project = "api"
+//!
+//! [[templates.release.tasks]]
+//! text = "Freeze v{{version}}"
+//! due  = "+1d"
+//!
+//! [[templates.release.tasks]]
+//! text       = "Publish v{{version}}"
+//! priority   = "high"
+//! due        = "+3d"
+//! depends_on = [1]
+//! ```
+//!
+//! Notes are rendered with the built-in markdown renderer and paged through
+//! `$PAGER` (or `less -R`), and keep their last 50 revisions. All three can
+//! be changed (see [`NotesConfig`]):
+//!
+//! ```toml
+//! [notes]
+//! renderer       = "glow -"  # reads the note body on stdin
+//! pager          = "none"    # never page
+//! keep_revisions = 20        # history kept per note by `todo note edit`
+//! ```
+//!
+//! `todo journal` prefills new entries from a template (placeholders are
+//! listed in [`crate::services::journal`]):
+//!
+//! ```toml
+//! [notes]
+//! journal_template = """
+//! # Standup {{date}}
+//!
+//! Yesterday:
+//! Today:
+//! {{due}}
+//! """
 //! ```
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 
@@ -27,6 +87,7 @@ use anyhow::Result;
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 
+use crate::models::{Template, UrgencyConfig};
 use crate::tui::style::Theme;
 
 // ── Config ────────────────────────────────────────────────────────────────────
@@ -39,6 +100,14 @@ pub struct Config {
     pub holidays_locale: String,
     /// TUI color theme
     pub theme: Theme,
+    /// Coefficients for task urgency scoring (`todo next`, `--sort urgency`)
+    pub urgency: UrgencyConfig,
+    /// Read-only task templates, keyed by name
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, Template>,
+    /// How notes are rendered and paged
+    #[serde(skip_serializing_if = "NotesConfig::is_empty")]
+    pub notes: NotesConfig,
 }
 
 impl Config {
@@ -54,7 +123,10 @@ impl Config {
         }
 
         let contents = fs::read_to_string(&path)?;
-        let cfg: Config = toml::from_str(&contents)?;
+        let mut cfg: Config = toml::from_str(&contents)?;
+        for (name, template) in cfg.templates.iter_mut() {
+            template.name = name.clone();
+        }
         Ok(cfg)
     }
 
@@ -73,6 +145,61 @@ impl Config {
     pub fn path() -> Result<PathBuf> {
         config_path()
     }
+
+    /// The directory `todo note add --template` reads templates from, next
+    /// to the config file.
+    pub fn note_templates_dir() -> Result<PathBuf> {
+        let path = config_path()?;
+        Ok(path
+            .parent()
+            .unwrap_or(std::path::Path::new("."))
+            .join("note-templates"))
+    }
+}
+
+// ── Notes ─────────────────────────────────────────────────────────────────────
+
+/// The `[notes]` section.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct NotesConfig {
+    /// External markdown renderer for `todo note preview`, given the note
+    /// body on stdin. Unset means the built-in renderer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renderer: Option<String>,
+    /// Pager for long note output. Unset means `$PAGER`, then `less -R`;
+    /// `"none"` disables paging.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pager: Option<String>,
+    /// How many previous revisions to keep per note. Unset means
+    /// [`DEFAULT_KEEP_REVISIONS`]; `0` keeps no history.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_revisions: Option<usize>,
+    /// Template for new `todo journal` entries. Unset means
+    /// [`journal::DEFAULT_TEMPLATE`](crate::services::journal::DEFAULT_TEMPLATE).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journal_template: Option<String>,
+}
+
+/// Revisions kept per note when `[notes] keep_revisions` is unset.
+pub const DEFAULT_KEEP_REVISIONS: usize = 50;
+
+impl NotesConfig {
+    fn is_empty(&self) -> bool {
+        self == &NotesConfig::default()
+    }
+
+    /// The revision retention, with the default applied.
+    pub fn keep_revisions(&self) -> usize {
+        self.keep_revisions.unwrap_or(DEFAULT_KEEP_REVISIONS)
+    }
+
+    /// The journal template, with the default applied.
+    pub fn journal_template(&self) -> &str {
+        self.journal_template
+            .as_deref()
+            .unwrap_or(crate::services::journal::DEFAULT_TEMPLATE)
+    }
 }
 
 fn default_holidays_locale() -> String {
@@ -13,10 +13,28 @@ pub enum TodoError {
     #[error("Task ID {id} is invalid (valid range: 1-{max})")]
     InvalidTaskId { id: usize, max: usize },
 
+    #[error("Invalid ID list '{input}': {reason} (expected e.g. 1-5,8,12)")]
+    InvalidIdList { input: String, reason: String },
+
+    #[error("Invalid quick-capture token '{token}': {reason} (prefix with \\ to keep it as text)")]
+    InvalidQuickCapture { token: String, reason: String },
+
+    #[error("Invalid filter: {reason}\n\n    {input}\n    {caret}^", caret = " ".repeat(*.column))]
+    InvalidFilter {
+        input: String,
+        column: usize,
+        reason: String,
+    },
+
     // === State Transition Errors ===
     #[error("Task #{id} is already marked as {status}")]
     TaskAlreadyInStatus { id: usize, status: String },
 
+    #[error(
+        "Task #{id} is {from} and cannot become {to} (reopen it first with 'todo undone {id}')"
+    )]
+    InvalidStatusTransition { id: usize, from: String, to: String },
+
     // === Search/Filter Errors ===
     #[error("Tag '{0}' not found in any task")]
     TagNotFound(String),
@@ -69,6 +87,9 @@ pub enum TodoError {
     #[error("Due date cannot be in the past: {date}")]
     DueDateInPast { date: NaiveDate },
 
+    #[error("A duration of {0} days is out of range")]
+    DurationOutOfRange(u32),
+
     // === Recurrence Validation Errors ===
     #[error("Recurring tasks must have a due date. Use --due YYYY-MM-DD")]
     RecurrenceRequiresDueDate,
@@ -88,4 +109,36 @@ pub enum TodoError {
 
     #[error("Task #{task_id} already depends on task #{dep_id}")]
     DuplicateDependency { task_id: usize, dep_id: usize },
+
+    // === Merge Errors ===
+    #[error("Task {0} to merge into not found")]
+    MergeTargetNotFound(uuid::Uuid),
+
+    // === Template Errors ===
+    #[error("Template '{0}' not found (see 'todo template list')")]
+    TemplateNotFound(String),
+
+    #[error("Template '{0}' already exists (use --force to overwrite)")]
+    TemplateAlreadyExists(String),
+
+    #[error("Template '{name}' is invalid: {reason}")]
+    InvalidTemplate { name: String, reason: String },
+
+    #[error("Template '{name}' needs values for: {vars} (pass --var KEY=VALUE)")]
+    MissingTemplateVars { name: String, vars: String },
+
+    // === View Errors ===
+    #[error("View '{0}' not found (see 'todo view list')")]
+    ViewNotFound(String),
+
+    #[error("View '{0}' already exists (use --force to overwrite)")]
+    ViewAlreadyExists(String),
+
+    // === Archive Errors ===
+    #[error("No archived item #{0} (see 'todo archive --list')")]
+    ArchivedItemNotFound(usize),
+
+    // === Trash Errors ===
+    #[error("No deleted item #{0} (see 'todo trash')")]
+    TrashItemNotFound(usize),
 }
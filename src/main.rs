@@ -9,9 +9,13 @@ use clap::Parser;
 use colored::Colorize;
 
 use rustodo::cli::{
-    Cli, Commands, HolidaysCommands, NoteCommands, ProjectCommands, ResourceCommands, StatsCommands,
+    Cli, Commands, ContextCommands, EditArgs, HolidaysCommands, JournalCommands, NoteCommands,
+    ProjectCommands, ResourceCommands, StatsCommands, TaskTarget, TemplateCommands, TrashCommands,
+    ViewCommands,
 };
 use rustodo::commands;
+use rustodo::commands::task::bulk;
+use rustodo::models::TaskStatus;
 use rustodo::storage::{SqliteStorage, Storage, backup, get_db_path};
 
 fn main() {
@@ -68,17 +72,52 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
             tag,
             project,
             recurrence: recur,
-        } => {
-            commands::task::list::execute(storage, status, priority, due, sort, tag, project, recur)
+            filter,
+        } => commands::task::list::execute(
+            storage, status, priority, due, sort, tag, project, recur, filter,
+        ),
+
+        Commands::Done { target } => bulk::dispatch(
+            storage,
+            &target,
+            "complete",
+            commands::task::done::execute,
+            commands::task::done::execute_silent,
+        ),
+
+        Commands::Undone { target } => bulk::dispatch(
+            storage,
+            &target,
+            "reopen",
+            commands::task::undone::execute,
+            commands::task::undone::execute_silent,
+        ),
+        Commands::Start { target } => set_status(storage, &target, "start", TaskStatus::Active),
+        Commands::Wait { target } => {
+            set_status(storage, &target, "mark as waiting", TaskStatus::Waiting)
+        }
+        Commands::Cancel { target } => {
+            set_status(storage, &target, "cancel", TaskStatus::Cancelled)
         }
 
-        Commands::Done { id } => commands::task::done::execute(storage, id),
-
-        Commands::Undone { id } => commands::task::undone::execute(storage, id),
-
-        Commands::Remove { id, yes } => commands::task::remove::execute(storage, id, yes),
+        Commands::Remove { target } => {
+            let yes = target.yes;
+            bulk::dispatch(
+                storage,
+                &target,
+                "remove",
+                |s, id| commands::task::remove::execute(s, id, yes),
+                commands::task::remove::execute_silent,
+            )
+        }
 
-        Commands::Edit(args) => commands::task::edit::execute(storage, args),
+        Commands::Edit { target, args } => bulk::dispatch(
+            storage,
+            &target,
+            "edit",
+            |s, id| commands::task::edit::execute(s, EditArgs { id, ..args.clone() }),
+            |s, id| commands::task::edit::execute_silent(s, EditArgs { id, ..args.clone() }),
+        ),
 
         Commands::Clear { yes } => commands::task::clear::execute(storage, yes),
 
@@ -87,7 +126,15 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
             tag,
             project,
             status,
-        } => commands::search::execute(storage, query, tag, project, status),
+            filter,
+            archived,
+        } => {
+            if archived {
+                commands::archive::search(storage, &query, &tag, project.as_deref(), status)
+            } else {
+                commands::search::execute(storage, query, tag, project, status, filter)
+            }
+        }
 
         // `todo stats` with no subcommand → show overview
         // `todo stats show`    → overview
@@ -103,14 +150,20 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
         Commands::Calendar { month, year } => commands::calendar::execute(storage, month, year),
 
         Commands::Next { limit } => commands::next::execute(storage, Some(limit)),
+        Commands::Urgency { id } => commands::urgency::execute(storage, id),
 
         Commands::Tags { tag } => commands::tags::execute(storage, tag),
 
         Commands::Project(sub) => match sub {
             ProjectCommands::Add(args) => commands::project::add::execute(storage, args),
-            ProjectCommands::List => commands::project::list::execute(storage),
+            ProjectCommands::List { status, group } => {
+                commands::project::list::execute(storage, &status, group)
+            }
             ProjectCommands::Show { id } => commands::project::show::execute(storage, id),
             ProjectCommands::Edit(args) => commands::project::edit::execute(storage, args),
+            ProjectCommands::Forecast { id, weeks } => {
+                commands::project::forecast::execute(storage, id, weeks)
+            }
             ProjectCommands::Done { id } => commands::project::done::execute(storage, id),
             ProjectCommands::Undone { id } => commands::project::undone::execute(storage, id),
             ProjectCommands::Remove { id, yes } => {
@@ -125,10 +178,35 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
             NoteCommands::Show { id } => commands::note::show::execute(storage, id),
             NoteCommands::Preview { id } => commands::note::preview::execute(storage, id),
             NoteCommands::Edit(args) => commands::note::edit::execute(storage, args),
+            NoteCommands::History { id } => commands::note::history::execute(storage, id),
+            NoteCommands::Diff { id, rev } => commands::note::diff::execute(storage, id, rev),
+            NoteCommands::Revert { id, rev } => commands::note::revert::execute(storage, id, rev),
+            NoteCommands::Export { dir } => commands::note::vault::execute_export(storage, dir),
+            NoteCommands::Import { dir } => commands::note::vault::execute_import(storage, dir),
+            NoteCommands::Sync { dir } => commands::note::vault::execute_sync(storage, dir),
+            NoteCommands::Templates => commands::note::templates::execute(),
             NoteCommands::Remove { id, yes } => commands::note::remove::execute(storage, id, yes),
             NoteCommands::Clear { yes } => commands::note::clear::execute(storage, yes),
         },
 
+        Commands::Template(sub) => match sub {
+            TemplateCommands::Apply {
+                name,
+                project,
+                vars,
+            } => commands::template::apply::execute(storage, &name, project, vars),
+            TemplateCommands::Save {
+                name,
+                ids,
+                description,
+                force,
+            } => commands::template::save::execute(storage, &name, &ids, description, force),
+            TemplateCommands::List => commands::template::list::execute(storage),
+            TemplateCommands::Show { name } => commands::template::show::execute(storage, &name),
+            TemplateCommands::Remove { name } => {
+                commands::template::remove::execute(storage, &name)
+            }
+        },
         Commands::Resource(sub) => match sub {
             ResourceCommands::Add(args) => commands::resource::add::execute(storage, args),
             ResourceCommands::List(args) => commands::resource::list::execute(storage, args),
@@ -138,23 +216,126 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
                 commands::resource::remove::execute(storage, id, yes)
             }
             ResourceCommands::Clear { yes } => commands::resource::clear::execute(storage, yes),
+            ResourceCommands::Check(args) => commands::resource::check::execute(storage, args),
         },
 
-        Commands::Context { id } => commands::context::execute(storage, id),
+        Commands::Context { action, id } => match (action, id) {
+            (Some(ContextCommands::Set { name }), _) => {
+                commands::view::context::set(storage, &name)
+            }
+            (Some(ContextCommands::Clear), _) => commands::view::context::clear(storage),
+            (Some(ContextCommands::Show), _) | (None, None) => {
+                commands::view::context::show(storage)
+            }
+            (None, Some(id)) => commands::context::execute(storage, id),
+        },
 
-        Commands::Deps { id } => commands::task::deps::execute(storage, id),
+        Commands::View(sub) => match sub {
+            ViewCommands::Save(args) => commands::view::save::execute(storage, args),
+            ViewCommands::Run { name } => commands::view::run::execute(storage, &name),
+            ViewCommands::List => commands::view::list::execute(storage),
+            ViewCommands::Remove { name } => commands::view::remove::execute(storage, &name),
+        },
 
-        Commands::Info => commands::task::info::execute(),
+        Commands::Journal {
+            action,
+            date,
+            no_edit,
+        } => match action {
+            None => commands::journal::execute_open(storage, date, no_edit),
+            Some(JournalCommands::List { month }) => {
+                commands::journal::execute_list(storage, month)
+            }
+        },
+
+        Commands::Attach { id, file, note } => {
+            commands::attachment::execute_attach(storage, id, file, note)
+        }
+        Commands::Attachments { id, note } => commands::attachment::execute_list(storage, id, note),
+        Commands::OpenAttachment { id, n, note, path } => {
+            commands::attachment::execute_open(storage, id, n, note, path)
+        }
+
+        Commands::Deps {
+            id,
+            graph,
+            project,
+            format,
+            output,
+            collapse_done,
+        } => match id {
+            Some(id) if !graph => commands::task::deps::execute(storage, id),
+            _ => commands::task::deps::execute_graph(
+                storage,
+                project.as_deref(),
+                format,
+                output,
+                collapse_done,
+            ),
+        },
 
-        Commands::Recur { id, pattern } => commands::task::recur::execute(storage, id, pattern),
+        Commands::Plan { project } => commands::plan::execute(storage, project.as_deref()),
 
-        Commands::ClearRecur { id } => commands::task::clear_recur::execute(storage, id),
+        Commands::Info => commands::task::info::execute(),
+
+        Commands::Recur {
+            ids,
+            pattern,
+            filter,
+            yes,
+        } => bulk::dispatch(
+            storage,
+            &TaskTarget {
+                ids: Some(ids),
+                filter,
+                yes,
+            },
+            "set recurrence on",
+            |s, id| commands::task::recur::execute(s, id, pattern),
+            |s, id| commands::task::recur::execute_silent(s, id, pattern),
+        ),
+
+        Commands::ClearRecur { target } => bulk::dispatch(
+            storage,
+            &target,
+            "clear recurrence on",
+            commands::task::clear_recur::execute,
+            commands::task::clear_recur::execute_silent,
+        ),
+
+        Commands::Dedupe { yes } => commands::task::dedupe::execute(storage, yes),
+
+        Commands::Merge { into, ids, yes } => {
+            commands::task::merge::execute(storage, into, &ids, yes)
+        }
 
+        Commands::Trash { action, kind } => match action {
+            None => commands::trash::list(storage, kind),
+            Some(TrashCommands::Restore { id }) => commands::trash::restore(storage, id),
+            Some(TrashCommands::Empty {
+                older_than,
+                kind,
+                dry_run,
+                yes,
+            }) => commands::trash::empty(storage, older_than.as_deref(), kind, dry_run, yes),
+        },
         Commands::Purge { days, dry_run, yes } => {
             commands::purge::execute(storage, days, dry_run, yes)
         }
+        Commands::Archive {
+            older_than,
+            list,
+            dry_run,
+            yes,
+        } => match older_than {
+            Some(age) if !list => commands::archive::execute(storage, &age, dry_run, yes),
+            _ => commands::archive::list(storage),
+        },
+        Commands::Unarchive { id } => commands::archive::unarchive(storage, id),
 
-        Commands::Export { file } => commands::portability::execute_export(storage, file),
+        Commands::Export { file, filter } => {
+            commands::portability::execute_export(storage, file, filter)
+        }
 
         Commands::Import { file, replace, yes } => {
             commands::portability::execute_import(storage, file, replace, yes)
@@ -171,3 +352,18 @@ fn run(cli: Cli, storage: &impl Storage) -> Result<()> {
         },
     }
 }
+
+fn set_status(
+    storage: &impl Storage,
+    target: &TaskTarget,
+    verb: &str,
+    status: TaskStatus,
+) -> Result<()> {
+    bulk::dispatch(
+        storage,
+        target,
+        verb,
+        |s, id| commands::task::status::execute(s, id, status),
+        |s, id| commands::task::status::execute_silent(s, id, status),
+    )
+}
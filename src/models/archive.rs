@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::note::{Note, NoteRevision};
+use super::project::Project;
+use super::task::Task;
+
+/// An entity moved out of the live tables by `todo archive`.
+#[derive(Debug, Clone)]
+pub enum Archived {
+    Task(Task),
+    Project(Project),
+    Note(Note),
+}
+
+/// An archived entity and when it was archived.
+///
+/// Archived entries are invisible to every command except `todo archive
+/// --list`, `todo search --archived` and `todo unarchive`. Their events stay
+/// in the event log, so `todo stats history` still counts them.
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+    pub item: Archived,
+    pub archived_at: DateTime<Utc>,
+    /// Saved revisions of an archived note, restored along with it. Filled
+    /// in by [`crate::storage::Storage::archive`].
+    pub revisions: Vec<NoteRevision>,
+}
+
+impl ArchiveEntry {
+    /// Wraps `item`, stamped with the current time.
+    pub fn new(item: Archived) -> Self {
+        Self {
+            item,
+            archived_at: Utc::now(),
+            revisions: Vec::new(),
+        }
+    }
+
+    pub fn uuid(&self) -> Uuid {
+        match &self.item {
+            Archived::Task(t) => t.uuid,
+            Archived::Project(p) => p.uuid,
+            Archived::Note(n) => n.uuid,
+        }
+    }
+
+    /// `"task"`, `"project"` or `"note"`.
+    pub fn kind(&self) -> &'static str {
+        match self.item {
+            Archived::Task(_) => "task",
+            Archived::Project(_) => "project",
+            Archived::Note(_) => "note",
+        }
+    }
+
+    /// Task text, project name, or note title (first line if untitled).
+    pub fn label(&self) -> String {
+        match &self.item {
+            Archived::Task(t) => t.text.clone(),
+            Archived::Project(p) => p.name.clone(),
+            Archived::Note(n) => n.title.clone().unwrap_or_else(|| {
+                n.body
+                    .lines()
+                    .find(|l| !l.trim().is_empty())
+                    .map(|l| l.trim_start_matches('#').trim().to_string())
+                    .unwrap_or_default()
+            }),
+        }
+    }
+
+    /// Tags of an archived task or note; projects have none.
+    pub fn tags(&self) -> &[String] {
+        match &self.item {
+            Archived::Task(t) => &t.tags,
+            Archived::Project(_) => &[],
+            Archived::Note(n) => &n.tags,
+        }
+    }
+
+    /// The project this entry is, or belongs to — the first one for a
+    /// note linked to several.
+    pub fn project_id(&self) -> Option<Uuid> {
+        self.project_ids().first().copied()
+    }
+
+    /// Every project this entry is, or belongs to.
+    pub fn project_ids(&self) -> Vec<Uuid> {
+        match &self.item {
+            Archived::Task(t) => t.project_id.into_iter().collect(),
+            Archived::Project(p) => vec![p.uuid],
+            Archived::Note(n) => n.project_ids.clone(),
+        }
+    }
+
+    /// Case-insensitive substring match on the same fields `todo search`
+    /// looks at for live entities.
+    pub fn matches_text(&self, query: &str) -> bool {
+        let q = query.to_lowercase();
+        let has = |s: &str| s.to_lowercase().contains(&q);
+        match &self.item {
+            Archived::Task(t) => has(&t.text),
+            Archived::Project(p) => has(&p.name) || p.tech.iter().any(|t| has(t)),
+            Archived::Note(n) => {
+                n.title.as_deref().is_some_and(has)
+                    || has(&n.body)
+                    || n.tags.iter().any(|t| has(t))
+                    || n.language.as_deref().is_some_and(has)
+            }
+        }
+    }
+}
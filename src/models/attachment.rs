@@ -0,0 +1,83 @@
+//! Attachment — a file attached to a [`Task`] or a [`Note`].
+//!
+//! The file contents live in a content-addressed blob store next to the
+//! database (see [`crate::storage::attachments`]); an attachment row only
+//! records which blob belongs to which item and under what name. Two
+//! attachments of the same file share one blob.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// ── AttachmentOwner ───────────────────────────────────────────────────────────
+
+/// The item a file is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "uuid", rename_all = "lowercase")]
+pub enum AttachmentOwner {
+    Task(Uuid),
+    Note(Uuid),
+}
+
+impl AttachmentOwner {
+    /// `"task"` or `"note"`, as stored in the database.
+    pub fn kind(self) -> &'static str {
+        match self {
+            AttachmentOwner::Task(_) => "task",
+            AttachmentOwner::Note(_) => "note",
+        }
+    }
+
+    pub fn uuid(self) -> Uuid {
+        match self {
+            AttachmentOwner::Task(u) | AttachmentOwner::Note(u) => u,
+        }
+    }
+
+    /// Inverse of [`kind`](Self::kind) plus [`uuid`](Self::uuid).
+    pub fn from_parts(kind: &str, uuid: Uuid) -> Option<Self> {
+        match kind {
+            "task" => Some(AttachmentOwner::Task(uuid)),
+            "note" => Some(AttachmentOwner::Note(uuid)),
+            _ => None,
+        }
+    }
+}
+
+// ── Attachment ────────────────────────────────────────────────────────────────
+
+/// A file attached to a task or note.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub uuid: Uuid,
+    pub owner: AttachmentOwner,
+    /// The original file name, used when listing and opening the file.
+    pub name: String,
+    /// SHA-256 of the contents, in lowercase hex — the blob's address.
+    pub hash: String,
+    /// Size in bytes.
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    pub fn new(owner: AttachmentOwner, name: String, hash: String, size: u64) -> Self {
+        Attachment {
+            uuid: Uuid::new_v4(),
+            owner,
+            name,
+            hash,
+            size,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// The last component of `name`, safe to join onto a directory.
+    /// Names with none (such as `..`) fall back to `attachment`.
+    pub fn file_name(&self) -> &str {
+        std::path::Path::new(&self.name)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("attachment")
+    }
+}
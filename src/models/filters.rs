@@ -1,14 +1,21 @@
 use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 
-/// Filters tasks by completion status.
+/// Filters tasks by lifecycle status.
 ///
 /// Used by `todo list --status` and `todo search --status`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum StatusFilter {
-    /// Show only pending tasks.
+    /// Show only open tasks (pending, active or waiting).
     Pending,
+    /// Show only tasks in progress.
+    Active,
+    /// Show only tasks that are waiting on something.
+    Waiting,
     /// Show only completed tasks.
     Done,
+    /// Show only cancelled tasks.
+    Cancelled,
     /// Show all tasks (default).
     All,
 }
@@ -47,8 +54,9 @@ pub enum RecurrenceFilter {
 
 /// Sort order for `todo list`.
 ///
-/// Used by `todo list --sort`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+/// Used by `todo list --sort` and stored with saved views.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum SortBy {
     /// Sort by priority: High → Medium → Low.
     Priority,
@@ -59,3 +67,25 @@ pub enum SortBy {
     /// Sort by calculated urgency score (highest first).
     Urgency,
 }
+
+/// Filters the trash by entity type.
+///
+/// Used by `todo trash --type` and `todo trash empty --type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TrashType {
+    Task,
+    Project,
+    Note,
+    Resource,
+}
+
+impl TrashType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TrashType::Task => "task",
+            TrashType::Project => "project",
+            TrashType::Note => "note",
+            TrashType::Resource => "resource",
+        }
+    }
+}
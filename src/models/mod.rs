@@ -4,28 +4,54 @@
 //! |---|---|
 //! | [`Task`]     | A single todo item with all its metadata |
 //! | [`Note`]     | A free-form documentation note, optionally linked to a Project, Task, or Resources |
+//! | [`NoteLink`] | A `[[…]]` wiki-link from a note body to a note, task or resource |
+//! | [`NoteRevision`] | A previous title and body of a note, kept by `todo note edit` |
+//! | [`Attachment`] | A file attached to a task or note, stored by content hash |
 //! | [`Project`]  | A project entity that groups tasks and notes |
 //! | [`ResourceType`] | Docs / Article / Video / Repo / Crate / Book / Spec / Tool |
+//! | [`LinkCheck`] | The result of the last `todo resource check` of a resource URL |
+//! | [`Template`] | A named checklist that expands into several tasks |
+//! | [`View`]     | A saved filter expression and sort order (also used as the context) |
+//! | [`ArchiveEntry`] | A task, project or note moved to the archive |
 //! | [`Priority`]         | High / Medium / Low priority levels |
 //! | [`Recurrence`]       | Daily / Weekly / Monthly repeat patterns |
-//! | [`StatusFilter`]     | Filter tasks by completion status |
+//! | [`TaskStatus`]       | Pending / Active / Waiting / Done / Cancelled lifecycle states |
+//! | [`ProjectStatus`]    | Idea / Planning / Active / On hold / Done / Abandoned project states |
+//! | [`UrgencyConfig`]    | Tunable urgency coefficients from `[urgency]` in config.toml |
+//! | [`UrgencyBreakdown`] | Per-term explanation of a task's urgency score |
+//! | [`StatusFilter`]     | Filter tasks by lifecycle status |
 //! | [`DueFilter`]        | Filter tasks by due-date window |
 //! | [`RecurrenceFilter`] | Filter tasks by recurrence pattern |
 //! | [`SortBy`]           | Sort order options for task lists |
+//! | [`TrashType`]        | Filter the trash by entity type |
 
+mod archive;
+mod attachment;
 mod filters;
 mod note;
 mod priority;
 mod project;
+mod project_status;
 mod recurrence;
 mod resource;
 mod task;
+mod task_status;
+mod template;
+mod urgency;
+mod view;
 
-pub use filters::{DueFilter, RecurrenceFilter, SortBy, StatusFilter};
-pub use note::{Note, NoteFormat};
+pub use archive::{ArchiveEntry, Archived};
+pub use attachment::{Attachment, AttachmentOwner};
+pub use filters::{DueFilter, RecurrenceFilter, SortBy, StatusFilter, TrashType};
+pub use note::{LinkTarget, Note, NoteFormat, NoteLink, NoteRevision};
 pub use priority::Priority;
-pub use project::{Difficulty, Project};
+pub use project::{Difficulty, PATH_SEPARATOR, Project};
+pub use project_status::ProjectStatus;
 pub use recurrence::Recurrence;
-pub use resource::{Resource, ResourceType};
+pub use resource::{LinkCheck, Resource, ResourceType};
 pub(crate) use task::detect_cycle;
-pub use task::{Task, count_by_project};
+pub use task::{Task, count_by_project, count_by_project_tree};
+pub use task_status::TaskStatus;
+pub use template::{RelativeDue, Template, TemplateTask, parse_offset, substitute};
+pub use urgency::{UrgencyBreakdown, UrgencyComponent, UrgencyConfig};
+pub use view::{View, ViewFlags};
@@ -1,12 +1,13 @@
-//! Note — a free-form documentation entity that can optionally link to a
-//! [`Project`], a [`Task`], and/or one or more [`Resource`]s.
+//! Note — a free-form documentation entity that can optionally link to
+//! any number of [`Project`]s, [`Task`]s and [`Resource`]s.
 //!
 //! Notes are first-class citizens: they exist independently and are only
-//! associated with other entities when the user explicitly sets `project_id`,
-//! `task_id`, or `resource_ids`.
+//! associated with other entities when the user explicitly sets
+//! `project_ids`, `task_ids`, or `resource_ids`, or writes a `[[…]]`
+//! wiki-link in the body (see [`NoteLink`]).
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
 
 // ── NoteFormat ────────────────────────────────────────────────────────────────
@@ -29,14 +30,64 @@ impl NoteFormat {
     }
 }
 
+// ── NoteLink ──────────────────────────────────────────────────────────────────
+
+/// What a wiki-link points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "uuid", rename_all = "lowercase")]
+pub enum LinkTarget {
+    Note(Uuid),
+    Task(Uuid),
+    Resource(Uuid),
+}
+
+impl LinkTarget {
+    /// `"note"`, `"task"` or `"resource"`, as stored in the database.
+    pub fn kind(self) -> &'static str {
+        match self {
+            LinkTarget::Note(_) => "note",
+            LinkTarget::Task(_) => "task",
+            LinkTarget::Resource(_) => "resource",
+        }
+    }
+
+    pub fn uuid(self) -> Uuid {
+        match self {
+            LinkTarget::Note(u) | LinkTarget::Task(u) | LinkTarget::Resource(u) => u,
+        }
+    }
+
+    /// Inverse of [`kind`](Self::kind) plus [`uuid`](Self::uuid).
+    pub fn from_parts(kind: &str, uuid: Uuid) -> Option<Self> {
+        match kind {
+            "note" => Some(LinkTarget::Note(uuid)),
+            "task" => Some(LinkTarget::Task(uuid)),
+            "resource" => Some(LinkTarget::Resource(uuid)),
+            _ => None,
+        }
+    }
+}
+
+/// A resolved `[[…]]` reference in a note body.
+///
+/// `reference` is the text between the brackets, exactly as written. The
+/// target is resolved once, when the note is saved, so the link keeps
+/// pointing at the same item when list IDs shift.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteLink {
+    pub reference: String,
+    pub target: LinkTarget,
+}
+
 // ── Note ──────────────────────────────────────────────────────────────────────
 
 /// A free-form documentation note.
 ///
 /// # Relationships
-/// - `project_id`   → links to a [`Project`]          (optional, one)
-/// - `task_id`      → links to a [`Task`]              (optional, one)
+/// - `project_ids`  → links to one or more [`Project`]s  (optional, many)
+/// - `task_ids`     → links to one or more [`Task`]s     (optional, many)
 /// - `resource_ids` → links to one or more [`Resource`]s (optional, many)
+/// - `links`        → wiki-links from the body to notes, tasks and resources
 ///
 /// All can be set simultaneously, or none.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,13 +118,18 @@ pub struct Note {
     #[serde(default)]
     pub language: Option<String>,
 
-    /// Optional link to a Project.
-    #[serde(default)]
-    pub project_id: Option<Uuid>,
+    /// Links to zero or more Projects.
+    ///
+    /// Older data with a single `project_id` deserialises as a one-element
+    /// list.
+    #[serde(default, alias = "project_id", deserialize_with = "one_or_many")]
+    pub project_ids: Vec<Uuid>,
 
-    /// Optional link to a Task.
-    #[serde(default)]
-    pub task_id: Option<Uuid>,
+    /// Links to zero or more Tasks.
+    ///
+    /// Older data with a single `task_id` deserialises as a one-element list.
+    #[serde(default, alias = "task_id", deserialize_with = "one_or_many")]
+    pub task_ids: Vec<Uuid>,
 
     /// Links to zero or more Resources.
     ///
@@ -82,6 +138,10 @@ pub struct Note {
     #[serde(default)]
     pub resource_ids: Vec<Uuid>,
 
+    /// Resolved `[[…]]` references in the body (see `services::links`).
+    #[serde(default)]
+    pub links: Vec<NoteLink>,
+
     /// Timestamp when the note was created (UTC).
     pub created_at: DateTime<Utc>,
 
@@ -104,9 +164,10 @@ impl Note {
             format: NoteFormat::Plain,
             tags: Vec::new(),
             language: None,
-            project_id: None,
-            task_id: None,
+            project_ids: Vec::new(),
+            task_ids: Vec::new(),
             resource_ids: Vec::new(),
+            links: Vec::new(),
             created_at: Utc::now(),
             updated_at: Some(Utc::now()),
             deleted_at: None,
@@ -132,6 +193,12 @@ impl Note {
         self.touch();
     }
 
+    /// Undo a soft delete.
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+        self.touch();
+    }
+
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
     }
@@ -142,12 +209,12 @@ impl Note {
 
     /// Returns `true` if the note is linked to the given project UUID.
     pub fn belongs_to_project(&self, project_id: Uuid) -> bool {
-        self.project_id == Some(project_id)
+        self.project_ids.contains(&project_id)
     }
 
     /// Returns `true` if the note is linked to the given task UUID.
     pub fn belongs_to_task(&self, task_id: Uuid) -> bool {
-        self.task_id == Some(task_id)
+        self.task_ids.contains(&task_id)
     }
 
     /// Returns `true` if the note references the given resource UUID.
@@ -155,6 +222,11 @@ impl Note {
         self.resource_ids.contains(&resource_id)
     }
 
+    /// Returns `true` if the body links to `target` with a `[[…]]` reference.
+    pub fn links_to(&self, target: LinkTarget) -> bool {
+        self.links.iter().any(|l| l.target == target)
+    }
+
     /// Attach a resource. No-op if already present.
     pub fn add_resource(&mut self, resource_id: Uuid) {
         if !self.resource_ids.contains(&resource_id) {
@@ -166,4 +238,99 @@ impl Note {
     pub fn remove_resource(&mut self, resource_id: Uuid) {
         self.resource_ids.retain(|id| *id != resource_id);
     }
+
+    /// Link a project. No-op if already present.
+    pub fn add_project(&mut self, project_id: Uuid) {
+        if !self.project_ids.contains(&project_id) {
+            self.project_ids.push(project_id);
+        }
+    }
+
+    /// Unlink a project. No-op if not present.
+    pub fn remove_project(&mut self, project_id: Uuid) {
+        self.project_ids.retain(|id| *id != project_id);
+    }
+
+    /// Link a task. No-op if already present.
+    pub fn add_task(&mut self, task_id: Uuid) {
+        if !self.task_ids.contains(&task_id) {
+            self.task_ids.push(task_id);
+        }
+    }
+
+    /// Unlink a task. No-op if not present.
+    pub fn remove_task(&mut self, task_id: Uuid) {
+        self.task_ids.retain(|id| *id != task_id);
+    }
+}
+
+/// Reads a list of UUIDs, also accepting the single (possibly `null`) UUID
+/// of the old `project_id` / `task_id` fields.
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<Uuid>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<Uuid>),
+        One(Option<Uuid>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(ids) => ids,
+        OneOrMany::One(id) => id.into_iter().collect(),
+    })
+}
+
+// ── NoteRevision ──────────────────────────────────────────────────────────────
+
+/// A previous version of a note's title and body.
+///
+/// A revision is saved each time `todo note edit` or `todo note revert`
+/// replaces the title or body. Revisions are numbered per note from 1 and
+/// are never renumbered, so `todo note diff 3 2` keeps meaning the same
+/// thing after older revisions are pruned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteRevision {
+    pub note_uuid: Uuid,
+    pub number: u32,
+    pub title: Option<String>,
+    pub body: String,
+    /// When this version was replaced.
+    pub created_at: DateTime<Utc>,
+}
+
+impl NoteRevision {
+    /// Snapshots the current title and body of `note` as revision `number`.
+    pub fn of(note: &Note, number: u32) -> Self {
+        NoteRevision {
+            note_uuid: note.uuid,
+            number,
+            title: note.title.clone(),
+            body: note.body.clone(),
+            created_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_old_single_links_deserialise_as_lists() {
+        let task = Uuid::new_v4();
+        let json = format!(
+            r#"{{"body": "b", "created_at": "2026-01-01T00:00:00Z",
+                "project_id": null, "task_id": "{task}"}}"#
+        );
+        let note: Note = serde_json::from_str(&json).unwrap();
+        assert!(note.project_ids.is_empty());
+        assert_eq!(note.task_ids, vec![task]);
+
+        let json = serde_json::to_string(&note).unwrap();
+        assert!(json.contains("\"task_ids\""));
+        let back: Note = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.task_ids, vec![task]);
+    }
 }
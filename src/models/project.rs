@@ -1,9 +1,13 @@
+use std::collections::HashSet;
+
 use anyhow::Result;
 use chrono::{DateTime, Local, NaiveDate, Utc};
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::ProjectStatus;
+
 // ── Difficulty ────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ValueEnum)]
@@ -43,14 +47,37 @@ impl Difficulty {
 
 // ── Project ───────────────────────────────────────────────────────────────────
 
+/// Separator between project names in a path such as `acme.api.auth`.
+pub const PATH_SEPARATOR: char = '.';
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     #[serde(default = "Uuid::new_v4")]
     pub uuid: Uuid,
     pub name: String,
+    /// The project this one is nested under, if any.
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
     /// Whether the project has been completed.
+    ///
+    /// Kept in sync with `status`; see [`Project::set_status`].
     #[serde(default)]
     pub completed: bool,
+    /// Lifecycle state. Old data without this field is migrated via
+    /// [`Project::normalize_status`].
+    #[serde(default)]
+    pub status: ProjectStatus,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// A color name (`blue`, `bright red`) or `#rrggbb` hex code.
+    #[serde(default)]
+    pub color: Option<String>,
     #[serde(default)]
     pub difficulty: Difficulty,
     #[serde(default)]
@@ -71,7 +98,14 @@ impl Project {
         Self {
             uuid: Uuid::new_v4(),
             name,
+            parent_id: None,
             completed: false,
+            status: ProjectStatus::Active,
+            description: None,
+            start_date: None,
+            owner: None,
+            url: None,
+            color: None,
             difficulty: Difficulty::Medium,
             tech: Vec::new(),
             due_date: None,
@@ -87,22 +121,53 @@ impl Project {
     }
 
     pub fn mark_done(&mut self) {
-        self.completed = true;
-        self.completed_at = Some(Local::now().naive_local().date());
-        self.touch();
+        self.set_status(ProjectStatus::Done);
     }
 
     pub fn mark_undone(&mut self) {
-        self.completed = false;
-        self.completed_at = None;
+        self.set_status(ProjectStatus::Active);
+    }
+
+    /// Moves the project to `status`, keeping `completed` and `completed_at`
+    /// in sync.
+    pub fn set_status(&mut self, status: ProjectStatus) {
+        self.status = status;
+        self.completed = status == ProjectStatus::Done;
+        self.completed_at = if status.is_closed() {
+            Some(Local::now().naive_local().date())
+        } else {
+            None
+        };
         self.touch();
     }
 
+    /// Returns `true` while the project still requires work.
+    pub fn is_open(&self) -> bool {
+        !self.completed && self.status.is_open()
+    }
+
+    /// Reconciles `status` with the legacy `completed` flag.
+    ///
+    /// Data written before project states existed only carries `completed`;
+    /// such projects deserialize as `Active` and are promoted to `Done` here.
+    pub fn normalize_status(&mut self) {
+        if self.completed && self.status != ProjectStatus::Done {
+            self.status = ProjectStatus::Done;
+        } else if !self.completed && self.status == ProjectStatus::Done {
+            self.completed = true;
+        }
+    }
+
     pub fn soft_delete(&mut self) {
         self.deleted_at = Some(Utc::now());
         self.touch();
     }
 
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+        self.touch();
+    }
+
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
     }
@@ -110,13 +175,193 @@ impl Project {
     pub fn is_overdue(&self) -> bool {
         if let Some(due) = self.due_date {
             let today = Local::now().naive_local().date();
-            due < today && !self.completed
+            due < today && self.is_open()
         } else {
             false
         }
     }
 
-    /// Finds a project by name (case-insensitive) or creates a new one.
+    /// Returns the live project this one is nested under.
+    ///
+    /// A parent that has been deleted counts as no parent, so the project
+    /// shows up at the top level.
+    pub fn parent<'a>(&self, projects: &'a [Project]) -> Option<&'a Project> {
+        let parent_id = self.parent_id?;
+        projects
+            .iter()
+            .find(|p| p.uuid == parent_id && !p.is_deleted())
+    }
+
+    /// Ancestors from the top-level project down to this one (inclusive).
+    pub fn ancestry<'a>(&'a self, projects: &'a [Project]) -> Vec<&'a Project> {
+        let mut chain = vec![self];
+        let mut current = self;
+        while let Some(parent) = current.parent(projects) {
+            // Guards against a corrupt parent loop.
+            if chain.iter().any(|p| p.uuid == parent.uuid) {
+                break;
+            }
+            chain.push(parent);
+            current = parent;
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Full dotted path, e.g. `acme.api.auth`.
+    pub fn path(&self, projects: &[Project]) -> String {
+        self.ancestry(projects)
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect::<Vec<_>>()
+            .join(&PATH_SEPARATOR.to_string())
+    }
+
+    /// Number of ancestors; top-level projects have depth 0.
+    pub fn depth(&self, projects: &[Project]) -> usize {
+        self.ancestry(projects).len() - 1
+    }
+
+    /// Finds a live project by its full dotted path (case-insensitive).
+    ///
+    /// Top-level projects created before nesting existed may contain a dot in
+    /// their name; their path is just the name, so they still match.
+    pub fn find_by_path<'a>(projects: &'a [Project], path: &str) -> Option<&'a Project> {
+        let path = path.trim();
+        projects
+            .iter()
+            .filter(|p| !p.is_deleted())
+            .find(|p| p.path(projects).eq_ignore_ascii_case(path))
+    }
+
+    /// Finds a live project by the value given to `--project`.
+    ///
+    /// Accepts a full dotted path, or the bare name of a nested project when
+    /// no other project has that name.
+    pub fn find<'a>(projects: &'a [Project], name: &str) -> Option<&'a Project> {
+        if let Some(project) = Self::find_by_path(projects, name) {
+            return Some(project);
+        }
+        let mut named = projects
+            .iter()
+            .filter(|p| !p.is_deleted() && p.name.eq_ignore_ascii_case(name.trim()));
+        match (named.next(), named.next()) {
+            (Some(project), None) => Some(project),
+            _ => None,
+        }
+    }
+
+    /// UUIDs of `root` and every live project nested under it, at any depth.
+    ///
+    /// Used wherever `--project` should match a project's descendants too.
+    pub fn subtree(projects: &[Project], root: Uuid) -> HashSet<Uuid> {
+        let mut set = HashSet::from([root]);
+        loop {
+            let before = set.len();
+            for project in projects.iter().filter(|p| !p.is_deleted()) {
+                if project
+                    .parent(projects)
+                    .is_some_and(|p| set.contains(&p.uuid))
+                {
+                    set.insert(project.uuid);
+                }
+            }
+            if set.len() == before {
+                return set;
+            }
+        }
+    }
+
+    /// Live projects in tree order: each project followed by its children,
+    /// siblings sorted by name.
+    ///
+    /// This is the order `todo project list` numbers projects in. Without
+    /// nesting it is plain alphabetical order.
+    pub fn tree_order(projects: &[Project]) -> Vec<&Project> {
+        fn visit<'a>(
+            parent: Option<Uuid>,
+            projects: &'a [Project],
+            seen: &mut HashSet<Uuid>,
+            out: &mut Vec<&'a Project>,
+        ) {
+            let mut children: Vec<&Project> = projects
+                .iter()
+                .filter(|p| !p.is_deleted() && p.parent(projects).map(|p| p.uuid) == parent)
+                .collect();
+            children.sort_by(|a, b| a.name.cmp(&b.name));
+            for child in children {
+                if seen.insert(child.uuid) {
+                    out.push(child);
+                    visit(Some(child.uuid), projects, seen, out);
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        visit(None, projects, &mut HashSet::new(), &mut out);
+        out
+    }
+
+    /// Whether another live project has the same full path as the project
+    /// `uuid` (case-insensitive), as `todo project add` refuses.
+    pub fn path_taken(projects: &[Project], uuid: Uuid) -> bool {
+        let Some(project) = projects.iter().find(|p| p.uuid == uuid) else {
+            return false;
+        };
+        let path = project.path(projects);
+        projects.iter().any(|p| {
+            p.uuid != uuid && !p.is_deleted() && p.path(projects).eq_ignore_ascii_case(&path)
+        })
+    }
+
+    /// Index into `projects` of the project numbered `id` (1-based) in
+    /// [`tree_order`](Self::tree_order), as `todo project list` shows it.
+    pub fn index_of_id(projects: &[Project], id: usize) -> Option<usize> {
+        let uuid = Self::tree_order(projects).get(id.checked_sub(1)?)?.uuid;
+        projects.iter().position(|p| p.uuid == uuid)
+    }
+
+    /// Appends whatever projects are missing along `path` to `projects` and
+    /// returns the UUID of the last one.
+    ///
+    /// Existing projects along the path are reused, so `acme.api` only
+    /// creates `api` when `acme` already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path has an empty segment (`acme..api`).
+    pub fn create_path(projects: &mut Vec<Project>, path: &str) -> Result<Uuid> {
+        let segments: Vec<&str> = path.split(PATH_SEPARATOR).map(str::trim).collect();
+        if segments.iter().any(|s| s.is_empty()) {
+            return Err(anyhow::anyhow!("Invalid project path \"{}\"", path));
+        }
+
+        let mut parent: Option<Uuid> = None;
+        for segment in segments {
+            let existing = projects
+                .iter()
+                .find(|p| {
+                    !p.is_deleted()
+                        && p.name.eq_ignore_ascii_case(segment)
+                        && p.parent(projects).map(|p| p.uuid) == parent
+                })
+                .map(|p| p.uuid);
+            parent = Some(match existing {
+                Some(uuid) => uuid,
+                None => {
+                    let mut project = Project::new(segment.to_string());
+                    project.parent_id = parent;
+                    let uuid = project.uuid;
+                    projects.push(project);
+                    uuid
+                }
+            });
+        }
+        Ok(parent.expect("path has at least one segment"))
+    }
+
+    /// Finds a project by name or dotted path (case-insensitive) or creates
+    /// it.
     ///
     /// This is the single shared implementation used by every command handler
     /// that accepts `--project <NAME>` — `task add`, `task edit`, `note add`,
@@ -125,9 +370,10 @@ impl Project {
     ///
     /// # Behaviour
     ///
-    /// - If a non-deleted project with `name` already exists → returns its UUID.
-    /// - Otherwise → creates a new `Project`, appends it to `projects`, calls
-    ///   `storage.save_projects`, and returns the new UUID.
+    /// - If [`Project::find`] matches a non-deleted project → returns its UUID.
+    /// - Otherwise → creates the missing projects along the path (see
+    ///   [`Project::create_path`]), calls `storage.save_projects`, and returns
+    ///   the UUID of the last one.
     ///
     /// # Example
     ///
@@ -143,31 +389,52 @@ impl Project {
     /// // Second call with the same name: finds the existing one.
     /// let projects = storage.load_projects().unwrap();
     /// let uuid2 = Project::resolve_or_create(&storage, &projects, "backend").unwrap();
-    ///
     /// assert_eq!(uuid1, uuid2);
+    ///
+    /// // A dotted path nests under the existing project.
+    /// let projects = storage.load_projects().unwrap();
+    /// let auth = Project::resolve_or_create(&storage, &projects, "backend.auth").unwrap();
+    /// let projects = storage.load_projects().unwrap();
+    /// let auth = projects.iter().find(|p| p.uuid == auth).unwrap();
+    /// assert_eq!(auth.parent_id, Some(uuid1));
     /// ```
     pub fn resolve_or_create(
         storage: &impl crate::storage::Storage,
         projects: &[Project],
         name: &str,
     ) -> Result<Uuid> {
-        // Case-insensitive lookup among non-deleted projects
-        if let Some(existing) = projects
-            .iter()
-            .find(|p| p.name.to_lowercase() == name.to_lowercase() && !p.is_deleted())
-        {
+        if let Some(existing) = Self::find(projects, name) {
             return Ok(existing.uuid);
         }
 
         // Project does not exist yet — create and persist it
-        let new_project = Project::new(name.to_string());
-        let uuid = new_project.uuid;
         let mut all = projects.to_vec();
-        all.push(new_project);
+        let uuid = Self::create_path(&mut all, name)?;
         storage.save_projects(&all)?;
 
         Ok(uuid)
     }
+
+    /// [`Project::resolve_or_create`] for several names, as used by
+    /// `note add` / `note edit --project a,b`. Returns each project once, in
+    /// the order given.
+    ///
+    /// Projects are reloaded between names so that one created for an
+    /// earlier name is not lost when a later one is saved.
+    pub fn resolve_or_create_all(
+        storage: &impl crate::storage::Storage,
+        names: &[String],
+    ) -> Result<Vec<Uuid>> {
+        let mut uuids = Vec::new();
+        for name in names {
+            let projects = storage.load_projects()?;
+            let uuid = Self::resolve_or_create(storage, &projects, name)?;
+            if !uuids.contains(&uuid) {
+                uuids.push(uuid);
+            }
+        }
+        Ok(uuids)
+    }
 }
 
 #[cfg(test)]
@@ -248,4 +515,119 @@ mod tests {
         assert_eq!(uuid1, uuid2);
         assert_eq!(storage.load_projects().unwrap().len(), 1);
     }
+
+    #[test]
+    fn test_resolve_all_creates_each_once_in_order() {
+        let storage = InMemoryStorage::default();
+        let names = ["Web", "Api", "web"].map(String::from);
+
+        let uuids = Project::resolve_or_create_all(&storage, &names).unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects.len(), 2);
+        let find = |name: &str| projects.iter().find(|p| p.name == name).unwrap().uuid;
+        assert_eq!(uuids, vec![find("Web"), find("Api")]);
+    }
+
+    fn nested(names: &[&str]) -> Vec<Project> {
+        let mut projects: Vec<Project> = Vec::new();
+        for name in names {
+            let mut project = Project::new(name.to_string());
+            project.parent_id = projects.last().map(|p| p.uuid);
+            projects.push(project);
+        }
+        projects
+    }
+
+    #[test]
+    fn test_path_and_depth() {
+        let projects = nested(&["acme", "api", "auth"]);
+        assert_eq!(projects[2].path(&projects), "acme.api.auth");
+        assert_eq!(projects[2].depth(&projects), 2);
+        assert_eq!(projects[0].depth(&projects), 0);
+    }
+
+    #[test]
+    fn test_deleted_parent_counts_as_top_level() {
+        let mut projects = nested(&["acme", "api"]);
+        projects[0].soft_delete();
+        assert!(projects[1].parent(&projects).is_none());
+        assert_eq!(projects[1].path(&projects), "api");
+    }
+
+    #[test]
+    fn test_find_by_path_and_bare_name() {
+        let mut projects = nested(&["acme", "api"]);
+        projects.push(Project::new("web".into()));
+        assert_eq!(
+            Project::find(&projects, "ACME.Api").map(|p| p.uuid),
+            Some(projects[1].uuid)
+        );
+        assert_eq!(
+            Project::find(&projects, "api").map(|p| p.uuid),
+            Some(projects[1].uuid)
+        );
+
+        // A bare name shared by two projects is ambiguous.
+        let mut other_api = Project::new("api".into());
+        other_api.parent_id = Some(projects[2].uuid);
+        projects.push(other_api);
+        assert!(Project::find(&projects, "api").is_none());
+        assert!(Project::find(&projects, "web.api").is_some());
+    }
+
+    #[test]
+    fn test_subtree_includes_all_descendants() {
+        let mut projects = nested(&["acme", "api", "auth"]);
+        projects.push(Project::new("other".into()));
+        let subtree = Project::subtree(&projects, projects[0].uuid);
+        assert_eq!(subtree.len(), 3);
+        assert!(!subtree.contains(&projects[3].uuid));
+    }
+
+    #[test]
+    fn test_tree_order_puts_children_after_parent() {
+        let mut projects = vec![Project::new("zeta".into())];
+        projects.extend(nested(&["alpha", "beta"]));
+        projects.push(Project::new("gamma".into()));
+        let names: Vec<&str> = Project::tree_order(&projects)
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, ["alpha", "beta", "gamma", "zeta"]);
+    }
+
+    #[test]
+    fn test_index_of_id_follows_tree_order() {
+        let mut projects = vec![Project::new("zeta".into())];
+        projects.extend(nested(&["alpha", "beta"]));
+        assert_eq!(Project::index_of_id(&projects, 1), Some(1));
+        assert_eq!(Project::index_of_id(&projects, 2), Some(2));
+        assert_eq!(Project::index_of_id(&projects, 3), Some(0));
+        assert_eq!(Project::index_of_id(&projects, 0), None);
+        assert_eq!(Project::index_of_id(&projects, 4), None);
+    }
+
+    #[test]
+    fn test_resolve_dotted_path_creates_missing_segments() {
+        let storage = InMemoryStorage::default();
+        storage
+            .save_projects(&[Project::new("Acme".into())])
+            .unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        let uuid = Project::resolve_or_create(&storage, &projects, "acme.api.auth").unwrap();
+
+        let saved = storage.load_projects().unwrap();
+        assert_eq!(saved.len(), 3);
+        let auth = saved.iter().find(|p| p.uuid == uuid).unwrap();
+        assert_eq!(auth.path(&saved), "Acme.api.auth");
+    }
+
+    #[test]
+    fn test_create_path_rejects_empty_segment() {
+        let mut projects = Vec::new();
+        assert!(Project::create_path(&mut projects, "acme..api").is_err());
+        assert!(projects.is_empty());
+    }
 }
@@ -0,0 +1,106 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Lifecycle state of a project.
+///
+/// `Idea`, `Planning`, `Active` and `OnHold` are *open* states. `Done` and
+/// `Abandoned` are *closed* states — only `Done` counts as finished work.
+///
+/// `Project::completed` is kept in sync with this value (`completed` is `true`
+/// exactly when the status is `Done`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProjectStatus {
+    /// Something worth doing some day.
+    Idea,
+    /// Being scoped and broken down into tasks.
+    Planning,
+    /// Being worked on (default for new projects).
+    #[default]
+    Active,
+    /// Paused for now.
+    OnHold,
+    /// Finished (`todo project done`).
+    Done,
+    /// Dropped without being finished.
+    Abandoned,
+}
+
+impl ProjectStatus {
+    /// Every status, in lifecycle order.
+    pub const ALL: [ProjectStatus; 6] = [
+        ProjectStatus::Idea,
+        ProjectStatus::Planning,
+        ProjectStatus::Active,
+        ProjectStatus::OnHold,
+        ProjectStatus::Done,
+        ProjectStatus::Abandoned,
+    ];
+
+    /// Returns `true` for states that still require work.
+    pub fn is_open(self) -> bool {
+        !self.is_closed()
+    }
+
+    /// Returns `true` for `Done` and `Abandoned`.
+    pub fn is_closed(self) -> bool {
+        matches!(self, ProjectStatus::Done | ProjectStatus::Abandoned)
+    }
+
+    /// Kebab-case name, as stored in the database and accepted by `--status`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProjectStatus::Idea => "idea",
+            ProjectStatus::Planning => "planning",
+            ProjectStatus::Active => "active",
+            ProjectStatus::OnHold => "on-hold",
+            ProjectStatus::Done => "done",
+            ProjectStatus::Abandoned => "abandoned",
+        }
+    }
+
+    /// Parses the database representation.
+    ///
+    /// Unknown values fall back to `Active`.
+    pub fn from_str_lossy(s: &str) -> Self {
+        match s {
+            "idea" => ProjectStatus::Idea,
+            "planning" => ProjectStatus::Planning,
+            "on-hold" => ProjectStatus::OnHold,
+            "done" => ProjectStatus::Done,
+            "abandoned" => ProjectStatus::Abandoned,
+            _ => ProjectStatus::Active,
+        }
+    }
+
+    /// Heading used when grouping project lists.
+    pub fn label(self) -> &'static str {
+        match self {
+            ProjectStatus::Idea => "Ideas",
+            ProjectStatus::Planning => "Planning",
+            ProjectStatus::Active => "Active",
+            ProjectStatus::OnHold => "On hold",
+            ProjectStatus::Done => "Done",
+            ProjectStatus::Abandoned => "Abandoned",
+        }
+    }
+
+    /// Single-letter code used in table views.
+    pub fn letter(self) -> &'static str {
+        match self {
+            ProjectStatus::Idea => "I",
+            ProjectStatus::Planning => "P",
+            ProjectStatus::Active => "A",
+            ProjectStatus::OnHold => "H",
+            ProjectStatus::Done => "D",
+            ProjectStatus::Abandoned => "X",
+        }
+    }
+}
+
+impl fmt::Display for ProjectStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -4,6 +4,9 @@
 //! Resources are first-class citizens: they exist independently of any other
 //! entity. The association with a Note is stored on the Note side via
 //! `resource_ids: Vec<Uuid>`.
+//!
+//! `todo resource check` records the outcome of fetching a resource's URL as a
+//! [`LinkCheck`].
 
 use chrono::{DateTime, Utc};
 use clap::ValueEnum;
@@ -55,6 +58,52 @@ impl std::fmt::Display for ResourceType {
     }
 }
 
+// ── LinkCheck ─────────────────────────────────────────────────────────────────
+
+/// The result of the last `todo resource check` of a resource's URL.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LinkCheck {
+    /// When the URL was checked (UTC).
+    pub checked_at: DateTime<Utc>,
+
+    /// HTTP status of the final response; `None` when no response came back.
+    #[serde(default)]
+    pub status: Option<u16>,
+
+    /// Where redirects led, when the URL was redirected.
+    #[serde(default)]
+    pub final_url: Option<String>,
+
+    /// Why the request failed (DNS, timeout, TLS, ...).
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl LinkCheck {
+    /// Whether the link is broken: the request failed or the server answered
+    /// with an error status.
+    pub fn is_dead(&self) -> bool {
+        self.error.is_some() || self.status.is_none_or(|s| s >= 400)
+    }
+
+    /// The redirect target of a link that still works.
+    pub fn redirect(&self) -> Option<&str> {
+        if self.is_dead() {
+            return None;
+        }
+        self.final_url.as_deref()
+    }
+
+    /// A short description of the outcome: `200`, `404`, or the error.
+    pub fn summary(&self) -> String {
+        match (&self.error, self.status) {
+            (Some(error), _) => error.clone(),
+            (None, Some(status)) => status.to_string(),
+            (None, None) => "no response".to_string(),
+        }
+    }
+}
+
 // ── Resource ──────────────────────────────────────────────────────────────────
 
 /// An independent external reference — a URL, documentation link, or asset.
@@ -102,6 +151,10 @@ pub struct Resource {
     /// Soft-deletion timestamp — `None` means not deleted.
     #[serde(default)]
     pub deleted_at: Option<DateTime<Utc>>,
+
+    /// Outcome of the last link check — `None` means never checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_check: Option<LinkCheck>,
 }
 
 impl Resource {
@@ -117,6 +170,7 @@ impl Resource {
             created_at: Utc::now(),
             updated_at: Some(Utc::now()),
             deleted_at: None,
+            last_check: None,
         }
     }
 
@@ -131,7 +185,59 @@ impl Resource {
         self.touch();
     }
 
+    /// Undo a soft delete.
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+        self.touch();
+    }
+
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
     }
+
+    /// The URL if it can be checked over HTTP(S); file paths and other
+    /// schemes return `None`.
+    pub fn web_url(&self) -> Option<&str> {
+        self.url.as_deref().map(str::trim).filter(|url| {
+            let lower = url.to_ascii_lowercase();
+            lower.starts_with("http://") || lower.starts_with("https://")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(status: Option<u16>, final_url: Option<&str>, error: Option<&str>) -> LinkCheck {
+        LinkCheck {
+            checked_at: Utc::now(),
+            status,
+            final_url: final_url.map(str::to_string),
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_link_check_classification() {
+        assert!(!check(Some(200), None, None).is_dead());
+        assert!(check(Some(404), None, None).is_dead());
+        assert!(check(None, None, Some("timed out")).is_dead());
+
+        let moved = check(Some(200), Some("https://new.example"), None);
+        assert_eq!(moved.redirect(), Some("https://new.example"));
+        let gone = check(Some(410), Some("https://new.example"), None);
+        assert_eq!(gone.redirect(), None);
+        assert_eq!(gone.summary(), "410");
+    }
+
+    #[test]
+    fn test_web_url() {
+        let mut resource = Resource::new("Docs".into());
+        assert_eq!(resource.web_url(), None);
+        resource.url = Some(" HTTPS://docs.rs ".into());
+        assert_eq!(resource.web_url(), Some("HTTPS://docs.rs"));
+        resource.url = Some("~/books/rust.pdf".into());
+        assert_eq!(resource.web_url(), None);
+    }
 }
@@ -4,7 +4,10 @@ use uuid::Uuid;
 
 use super::filters::{DueFilter, StatusFilter};
 use super::priority::Priority;
+use super::project::Project;
 use super::recurrence::Recurrence;
+use super::task_status::TaskStatus;
+use super::urgency::{UrgencyBreakdown, UrgencyConfig};
 
 /// Represents a single task in the todo list.
 ///
@@ -46,7 +49,15 @@ pub struct Task {
     /// The task description/content
     pub text: String,
     /// Whether the task has been completed
+    ///
+    /// Mirrors `status == TaskStatus::Done`; kept for backward compatibility
+    /// with older JSON exports and the `completed` column in SQLite.
     pub completed: bool,
+    /// Lifecycle state (pending, active, waiting, done, cancelled).
+    ///
+    /// Old data without this field is migrated via [`Task::normalize_status`].
+    #[serde(default)]
+    pub status: TaskStatus,
     /// Priority level of the task
     pub priority: Priority,
     /// List of tags for categorization
@@ -82,6 +93,9 @@ pub struct Task {
     /// IDs (1- based) of tasks that must be completed before this one
     #[serde(default)]
     pub depends_on: Vec<Uuid>,
+    /// Estimated duration in days, used by `todo plan`.
+    #[serde(default)]
+    pub estimate: Option<u32>,
     /// Date when the task was marked as completed.
     #[serde(default)]
     pub completed_at: Option<NaiveDate>,
@@ -158,6 +172,7 @@ impl Task {
             uuid: Uuid::new_v4(),
             text,
             completed: false,
+            status: TaskStatus::Pending,
             priority,
             tags,
             project_id,
@@ -167,6 +182,7 @@ impl Task {
             recurrence,
             parent_id: None,
             depends_on: Vec::new(),
+            estimate: None,
             completed_at: None,
             updated_at: Some(Utc::now()),
             deleted_at: None,
@@ -199,6 +215,12 @@ impl Task {
         self.touch();
     }
 
+    /// Undoes [`soft_delete`](Task::soft_delete), used by `todo trash restore`.
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+        self.touch();
+    }
+
     /// Returns `true` if this task has been soft-deleted.
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
@@ -206,26 +228,58 @@ impl Task {
 
     /// Marks this task as completed.
     pub fn mark_done(&mut self) {
-        self.completed = true;
-        self.completed_at = Some(Local::now().naive_local().date());
-        self.touch();
+        self.set_status(TaskStatus::Done);
     }
 
     /// Marks this task as pending (not completed).
     pub fn mark_undone(&mut self) {
-        self.completed = false;
-        self.completed_at = None;
+        self.set_status(TaskStatus::Pending);
+    }
+
+    /// Moves the task to `status`, keeping `completed` and `completed_at` in sync.
+    ///
+    /// `completed_at` records the day the task was closed, so it is set for
+    /// both `Done` and `Cancelled` and cleared when the task is reopened.
+    pub fn set_status(&mut self, status: TaskStatus) {
+        self.status = status;
+        self.completed = status == TaskStatus::Done;
+        self.completed_at = if status.is_closed() {
+            Some(Local::now().naive_local().date())
+        } else {
+            None
+        };
         self.touch();
     }
 
+    /// Returns `true` while the task still requires work
+    /// (pending, active or waiting).
+    ///
+    /// The legacy `completed` flag is honoured too, so a task whose status
+    /// has not been normalized yet is never reported as open once done.
+    pub fn is_open(&self) -> bool {
+        !self.completed && self.status.is_open()
+    }
+
+    /// Reconciles `status` with the legacy `completed` flag.
+    ///
+    /// Data written before task states existed only carries `completed`;
+    /// such tasks deserialize as `Pending` and are promoted to `Done` here.
+    pub fn normalize_status(&mut self) {
+        if self.completed && self.status != TaskStatus::Done {
+            self.status = TaskStatus::Done;
+        } else if !self.completed && self.status == TaskStatus::Done {
+            self.completed = true;
+        }
+    }
+
     /// Checks if this is overdue.
     ///
     /// A task is considered overdue if it has a due date in the past
-    /// and is not yet completed.
+    /// and is still open.
     pub fn is_overdue(&self) -> bool {
         if let Some(due) = self.due_date {
             let today = Local::now().naive_local().date();
-            due < today && !self.completed
+            due < today && self.is_open()
         } else {
             false
         }
@@ -240,12 +294,12 @@ impl Task {
     /// # Returns
     ///
     /// `true` if the task is due within the specified number of days and
-    /// is not yet completed, `false` otherwise.
+    /// is still open, `false` otherwise.
     pub fn is_due_soon(&self, days: i64) -> bool {
         if let Some(due) = self.due_date {
             let today = Local::now().naive_local().date();
             let days_until = (due - today).num_days();
-            days_until >= 0 && days_until <= days && !self.completed
+            days_until >= 0 && days_until <= days && self.is_open()
         } else {
             false
         }
@@ -254,8 +308,11 @@ impl Task {
     /// Checks if this task matches the given status filter.
     pub fn matches_status(&self, status: StatusFilter) -> bool {
         match status {
-            StatusFilter::Pending => !self.completed,
+            StatusFilter::Pending => self.is_open(),
+            StatusFilter::Active => self.is_open() && self.status == TaskStatus::Active,
+            StatusFilter::Waiting => self.is_open() && self.status == TaskStatus::Waiting,
             StatusFilter::Done => self.completed,
+            StatusFilter::Cancelled => self.status == TaskStatus::Cancelled,
             StatusFilter::All => true,
         }
     }
@@ -270,20 +327,20 @@ impl Task {
         }
     }
 
-    /// Returns true if any dependency task is still pending.
+    /// Returns true if any dependency task is still open.
     ///
-    /// `all_tasks` is the full 0-indexed task list; IDs in `depends_on` are 1-based.
+    /// Cancelled dependencies no longer block — there is nothing left to wait for.
     pub fn is_blocked(&self, all_tasks: &[Task]) -> bool {
         self.depends_on.iter().any(|dep_uuid| {
             all_tasks
                 .iter()
                 .find(|t| t.uuid == *dep_uuid)
-                .map(|t| !t.completed)
+                .map(|t| t.is_open())
                 .unwrap_or(false)
         })
     }
 
-    /// Returns the IDs of blocking (still-pending) dependencies.
+    /// Returns the IDs of blocking (still-open) dependencies.
     pub fn blocking_deps(&self, all_tasks: &[Task]) -> Vec<Uuid> {
         self.depends_on
             .iter()
@@ -292,60 +349,127 @@ impl Task {
                 all_tasks
                     .iter()
                     .find(|t| t.uuid == *dep_uuid)
-                    .map(|t| !t.completed)
+                    .map(|t| t.is_open())
                     .unwrap_or(false)
             })
             .collect()
     }
 
-    /// Calculates an urgency score combining priority, due date, dependencies,
-    /// blocking status, age, and tags — following the Taskwarrior model.
+    /// Calculates an urgency score with the default coefficients.
     ///
-    /// Higher scores surface first in sorted lists.
-    /// Returns 0.0 for completed or deleted tasks.
+    /// Shorthand for [`urgency_score_with`](Self::urgency_score_with) using
+    /// [`UrgencyConfig::default`] and no project boosts.
     pub fn urgency_score(&self, all_tasks: &[Task]) -> f32 {
-        if self.completed || self.is_deleted() {
-            return 0.0;
-        }
+        self.urgency_score_with(all_tasks, &[], &UrgencyConfig::default())
+    }
 
-        let mut score = 0.0_f32;
+    /// Calculates an urgency score using the coefficients in `cfg`.
+    ///
+    /// Higher scores surface first in sorted lists.
+    /// Returns 0.0 for closed (done/cancelled) or deleted tasks.
+    pub fn urgency_score_with(
+        &self,
+        all_tasks: &[Task],
+        projects: &[Project],
+        cfg: &UrgencyConfig,
+    ) -> f32 {
+        self.urgency_breakdown(all_tasks, projects, cfg).total
+    }
 
-        score += match self.priority {
-            Priority::High => 6.0,
-            Priority::Medium => 3.0,
-            Priority::Low => 1.0,
+    /// Breaks the urgency score down into its individual terms — priority,
+    /// due date, dependencies, blocking status, age, tags and configured
+    /// tag/project boosts — following the Taskwarrior model.
+    ///
+    /// `projects` is only used to resolve the task's project name for
+    /// `[urgency.project]` boosts; pass `&[]` when that is not needed.
+    pub fn urgency_breakdown(
+        &self,
+        all_tasks: &[Task],
+        projects: &[Project],
+        cfg: &UrgencyConfig,
+    ) -> UrgencyBreakdown {
+        let mut b = UrgencyBreakdown::default();
+        if !self.is_open() || self.is_deleted() {
+            return b;
+        }
+
+        let (key, label) = match self.priority {
+            Priority::High => ("priority_high", "high priority"),
+            Priority::Medium => ("priority_medium", "medium priority"),
+            Priority::Low => ("priority_low", "low priority"),
         };
+        b.push(key, label, cfg.priority(self.priority));
 
         // Due date: time-decay urgency — score increases continuously as deadline approaches.
-        // Overdue tasks always get max due-score (+12).
+        // Overdue tasks always get the flat `overdue` score.
         if let Some(due) = self.due_date {
             let today = chrono::Local::now().naive_local().date();
             let days = (due - today).num_days();
             if days < 0 {
-                score += 12.0; // overdue
+                b.push("overdue", format!("{} day(s) overdue", -days), cfg.overdue);
             } else {
-                score += (10.0 / (days as f32 + 1.0)).min(10.0);
+                let detail = match days {
+                    0 => "due today".to_string(),
+                    d => format!("due in {} day(s)", d),
+                };
+                b.push("due", detail, cfg.due / (days as f32 + 1.0));
             }
         }
 
-        let is_blocking = all_tasks
+        let blocking = all_tasks
             .iter()
-            .any(|t| !t.completed && !t.is_deleted() && t.depends_on.contains(&self.uuid));
-        if is_blocking {
-            score += 8.0
+            .filter(|t| t.is_open() && !t.is_deleted() && t.depends_on.contains(&self.uuid))
+            .count();
+        if blocking > 0 {
+            b.push(
+                "blocking",
+                format!("{} open task(s) depend on this", blocking),
+                cfg.blocking,
+            );
         }
 
-        if self.is_blocked(all_tasks) {
-            score -= 5.0;
+        let blocked_by = self.blocking_deps(all_tasks).len();
+        if blocked_by > 0 {
+            b.push(
+                "blocked",
+                format!("waiting on {} open dependency(ies)", blocked_by),
+                cfg.blocked,
+            );
         }
 
         // Age: logarithmic growth — old tasks gain weight gradually without dominating.
-        let age_days = (chrono::Utc::now() - self.created_at).num_days();
-        score += (age_days.max(0) as f32).ln().clamp(0.0, 2.0);
+        let age_days = (chrono::Utc::now() - self.created_at).num_days().max(0);
+        b.push(
+            "age",
+            format!("{} day(s) old", age_days),
+            (age_days as f32).ln().clamp(0.0, cfg.age.max(0.0)),
+        );
 
-        score += (self.tags.len() as f32 * 0.5).min(1.0);
+        b.push(
+            "tags",
+            format!("{} tag(s)", self.tags.len()),
+            (self.tags.len() as f32 * cfg.tags).min(cfg.tags_max),
+        );
 
-        score.max(0.0)
+        for tag in &self.tags {
+            if let Some(boost) = cfg.tag_boost(tag) {
+                b.push(format!("tag.{}", tag), format!("tagged '{}'", tag), boost);
+            }
+        }
+
+        if let Some(project) = self
+            .project_id
+            .and_then(|pid| projects.iter().find(|p| p.uuid == pid))
+            && let Some(boost) = cfg.project_boost(&project.name)
+        {
+            b.push(
+                format!("project.{}", project.name),
+                format!("in project '{}'", project.name),
+                boost,
+            );
+        }
+
+        b.finish()
     }
 
     /// Creates a new task for the next recurrence cycle.
@@ -362,7 +486,7 @@ impl Task {
     /// # Behavior
     ///
     /// - Preserves: text, priority, tags, recurrence pattern
-    /// - Resets: completed = false
+    /// - Resets: completed = false, status = pending
     /// - Updates: due_date (calculated from recurrence), created_at (now), updated_at (now)
     /// - Generates: New UUID for the next occurrence
     /// - Sets: parent_id (to link the chain)
@@ -419,6 +543,9 @@ impl Task {
 
 /// Counts the tasks of a project by UUID, returning (total, completed).
 ///
+/// Cancelled tasks are left out of both numbers so they do not drag down
+/// project progress.
+///
 /// # Example
 ///
 /// ```
@@ -436,7 +563,33 @@ impl Task {
 pub fn count_by_project(tasks: &[Task], project_uuid: uuid::Uuid) -> (usize, usize) {
     let matching: Vec<_> = tasks
         .iter()
-        .filter(|t| !t.is_deleted() && t.project_id == Some(project_uuid))
+        .filter(|t| {
+            !t.is_deleted()
+                && t.status != TaskStatus::Cancelled
+                && t.project_id == Some(project_uuid)
+        })
+        .collect();
+
+    let total = matching.len();
+    let done = matching.iter().filter(|t| t.completed).count();
+    (total, done)
+}
+
+/// Like [`count_by_project`], but also counts tasks in every project nested
+/// under `project_uuid`, returning (total, completed).
+pub fn count_by_project_tree(
+    tasks: &[Task],
+    projects: &[Project],
+    project_uuid: Uuid,
+) -> (usize, usize) {
+    let subtree = Project::subtree(projects, project_uuid);
+    let matching: Vec<_> = tasks
+        .iter()
+        .filter(|t| {
+            !t.is_deleted()
+                && t.status != TaskStatus::Cancelled
+                && t.project_id.is_some_and(|p| subtree.contains(&p))
+        })
         .collect();
 
     let total = matching.len();
@@ -635,6 +788,99 @@ mod tests {
         assert!(task.updated_at > before);
     }
 
+    #[test]
+    fn test_cancelled_dep_does_not_block() {
+        let mut dep = make_task("Dep");
+        dep.set_status(TaskStatus::Cancelled);
+        let mut task = make_task("Task");
+        task.depends_on = vec![dep.uuid];
+        assert!(!task.is_blocked(&[dep]));
+    }
+
+    #[test]
+    fn test_set_status_keeps_completed_in_sync() {
+        let mut task = make_task("A");
+        task.set_status(TaskStatus::Done);
+        assert!(task.completed);
+        assert!(task.completed_at.is_some());
+
+        task.set_status(TaskStatus::Cancelled);
+        assert!(!task.completed);
+        assert!(task.completed_at.is_some());
+
+        task.set_status(TaskStatus::Active);
+        assert!(!task.completed);
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_status_filters() {
+        let mut task = make_task("A");
+        task.set_status(TaskStatus::Waiting);
+        assert!(task.matches_status(StatusFilter::Pending));
+        assert!(task.matches_status(StatusFilter::Waiting));
+        assert!(!task.matches_status(StatusFilter::Active));
+
+        task.set_status(TaskStatus::Cancelled);
+        assert!(!task.matches_status(StatusFilter::Pending));
+        assert!(!task.matches_status(StatusFilter::Done));
+        assert!(task.matches_status(StatusFilter::Cancelled));
+    }
+
+    #[test]
+    fn test_cancelled_task_has_zero_urgency_and_is_not_overdue() {
+        let mut task = make_task("A");
+        task.due_date = Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+        task.set_status(TaskStatus::Cancelled);
+        assert_eq!(task.urgency_score(&[]), 0.0);
+        assert!(!task.is_overdue());
+    }
+
+    #[test]
+    fn test_urgency_default_breakdown_matches_score() {
+        let dep = make_task("Dep");
+        let mut task = make_task("Task");
+        task.depends_on = vec![dep.uuid];
+        task.tags = vec!["a".into()];
+        let all = vec![dep.clone(), task.clone()];
+
+        let b = dep.urgency_breakdown(&all, &[], &UrgencyConfig::default());
+        let names: Vec<_> = b.components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["priority_medium", "blocking"]);
+        assert_eq!(b.total, dep.urgency_score(&all));
+        assert_eq!(task.urgency_score(&all), 0.0, "blocked sum floors at 0");
+    }
+
+    #[test]
+    fn test_urgency_uses_configured_coefficients_and_boosts() {
+        let mut project = Project::new("Backend".into());
+        project.uuid = Uuid::new_v4();
+        let mut task = make_task("Task");
+        task.tags = vec!["Urgent".into()];
+        task.project_id = Some(project.uuid);
+
+        let mut cfg = UrgencyConfig {
+            priority_medium: 10.0,
+            tags: 0.0,
+            ..UrgencyConfig::default()
+        };
+        cfg.tag.insert("urgent".into(), 5.0);
+        cfg.project.insert("backend".into(), 2.0);
+
+        let b = task.urgency_breakdown(&[], &[project], &cfg);
+        assert_eq!(b.total, 17.0);
+        assert!(b.components.iter().any(|c| c.name == "tag.Urgent"));
+        assert!(b.components.iter().any(|c| c.name == "project.Backend"));
+    }
+
+    #[test]
+    fn test_normalize_status_promotes_legacy_completed() {
+        let mut task = make_task("A");
+        task.completed = true;
+        task.normalize_status();
+        assert_eq!(task.status, TaskStatus::Done);
+    }
+
     fn make_recurring(recurrence: Option<Recurrence>, due: Option<NaiveDate>) -> Task {
         Task::new(
             "Test".to_string(),
@@ -0,0 +1,83 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Lifecycle state of a task.
+///
+/// `Pending`, `Active` and `Waiting` are *open* states — the task still needs
+/// work. `Done` and `Cancelled` are *closed* states — nothing left to do, but
+/// only `Done` counts as completed work in statistics.
+///
+/// `Task::completed` is kept in sync with this value (`completed` is `true`
+/// exactly when the status is `Done`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskStatus {
+    /// Not started yet (default for new tasks).
+    #[default]
+    Pending,
+    /// Currently being worked on (`todo start`).
+    Active,
+    /// Parked until something external happens (`todo wait`).
+    Waiting,
+    /// Finished (`todo done`).
+    Done,
+    /// Dropped without being finished (`todo cancel`).
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Returns `true` for states that still require work.
+    pub fn is_open(self) -> bool {
+        matches!(
+            self,
+            TaskStatus::Pending | TaskStatus::Active | TaskStatus::Waiting
+        )
+    }
+
+    /// Returns `true` for `Done` and `Cancelled`.
+    pub fn is_closed(self) -> bool {
+        !self.is_open()
+    }
+
+    /// Lowercase name, as stored in the database and shown in messages.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskStatus::Pending => "pending",
+            TaskStatus::Active => "active",
+            TaskStatus::Waiting => "waiting",
+            TaskStatus::Done => "done",
+            TaskStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Parses the lowercase database representation.
+    ///
+    /// Unknown values fall back to `Pending`.
+    pub fn from_str_lossy(s: &str) -> Self {
+        match s {
+            "active" => TaskStatus::Active,
+            "waiting" => TaskStatus::Waiting,
+            "done" => TaskStatus::Done,
+            "cancelled" => TaskStatus::Cancelled,
+            _ => TaskStatus::Pending,
+        }
+    }
+
+    /// Single-letter code used in table views.
+    pub fn letter(self) -> &'static str {
+        match self {
+            TaskStatus::Pending => "P",
+            TaskStatus::Active => "A",
+            TaskStatus::Waiting => "W",
+            TaskStatus::Done => "D",
+            TaskStatus::Cancelled => "C",
+        }
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
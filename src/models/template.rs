@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+
+use chrono::{Duration, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::priority::Priority;
+
+/// A named, reusable checklist that expands into several tasks at once.
+///
+/// Templates live either in the database (`todo template save`) or in the
+/// `[templates.<name>]` section of `config.toml`. Database templates take
+/// precedence when both define the same name.
+///
+/// Text, tags and project may contain `{{var}}` placeholders that are filled
+/// in from `--var key=value` when the template is applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Template {
+    /// Unique, case-insensitive name. Taken from the table key in `config.toml`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Default project for every generated task; `--project` overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub tasks: Vec<TemplateTask>,
+}
+
+/// One step of a [`Template`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateTask {
+    pub text: String,
+    #[serde(default = "default_priority")]
+    pub priority: Priority,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Relative due offset from the day the template is applied (`"+2d"`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// 1-based positions of earlier steps in the same template.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<usize>,
+}
+
+fn default_priority() -> Priority {
+    Priority::Medium
+}
+
+impl Template {
+    pub fn new(name: String, tasks: Vec<TemplateTask>) -> Self {
+        Self {
+            name,
+            description: None,
+            project: None,
+            tasks,
+        }
+    }
+
+    /// Checks structural rules that do not depend on variables: at least one
+    /// step, valid due offsets, and dependencies that point at earlier steps
+    /// only (which also rules out cycles).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.tasks.is_empty() {
+            return Err(format!("template '{}' has no tasks", self.name));
+        }
+        for (i, step) in self.tasks.iter().enumerate() {
+            let pos = i + 1;
+            if let Some(ref due) = step.due {
+                parse_offset(due).map_err(|e| format!("step {}: {}", pos, e))?;
+            }
+            for &dep in &step.depends_on {
+                if dep == 0 || dep >= pos {
+                    return Err(format!(
+                        "step {} depends on step {}, but steps may only depend on earlier ones",
+                        pos, dep
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Names of all `{{var}}` placeholders used by this template, sorted.
+    pub fn variables(&self) -> Vec<String> {
+        let mut vars = Vec::new();
+        let fields = self
+            .tasks
+            .iter()
+            .flat_map(|t| std::iter::once(&t.text).chain(t.tags.iter()))
+            .chain(self.project.iter());
+        for field in fields {
+            let mut rest = field.as_str();
+            while let Some(start) = rest.find("{{") {
+                let Some(len) = rest[start + 2..].find("}}") else {
+                    break;
+                };
+                let name = rest[start + 2..start + 2 + len].trim().to_string();
+                if !vars.contains(&name) {
+                    vars.push(name);
+                }
+                rest = &rest[start + 2 + len + 2..];
+            }
+        }
+        vars.sort();
+        vars
+    }
+}
+
+/// Replaces every `{{var}}` in `input` with its value from `vars`.
+///
+/// Returns the name of the first placeholder without a value as `Err`.
+pub fn substitute(input: &str, vars: &BTreeMap<String, String>) -> Result<String, String> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+        let value = vars.get(name).ok_or_else(|| name.to_string())?;
+        out.push_str(&rest[..start]);
+        out.push_str(value);
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Parses a relative due offset such as `+0d`, `+3d`, `+2w` or `+1m`.
+///
+/// The leading `+` is optional.
+pub fn parse_offset(input: &str) -> Result<RelativeDue, String> {
+    let s = input.trim();
+    let s = s.strip_prefix('+').unwrap_or(s);
+    let err = || {
+        format!(
+            "invalid due offset '{}' (expected +Nd, +Nw or +Nm, e.g. +2d)",
+            input.trim()
+        )
+    };
+    let unit = s.chars().last().ok_or_else(err)?;
+    let n: u32 = s[..s.len() - unit.len_utf8()].parse().map_err(|_| err())?;
+    match unit {
+        'd' => Ok(RelativeDue::Days(n)),
+        'w' => n.checked_mul(7).map(RelativeDue::Days).ok_or_else(err),
+        'm' => Ok(RelativeDue::Months(n)),
+        _ => Err(err()),
+    }
+}
+
+/// A due date offset relative to the day a template is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeDue {
+    Days(u32),
+    Months(u32),
+}
+
+impl RelativeDue {
+    /// The due date this offset gives when applied on `base`.
+    ///
+    /// `None` when the date would fall outside the supported range.
+    pub fn resolve(self, base: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            RelativeDue::Days(n) => base.checked_add_signed(Duration::days(n as i64)),
+            RelativeDue::Months(n) => base.checked_add_months(Months::new(n)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(text: &str, deps: Vec<usize>) -> TemplateTask {
+        TemplateTask {
+            text: text.into(),
+            priority: Priority::Medium,
+            tags: vec![],
+            due: None,
+            depends_on: deps,
+        }
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("+2d"), Ok(RelativeDue::Days(2)));
+        assert_eq!(parse_offset("1w"), Ok(RelativeDue::Days(7)));
+        assert_eq!(parse_offset("+3m"), Ok(RelativeDue::Months(3)));
+        assert!(parse_offset("+d").is_err());
+        assert!(parse_offset("-2d").is_err());
+        assert!(parse_offset("tomorrow").is_err());
+        assert!(parse_offset("+4294967295w").is_err());
+    }
+
+    #[test]
+    fn test_resolve_out_of_range_is_none() {
+        let base = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        assert_eq!(
+            RelativeDue::Days(3).resolve(base),
+            NaiveDate::from_ymd_opt(2026, 2, 3)
+        );
+        assert_eq!(
+            RelativeDue::Months(1).resolve(base),
+            NaiveDate::from_ymd_opt(2026, 2, 28)
+        );
+        assert_eq!(RelativeDue::Days(u32::MAX).resolve(base), None);
+        assert_eq!(RelativeDue::Months(u32::MAX).resolve(base), None);
+    }
+
+    #[test]
+    fn test_substitute_and_variables() {
+        let mut t = Template::new("release".into(), vec![step("Tag v{{version}}", vec![])]);
+        t.project = Some("{{ service }}".into());
+        assert_eq!(t.variables(), vec!["service", "version"]);
+
+        let vars = BTreeMap::from([("version".to_string(), "2.4".to_string())]);
+        assert_eq!(substitute("Tag v{{version}}", &vars).unwrap(), "Tag v2.4");
+        assert_eq!(substitute("{{ service }}", &vars).unwrap_err(), "service");
+    }
+
+    #[test]
+    fn test_validate_rejects_forward_dependencies() {
+        let ok = Template::new("t".into(), vec![step("A", vec![]), step("B", vec![1])]);
+        assert!(ok.validate().is_ok());
+
+        let bad = Template::new("t".into(), vec![step("A", vec![2]), step("B", vec![])]);
+        assert!(bad.validate().unwrap_err().contains("earlier"));
+
+        assert!(Template::new("t".into(), vec![]).validate().is_err());
+    }
+
+    #[test]
+    fn test_parses_config_toml_shape() {
+        let t: Template = toml::from_str(
+            r#"
+            project = "api"
+            [[tasks]]
+            text = "Freeze {{version}}"
+            due = "+1d"
+            [[tasks]]
+            text = "Publish"
+            priority = "high"
+            depends_on = [1]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(t.tasks.len(), 2);
+        assert_eq!(t.tasks[1].priority, Priority::High);
+        assert!(t.validate().is_ok());
+    }
+}
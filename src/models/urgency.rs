@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::priority::Priority;
+
+/// Coefficients used by [`Task::urgency_breakdown`](super::Task::urgency_breakdown).
+///
+/// Loaded from the `[urgency]` section of `config.toml`. Every field is
+/// optional; missing values fall back to the defaults below, which reproduce
+/// the original hardcoded weights.
+///
+/// ```toml
+/// [urgency]
+/// priority_high   = 6.0
+/// priority_medium = 3.0
+/// priority_low    = 1.0
+/// overdue         = 12.0   # flat score once the due date has passed
+/// due             = 10.0   # due today; decays as 1/(days+1)
+/// blocking        = 8.0    # other open tasks depend on this one
+/// blocked         = -5.0   # waiting on open dependencies
+/// age             = 2.0    # cap for ln(age in days)
+/// tags            = 0.5    # per tag…
+/// tags_max        = 1.0    # …up to this total
+///
+/// tag.urgent      = 5.0    # extra boost per tag name
+/// project.Backend = 2.0    # extra boost per project name
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrgencyConfig {
+    pub priority_high: f32,
+    pub priority_medium: f32,
+    pub priority_low: f32,
+    pub overdue: f32,
+    pub due: f32,
+    pub blocking: f32,
+    pub blocked: f32,
+    pub age: f32,
+    pub tags: f32,
+    pub tags_max: f32,
+    /// Per-tag boosts, matched case-insensitively.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub tag: BTreeMap<String, f32>,
+    /// Per-project boosts keyed by project name, matched case-insensitively.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub project: BTreeMap<String, f32>,
+}
+
+impl Default for UrgencyConfig {
+    fn default() -> Self {
+        Self {
+            priority_high: 6.0,
+            priority_medium: 3.0,
+            priority_low: 1.0,
+            overdue: 12.0,
+            due: 10.0,
+            blocking: 8.0,
+            blocked: -5.0,
+            age: 2.0,
+            tags: 0.5,
+            tags_max: 1.0,
+            tag: BTreeMap::new(),
+            project: BTreeMap::new(),
+        }
+    }
+}
+
+impl UrgencyConfig {
+    /// Weight for a priority level.
+    pub fn priority(&self, priority: Priority) -> f32 {
+        match priority {
+            Priority::High => self.priority_high,
+            Priority::Medium => self.priority_medium,
+            Priority::Low => self.priority_low,
+        }
+    }
+
+    /// Configured boost for a tag, if any.
+    pub fn tag_boost(&self, tag: &str) -> Option<f32> {
+        lookup_ci(&self.tag, tag)
+    }
+
+    /// Configured boost for a project name, if any.
+    pub fn project_boost(&self, name: &str) -> Option<f32> {
+        lookup_ci(&self.project, name)
+    }
+}
+
+fn lookup_ci(map: &BTreeMap<String, f32>, key: &str) -> Option<f32> {
+    map.iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(key))
+        .map(|(_, v)| *v)
+}
+
+/// One term of an urgency score, e.g. `priority_high = +6.0 (high priority)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UrgencyComponent {
+    /// Coefficient name as written in `config.toml`.
+    pub name: String,
+    /// Human-readable reason (e.g. `"due in 3 days"`).
+    pub detail: String,
+    pub value: f32,
+}
+
+/// The terms that make up an urgency score and their clamped total.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UrgencyBreakdown {
+    pub components: Vec<UrgencyComponent>,
+    /// Sum of all components, floored at `0.0`.
+    pub total: f32,
+}
+
+impl UrgencyBreakdown {
+    pub(crate) fn push(&mut self, name: impl Into<String>, detail: impl Into<String>, value: f32) {
+        if value != 0.0 {
+            self.components.push(UrgencyComponent {
+                name: name.into(),
+                detail: detail.into(),
+                value,
+            });
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> Self {
+        self.total = self
+            .components
+            .iter()
+            .map(|c| c.value)
+            .sum::<f32>()
+            .max(0.0);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_boost_tables_from_toml() {
+        let cfg: UrgencyConfig =
+            toml::from_str("priority_high = 9.0\ntag.urgent = 5.0\nproject.Backend = 2.5\n")
+                .unwrap();
+        assert_eq!(cfg.priority(Priority::High), 9.0);
+        assert_eq!(
+            cfg.priority(Priority::Low),
+            1.0,
+            "unset fields keep defaults"
+        );
+        assert_eq!(cfg.tag_boost("URGENT"), Some(5.0));
+        assert_eq!(cfg.project_boost("backend"), Some(2.5));
+        assert_eq!(cfg.tag_boost("other"), None);
+    }
+
+    #[test]
+    fn test_breakdown_skips_zero_terms_and_floors_total() {
+        let mut b = UrgencyBreakdown::default();
+        b.push("priority", "low", 1.0);
+        b.push("tags", "none", 0.0);
+        b.push("blocked", "1 open dependency", -5.0);
+        let b = b.finish();
+        assert_eq!(b.components.len(), 2);
+        assert_eq!(b.total, 0.0);
+    }
+}
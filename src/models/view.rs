@@ -0,0 +1,111 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::filters::{DueFilter, RecurrenceFilter, SortBy, StatusFilter};
+use super::priority::Priority;
+
+/// A named task list: a filter expression plus an optional sort order.
+///
+/// Views are saved with `todo view save` and replayed with `todo view run`.
+/// One view at a time can be made the *context* (`todo ctx set`), which
+/// narrows `list`, `next`, `calendar`, `stats` and the TUI until cleared.
+///
+/// The `list` flags a view is saved from are folded into a single
+/// expression in the [`crate::services::query`] language, so a view is
+/// evaluated exactly like `todo list --filter`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    /// Unique, case-insensitive name.
+    pub name: String,
+    /// Filter expression; empty matches every task.
+    #[serde(default)]
+    pub filter: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortBy>,
+}
+
+/// The `todo list` flags a view can be built from.
+#[derive(Debug, Clone, Default)]
+pub struct ViewFlags {
+    pub status: Option<StatusFilter>,
+    pub priority: Option<Priority>,
+    pub due: Option<DueFilter>,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    pub recurrence: Option<RecurrenceFilter>,
+    pub filter: Option<String>,
+}
+
+impl ViewFlags {
+    /// Folds the flags into one filter expression (all terms ANDed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustodo::models::{DueFilter, StatusFilter, ViewFlags};
+    ///
+    /// let flags = ViewFlags {
+    ///     status: Some(StatusFilter::Pending),
+    ///     project: Some("Day job".into()),
+    ///     due: Some(DueFilter::Overdue),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(flags.to_expression(), r#"status:pending due:overdue project:"Day job""#);
+    /// ```
+    pub fn to_expression(&self) -> String {
+        let mut terms = Vec::new();
+        if let Some(status) = self.status.filter(|s| *s != StatusFilter::All) {
+            terms.push(format!("status:{}", name(status)));
+        }
+        if let Some(priority) = self.priority {
+            terms.push(format!("priority:{}", name(priority)));
+        }
+        if let Some(due) = self.due {
+            terms.push(format!("due:{}", name(due)));
+        }
+        for tag in &self.tags {
+            terms.push(format!("tag:{}", quote(tag)));
+        }
+        if let Some(ref project) = self.project {
+            terms.push(format!("project:{}", quote(project)));
+        }
+        if let Some(recurrence) = self.recurrence {
+            let value = match recurrence {
+                RecurrenceFilter::NonRecurring => "none".to_string(),
+                other => name(other),
+            };
+            terms.push(format!("recur:{}", value));
+        }
+        if let Some(filter) = self
+            .filter
+            .as_deref()
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+        {
+            if terms.is_empty() {
+                terms.push(filter.to_string());
+            } else {
+                terms.push(format!("({})", filter));
+            }
+        }
+        terms.join(" ")
+    }
+}
+
+fn name<T: ValueEnum>(value: T) -> String {
+    value
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn quote(value: &str) -> String {
+    if value
+        .chars()
+        .any(|c| c.is_whitespace() || "()\"".contains(c))
+    {
+        format!("\"{}\"", value.replace('"', ""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,83 @@
+//! Terminal rendering for archived items.
+
+use colored::Colorize;
+
+use crate::models::{ArchiveEntry, Archived, Project};
+use crate::render::formatting::truncate;
+
+/// Prints archived entries with the IDs `todo unarchive` accepts.
+///
+/// `projects` resolves project names; archived projects are looked up among
+/// the entries themselves.
+pub fn display_archive(entries: &[(usize, &ArchiveEntry)], projects: &[Project], title: &str) {
+    let label_w = entries
+        .iter()
+        .map(|(_, e)| e.label().chars().count())
+        .max()
+        .unwrap_or(5)
+        .clamp(5, 40);
+
+    let project_name = |entry: &ArchiveEntry| -> Option<String> {
+        if let Archived::Project(_) = entry.item {
+            return None;
+        }
+        let uuid = entry.project_id()?;
+        projects
+            .iter()
+            .find(|p| p.uuid == uuid)
+            .map(|p| p.name.clone())
+            .or_else(|| {
+                entries.iter().find_map(|(_, e)| match &e.item {
+                    Archived::Project(p) if p.uuid == uuid => Some(p.name.clone()),
+                    _ => None,
+                })
+            })
+    };
+
+    let total_w = 4 + 2 + 7 + 2 + label_w + 2 + 16 + 2 + 10 + 2 + 10;
+    println!("\n{}\n", title.bright_white().bold());
+    println!(
+        "{:>4}  {:<7}  {:<label_w$}  {:<16}  {:<10}  {:<10}",
+        "ID".dimmed(),
+        "Kind".dimmed(),
+        "Item".dimmed(),
+        "Project".dimmed(),
+        "Closed".dimmed(),
+        "Archived".dimmed(),
+    );
+    println!("{}", "─".repeat(total_w).dimmed());
+
+    for (id, entry) in entries {
+        let closed = match &entry.item {
+            Archived::Task(t) => t.completed_at,
+            Archived::Project(p) => p.completed_at,
+            Archived::Note(_) => None,
+        }
+        .map(|d| d.to_string())
+        .unwrap_or_else(|| "—".into());
+        let kind = match entry.item {
+            Archived::Task(_) => format!("{:<7}", "task").normal(),
+            Archived::Project(_) => format!("{:<7}", "project").magenta(),
+            Archived::Note(_) => format!("{:<7}", "note").cyan(),
+        };
+        let project = project_name(entry)
+            .map(|n| truncate(&n, 16))
+            .unwrap_or_else(|| "—".into());
+
+        println!(
+            "{:>4}  {}  {:<label_w$}  {:<16}  {:<10}  {}",
+            format!("#{}", id).dimmed(),
+            kind,
+            truncate(&entry.label(), label_w),
+            project.magenta(),
+            closed.dimmed(),
+            entry
+                .archived_at
+                .with_timezone(&chrono::Local)
+                .date_naive()
+                .to_string()
+                .dimmed(),
+        );
+    }
+    println!();
+}
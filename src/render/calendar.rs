@@ -16,6 +16,8 @@ pub struct DayInfo {
     pub count: usize,
     pub overdue: bool,
     pub holiday: bool,
+    /// The day has a `todo journal` entry.
+    pub journal: bool,
 }
 
 // ── Public entry point ────────────────────────────────────────────────────────
@@ -61,6 +63,8 @@ fn print_legend() {
     print!(", ");
     print!("{}", "weekend".black().on_blue());
     print!(", ");
+    print!("{}", "journal".underline());
+    print!(", ");
     print!("{}", "weeknum".cyan());
     println!();
 }
@@ -130,10 +134,17 @@ fn render_month(
             let overdue = info.map(|i| i.overdue).unwrap_or(false);
             let has_due = info.map(|i| i.count > 0).unwrap_or(false);
             let is_holiday = info.map(|i| i.holiday).unwrap_or(false);
+            let has_journal = info.map(|i| i.journal).unwrap_or(false);
             let is_today = date == today;
             let is_past = date < today;
 
             let s = format!("{:>2}", day);
+            // A journal entry underlines the day, whatever its color.
+            let s = if has_journal {
+                s.underline()
+            } else {
+                s.normal()
+            };
 
             // past/future < weekend < holiday < today < due < due-today < overdue
             let colored = if overdue {
@@ -151,7 +162,7 @@ fn render_month(
             } else if is_past {
                 s.bright_black().to_string()
             } else {
-                s.normal().to_string()
+                s.to_string()
             };
 
             line.push_str(&colored);
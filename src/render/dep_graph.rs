@@ -0,0 +1,336 @@
+//! Dependency graph export for `todo deps --graph`.
+//!
+//! Renders the `depends_on` graph as Graphviz DOT or a Mermaid flowchart.
+//! Edges point from a dependency to the task waiting on it, so the graph
+//! reads left to right in the order work has to happen.
+//!
+//! Node styling:
+//!
+//! | Property | DOT | Mermaid |
+//! |---|---|---|
+//! | status   | fill colour | fill colour (`classDef <status>`) |
+//! | priority | border colour (high red, medium orange, low grey) | stroke colour |
+//! | blocked  | dashed border, `(blocked)` in the label | dashed stroke |
+
+use std::collections::{HashMap, HashSet};
+
+use clap::ValueEnum;
+use uuid::Uuid;
+
+use crate::models::{Priority, Task, TaskStatus};
+
+/// Output format for `todo deps --graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum GraphFormat {
+    /// Graphviz DOT (`dot -Tsvg graph.dot -o graph.svg`)
+    #[default]
+    Dot,
+    /// Mermaid flowchart, renders in GitHub/GitLab markdown
+    Mermaid,
+}
+
+/// Which part of the graph to render.
+#[derive(Debug, Clone, Default)]
+pub struct GraphOptions {
+    /// Only tasks in this project, plus tasks directly linked to them.
+    pub project: Option<Uuid>,
+    /// Drop done and cancelled tasks, linking their neighbours through them.
+    pub collapse_done: bool,
+}
+
+/// A task in the rendered graph.
+struct Node<'a> {
+    id: usize,
+    task: &'a Task,
+    blocked: bool,
+}
+
+/// Renders the dependency graph of `tasks` (including soft-deleted ones,
+/// which are skipped) in `format`.
+///
+/// Only tasks with at least one edge are drawn. Visible IDs are used as
+/// node labels, matching `todo list`.
+pub fn render(tasks: &[Task], format: GraphFormat, opts: &GraphOptions) -> String {
+    let (nodes, edges) = build(tasks, opts);
+    match format {
+        GraphFormat::Dot => to_dot(&nodes, &edges),
+        GraphFormat::Mermaid => to_mermaid(&nodes, &edges),
+    }
+}
+
+/// Collects nodes and `(dependency, dependent)` edges, by visible ID.
+fn build<'a>(tasks: &'a [Task], opts: &GraphOptions) -> (Vec<Node<'a>>, Vec<(usize, usize)>) {
+    let visible: Vec<&Task> = tasks.iter().filter(|t| !t.is_deleted()).collect();
+    let id_of: HashMap<Uuid, usize> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (t.uuid, i + 1))
+        .collect();
+    let kept = |t: &Task| !opts.collapse_done || t.is_open();
+
+    // Direct edges between visible tasks.
+    let direct: HashMap<usize, Vec<usize>> = visible
+        .iter()
+        .map(|t| {
+            let deps = t
+                .depends_on
+                .iter()
+                .filter_map(|d| id_of.get(d).copied())
+                .collect();
+            (id_of[&t.uuid], deps)
+        })
+        .collect();
+
+    // With collapsing, a kept task depends on the nearest kept tasks reachable
+    // through closed ones.
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for task in visible.iter().filter(|t| kept(t)) {
+        let to = id_of[&task.uuid];
+        let mut stack = direct[&to].clone();
+        let mut seen = HashSet::new();
+        while let Some(from) = stack.pop() {
+            if !seen.insert(from) {
+                continue;
+            }
+            if kept(visible[from - 1]) {
+                edges.push((from, to));
+            } else {
+                stack.extend(&direct[&from]);
+            }
+        }
+    }
+
+    if let Some(project) = opts.project {
+        let in_project = |id: usize| visible[id - 1].project_id == Some(project);
+        edges.retain(|&(a, b)| in_project(a) || in_project(b));
+    }
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut ids: Vec<usize> = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let nodes = ids
+        .into_iter()
+        .map(|id| {
+            let task = visible[id - 1];
+            Node {
+                id,
+                task,
+                blocked: task.is_open()
+                    && task
+                        .depends_on
+                        .iter()
+                        .any(|d| visible.iter().any(|t| t.uuid == *d && t.is_open())),
+            }
+        })
+        .collect();
+    (nodes, edges)
+}
+
+fn to_dot(nodes: &[Node], edges: &[(usize, usize)]) -> String {
+    let mut out = String::from("digraph dependencies {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n\n");
+    for node in nodes {
+        let mut style = String::from("rounded,filled");
+        if node.blocked {
+            style.push_str(",dashed");
+        }
+        out.push_str(&format!(
+            "  t{} [label=\"{}\", fillcolor=\"{}\", color=\"{}\", style=\"{}\"{}];\n",
+            node.id,
+            escape_dot(&label(node)),
+            status_color(node.task.status),
+            priority_color(node.task.priority),
+            style,
+            if node.task.priority == Priority::High {
+                ", penwidth=2"
+            } else {
+                ""
+            },
+        ));
+    }
+    if !edges.is_empty() {
+        out.push('\n');
+    }
+    for (from, to) in edges {
+        out.push_str(&format!("  t{} -> t{};\n", from, to));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn to_mermaid(nodes: &[Node], edges: &[(usize, usize)]) -> String {
+    let mut out = String::from("flowchart LR\n");
+    for node in nodes {
+        out.push_str(&format!(
+            "  t{}[\"{}\"]\n",
+            node.id,
+            escape_mermaid(&label(node))
+        ));
+    }
+    for (from, to) in edges {
+        out.push_str(&format!("  t{} --> t{}\n", from, to));
+    }
+
+    for status in [
+        TaskStatus::Pending,
+        TaskStatus::Active,
+        TaskStatus::Waiting,
+        TaskStatus::Done,
+        TaskStatus::Cancelled,
+    ] {
+        out.push_str(&format!(
+            "  classDef {} fill:{}\n",
+            status.as_str(),
+            status_color(status)
+        ));
+    }
+    for priority in [Priority::High, Priority::Medium, Priority::Low] {
+        out.push_str(&format!(
+            "  classDef {} stroke:{}\n",
+            priority_class(priority),
+            priority_color(priority)
+        ));
+    }
+    out.push_str("  classDef blocked stroke-dasharray:5 5\n");
+
+    for node in nodes {
+        out.push_str(&format!(
+            "  class t{} {}\n  class t{} {}\n",
+            node.id,
+            node.task.status.as_str(),
+            node.id,
+            priority_class(node.task.priority)
+        ));
+        if node.blocked {
+            out.push_str(&format!("  class t{} blocked\n", node.id));
+        }
+    }
+    out
+}
+
+fn label(node: &Node) -> String {
+    let mut label = format!("#{} {}", node.id, node.task.text);
+    if node.blocked {
+        label.push_str(" (blocked)");
+    }
+    label
+}
+
+fn status_color(status: TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Pending => "#ffffff",
+        TaskStatus::Active => "#cce5ff",
+        TaskStatus::Waiting => "#fff3cd",
+        TaskStatus::Done => "#d4edda",
+        TaskStatus::Cancelled => "#e2e3e5",
+    }
+}
+
+fn priority_color(priority: Priority) -> &'static str {
+    match priority {
+        Priority::High => "#dc3545",
+        Priority::Medium => "#fd7e14",
+        Priority::Low => "#6c757d",
+    }
+}
+
+fn priority_class(priority: Priority) -> &'static str {
+    match priority {
+        Priority::High => "high",
+        Priority::Medium => "medium",
+        Priority::Low => "low",
+    }
+}
+
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mermaid entity codes: `#` itself must be escaped first.
+fn escape_mermaid(s: &str) -> String {
+    s.replace('#', "#35;").replace('"', "#quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    /// Design ← Build ← Ship, plus an unrelated task.
+    fn chain() -> Vec<Task> {
+        let mut design = task("Design");
+        design.mark_done();
+        let mut build = task("Build");
+        build.depends_on = vec![design.uuid];
+        let mut ship = task("Ship \"v2\"");
+        ship.depends_on = vec![build.uuid];
+        ship.priority = Priority::High;
+        vec![design, build, ship, task("Unrelated")]
+    }
+
+    #[test]
+    fn test_dot_output() {
+        let dot = render(&chain(), GraphFormat::Dot, &GraphOptions::default());
+        assert!(dot.starts_with("digraph dependencies {"));
+        assert!(dot.contains("t1 -> t2;"));
+        assert!(dot.contains("t2 -> t3;"));
+        assert!(dot.contains(r##"label="#3 Ship \"v2\" (blocked)""##));
+        assert!(dot.contains("penwidth=2"));
+        assert!(!dot.contains("Unrelated"));
+    }
+
+    #[test]
+    fn test_mermaid_output() {
+        let mm = render(&chain(), GraphFormat::Mermaid, &GraphOptions::default());
+        assert!(mm.starts_with("flowchart LR\n"));
+        assert!(mm.contains("t1 --> t2"));
+        assert!(mm.contains("t3[\"#35;3 Ship #quot;v2#quot; (blocked)\"]"));
+        assert!(mm.contains("class t1 done"));
+        assert!(mm.contains("class t3 blocked"));
+    }
+
+    #[test]
+    fn test_collapse_done_links_through_closed_tasks() {
+        let mut tasks = chain();
+        tasks[1].mark_done();
+        let opts = GraphOptions {
+            collapse_done: true,
+            ..Default::default()
+        };
+        // Design and Build are done: Ship has nothing left to wait on.
+        let dot = render(&tasks, GraphFormat::Dot, &opts);
+        assert!(!dot.contains("->"));
+
+        // A → (done) → C collapses to A → C.
+        let a = task("A");
+        let mut b = task("B");
+        b.depends_on = vec![a.uuid];
+        b.mark_done();
+        let mut c = task("C");
+        c.depends_on = vec![b.uuid];
+        let dot = render(&[a, b, c], GraphFormat::Dot, &opts);
+        assert!(dot.contains("t1 -> t3;"));
+        assert!(!dot.contains("t2"));
+    }
+
+    #[test]
+    fn test_project_limits_graph_to_linked_tasks() {
+        let project = Uuid::new_v4();
+        let mut tasks = chain();
+        tasks[2].project_id = Some(project);
+        let opts = GraphOptions {
+            project: Some(project),
+            ..Default::default()
+        };
+        let dot = render(&tasks, GraphFormat::Dot, &opts);
+        assert!(dot.contains("t2 -> t3;"));
+        assert!(!dot.contains("t1"));
+    }
+}
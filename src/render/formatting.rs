@@ -1,8 +1,8 @@
 use chrono::Local;
-use colored::{ColoredString, Colorize};
+use colored::{Color, ColoredString, Colorize};
 use uuid::Uuid;
 
-use crate::models::{Note, Project, Task};
+use crate::models::{Note, Project, ProjectStatus, Task, TaskStatus};
 
 /// Resolves a `project_id` to its display name.
 ///
@@ -26,6 +26,54 @@ pub fn project_colored(name: &str) -> ColoredString {
     }
 }
 
+/// Colorizes a task's lifecycle status, with `blocked` taking precedence over
+/// the open states.
+///
+/// With `short = true` returns the single-letter code used in tables
+/// (`P`/`A`/`W`/`B`/`D`/`C`), otherwise the full word.
+pub fn status_colored(task: &Task, blocked: bool, short: bool) -> ColoredString {
+    let text = match (blocked && task.is_open(), short) {
+        (true, true) => "B",
+        (true, false) => "blocked",
+        (false, true) => task.status.letter(),
+        (false, false) => task.status.as_str(),
+    };
+    if blocked && task.is_open() {
+        return text.red();
+    }
+    match task.status {
+        TaskStatus::Pending => text.yellow(),
+        TaskStatus::Active => text.cyan(),
+        TaskStatus::Waiting => text.magenta(),
+        TaskStatus::Done => text.green(),
+        TaskStatus::Cancelled => text.dimmed(),
+    }
+}
+
+/// Colorizes a project's lifecycle status, as a letter (`short = true`) or
+/// the full name.
+pub fn project_status_colored(status: ProjectStatus, short: bool) -> ColoredString {
+    let text = if short {
+        status.letter()
+    } else {
+        status.as_str()
+    };
+    match status {
+        ProjectStatus::Idea => text.blue(),
+        ProjectStatus::Planning => text.magenta(),
+        ProjectStatus::Active => text.cyan(),
+        ProjectStatus::OnHold => text.yellow(),
+        ProjectStatus::Done => text.green(),
+        ProjectStatus::Abandoned => text.dimmed(),
+    }
+}
+
+/// Parses a project color: a terminal color name such as `blue` or
+/// `bright red`, or a `#rrggbb` hex code.
+pub fn parse_color(spec: &str) -> Option<Color> {
+    spec.trim().parse().ok()
+}
+
 /// Converts a `NaiveDate` into a full date string (YYYY-MM-DD).
 /// Shared by task list, project list, and any other due-date display.
 pub fn due_relative_text(due: chrono::NaiveDate) -> String {
@@ -43,13 +91,13 @@ pub fn get_due_text(task: &Task) -> String {
 /// - Yellow (bold): Due today
 /// - Yellow: Due within 7 days
 /// - Cyan: Due later
-/// - Dimmed: Completed tasks
+/// - Dimmed: Completed or cancelled tasks
 pub fn get_due_colored(task: &Task, text: &str) -> ColoredString {
     if text.is_empty() {
         return "".normal();
     }
 
-    if task.completed {
+    if !task.is_open() {
         return text.dimmed();
     }
 
@@ -83,6 +131,17 @@ pub fn truncate(text: &str, max: usize) -> String {
     }
 }
 
+/// "512 B", "1.5 KB", "2.0 MB".
+pub fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
 /// Returns a single-line preview of a note.
 ///
 /// Uses the title if set, otherwise the first non-empty line of the body
@@ -0,0 +1,776 @@
+//! Syntax highlighting for code notes and fenced code blocks.
+//!
+//! A small lexer per language family picks out keywords, types, function
+//! calls, strings, numbers and comments — enough to make snippets readable
+//! without shipping grammar files. Languages are matched on the note's
+//! `language` field or a code fence's info string (`rust`, `py`, `sh`, …);
+//! anything unknown is left plain.
+//!
+//! Colors come from the `syntax_*` keys of the `[theme]` config section.
+
+/// Kind of highlighted token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Syntax {
+    Keyword,
+    Type,
+    Function,
+    String,
+    Number,
+    Comment,
+}
+
+/// A run of text on one line and how to color it (`None` is plain).
+pub type Span = (Option<Syntax>, String);
+
+// ── Languages ─────────────────────────────────────────────────────────────────
+
+struct Language {
+    names: &'static [&'static str],
+    keywords: &'static [&'static str],
+    types: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    /// Strings that may span lines, such as Python's `"""`.
+    long_strings: &'static [&'static str],
+    /// `'x'` is a character literal but `'a` is not a string (Rust lifetimes).
+    char_literals: bool,
+    /// Identifiers starting with an uppercase letter are types.
+    capitalized_types: bool,
+    case_insensitive: bool,
+    /// For config formats: the key before this separator is highlighted.
+    key_separator: Option<char>,
+    /// `$NAME` and `${NAME}` variables.
+    sigils: bool,
+    /// `#include`-style preprocessor directives.
+    directives: bool,
+}
+
+const BASE: Language = Language {
+    names: &[],
+    keywords: &[],
+    types: &[],
+    line_comments: &[],
+    block_comment: None,
+    quotes: &['"', '\''],
+    long_strings: &[],
+    char_literals: false,
+    capitalized_types: false,
+    case_insensitive: false,
+    key_separator: None,
+    sigils: false,
+    directives: false,
+};
+
+const LANGUAGES: &[Language] = &[
+    Language {
+        names: &["rust", "rs"],
+        keywords: &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+            "move", "mut", "pub", "ref", "return", "self", "Self", "static", "struct", "super",
+            "trait", "true", "type", "unsafe", "use", "where", "while",
+        ],
+        types: &[
+            "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+            "f32", "f64", "bool", "char", "str",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        char_literals: true,
+        capitalized_types: true,
+        ..BASE
+    },
+    Language {
+        names: &["python", "py"],
+        keywords: &[
+            "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+            "elif", "else", "except", "False", "finally", "for", "from", "global", "if", "import",
+            "in", "is", "lambda", "None", "nonlocal", "not", "or", "pass", "raise", "return",
+            "True", "try", "while", "with", "yield",
+        ],
+        types: &[
+            "int", "float", "str", "bool", "list", "dict", "set", "tuple", "bytes", "object",
+        ],
+        line_comments: &["#"],
+        long_strings: &["\"\"\"", "'''"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Language {
+        names: &["javascript", "js", "jsx", "typescript", "ts", "tsx", "mjs"],
+        keywords: &[
+            "as",
+            "async",
+            "await",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "delete",
+            "do",
+            "else",
+            "enum",
+            "export",
+            "extends",
+            "false",
+            "finally",
+            "for",
+            "from",
+            "function",
+            "if",
+            "implements",
+            "import",
+            "in",
+            "instanceof",
+            "interface",
+            "let",
+            "new",
+            "null",
+            "of",
+            "private",
+            "protected",
+            "public",
+            "readonly",
+            "return",
+            "static",
+            "super",
+            "switch",
+            "this",
+            "throw",
+            "true",
+            "try",
+            "type",
+            "typeof",
+            "undefined",
+            "var",
+            "void",
+            "while",
+            "yield",
+        ],
+        types: &[
+            "string", "number", "boolean", "any", "unknown", "never", "object", "bigint",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+        capitalized_types: true,
+        ..BASE
+    },
+    Language {
+        names: &["go", "golang"],
+        keywords: &[
+            "break",
+            "case",
+            "chan",
+            "const",
+            "continue",
+            "default",
+            "defer",
+            "else",
+            "fallthrough",
+            "false",
+            "for",
+            "func",
+            "go",
+            "goto",
+            "if",
+            "import",
+            "interface",
+            "map",
+            "nil",
+            "package",
+            "range",
+            "return",
+            "select",
+            "struct",
+            "switch",
+            "true",
+            "type",
+            "var",
+        ],
+        types: &[
+            "bool", "byte", "error", "float32", "float64", "int", "int8", "int16", "int32",
+            "int64", "rune", "string", "uint", "uint8", "uint16", "uint32", "uint64", "uintptr",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+        ..BASE
+    },
+    Language {
+        names: &["c", "h", "cpp", "c++", "cc", "hpp", "cxx"],
+        keywords: &[
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "constexpr",
+            "continue",
+            "default",
+            "delete",
+            "do",
+            "else",
+            "enum",
+            "extern",
+            "false",
+            "for",
+            "goto",
+            "if",
+            "inline",
+            "namespace",
+            "new",
+            "nullptr",
+            "private",
+            "protected",
+            "public",
+            "return",
+            "sizeof",
+            "static",
+            "struct",
+            "switch",
+            "template",
+            "this",
+            "throw",
+            "true",
+            "try",
+            "typedef",
+            "typename",
+            "union",
+            "using",
+            "virtual",
+            "volatile",
+            "while",
+            "NULL",
+        ],
+        types: &[
+            "auto", "bool", "char", "double", "float", "int", "long", "short", "signed",
+            "unsigned", "void", "size_t", "int8_t", "int16_t", "int32_t", "int64_t", "uint8_t",
+            "uint16_t", "uint32_t", "uint64_t", "std", "string", "vector",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        directives: true,
+        ..BASE
+    },
+    Language {
+        names: &[
+            "java", "kotlin", "kt", "csharp", "cs", "c#", "scala", "swift",
+        ],
+        keywords: &[
+            "abstract",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "do",
+            "else",
+            "enum",
+            "extends",
+            "false",
+            "final",
+            "finally",
+            "for",
+            "fun",
+            "func",
+            "if",
+            "implements",
+            "import",
+            "interface",
+            "is",
+            "let",
+            "namespace",
+            "new",
+            "null",
+            "object",
+            "override",
+            "package",
+            "private",
+            "protected",
+            "public",
+            "return",
+            "static",
+            "super",
+            "switch",
+            "this",
+            "throw",
+            "throws",
+            "true",
+            "try",
+            "using",
+            "val",
+            "var",
+            "void",
+            "when",
+            "while",
+        ],
+        types: &[
+            "boolean", "bool", "byte", "char", "double", "float", "int", "long", "short", "string",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        capitalized_types: true,
+        ..BASE
+    },
+    Language {
+        names: &["sh", "bash", "zsh", "shell", "fish", "console"],
+        keywords: &[
+            "alias", "case", "do", "done", "elif", "else", "esac", "exit", "export", "fi", "for",
+            "function", "if", "in", "local", "return", "source", "then", "until", "while",
+        ],
+        line_comments: &["#"],
+        sigils: true,
+        ..BASE
+    },
+    Language {
+        names: &["sql", "sqlite", "postgres", "mysql"],
+        keywords: &[
+            "add",
+            "all",
+            "alter",
+            "and",
+            "as",
+            "asc",
+            "begin",
+            "between",
+            "by",
+            "case",
+            "commit",
+            "create",
+            "default",
+            "delete",
+            "desc",
+            "distinct",
+            "drop",
+            "else",
+            "end",
+            "exists",
+            "foreign",
+            "from",
+            "group",
+            "having",
+            "if",
+            "in",
+            "index",
+            "inner",
+            "insert",
+            "into",
+            "is",
+            "join",
+            "key",
+            "left",
+            "like",
+            "limit",
+            "not",
+            "null",
+            "offset",
+            "on",
+            "or",
+            "order",
+            "outer",
+            "primary",
+            "references",
+            "right",
+            "rollback",
+            "select",
+            "set",
+            "table",
+            "then",
+            "union",
+            "update",
+            "values",
+            "when",
+            "where",
+            "with",
+        ],
+        types: &[
+            "integer",
+            "int",
+            "text",
+            "varchar",
+            "char",
+            "real",
+            "boolean",
+            "date",
+            "timestamp",
+            "blob",
+            "numeric",
+        ],
+        line_comments: &["--"],
+        block_comment: Some(("/*", "*/")),
+        case_insensitive: true,
+        ..BASE
+    },
+    Language {
+        names: &["ruby", "rb"],
+        keywords: &[
+            "alias", "and", "begin", "break", "case", "class", "def", "do", "else", "elsif", "end",
+            "ensure", "false", "for", "if", "in", "module", "next", "nil", "not", "or", "redo",
+            "require", "rescue", "retry", "return", "self", "super", "then", "true", "undef",
+            "unless", "until", "when", "while", "yield",
+        ],
+        line_comments: &["#"],
+        capitalized_types: true,
+        ..BASE
+    },
+    Language {
+        names: &["toml", "ini"],
+        keywords: &["true", "false"],
+        line_comments: &["#", ";"],
+        key_separator: Some('='),
+        ..BASE
+    },
+    Language {
+        names: &["yaml", "yml"],
+        keywords: &["true", "false", "null", "yes", "no"],
+        line_comments: &["#"],
+        key_separator: Some(':'),
+        ..BASE
+    },
+    Language {
+        names: &["json", "jsonc"],
+        keywords: &["true", "false", "null"],
+        line_comments: &["//"],
+        quotes: &['"'],
+        key_separator: Some(':'),
+        ..BASE
+    },
+];
+
+fn language(name: &str) -> Option<&'static Language> {
+    let name = name.trim().to_lowercase();
+    LANGUAGES.iter().find(|l| l.names.contains(&name.as_str()))
+}
+
+/// Returns `true` when `name` is a language the highlighter knows.
+pub fn is_supported(name: &str) -> bool {
+    language(name).is_some()
+}
+
+// ── Highlighter ───────────────────────────────────────────────────────────────
+
+/// Highlights code line by line, carrying block comments and long strings
+/// over from one line to the next.
+pub struct Highlighter {
+    language: Option<&'static Language>,
+    /// Closing delimiter of a comment or string still open from earlier lines.
+    open: Option<(&'static str, Syntax)>,
+}
+
+impl Highlighter {
+    pub fn new(language_name: Option<&str>) -> Self {
+        Self {
+            language: language_name.and_then(language),
+            open: None,
+        }
+    }
+
+    /// Splits one line of code into colored spans.
+    pub fn line(&mut self, line: &str) -> Vec<Span> {
+        let mut out: Vec<Span> = Vec::new();
+        let Some(lang) = self.language else {
+            push(&mut out, None, line);
+            return out;
+        };
+
+        let mut i = 0;
+        if let Some((end, kind)) = self.open {
+            match line.find(end) {
+                Some(p) => {
+                    i = p + end.len();
+                    push(&mut out, Some(kind), &line[..i]);
+                    self.open = None;
+                }
+                None => {
+                    push(&mut out, Some(kind), line);
+                    return out;
+                }
+            }
+        }
+
+        // Config formats color the key at the start of each line.
+        let mut at_key = lang.key_separator.is_some() && line[..i].trim().is_empty();
+        if lang.key_separator == Some('=') && line.trim_start().starts_with('[') {
+            push(&mut out, Some(Syntax::Type), line);
+            return out;
+        }
+
+        while i < line.len() {
+            let rest = &line[i..];
+            let Some(c) = rest.chars().next() else {
+                break;
+            };
+
+            if c.is_whitespace() {
+                push(&mut out, None, &rest[..c.len_utf8()]);
+                i += c.len_utf8();
+                continue;
+            }
+
+            if lang.directives && c == '#' && line[..i].trim().is_empty() {
+                let len = 1 + ident_len(&rest[1..]);
+                push(&mut out, Some(Syntax::Keyword), &rest[..len]);
+                i += len;
+                at_key = false;
+                continue;
+            }
+
+            let comment_here = lang.line_comments.iter().any(|lc| rest.starts_with(lc))
+                // `a#b` and `$#` are not comments in shell.
+                && !(lang.sigils && i > 0 && !line[..i].ends_with(char::is_whitespace));
+            if comment_here {
+                push(&mut out, Some(Syntax::Comment), rest);
+                break;
+            }
+
+            let block = lang
+                .block_comment
+                .map(|(open, close)| (open, close, Syntax::Comment))
+                .into_iter()
+                .chain(lang.long_strings.iter().map(|d| (*d, *d, Syntax::String)))
+                .find(|(open, _, _)| rest.starts_with(open));
+            if let Some((open, close, kind)) = block {
+                match rest[open.len()..].find(close) {
+                    Some(p) => {
+                        let len = open.len() + p + close.len();
+                        push(&mut out, Some(kind), &rest[..len]);
+                        i += len;
+                    }
+                    None => {
+                        push(&mut out, Some(kind), rest);
+                        self.open = Some((close, kind));
+                        break;
+                    }
+                }
+                at_key = false;
+                continue;
+            }
+
+            if lang.quotes.contains(&c) {
+                let end = closing_quote(rest, c);
+                if lang.char_literals && c == '\'' {
+                    // `'x'`, `'\n'` and `'\u{1F600}'` are chars; `'a` is a lifetime.
+                    let is_char = end.is_some_and(|e| e <= 2 || rest[1..].starts_with('\\'));
+                    if !is_char {
+                        push(&mut out, None, "'");
+                        i += 1;
+                        continue;
+                    }
+                }
+                let len = end.map_or(rest.len(), |e| e + c.len_utf8());
+                let kind = if at_key && followed_by(&rest[len..], lang.key_separator) {
+                    Syntax::Keyword
+                } else {
+                    Syntax::String
+                };
+                push(&mut out, Some(kind), &rest[..len]);
+                i += len;
+                at_key = false;
+                continue;
+            }
+
+            if c.is_ascii_digit() && !line[..i].ends_with(is_ident_char) {
+                let len = rest
+                    .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_' || ch == '.'))
+                    .unwrap_or(rest.len());
+                push(&mut out, Some(Syntax::Number), &rest[..len]);
+                i += len;
+                at_key = false;
+                continue;
+            }
+
+            if lang.sigils && c == '$' {
+                let len = if rest[1..].starts_with('{') {
+                    rest.find('}').map_or(rest.len(), |p| p + 1)
+                } else {
+                    1 + ident_len(&rest[1..])
+                };
+                push(&mut out, Some(Syntax::Type), &rest[..len]);
+                i += len;
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' {
+                let mut len = ident_len(rest);
+                // YAML and TOML keys may contain dashes.
+                if at_key {
+                    len += rest[len..]
+                        .find(|ch: char| !(is_ident_char(ch) || ch == '-'))
+                        .unwrap_or(rest.len() - len);
+                }
+                let word = &rest[..len];
+                let after = &rest[len..];
+                let kind = if (at_key && followed_by(after, lang.key_separator))
+                    || is_keyword(lang, word)
+                {
+                    Some(Syntax::Keyword)
+                } else if lang.types.contains(&word) {
+                    Some(Syntax::Type)
+                } else if after.starts_with('(')
+                    || after.starts_with("!(")
+                    || after.starts_with("![")
+                    || after.starts_with("!{")
+                {
+                    Some(Syntax::Function)
+                } else if lang.capitalized_types && word.starts_with(char::is_uppercase) {
+                    Some(Syntax::Type)
+                } else {
+                    None
+                };
+                push(&mut out, kind, word);
+                i += len;
+                at_key = false;
+                continue;
+            }
+
+            push(&mut out, None, &rest[..c.len_utf8()]);
+            i += c.len_utf8();
+            at_key = false;
+        }
+        out
+    }
+}
+
+/// Highlights a whole snippet; unknown languages come back as plain lines.
+pub fn highlight(code: &str, language_name: Option<&str>) -> Vec<Vec<Span>> {
+    let mut highlighter = Highlighter::new(language_name);
+    code.lines().map(|line| highlighter.line(line)).collect()
+}
+
+fn push(out: &mut Vec<Span>, kind: Option<Syntax>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match out.last_mut() {
+        Some((last, buf)) if *last == kind => buf.push_str(text),
+        _ => out.push((kind, text.to_string())),
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn ident_len(s: &str) -> usize {
+    s.find(|c: char| !is_ident_char(c)).unwrap_or(s.len())
+}
+
+fn is_keyword(lang: &Language, word: &str) -> bool {
+    if lang.case_insensitive {
+        lang.keywords.iter().any(|k| k.eq_ignore_ascii_case(word))
+    } else {
+        lang.keywords.contains(&word)
+    }
+}
+
+fn followed_by(s: &str, separator: Option<char>) -> bool {
+    separator.is_some_and(|sep| s.trim_start().starts_with(sep))
+}
+
+/// Byte offset of the quote closing the string that starts `s`.
+fn closing_quote(s: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in s.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if c == quote => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(code: &str, lang: &str) -> Vec<(Option<Syntax>, String)> {
+        highlight(code, Some(lang))
+            .into_iter()
+            .flatten()
+            .filter(|(_, t)| !t.trim().is_empty())
+            .map(|(k, t)| (k, t.trim().to_string()))
+            .collect()
+    }
+
+    fn kind_of(spans: &[(Option<Syntax>, String)], text: &str) -> Option<Syntax> {
+        spans
+            .iter()
+            .find(|(_, t)| t == text)
+            .unwrap_or_else(|| panic!("no span {:?} in {:?}", text, spans))
+            .0
+    }
+
+    #[test]
+    fn test_rust_tokens() {
+        let spans = kinds(
+            "fn main<'a>(x: &'a str) -> Vec<u8> { println!(\"hi {}\", 'c'); 42 } // done",
+            "rust",
+        );
+        assert_eq!(kind_of(&spans, "fn"), Some(Syntax::Keyword));
+        assert_eq!(kind_of(&spans, "str"), Some(Syntax::Type));
+        assert_eq!(kind_of(&spans, "Vec"), Some(Syntax::Type));
+        assert_eq!(kind_of(&spans, "println"), Some(Syntax::Function));
+        assert_eq!(kind_of(&spans, "\"hi {}\""), Some(Syntax::String));
+        assert_eq!(kind_of(&spans, "'c'"), Some(Syntax::String));
+        assert_eq!(kind_of(&spans, "42"), Some(Syntax::Number));
+        assert_eq!(kind_of(&spans, "// done"), Some(Syntax::Comment));
+    }
+
+    #[test]
+    fn test_block_comments_and_long_strings_span_lines() {
+        let lines = highlight("a = 1 /* start\nstill\nend */ b", Some("c"));
+        assert_eq!(lines[1], vec![(Some(Syntax::Comment), "still".to_string())]);
+        assert_eq!(lines[2][0], (Some(Syntax::Comment), "end */".to_string()));
+
+        let lines = highlight(
+            "def f():\n    \"\"\"Doc\n    more\"\"\"\n    return 1",
+            Some("py"),
+        );
+        assert_eq!(lines[2][0].0, Some(Syntax::String));
+        assert_eq!(kind_of(&lines[3], "return"), Some(Syntax::Keyword));
+    }
+
+    #[test]
+    fn test_config_formats_highlight_keys() {
+        let spans = kinds("[server]\nport = 8080\nname = \"api\"", "toml");
+        assert_eq!(kind_of(&spans, "[server]"), Some(Syntax::Type));
+        assert_eq!(kind_of(&spans, "port"), Some(Syntax::Keyword));
+        assert_eq!(kind_of(&spans, "8080"), Some(Syntax::Number));
+        assert_eq!(kind_of(&spans, "\"api\""), Some(Syntax::String));
+
+        let spans = kinds("{\"enabled\": true, \"name\": \"x\"}", "json");
+        assert_eq!(kind_of(&spans, "true"), Some(Syntax::Keyword));
+        assert_eq!(kind_of(&spans, "\"x\""), Some(Syntax::String));
+    }
+
+    #[test]
+    fn test_shell_and_sql() {
+        let spans = kinds("if [ -n \"$HOME\" ]; then echo ${USER}; fi # note", "bash");
+        assert_eq!(kind_of(&spans, "if"), Some(Syntax::Keyword));
+        assert_eq!(kind_of(&spans, "${USER}"), Some(Syntax::Type));
+        assert_eq!(kind_of(&spans, "# note"), Some(Syntax::Comment));
+
+        let spans = kinds("SELECT id FROM tasks -- all", "sql");
+        assert_eq!(kind_of(&spans, "SELECT"), Some(Syntax::Keyword));
+        assert_eq!(kind_of(&spans, "-- all"), Some(Syntax::Comment));
+    }
+
+    #[test]
+    fn test_unknown_language_is_plain() {
+        assert_eq!(
+            highlight("fn x", Some("brainfuck")),
+            vec![vec![(None, "fn x".to_string())]]
+        );
+        assert!(is_supported("Rust"));
+        assert!(!is_supported("cobol"));
+    }
+}
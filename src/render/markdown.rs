@@ -0,0 +1,1227 @@
+//! Markdown rendering for notes.
+//!
+//! Covers the parts of CommonMark (plus GitHub tables, task lists and
+//! strikethrough) that notes use in practice: headings, paragraphs, bullet,
+//! numbered and task lists, block quotes, fenced code blocks, tables and
+//! horizontal rules, with emphasis, strong, strikethrough, code spans, links
+//! and autolinks inline, plus `[[…]]` wiki-links. Anything else is shown as
+//! plain text.
+//!
+//! [`render`] lays a document out for a given width as [`StyledLine`]s.
+//! [`to_ansi`] turns those into colored strings for `todo note show` and
+//! `todo note preview`; the TUI maps the same [`Role`]s onto its own styles.
+//! Fenced code blocks, and whole code notes via [`render_code`], are
+//! highlighted by [`highlight`](super::highlight).
+
+use colored::{ColoredString, Colorize};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use super::formatting::parse_color;
+use super::highlight::{Highlighter, Syntax};
+use crate::models::Note;
+use crate::services::links;
+
+/// Notes are wrapped to the terminal width, but never wider than this.
+pub const MAX_WIDTH: usize = 100;
+
+/// Narrowest layout; tables and nested lists get unreadable below this.
+const MIN_WIDTH: usize = 20;
+
+// ── Styled output ─────────────────────────────────────────────────────────────
+
+/// What a piece of text is, which decides its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Text,
+    Heading(u8),
+    /// Inline code span.
+    Code,
+    /// Unhighlighted text inside a code block.
+    CodeBlock,
+    /// A highlighted token inside a code block.
+    Syntax(Syntax),
+    Link,
+    /// The target printed after a link's text.
+    Url,
+    /// List bullets and numbers.
+    Marker,
+    /// A checked task-list box.
+    Checked,
+    /// Gutters, rules and table borders.
+    Decoration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub text: String,
+    pub role: Role,
+    pub bold: bool,
+    pub italic: bool,
+    pub strike: bool,
+}
+
+impl Segment {
+    fn new(text: impl Into<String>, role: Role) -> Self {
+        Self {
+            text: text.into(),
+            role,
+            bold: false,
+            italic: false,
+            strike: false,
+        }
+    }
+
+    fn styled(text: impl Into<String>, role: Role, em: Emphasis) -> Self {
+        Self {
+            text: text.into(),
+            role,
+            bold: em.bold,
+            italic: em.italic,
+            strike: em.strike,
+        }
+    }
+
+    fn with_text(&self, text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            ..self.clone()
+        }
+    }
+
+    fn same_style(&self, other: &Segment) -> bool {
+        self.role == other.role
+            && self.bold == other.bold
+            && self.italic == other.italic
+            && self.strike == other.strike
+    }
+}
+
+/// One rendered line: styled segments laid out left to right.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyledLine {
+    pub segments: Vec<Segment>,
+}
+
+impl StyledLine {
+    fn from_segments(segments: Vec<Segment>) -> Self {
+        let mut line = Self::default();
+        for segment in segments {
+            line.push(segment);
+        }
+        line
+    }
+
+    /// Appends `segment`, merging it into the last one when styled alike.
+    fn push(&mut self, segment: Segment) {
+        if segment.text.is_empty() {
+            return;
+        }
+        match self.segments.last_mut() {
+            Some(last) if last.same_style(&segment) => last.text.push_str(&segment.text),
+            _ => self.segments.push(segment),
+        }
+    }
+
+    /// The line's text without styling.
+    pub fn plain(&self) -> String {
+        self.segments.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    /// Display width in terminal columns.
+    pub fn width(&self) -> usize {
+        self.segments.iter().map(|s| s.text.width()).sum()
+    }
+}
+
+/// Lays `source` out as styled lines no wider than `width` columns, except
+/// for words and code lines that cannot be broken.
+pub fn render(source: &str, width: usize) -> Vec<StyledLine> {
+    let lines: Vec<&str> = source.lines().collect();
+    render_blocks(&parse_blocks(&lines), width.max(MIN_WIDTH))
+}
+
+/// Lays out a note body: markdown notes are rendered, notes with a
+/// `language` are highlighted as code, anything else is shown as is.
+pub fn render_note(note: &Note, width: usize) -> Vec<StyledLine> {
+    if note.is_markdown() {
+        render(&note.body, width)
+    } else if note.language.is_some() {
+        render_code(&note.body, note.language.as_deref())
+    } else {
+        note.body
+            .lines()
+            .map(|line| StyledLine::from_segments(vec![Segment::new(line, Role::Text)]))
+            .collect()
+    }
+}
+
+/// Lays out a code note: one styled line per source line, highlighted for
+/// `language` and never wrapped.
+pub fn render_code(source: &str, language: Option<&str>) -> Vec<StyledLine> {
+    let mut highlighter = Highlighter::new(language);
+    source
+        .lines()
+        .map(|line| StyledLine::from_segments(code_segments(&mut highlighter, line)))
+        .collect()
+}
+
+fn code_segments(highlighter: &mut Highlighter, line: &str) -> Vec<Segment> {
+    highlighter
+        .line(&line.replace('\t', "    "))
+        .into_iter()
+        .map(|(kind, text)| Segment::new(text, kind.map_or(Role::CodeBlock, Role::Syntax)))
+        .collect()
+}
+
+/// Converts rendered lines into strings with ANSI colors; code is colored
+/// with `syntax_color`, usually the theme's `syntax_*` entries.
+pub fn to_ansi<'a>(lines: &[StyledLine], syntax_color: impl Fn(Syntax) -> &'a str) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| {
+            line.segments
+                .iter()
+                .map(|s| paint(s, &syntax_color).to_string())
+                .collect()
+        })
+        .collect()
+}
+
+fn paint<'a>(segment: &Segment, syntax_color: &impl Fn(Syntax) -> &'a str) -> ColoredString {
+    let text = segment.text.as_str();
+    let mut colored = match segment.role {
+        Role::Syntax(kind) => match parse_color(syntax_color(kind)) {
+            Some(color) => text.color(color),
+            None => text.normal(),
+        },
+        Role::Text | Role::CodeBlock => text.normal(),
+        Role::Heading(1) => text.bright_magenta(),
+        Role::Heading(2) => text.cyan(),
+        Role::Heading(_) => text.blue(),
+        Role::Code => text.yellow(),
+        Role::Link => text.blue().underline(),
+        Role::Url | Role::Decoration => text.dimmed(),
+        Role::Marker => text.cyan(),
+        Role::Checked => text.green(),
+    };
+    if segment.bold {
+        colored = colored.bold();
+    }
+    if segment.italic {
+        colored = colored.italic();
+    }
+    if segment.strike {
+        colored = colored.strikethrough();
+    }
+    colored
+}
+
+// ── Block structure ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Marker {
+    Bullet,
+    Number(u64),
+    Task(bool),
+}
+
+#[derive(Debug)]
+struct Item {
+    level: usize,
+    marker: Marker,
+    text: String,
+}
+
+#[derive(Debug)]
+enum Block {
+    Heading(u8, String),
+    Paragraph(String),
+    List(Vec<Item>),
+    Quote(Vec<Block>),
+    Code {
+        lang: Option<String>,
+        lines: Vec<String>,
+    },
+    Table {
+        header: Vec<String>,
+        aligns: Vec<Align>,
+        rows: Vec<Vec<String>>,
+    },
+    Rule,
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+
+        if trimmed.is_empty() {
+            i += 1;
+            continue;
+        }
+
+        if let Some(fence) = fence(trimmed) {
+            let indent = line.len() - trimmed.len();
+            let lang = trimmed[fence.len()..]
+                .split_whitespace()
+                .next()
+                .map(str::to_string);
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with(fence) {
+                code.push(strip_indent(lines[i], indent).to_string());
+                i += 1;
+            }
+            // Skip the closing fence; an unclosed block runs to the end.
+            i += 1;
+            blocks.push(Block::Code { lang, lines: code });
+            continue;
+        }
+
+        if let Some((level, text)) = heading(trimmed) {
+            blocks.push(Block::Heading(level, text.to_string()));
+            i += 1;
+            continue;
+        }
+
+        if is_rule(trimmed) {
+            blocks.push(Block::Rule);
+            i += 1;
+            continue;
+        }
+
+        if trimmed.starts_with('>') {
+            let mut inner = Vec::new();
+            while let Some(rest) = lines.get(i).and_then(|l| l.trim_start().strip_prefix('>')) {
+                inner.push(rest.strip_prefix(' ').unwrap_or(rest));
+                i += 1;
+            }
+            blocks.push(Block::Quote(parse_blocks(&inner)));
+            continue;
+        }
+
+        if trimmed.contains('|')
+            && let Some(aligns) = lines.get(i + 1).and_then(|l| delimiter_row(l))
+        {
+            let header = split_row(trimmed);
+            if header.len() == aligns.len() {
+                let mut rows = Vec::new();
+                i += 2;
+                while i < lines.len() && lines[i].contains('|') {
+                    rows.push(split_row(lines[i].trim()));
+                    i += 1;
+                }
+                blocks.push(Block::Table {
+                    header,
+                    aligns,
+                    rows,
+                });
+                continue;
+            }
+        }
+
+        if list_item(line).is_some() {
+            let mut items: Vec<Item> = Vec::new();
+            while i < lines.len() {
+                let l = lines[i];
+                if let Some(item) = list_item(l) {
+                    items.push(item);
+                } else if l.trim().is_empty() {
+                    // A blank line only continues the list when an item follows.
+                    if lines
+                        .get(i + 1)
+                        .is_none_or(|next| list_item(next).is_none())
+                    {
+                        break;
+                    }
+                } else if starts_block(l.trim_start()) {
+                    break;
+                } else if let Some(last) = items.last_mut() {
+                    append_line(&mut last.text, l);
+                }
+                i += 1;
+            }
+            blocks.push(Block::List(items));
+            continue;
+        }
+
+        let mut text = String::new();
+        append_line(&mut text, line);
+        i += 1;
+        while i < lines.len() {
+            let l = lines[i].trim_start();
+            if l.is_empty() || starts_block(l) || list_item(lines[i]).is_some() {
+                break;
+            }
+            // A setext underline turns the paragraph into a heading.
+            if l.trim_end().chars().all(|c| c == '=') {
+                blocks.push(Block::Heading(1, text.trim_end().to_string()));
+                text.clear();
+                i += 1;
+                break;
+            }
+            append_line(&mut text, lines[i]);
+            i += 1;
+        }
+        if !text.is_empty() {
+            blocks.push(Block::Paragraph(text.trim_end().to_string()));
+        }
+    }
+
+    blocks
+}
+
+/// Joins a source line onto paragraph text, keeping hard line breaks (two
+/// trailing spaces or a trailing backslash) as `\n`.
+fn append_line(text: &mut String, line: &str) {
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push(' ');
+    }
+    let hard_break = line.ends_with("  ") || line.ends_with('\\');
+    let line = line.trim();
+    text.push_str(line.strip_suffix('\\').unwrap_or(line));
+    if hard_break {
+        text.push('\n');
+    }
+}
+
+fn starts_block(trimmed: &str) -> bool {
+    fence(trimmed).is_some()
+        || heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || trimmed.starts_with('>')
+}
+
+fn fence(trimmed: &str) -> Option<&'static str> {
+    ["```", "~~~"].into_iter().find(|f| trimmed.starts_with(f))
+}
+
+fn heading(trimmed: &str) -> Option<(u8, &str)> {
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    // Optional closing hashes: `## Title ##`.
+    let text = rest.trim().trim_end_matches('#').trim_end();
+    Some((level as u8, text))
+}
+
+fn is_rule(trimmed: &str) -> bool {
+    let compact: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| compact.chars().all(|c| c == m))
+}
+
+fn list_item(line: &str) -> Option<Item> {
+    let indent: usize = line
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum();
+    let rest = line.trim_start();
+    if is_rule(rest) {
+        return None;
+    }
+
+    let (marker, body) = if let Some(body) = rest
+        .strip_prefix(['-', '*', '+'])
+        .and_then(|b| b.strip_prefix(' '))
+    {
+        (Marker::Bullet, body)
+    } else {
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 || digits > 9 {
+            return None;
+        }
+        let body = rest[digits..].strip_prefix(['.', ')'])?.strip_prefix(' ')?;
+        (Marker::Number(rest[..digits].parse().ok()?), body)
+    };
+
+    let (marker, body) = match body.get(..4) {
+        Some("[ ] ") => (Marker::Task(false), &body[4..]),
+        Some("[x] " | "[X] ") => (Marker::Task(true), &body[4..]),
+        _ => (marker, body),
+    };
+
+    Some(Item {
+        level: indent / 2,
+        marker,
+        text: body.trim().to_string(),
+    })
+}
+
+/// Parses a table delimiter row such as `| :--- | ---: |`.
+fn delimiter_row(line: &str) -> Option<Vec<Align>> {
+    let line = line.trim();
+    if !line.contains('-') {
+        return None;
+    }
+    split_row(line)
+        .iter()
+        .map(|cell| {
+            let left = cell.starts_with(':');
+            let right = cell.ends_with(':');
+            let dashes = cell.trim_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (left, right) {
+                (true, true) => Align::Center,
+                (false, true) => Align::Right,
+                _ => Align::Left,
+            })
+        })
+        .collect()
+}
+
+/// Splits a table row on unescaped pipes, dropping the outer ones.
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = if line.ends_with('|') && !line.ends_with("\\|") {
+        &line[..line.len() - 1]
+    } else {
+        line
+    };
+
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn strip_indent(line: &str, indent: usize) -> &str {
+    let strip = line.chars().take(indent).take_while(|&c| c == ' ').count();
+    &line[strip..]
+}
+
+// ── Block layout ──────────────────────────────────────────────────────────────
+
+fn render_blocks(blocks: &[Block], width: usize) -> Vec<StyledLine> {
+    let mut out = Vec::new();
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            out.push(StyledLine::default());
+        }
+        match block {
+            Block::Heading(level, text) => render_heading(*level, text, width, &mut out),
+            Block::Paragraph(text) => out.extend(wrap(&parse_inline(text), width, &[], &[])),
+            Block::List(items) => render_list(items, width, &mut out),
+            Block::Quote(inner) => {
+                let gutter = Segment::new("│ ", Role::Decoration);
+                for mut line in render_blocks(inner, width.saturating_sub(2).max(MIN_WIDTH)) {
+                    for segment in &mut line.segments {
+                        if segment.role == Role::Text {
+                            segment.italic = true;
+                        }
+                    }
+                    let mut segments = vec![gutter.clone()];
+                    segments.extend(line.segments);
+                    out.push(StyledLine::from_segments(segments));
+                }
+            }
+            Block::Code { lang, lines } => {
+                let top = match lang {
+                    Some(lang) => format!("╭─ {}", lang),
+                    None => "╭─".to_string(),
+                };
+                out.push(StyledLine::from_segments(vec![Segment::new(
+                    top,
+                    Role::Decoration,
+                )]));
+                let mut highlighter = Highlighter::new(lang.as_deref());
+                for code in lines {
+                    let mut segments = vec![Segment::new("│ ", Role::Decoration)];
+                    segments.extend(code_segments(&mut highlighter, code));
+                    out.push(StyledLine::from_segments(segments));
+                }
+                out.push(StyledLine::from_segments(vec![Segment::new(
+                    "╰─",
+                    Role::Decoration,
+                )]));
+            }
+            Block::Table {
+                header,
+                aligns,
+                rows,
+            } => render_table(header, aligns, rows, width, &mut out),
+            Block::Rule => out.push(StyledLine::from_segments(vec![Segment::new(
+                "─".repeat(width),
+                Role::Decoration,
+            )])),
+        }
+    }
+    out
+}
+
+fn render_heading(level: u8, text: &str, width: usize, out: &mut Vec<StyledLine>) {
+    let mut segments = parse_inline(text);
+    for segment in &mut segments {
+        if segment.role == Role::Text {
+            segment.role = Role::Heading(level);
+        }
+        segment.bold = true;
+    }
+    let lines = wrap(&segments, width, &[], &[]);
+    let underline_width = lines.iter().map(StyledLine::width).max().unwrap_or(0);
+    out.extend(lines);
+    let underline = match level {
+        1 => "═",
+        2 => "─",
+        _ => return,
+    };
+    out.push(StyledLine::from_segments(vec![Segment::new(
+        underline.repeat(underline_width),
+        Role::Decoration,
+    )]));
+}
+
+fn render_list(items: &[Item], width: usize, out: &mut Vec<StyledLine>) {
+    for item in items {
+        let indent = "  ".repeat(item.level);
+        let marker = match item.marker {
+            Marker::Bullet => Segment::new(
+                format!("{}{} ", indent, ["•", "◦", "▪"][item.level % 3]),
+                Role::Marker,
+            ),
+            Marker::Number(n) => Segment::new(format!("{}{}. ", indent, n), Role::Marker),
+            Marker::Task(false) => Segment::new(format!("{}☐ ", indent), Role::Marker),
+            Marker::Task(true) => Segment::new(format!("{}☑ ", indent), Role::Checked),
+        };
+        let hanging = Segment::new(" ".repeat(marker.text.width()), Role::Text);
+        out.extend(wrap(
+            &parse_inline(&item.text),
+            width,
+            &[marker],
+            &[hanging],
+        ));
+    }
+}
+
+fn render_table(
+    header: &[String],
+    aligns: &[Align],
+    rows: &[Vec<String>],
+    width: usize,
+    out: &mut Vec<StyledLine>,
+) {
+    let columns = aligns.len();
+    // Tabs have no fixed width; lay cells out with spaces instead.
+    let parse_row = |cells: &[String]| -> Vec<Vec<Segment>> {
+        (0..columns)
+            .map(|c| {
+                cells
+                    .get(c)
+                    .map(|t| parse_inline(&t.replace('\t', "    ")))
+                    .unwrap_or_default()
+            })
+            .collect()
+    };
+    let mut header_cells = parse_row(header);
+    for cell in &mut header_cells {
+        for segment in cell {
+            segment.bold = true;
+        }
+    }
+    let body: Vec<Vec<Vec<Segment>>> = rows.iter().map(|r| parse_row(r)).collect();
+
+    let cell_width = |cell: &[Segment]| cell.iter().map(|s| s.text.width()).sum::<usize>();
+    let mut widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            std::iter::once(&header_cells)
+                .chain(body.iter())
+                .map(|row| cell_width(&row[c]))
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        })
+        .collect();
+
+    // Borders take `│ ` before each cell and ` │` after the last one.
+    let available = width.saturating_sub(3 * columns + 1);
+    while widths.iter().sum::<usize>() > available {
+        let widest = (0..columns).max_by_key(|&c| widths[c]).unwrap_or(0);
+        if widths[widest] <= 3 {
+            break;
+        }
+        widths[widest] -= 1;
+    }
+
+    let border = |left: &str, mid: &str, right: &str| {
+        let inner: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        StyledLine::from_segments(vec![Segment::new(
+            format!("{}{}{}", left, inner.join(mid), right),
+            Role::Decoration,
+        )])
+    };
+    let row_line = |cells: &[Vec<Segment>]| {
+        let mut line = StyledLine::default();
+        for (c, cell) in cells.iter().enumerate() {
+            line.push(Segment::new("│ ", Role::Decoration));
+            let fitted = fit(cell, widths[c]);
+            let pad = widths[c].saturating_sub(cell_width(&fitted));
+            let (before, after) = match aligns[c] {
+                Align::Left => (0, pad),
+                Align::Right => (pad, 0),
+                Align::Center => (pad / 2, pad - pad / 2),
+            };
+            line.push(Segment::new(" ".repeat(before), Role::Text));
+            for segment in fitted {
+                line.push(segment);
+            }
+            line.push(Segment::new(" ".repeat(after + 1), Role::Text));
+        }
+        line.push(Segment::new("│", Role::Decoration));
+        line
+    };
+
+    out.push(border("┌", "┬", "┐"));
+    out.push(row_line(&header_cells));
+    out.push(border("├", "┼", "┤"));
+    for row in &body {
+        out.push(row_line(row));
+    }
+    out.push(border("└", "┴", "┘"));
+}
+
+/// Cuts styled text down to `width` columns, ending in `…` when shortened.
+fn fit(segments: &[Segment], width: usize) -> Vec<Segment> {
+    let total: usize = segments.iter().map(|s| s.text.width()).sum();
+    if total <= width {
+        return segments.to_vec();
+    }
+    let mut out = Vec::new();
+    let mut used = 0;
+    'outer: for segment in segments {
+        let mut text = String::new();
+        for c in segment.text.chars() {
+            let w = c.width().unwrap_or(0);
+            if used + w + 1 > width {
+                out.push(segment.with_text(&text));
+                break 'outer;
+            }
+            text.push(c);
+            used += w;
+        }
+        out.push(segment.with_text(&text));
+    }
+    out.push(Segment::new("…", Role::Decoration));
+    out
+}
+
+/// Word-wraps `segments` to `width`. The first line starts with `first`,
+/// later lines with `rest`; `\n` in the text forces a break.
+fn wrap(
+    segments: &[Segment],
+    width: usize,
+    first: &[Segment],
+    rest: &[Segment],
+) -> Vec<StyledLine> {
+    enum Token {
+        Word(Vec<Segment>),
+        Break,
+    }
+
+    let mut tokens = Vec::new();
+    let mut word: Vec<Segment> = Vec::new();
+    for segment in segments {
+        for (k, part) in segment.text.split('\n').enumerate() {
+            if k > 0 {
+                if !word.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut word)));
+                }
+                tokens.push(Token::Break);
+            }
+            for (j, piece) in part.split(' ').enumerate() {
+                if j > 0 && !word.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut word)));
+                }
+                if !piece.is_empty() {
+                    word.push(segment.with_text(piece));
+                }
+            }
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(Token::Word(word));
+    }
+
+    let prefix_width = |p: &[Segment]| p.iter().map(|s| s.text.width()).sum::<usize>();
+    let mut lines = Vec::new();
+    let mut line = StyledLine::from_segments(first.to_vec());
+    let mut line_width = prefix_width(first);
+    let mut empty = true;
+
+    for token in tokens {
+        match token {
+            Token::Word(word) => {
+                let word_width: usize = word.iter().map(|s| s.text.width()).sum();
+                if !empty && line_width + 1 + word_width > width {
+                    lines.push(std::mem::replace(
+                        &mut line,
+                        StyledLine::from_segments(rest.to_vec()),
+                    ));
+                    line_width = prefix_width(rest);
+                    empty = true;
+                }
+                if !empty {
+                    // The space takes the style of the text around it, so
+                    // underlined links and struck-out phrases stay unbroken.
+                    let space = match line.segments.last() {
+                        Some(last) if last.same_style(&word[0]) => last.with_text(" "),
+                        _ => Segment::new(" ", Role::Text),
+                    };
+                    line.push(space);
+                    line_width += 1;
+                }
+                line_width += word_width;
+                for segment in word {
+                    line.push(segment);
+                }
+                empty = false;
+            }
+            Token::Break => {
+                lines.push(std::mem::replace(
+                    &mut line,
+                    StyledLine::from_segments(rest.to_vec()),
+                ));
+                line_width = prefix_width(rest);
+                empty = true;
+            }
+        }
+    }
+    if !empty || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+// ── Inline parsing ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Emphasis {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+}
+
+fn parse_inline(text: &str) -> Vec<Segment> {
+    let mut out = Vec::new();
+    inline_into(text, Emphasis::default(), &mut out);
+    out
+}
+
+fn inline_into(text: &str, em: Emphasis, out: &mut Vec<Segment>) {
+    let mut buf = String::new();
+    let flush = |buf: &mut String, out: &mut Vec<Segment>| {
+        if !buf.is_empty() {
+            out.push(Segment::styled(std::mem::take(buf), Role::Text, em));
+        }
+    };
+
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+
+        match c {
+            '\\' => {
+                if let Some(next) = rest[1..].chars().next()
+                    && next.is_ascii_punctuation()
+                {
+                    buf.push(next);
+                    i += 1 + next.len_utf8();
+                    continue;
+                }
+            }
+            '`' => {
+                let ticks = rest.chars().take_while(|&t| t == '`').count();
+                let fence = &rest[..ticks];
+                if let Some(end) = rest[ticks..].find(fence) {
+                    flush(&mut buf, out);
+                    let code = rest[ticks..ticks + end].trim();
+                    out.push(Segment::styled(code, Role::Code, em));
+                    i += 2 * ticks + end;
+                } else {
+                    buf.push_str(fence);
+                    i += ticks;
+                }
+                continue;
+            }
+            '!' if rest[1..].starts_with('[') => {
+                if let Some((label, url, len)) = link_at(&rest[1..]) {
+                    flush(&mut buf, out);
+                    let label = if label.is_empty() { "image" } else { label };
+                    out.push(Segment::styled(format!("[{}]", label), Role::Link, em));
+                    if !url.is_empty() {
+                        out.push(Segment::new(format!(" ({})", url), Role::Url));
+                    }
+                    i += 1 + len;
+                    continue;
+                }
+            }
+            '[' if rest.starts_with("[[") => {
+                if let Some(reference) = links::reference_at(rest) {
+                    flush(&mut buf, out);
+                    out.push(Segment::styled(reference.trim(), Role::Link, em));
+                    i += reference.len() + 4;
+                    continue;
+                }
+                buf.push('[');
+                i += 1;
+                continue;
+            }
+            '[' => {
+                if let Some((label, url, len)) = link_at(rest) {
+                    flush(&mut buf, out);
+                    let start = out.len();
+                    inline_into(label, em, out);
+                    for segment in &mut out[start..] {
+                        if segment.role == Role::Text {
+                            segment.role = Role::Link;
+                        }
+                    }
+                    if !url.is_empty() && url != label {
+                        out.push(Segment::new(format!(" ({})", url), Role::Url));
+                    }
+                    i += len;
+                    continue;
+                }
+            }
+            '<' => {
+                if let Some(end) = rest.find('>') {
+                    let target = &rest[1..end];
+                    let is_link = ["http://", "https://", "mailto:"]
+                        .iter()
+                        .any(|p| target.starts_with(p))
+                        && !target.contains(char::is_whitespace);
+                    if is_link {
+                        flush(&mut buf, out);
+                        out.push(Segment::styled(target, Role::Link, em));
+                        i += end + 1;
+                        continue;
+                    }
+                }
+            }
+            '*' | '_' | '~' => {
+                if let Some(delim) = ["**", "__", "~~", "*", "_"]
+                    .into_iter()
+                    .find(|d| rest.starts_with(d))
+                {
+                    let inner = &rest[delim.len()..];
+                    let intraword = c == '_'
+                        && text[..i]
+                            .chars()
+                            .next_back()
+                            .is_some_and(char::is_alphanumeric);
+                    let opens = inner.chars().next().is_some_and(|n| !n.is_whitespace());
+                    if opens
+                        && !intraword
+                        && let Some(end) = closing(inner, delim)
+                    {
+                        flush(&mut buf, out);
+                        let mut inner_em = em;
+                        match delim {
+                            "**" | "__" => inner_em.bold = true,
+                            "~~" => inner_em.strike = true,
+                            _ => inner_em.italic = true,
+                        }
+                        inline_into(&inner[..end], inner_em, out);
+                        i += 2 * delim.len() + end;
+                    } else {
+                        buf.push_str(delim);
+                        i += delim.len();
+                    }
+                    continue;
+                }
+            }
+            _ => {}
+        }
+
+        buf.push(c);
+        i += c.len_utf8();
+    }
+    flush(&mut buf, out);
+}
+
+/// Finds the delimiter closing an emphasis run that started just before `s`.
+fn closing(s: &str, delim: &str) -> Option<usize> {
+    let d = delim.chars().next()?;
+    let mut prev: Option<char> = None;
+    let mut i = 0;
+    while i < s.len() {
+        let rest = &s[i..];
+        let c = rest.chars().next()?;
+        if c == '\\' {
+            i += 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+            prev = None;
+            continue;
+        }
+        if c == '`' {
+            let ticks = rest.chars().take_while(|&t| t == '`').count();
+            i += match rest[ticks..].find(&rest[..ticks]) {
+                Some(end) => 2 * ticks + end,
+                None => ticks,
+            };
+            prev = Some('`');
+            continue;
+        }
+        if c == d {
+            let run = rest.chars().take_while(|&r| r == d).count();
+            let after = rest[run..].chars().next();
+            let intraword = d == '_' && after.is_some_and(char::is_alphanumeric);
+            // `**` inside `*…*` belongs to a nested strong run.
+            let nested = delim.len() == 1 && run == 2;
+            if run >= delim.len()
+                && !nested
+                && !intraword
+                && prev.is_some_and(|p| !p.is_whitespace())
+            {
+                // In a longer run such as `***`, close on its last delimiter.
+                return Some(i + run - delim.len());
+            }
+            i += run;
+            prev = Some(d);
+            continue;
+        }
+        prev = Some(c);
+        i += c.len_utf8();
+    }
+    None
+}
+
+/// Parses `[label](url "title")` at the start of `s`, returning the label,
+/// the url and the number of bytes consumed.
+fn link_at(s: &str) -> Option<(&str, &str, usize)> {
+    let mut depth = 0;
+    let mut label_end = None;
+    for (i, c) in s.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    label_end = Some(i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let label_end = label_end?;
+    let after = &s[label_end + 1..];
+    if !after.starts_with('(') {
+        return None;
+    }
+
+    let mut depth = 0;
+    let mut url_end = None;
+    for (i, c) in after.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    url_end = Some(i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let url_end = url_end?;
+    let target = after[1..url_end].trim();
+    let url = target.split_whitespace().next().unwrap_or("");
+    let url = url
+        .strip_prefix('<')
+        .and_then(|u| u.strip_suffix('>'))
+        .unwrap_or(url);
+    Some((&s[1..label_end], url, label_end + 1 + url_end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(source: &str, width: usize) -> Vec<String> {
+        render(source, width)
+            .iter()
+            .map(StyledLine::plain)
+            .collect()
+    }
+
+    fn find<'a>(lines: &'a [StyledLine], text: &str) -> &'a Segment {
+        lines
+            .iter()
+            .flat_map(|l| &l.segments)
+            .find(|s| s.text.trim() == text)
+            .unwrap_or_else(|| panic!("no segment {:?}", text))
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs_wrap() {
+        let lines = plain(
+            "# Title\n\nOne two three four five six seven eight nine ten eleven twelve\nthirteen",
+            24,
+        );
+        assert_eq!(
+            lines,
+            vec![
+                "Title",
+                "═════",
+                "",
+                "One two three four five",
+                "six seven eight nine ten",
+                "eleven twelve thirteen",
+            ]
+        );
+
+        let setext = render("Title\n=====\n### Small", 40);
+        assert_eq!(find(&setext, "Title").role, Role::Heading(1));
+        assert_eq!(find(&setext, "Small").role, Role::Heading(3));
+    }
+
+    #[test]
+    fn test_inline_emphasis_code_and_links() {
+        let lines = render(
+            "Use **bold**, *it*, ~~old~~, `cargo test` and [docs](https://docs.rs). snake_case_name",
+            80,
+        );
+        assert!(find(&lines, "bold").bold);
+        assert!(find(&lines, "it").italic);
+        assert!(find(&lines, "old").strike);
+        assert_eq!(find(&lines, "cargo test").role, Role::Code);
+        assert_eq!(find(&lines, "docs").role, Role::Link);
+        assert_eq!(find(&lines, "(https://docs.rs)").role, Role::Url);
+        assert!(lines[0].plain().ends_with("snake_case_name"));
+
+        let wiki = render("See [[Design notes]] and [[task:3]].", 80);
+        assert_eq!(find(&wiki, "Design notes").role, Role::Link);
+        assert_eq!(find(&wiki, "task:3").role, Role::Link);
+        assert_eq!(wiki[0].plain(), "See Design notes and task:3.");
+
+        let nested = render("***both*** and *a **b** c*", 80);
+        let both = find(&nested, "both");
+        assert!(both.bold && both.italic);
+        assert!(find(&nested, "b").bold && find(&nested, "b").italic);
+    }
+
+    #[test]
+    fn test_lists_nest_and_hang() {
+        let lines = plain(
+            "- first item that wraps onto a second line\n  - nested\n1. one\n2) two\n- [ ] todo\n- [x] done",
+            24,
+        );
+        assert_eq!(
+            lines,
+            vec![
+                "• first item that wraps",
+                "  onto a second line",
+                "  ◦ nested",
+                "1. one",
+                "2. two",
+                "☐ todo",
+                "☑ done",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_block_is_kept_verbatim() {
+        let lines = plain(
+            "```rust\nfn main() {\n    println!(\"*not emphasis*\");\n}\n```",
+            20,
+        );
+        assert_eq!(
+            lines,
+            vec![
+                "╭─ rust",
+                "│ fn main() {",
+                "│     println!(\"*not emphasis*\");",
+                "│ }",
+                "╰─",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_is_highlighted_by_language() {
+        let lines = render(
+            "```rust\nlet x = 1; // one\n```\n\n```\nlet y = 2;\n```",
+            40,
+        );
+        assert_eq!(find(&lines, "let").role, Role::Syntax(Syntax::Keyword));
+        assert_eq!(find(&lines, "// one").role, Role::Syntax(Syntax::Comment));
+        assert_eq!(find(&lines, "let y = 2;").role, Role::CodeBlock);
+
+        let code = render_code("def f():\n\treturn 'x'", Some("python"));
+        assert_eq!(code[1].plain(), "    return 'x'");
+        assert_eq!(find(&code, "'x'").role, Role::Syntax(Syntax::String));
+    }
+
+    #[test]
+    fn test_table_aligns_and_shrinks_columns() {
+        let source = "| Name | Qty |\n|:-----|----:|\n| apples | 3 |\n| kiwi | 12 |";
+        assert_eq!(
+            plain(source, 40),
+            vec![
+                "┌────────┬─────┐",
+                "│ Name   │ Qty │",
+                "├────────┼─────┤",
+                "│ apples │   3 │",
+                "│ kiwi   │  12 │",
+                "└────────┴─────┘",
+            ]
+        );
+
+        let wide = "| A | B |\n|---|---|\n| a very long cell indeed | short |";
+        let lines = plain(wide, 24);
+        assert!(lines.iter().all(|l| l.chars().count() <= 24));
+        assert!(lines[3].contains('…'));
+    }
+
+    #[test]
+    fn test_table_cell_with_tab_is_truncated() {
+        let source = "| A | B |\n|---|---|\n| a\tvery\tlong\tcell\tindeed | short |";
+        let lines = plain(source, 24);
+        assert!(lines.iter().all(|l| l.chars().count() <= 24));
+        assert!(lines[3].contains('…'));
+        assert!(!lines[3].contains('\t'));
+    }
+
+    #[test]
+    fn test_quote_and_rule() {
+        let lines = render("> quoted *text*\n\n---", 20);
+        assert_eq!(lines[0].plain(), "│ quoted text");
+        assert!(find(&lines, "quoted text").italic);
+        assert_eq!(lines[2].plain(), "─".repeat(20));
+    }
+}
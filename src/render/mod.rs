@@ -6,14 +6,24 @@
 //! - [`note_table`]     — `todo note list`
 //! - [`project_table`]  — `todo project list`
 //! - [`resource_table`] — `todo resource list`
+//! - [`archive_table`]  — `todo archive --list`, `todo search --archived`
 //! - [`formatting`]     — shared helpers (truncate, due text, colors)
+//! - [`dep_graph`]      — `todo deps --graph` (DOT / Mermaid)
 //! - [`next_table`]
 //! - [`calendar`]
+//! - [`markdown`]       — `todo note show`, `todo note preview`, TUI notes
+//! - [`highlight`]      — syntax highlighting for code notes and code blocks
+//! - [`pager`]          — paging long output
 
+pub mod archive_table;
 pub mod calendar;
+pub mod dep_graph;
 pub mod formatting;
+pub mod highlight;
+pub mod markdown;
 pub mod next_table;
 pub mod note_table;
+pub mod pager;
 pub mod project_table;
 pub mod resource_table;
 pub mod task_table;
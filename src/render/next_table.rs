@@ -6,7 +6,7 @@
 use chrono::Utc;
 use colored::Colorize;
 
-use crate::models::{Project, Task};
+use crate::models::{Project, Task, UrgencyConfig};
 
 use super::formatting::{get_due_colored, get_due_text, project_colored, project_name, truncate};
 
@@ -108,8 +108,15 @@ impl NextTableLayout {
         println!("{}", "─".repeat(self.total_width()).dimmed());
     }
 
-    pub fn display_row(&self, idx: usize, task: &Task, all_tasks: &[Task], projects: &[Project]) {
-        let score = task.urgency_score(all_tasks);
+    pub fn display_row(
+        &self,
+        idx: usize,
+        task: &Task,
+        all_tasks: &[Task],
+        projects: &[Project],
+        urgency: &UrgencyConfig,
+    ) {
+        let score = task.urgency_score_with(all_tasks, projects, urgency);
         let score_str = format!("{:.1}", score);
         let score_colored = if score >= 10.0 {
             score_str.red()
@@ -179,6 +186,7 @@ pub fn display_next(
     tasks: &[(&Task, usize)],
     all_tasks: &[Task],
     projects: &[Project],
+    urgency: &UrgencyConfig,
     ready_count: usize,
     blocked_count: usize,
 ) {
@@ -191,7 +199,7 @@ pub fn display_next(
     layout.display_separator();
 
     for (task, idx) in tasks {
-        layout.display_row(*idx, task, all_tasks, projects);
+        layout.display_row(*idx, task, all_tasks, projects, urgency);
     }
 
     layout.display_separator();
@@ -31,6 +31,21 @@ fn note_preview(note: &Note) -> String {
         .unwrap_or_default()
 }
 
+/// The names of the note's live projects, comma-separated, or `"—"`.
+fn project_names(note: &Note, projects: &[Project]) -> String {
+    let names: Vec<&str> = note
+        .project_ids
+        .iter()
+        .map(|&pid| project_name(Some(pid), projects))
+        .filter(|name| *name != "—")
+        .collect();
+    if names.is_empty() {
+        "—".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
 impl NoteTableLayout {
     pub fn new(
         notes: &[&Note],
@@ -46,11 +61,8 @@ impl NoteTableLayout {
 
         let proj_w = notes
             .iter()
-            .filter_map(|n| {
-                n.project_id
-                    .and_then(|pid| projects.iter().find(|p| p.uuid == pid))
-                    .map(|p| p.name.len())
-            })
+            .filter(|n| !n.project_ids.is_empty())
+            .map(|n| project_names(n, projects).len())
             .max()
             .unwrap_or(0)
             .clamp(7, 24);
@@ -75,7 +87,7 @@ impl NoteTableLayout {
             .unwrap_or(0)
             .clamp(4, 24);
 
-        let show_project = notes.iter().any(|n| n.project_id.is_some());
+        let show_project = notes.iter().any(|n| !n.project_ids.is_empty());
         let show_lang = notes.iter().any(|n| n.language.is_some());
         let show_tags = notes.iter().any(|n| !n.tags.is_empty());
         let show_resources = notes.iter().any(|n| {
@@ -147,8 +159,8 @@ impl NoteTableLayout {
     ) {
         let preview = truncate(&note_preview(note), self.body_w);
 
-        let name = project_name(note.project_id, projects);
-        let proj_str = truncate(name, self.proj_w);
+        let name = project_names(note, projects);
+        let proj_str = truncate(&name, self.proj_w);
         let proj_colored = project_colored(&proj_str);
 
         let lang_str = note
@@ -0,0 +1,96 @@
+//! Paging for long terminal output.
+//!
+//! Output that fits on one screen, or that is not going to a terminal, is
+//! printed directly. Otherwise it is piped to the configured pager, then
+//! `$PAGER`, then `less -R`; if the pager cannot be started the text is
+//! printed as is.
+
+use std::io::{IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use anyhow::Result;
+use ratatui::crossterm::terminal;
+
+/// Terminal `(width, height)`, or 80×24 when stdout is not a terminal.
+pub fn terminal_size() -> (usize, usize) {
+    terminal::size()
+        .map(|(w, h)| (w as usize, h as usize))
+        .unwrap_or((80, 24))
+}
+
+/// Prints `lines`, through a pager when they would scroll off the screen.
+///
+/// `pager` is the command from config; `"none"` disables paging.
+pub fn page(lines: &[String], pager: Option<&str>) -> Result<()> {
+    let (_, height) = terminal_size();
+    let command = pager_command(pager, std::env::var("PAGER").ok());
+
+    match command {
+        Some(command) if std::io::stdout().is_terminal() && lines.len() >= height => {
+            if spawn(&command, lines).is_err() {
+                print_all(lines);
+            }
+        }
+        _ => print_all(lines),
+    }
+    Ok(())
+}
+
+fn spawn(command: &str, lines: &[String]) -> std::io::Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts.next().unwrap_or("less");
+    let mut cmd = Command::new(program);
+    cmd.args(parts).stdin(Stdio::piped());
+    // Let `less` show colors and quit on short output, as git does.
+    if std::env::var_os("LESS").is_none() {
+        cmd.env("LESS", "FRX");
+    }
+
+    let mut child = cmd.spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        for line in lines {
+            // The user quitting the pager early closes the pipe.
+            if writeln!(stdin, "{}", line).is_err() {
+                break;
+            }
+        }
+    }
+    child.wait()?;
+    Ok(())
+}
+
+fn print_all(lines: &[String]) {
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+/// Chooses the pager command: config first, then `$PAGER`, then `less -R`.
+fn pager_command(configured: Option<&str>, env: Option<String>) -> Option<String> {
+    let command = configured
+        .map(str::to_string)
+        .or(env)
+        .unwrap_or_else(|| "less -R".to_string());
+    let command = command.trim();
+    (!command.is_empty() && command != "none").then(|| command.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pager_command_precedence() {
+        assert_eq!(
+            pager_command(Some("most"), Some("more".into())).as_deref(),
+            Some("most")
+        );
+        assert_eq!(
+            pager_command(None, Some("more".into())).as_deref(),
+            Some("more")
+        );
+        assert_eq!(pager_command(None, None).as_deref(), Some("less -R"));
+        assert_eq!(pager_command(Some("none"), Some("more".into())), None);
+        assert_eq!(pager_command(None, Some(String::new())), None);
+    }
+}
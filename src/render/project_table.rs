@@ -1,12 +1,21 @@
 //! Terminal rendering for project lists.
+//!
+//! Nested projects are indented under their parent when the parent is part of
+//! the same list, and the Tasks column counts sub-project tasks too. Rows can
+//! be narrowed to some lifecycle states with [`filter_by_status`] and split
+//! into one table per state with [`group_by_status`].
+
+use std::collections::HashMap;
 
 use chrono::Local;
 use colored::Colorize;
 
-use crate::models::{Difficulty, Note, Project, Task, count_by_project};
-use crate::render::formatting::{due_relative_text, truncate};
+use crate::models::{Difficulty, Note, Project, ProjectStatus, Task, count_by_project_tree};
+use crate::render::formatting::{due_relative_text, parse_color, project_status_colored, truncate};
 
 pub struct ProjectTableLayout {
+    /// Indent level of each project, counting only ancestors that are listed.
+    pub depths: HashMap<uuid::Uuid, usize>,
     pub name_w: usize,
     pub tech_w: usize,
     pub show_tech: bool,
@@ -17,10 +26,27 @@ pub struct ProjectTableLayout {
 }
 
 impl ProjectTableLayout {
-    pub fn new(projects: &[&Project], tasks: &[Task], notes: &[Note]) -> Self {
+    pub fn new(
+        projects: &[&Project],
+        all_projects: &[Project],
+        tasks: &[Task],
+        notes: &[Note],
+    ) -> Self {
+        let depths: HashMap<uuid::Uuid, usize> = projects
+            .iter()
+            .map(|p| {
+                let listed_ancestors = p
+                    .ancestry(all_projects)
+                    .iter()
+                    .filter(|a| a.uuid != p.uuid && projects.iter().any(|l| l.uuid == a.uuid))
+                    .count();
+                (p.uuid, listed_ancestors)
+            })
+            .collect();
+
         let name_w = projects
             .iter()
-            .map(|p| p.name.len())
+            .map(|p| p.name.len() + 2 * depths[&p.uuid])
             .max()
             .unwrap_or(7)
             .clamp(7, 32);
@@ -42,12 +68,13 @@ impl ProjectTableLayout {
         let show_notes = projects.iter().any(|p| {
             notes
                 .iter()
-                .any(|n| !n.is_deleted() && n.project_id == Some(p.uuid))
+                .any(|n| !n.is_deleted() && n.belongs_to_project(p.uuid))
         });
         let show_tasks = projects.iter().any(|p| {
+            let subtree = Project::subtree(all_projects, p.uuid);
             tasks
                 .iter()
-                .any(|t| !t.is_deleted() && t.project_id == Some(p.uuid))
+                .any(|t| !t.is_deleted() && t.project_id.is_some_and(|u| subtree.contains(&u)))
         });
         let show_due = projects.iter().any(|p| p.due_date.is_some());
 
@@ -67,6 +94,7 @@ impl ProjectTableLayout {
         }
 
         Self {
+            depths,
             name_w,
             tech_w,
             show_tech,
@@ -98,19 +126,22 @@ impl ProjectTableLayout {
         println!("{}", "─".repeat(self.total_w).dimmed());
     }
 
-    pub fn display_row(&self, id: usize, project: &Project, tasks: &[Task], notes: &[Note]) {
-        let (total, done) = count_by_project(tasks, project.uuid);
+    pub fn display_row(
+        &self,
+        id: usize,
+        project: &Project,
+        all_projects: &[Project],
+        tasks: &[Task],
+        notes: &[Note],
+    ) {
+        let (total, done) = count_by_project_tree(tasks, all_projects, project.uuid);
 
         let note_count = notes
             .iter()
-            .filter(|n| n.project_id == Some(project.uuid) && !n.is_deleted())
+            .filter(|n| n.belongs_to_project(project.uuid) && !n.is_deleted())
             .count();
 
-        let status_letter = if project.completed {
-            "D".green()
-        } else {
-            "P".yellow()
-        };
+        let status_letter = project_status_colored(project.status, true);
 
         let diff_letter = match project.difficulty {
             Difficulty::Easy => "E".green(),
@@ -118,13 +149,20 @@ impl ProjectTableLayout {
             Difficulty::Hard => "H".red(),
         };
 
+        let indent = "  ".repeat(self.depths.get(&project.uuid).copied().unwrap_or(0));
         let name_padded = format!(
             "{:<name_w$}",
-            truncate(&project.name, self.name_w),
+            format!(
+                "{}{}",
+                indent,
+                truncate(&project.name, self.name_w.saturating_sub(indent.len()))
+            ),
             name_w = self.name_w
         );
-        let name_colored = if project.completed {
+        let name_colored = if !project.is_open() {
             name_padded.dimmed()
+        } else if let Some(color) = project.color.as_deref().and_then(parse_color) {
+            name_padded.color(color)
         } else {
             name_padded.magenta()
         };
@@ -206,13 +244,95 @@ impl ProjectTableLayout {
     }
 }
 
-pub fn display_projects(projects: &[&Project], tasks: &[Task], notes: &[Note]) {
-    println!("\nProjects:\n");
-    let layout = ProjectTableLayout::new(projects, tasks, notes);
+/// Renders projects numbered from 1 in the order given.
+pub fn display_projects(
+    projects: &[&Project],
+    all_projects: &[Project],
+    tasks: &[Task],
+    notes: &[Note],
+) {
+    let rows: Vec<(usize, &Project)> = projects
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i + 1, *p))
+        .collect();
+    display_project_rows(&rows, "Projects", all_projects, tasks, notes);
+}
+
+/// Renders a titled project table, keeping each project's own ID.
+pub fn display_project_rows(
+    rows: &[(usize, &Project)],
+    title: &str,
+    all_projects: &[Project],
+    tasks: &[Task],
+    notes: &[Note],
+) {
+    println!("\n{}:\n", title);
+    let projects: Vec<&Project> = rows.iter().map(|(_, p)| *p).collect();
+    let layout = ProjectTableLayout::new(&projects, all_projects, tasks, notes);
     layout.display_header();
-    for (i, project) in projects.iter().enumerate() {
-        layout.display_row(i + 1, project, tasks, notes);
+    for (id, project) in rows {
+        layout.display_row(*id, project, all_projects, tasks, notes);
     }
     layout.display_separator();
     println!();
 }
+
+/// Keeps the rows whose status is one of `statuses`; all rows when empty.
+pub fn filter_by_status<'a>(
+    rows: &[(usize, &'a Project)],
+    statuses: &[ProjectStatus],
+) -> Vec<(usize, &'a Project)> {
+    rows.iter()
+        .filter(|(_, p)| statuses.is_empty() || statuses.contains(&p.status))
+        .copied()
+        .collect()
+}
+
+/// Splits rows into one group per status, in lifecycle order. Empty groups
+/// are left out and rows keep their relative order.
+pub fn group_by_status<'a>(
+    rows: &[(usize, &'a Project)],
+) -> Vec<(ProjectStatus, Vec<(usize, &'a Project)>)> {
+    ProjectStatus::ALL
+        .into_iter()
+        .map(|status| (status, filter_by_status(rows, &[status])))
+        .filter(|(_, group)| !group.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_status_keeps_ids_and_lifecycle_order() {
+        let mut idea = Project::new("idea".into());
+        idea.set_status(ProjectStatus::Idea);
+        let mut done = Project::new("done".into());
+        done.mark_done();
+        let active = Project::new("active".into());
+        let rows = vec![(1, &done), (2, &active), (3, &idea)];
+
+        let groups = group_by_status(&rows);
+        let summary: Vec<(ProjectStatus, Vec<usize>)> = groups
+            .iter()
+            .map(|(s, g)| (*s, g.iter().map(|(id, _)| *id).collect()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (ProjectStatus::Idea, vec![3]),
+                (ProjectStatus::Active, vec![2]),
+                (ProjectStatus::Done, vec![1]),
+            ]
+        );
+
+        let open = filter_by_status(&rows, &[ProjectStatus::Idea, ProjectStatus::Active]);
+        assert_eq!(
+            open.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(filter_by_status(&rows, &[]).len(), 3);
+    }
+}
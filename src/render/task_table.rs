@@ -5,9 +5,11 @@
 
 use colored::Colorize;
 
-use crate::models::{Project, Recurrence, Task};
+use crate::models::{Project, Recurrence, Task, TaskStatus};
 
-use super::formatting::{get_due_colored, get_due_text, project_colored, project_name, truncate};
+use super::formatting::{
+    get_due_colored, get_due_text, project_colored, project_name, status_colored, truncate,
+};
 
 const ID_WIDTH: usize = 4;
 const PRIORITY_WIDTH: usize = 1;
@@ -55,12 +57,12 @@ impl<'a> TableLayout<'a> {
         let show_notes = tasks.iter().any(|(_, t)| {
             notes
                 .iter()
-                .any(|n| !n.is_deleted() && n.task_id == Some(t.uuid))
+                .any(|n| !n.is_deleted() && n.belongs_to_task(t.uuid))
         });
         let show_resources = tasks.iter().any(|(_, t)| {
             notes.iter().any(|n| {
                 !n.is_deleted()
-                    && n.task_id == Some(t.uuid)
+                    && n.belongs_to_task(t.uuid)
                     && n.resource_ids
                         .iter()
                         .any(|rid| resources.iter().any(|r| !r.is_deleted() && r.uuid == *rid))
@@ -144,15 +146,9 @@ impl<'a> TableLayout<'a> {
     }
 
     pub fn display_task(&self, number: usize, task: &Task) {
-        let blocked = !task.completed && task.is_blocked(self.all_tasks);
+        let blocked = task.is_open() && task.is_blocked(self.all_tasks);
 
-        let status_letter = if blocked {
-            "B".red()
-        } else if task.completed {
-            "D".green()
-        } else {
-            "P".yellow()
-        };
+        let status_letter = status_colored(task, blocked, true);
 
         let letter = task.priority.letter();
         let task_text = truncate(&task.text, self.task);
@@ -177,6 +173,12 @@ impl<'a> TableLayout<'a> {
 
         let (text_colored, tags_colored, proj_colored) = if task.completed {
             (task_text.green(), tags_str.dimmed(), project_str.dimmed())
+        } else if task.status == TaskStatus::Cancelled {
+            (
+                task_text.dimmed().strikethrough(),
+                tags_str.dimmed(),
+                project_str.dimmed(),
+            )
         } else if blocked {
             (
                 task_text.truecolor(150, 150, 150),
@@ -220,7 +222,7 @@ impl<'a> TableLayout<'a> {
             let count = self
                 .notes
                 .iter()
-                .filter(|n| !n.is_deleted() && n.task_id == Some(task.uuid))
+                .filter(|n| !n.is_deleted() && n.belongs_to_task(task.uuid))
                 .count();
             let notes_str = if count > 0 {
                 format!("{:^5}", count).dimmed().to_string()
@@ -233,7 +235,7 @@ impl<'a> TableLayout<'a> {
             let count = self
                 .notes
                 .iter()
-                .filter(|n| !n.is_deleted() && n.task_id == Some(task.uuid))
+                .filter(|n| !n.is_deleted() && n.belongs_to_task(task.uuid))
                 .flat_map(|n| n.resource_ids.iter())
                 .filter(|rid| {
                     self.resources
@@ -303,13 +305,17 @@ pub fn display_lists(
     layout.display_separator();
 
     let mut completed = 0;
-    let total = tasks.len();
+    let mut total = 0;
 
     for (number, task) in tasks {
         layout.display_task(*number, task);
         if task.completed {
             completed += 1;
         }
+        // Cancelled tasks are shown but do not count towards completion.
+        if task.status != TaskStatus::Cancelled {
+            total += 1;
+        }
     }
 
     layout.display_separator();
@@ -0,0 +1,161 @@
+//! Line diffs between two versions of a text.
+//!
+//! Used by `todo note diff` and `todo note history`. The diff is a longest
+//! common subsequence over lines: fine for notes, which are short, but
+//! quadratic in the number of changed lines.
+
+use std::ops::Range;
+
+/// How a line differs between the old and the new text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Same,
+    Added,
+    Removed,
+}
+
+/// One line of a diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffLine<'a> {
+    pub change: Change,
+    pub text: &'a str,
+}
+
+/// Diffs `old` against `new` line by line. Removed lines come before the
+/// lines added in their place.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::services::diff::{Change, diff_lines};
+///
+/// let diff = diff_lines("a\nb\nc", "a\nB\nc");
+/// let changes: Vec<_> = diff.iter().map(|l| (l.change, l.text)).collect();
+/// assert_eq!(
+///     changes,
+///     vec![
+///         (Change::Same, "a"),
+///         (Change::Removed, "b"),
+///         (Change::Added, "B"),
+///         (Change::Same, "c"),
+///     ]
+/// );
+/// ```
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // Common prefix and suffix don't need the table.
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (mid_a, mid_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    // lcs[i][j] = length of the LCS of mid_a[i..] and mid_b[j..].
+    let (n, m) = (mid_a.len(), mid_b.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if mid_a[i] == mid_b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |change, text| DiffLine { change, text };
+    let mut out: Vec<DiffLine> = a[..prefix].iter().map(|t| line(Change::Same, *t)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && mid_a[i] == mid_b[j] {
+            out.push(line(Change::Same, mid_a[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(line(Change::Removed, mid_a[i]));
+            i += 1;
+        } else {
+            out.push(line(Change::Added, mid_b[j]));
+            j += 1;
+        }
+    }
+    out.extend(a[a.len() - suffix..].iter().map(|t| line(Change::Same, *t)));
+    out
+}
+
+/// Counts `(added, removed)` lines.
+pub fn stats(diff: &[DiffLine]) -> (usize, usize) {
+    let count = |c| diff.iter().filter(|l| l.change == c).count();
+    (count(Change::Added), count(Change::Removed))
+}
+
+/// Groups changed lines with up to `context` unchanged lines around them.
+///
+/// Returns index ranges into `diff`; ranges closer than `2 * context` lines
+/// are merged.
+pub fn hunks(diff: &[DiffLine], context: usize) -> Vec<Range<usize>> {
+    let mut out: Vec<Range<usize>> = Vec::new();
+    for (i, l) in diff.iter().enumerate() {
+        if l.change == Change::Same {
+            continue;
+        }
+        let start = i.saturating_sub(context);
+        let end = (i + 1 + context).min(diff.len());
+        match out.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => out.push(start..end),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(diff: &[DiffLine]) -> String {
+        diff.iter()
+            .map(|l| {
+                let sign = match l.change {
+                    Change::Same => ' ',
+                    Change::Added => '+',
+                    Change::Removed => '-',
+                };
+                format!("{}{}", sign, l.text)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_diff_insert_delete_and_identical() {
+        assert_eq!(render(&diff_lines("a\nc", "a\nb\nc")), " a\n+b\n c");
+        assert_eq!(render(&diff_lines("a\nb\nc", "a\nc")), " a\n-b\n c");
+        assert_eq!(render(&diff_lines("", "x")), "+x");
+        assert!(stats(&diff_lines("same\ntext", "same\ntext")) == (0, 0));
+    }
+
+    #[test]
+    fn test_diff_keeps_longest_common_lines() {
+        let diff = diff_lines("x\na\nb\nc\ny", "a\nz\nb\nc");
+        assert_eq!(render(&diff), "-x\n a\n+z\n b\n c\n-y");
+        assert_eq!(stats(&diff), (1, 2));
+    }
+
+    #[test]
+    fn test_hunks_merge_nearby_changes() {
+        let old = (1..=20)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let new = old.replace("\n3\n", "\nthree\n").replace("\n18\n", "\n!\n");
+        let diff = diff_lines(&old, &new);
+        assert_eq!(hunks(&diff, 2), vec![0..6, 16..22]);
+        assert_eq!(hunks(&diff, 10).len(), 1);
+    }
+}
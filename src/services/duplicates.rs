@@ -0,0 +1,331 @@
+//! Near-duplicate detection and merging for tasks.
+//!
+//! Task texts are compared word by word. Two words match when they are equal
+//! ignoring case and a plural `s`, or — for words of four or more letters —
+//! when their Levenshtein distance is 1, which catches typos such as
+//! `login`/`logn`. Words containing digits must match exactly, so
+//! `Review PR 12` and `Review PR 13` stay distinct. The similarity is the
+//! share of matched words (Dice coefficient over words), and texts are
+//! duplicates when it reaches [`SIMILARITY_THRESHOLD`].
+//!
+//! Only open, non-deleted tasks are compared: a completed task with the same
+//! text is history, not a duplicate.
+
+use std::collections::HashSet;
+
+use strsim::levenshtein;
+use uuid::Uuid;
+
+use crate::error::TodoError;
+use crate::models::{Note, Task, detect_cycle};
+
+/// Minimum similarity (0.0–1.0) for two texts to count as duplicates.
+pub const SIMILARITY_THRESHOLD: f64 = 0.85;
+
+/// Returns the similarity of two task texts, from 0.0 to 1.0.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::services::duplicates::similarity;
+///
+/// assert_eq!(similarity("Buy milk", "buy  MILK"), 1.0);
+/// assert_eq!(similarity("Fix login bug", "fix logn bugs"), 1.0);
+/// assert!(similarity("Fix login bug", "Fix the login bug") > 0.85);
+/// assert!(similarity("Task 1", "Task 2") < 0.85);
+/// ```
+pub fn similarity(a: &str, b: &str) -> f64 {
+    let a = words(a);
+    let b = words(b);
+    if a.is_empty() || b.is_empty() {
+        return if a == b { 1.0 } else { 0.0 };
+    }
+
+    let mut used = vec![false; b.len()];
+    let mut matched = 0;
+    for wa in &a {
+        if let Some(j) = (0..b.len()).find(|&j| !used[j] && words_match(wa, &b[j])) {
+            used[j] = true;
+            matched += 1;
+        }
+    }
+    (2 * matched) as f64 / (a.len() + b.len()) as f64
+}
+
+/// Returns `true` when `a` and `b` are similar enough to be duplicates.
+pub fn is_similar(a: &str, b: &str) -> bool {
+    similarity(a, b) >= SIMILARITY_THRESHOLD
+}
+
+/// Finds the open task most similar to `text`, as `(index, similarity)`.
+///
+/// Tasks whose UUID is in `exclude` are skipped.
+pub fn find_similar(text: &str, tasks: &[Task], exclude: &[Uuid]) -> Option<(usize, f64)> {
+    tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| is_candidate(t) && !exclude.contains(&t.uuid))
+        .map(|(i, t)| (i, similarity(text, &t.text)))
+        .filter(|(_, score)| *score >= SIMILARITY_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Groups open tasks into clusters of near-duplicates.
+///
+/// Similarity is applied transitively: if A≈B and B≈C, all three form one
+/// cluster. Each cluster holds task indices in ascending order, and clusters
+/// are ordered by their first index; singletons are omitted.
+pub fn clusters(tasks: &[Task]) -> Vec<Vec<usize>> {
+    let candidates: Vec<usize> = (0..tasks.len())
+        .filter(|&i| is_candidate(&tasks[i]))
+        .collect();
+    let mut parent: Vec<usize> = (0..tasks.len()).collect();
+
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    for (n, &i) in candidates.iter().enumerate() {
+        for &j in &candidates[n + 1..] {
+            if is_similar(&tasks[i].text, &tasks[j].text) {
+                let (ri, rj) = (root(&mut parent, i), root(&mut parent, j));
+                if ri != rj {
+                    parent[ri.max(rj)] = ri.min(rj);
+                }
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for &i in &candidates {
+        let r = root(&mut parent, i);
+        match groups.iter_mut().find(|g| g[0] == r) {
+            Some(group) => group.push(i),
+            None => groups.push(vec![i]),
+        }
+    }
+    groups.retain(|g| g.len() > 1);
+    groups
+}
+
+/// Folds `from` into `into` without touching storage.
+///
+/// Tags and dependencies are combined, the earliest due date and highest
+/// priority win, and the project is taken from `from` if `into` has none.
+/// Text, status and recurrence of `into` are kept.
+pub fn absorb(into: &mut Task, from: &Task) {
+    for tag in &from.tags {
+        if !into.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            into.tags.push(tag.clone());
+        }
+    }
+    for dep in &from.depends_on {
+        if *dep != into.uuid && !into.depends_on.contains(dep) {
+            into.depends_on.push(*dep);
+        }
+    }
+    into.due_date = match (into.due_date, from.due_date) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    };
+    if from.priority.order() < into.priority.order() {
+        into.priority = from.priority;
+    }
+    if into.project_id.is_none() {
+        into.project_id = from.project_id;
+    }
+    into.touch();
+}
+
+/// Merges the tasks in `others` into the task `keep`.
+///
+/// Besides [`absorb`]ing each duplicate, notes attached to a duplicate move to
+/// `keep`, tasks that depended on a duplicate depend on `keep` instead, and
+/// the duplicates are soft-deleted. Returns the UUIDs of the notes that were
+/// re-attached.
+///
+/// Fails if `keep` is not in `tasks`, or if the combined dependencies would
+/// form a cycle; `tasks` must not be saved after an error.
+pub fn merge(
+    tasks: &mut [Task],
+    notes: &mut [Note],
+    keep: Uuid,
+    others: &[Uuid],
+) -> Result<Vec<Uuid>, TodoError> {
+    let merged: HashSet<Uuid> = others.iter().copied().filter(|u| *u != keep).collect();
+    let keep_idx = tasks
+        .iter()
+        .position(|t| t.uuid == keep)
+        .ok_or(TodoError::MergeTargetNotFound(keep))?;
+
+    for i in 0..tasks.len() {
+        if merged.contains(&tasks[i].uuid) {
+            let from = tasks[i].clone();
+            absorb(&mut tasks[keep_idx], &from);
+            tasks[i].soft_delete();
+        }
+    }
+    tasks[keep_idx]
+        .depends_on
+        .retain(|dep| !merged.contains(dep));
+
+    for task in tasks.iter_mut().filter(|t| t.uuid != keep) {
+        if task.depends_on.iter().any(|d| merged.contains(d)) {
+            task.depends_on.retain(|d| !merged.contains(d));
+            if !task.depends_on.contains(&keep) {
+                task.depends_on.push(keep);
+            }
+            task.touch();
+        }
+    }
+    check_no_cycle(tasks, keep)?;
+
+    let mut moved = Vec::new();
+    for note in notes.iter_mut() {
+        if note.task_ids.iter().any(|id| merged.contains(id)) {
+            note.task_ids.retain(|id| !merged.contains(id));
+            note.add_task(keep);
+            note.touch();
+            moved.push(note.uuid);
+        }
+    }
+    Ok(moved)
+}
+
+/// Fails with [`TodoError::DependencyCycle`] if a dependency of `task` leads
+/// back to it.
+///
+/// Only `task`'s edges change in a merge, so any cycle it creates passes
+/// through one of them.
+pub fn check_no_cycle(tasks: &[Task], task: Uuid) -> Result<(), TodoError> {
+    let live: Vec<Task> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
+    let Some(t) = live.iter().find(|t| t.uuid == task) else {
+        return Ok(());
+    };
+    for &dep in &t.depends_on {
+        detect_cycle(&live, task, dep).map_err(TodoError::DependencyCycle)?;
+    }
+    Ok(())
+}
+
+fn is_candidate(task: &Task) -> bool {
+    task.is_open() && !task.is_deleted()
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+fn words_match(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.chars().any(|c| c.is_ascii_digit()) || b.chars().any(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let (sa, sb) = (
+        a.strip_suffix('s').unwrap_or(a),
+        b.strip_suffix('s').unwrap_or(b),
+    );
+    if sa == sb {
+        return true;
+    }
+    a.chars().count().min(b.chars().count()) >= 4 && levenshtein(a, b) <= 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use chrono::NaiveDate;
+
+    fn task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    #[test]
+    fn test_clusters_are_transitive_and_skip_closed_tasks() {
+        let mut done = task("Buy milk");
+        done.mark_done();
+        let tasks = vec![
+            task("Fix login bug"),
+            task("Write report"),
+            task("Fix the login bug"),
+            task("fix login bugs"),
+            task("Buy milk"),
+            done,
+        ];
+        assert_eq!(clusters(&tasks), vec![vec![0, 2, 3]]);
+    }
+
+    #[test]
+    fn test_find_similar_respects_exclude() {
+        let tasks = vec![task("Write report"), task("Fix login bug")];
+        assert_eq!(find_similar("fix login bug", &tasks, &[]).unwrap().0, 1);
+        assert!(find_similar("fix login bug", &tasks, &[tasks[1].uuid]).is_none());
+    }
+
+    #[test]
+    fn test_merge_combines_fields_and_rewires_references() {
+        let d = |day| NaiveDate::from_ymd_opt(2099, 1, day);
+        let blocker = task("Blocker");
+        let mut keep = task("Fix login bug");
+        keep.tags = vec!["bug".into()];
+        keep.due_date = d(20);
+        let mut dup = task("Fix the login bug");
+        dup.tags = vec!["Bug".into(), "auth".into()];
+        dup.due_date = d(10);
+        dup.priority = Priority::High;
+        dup.depends_on = vec![blocker.uuid];
+        let mut dependent = task("Deploy");
+        dependent.depends_on = vec![dup.uuid];
+        let mut note = Note::new("Stack trace".into());
+        note.task_ids = vec![dup.uuid];
+
+        let (keep_id, dup_id) = (keep.uuid, dup.uuid);
+        let mut tasks = vec![blocker.clone(), keep, dup, dependent];
+        let mut notes = vec![note];
+
+        let moved = merge(&mut tasks, &mut notes, keep_id, &[dup_id]).unwrap();
+
+        let kept = &tasks[1];
+        assert_eq!(kept.tags, vec!["bug", "auth"]);
+        assert_eq!(kept.due_date, d(10));
+        assert_eq!(kept.priority, Priority::High);
+        assert_eq!(kept.depends_on, vec![blocker.uuid]);
+        assert!(tasks[2].is_deleted());
+        assert_eq!(tasks[3].depends_on, vec![keep_id]);
+        assert_eq!(moved, vec![notes[0].uuid]);
+        assert_eq!(notes[0].task_ids, vec![keep_id]);
+    }
+
+    #[test]
+    fn test_merge_rejects_dependency_cycle_and_missing_target() {
+        let b = task("B");
+        let mut x = task("X");
+        x.depends_on = vec![b.uuid];
+        let mut a = task("A");
+        a.depends_on = vec![x.uuid];
+        let (a_id, b_id) = (a.uuid, b.uuid);
+        let mut tasks = vec![b, x, a];
+
+        assert!(matches!(
+            merge(&mut tasks, &mut [], a_id, &[b_id]),
+            Err(TodoError::DependencyCycle(_))
+        ));
+        assert!(matches!(
+            merge(&mut tasks, &mut [], Uuid::new_v4(), &[b_id]),
+            Err(TodoError::MergeTargetNotFound(_))
+        ));
+    }
+}
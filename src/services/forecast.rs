@@ -0,0 +1,314 @@
+//! Burndown and completion forecast for `todo project forecast`.
+//!
+//! A project's history is replayed from the event log: each task is open from
+//! the day it was created until it is completed, cancelled or deleted, and
+//! open again when it is reopened or restored. Closings that predate the event
+//! log fall back to `completed_at`, `deleted_at` or `updated_at`, so the replay
+//! always ends in each task's current state.
+//!
+//! Throughput is the number of completions in each of the last N weeks. The
+//! expected finish divides the open tasks by the mean weekly throughput; the
+//! range uses the mean plus and minus one standard deviation. Dates are
+//! calendar days.
+
+use chrono::{Duration, Local, NaiveDate};
+
+use crate::models::Task;
+use crate::storage::{Event, EventType};
+
+/// Weeks of history used when `--weeks` is not given.
+pub const DEFAULT_WEEKS: usize = 8;
+
+/// Most weeks of history a forecast looks back over (about ten years).
+pub const MAX_WEEKS: usize = 520;
+
+/// One week of the burndown, ending on `end` (inclusive).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeekPoint {
+    pub end: NaiveDate,
+    /// Tasks still open at the end of the week.
+    pub remaining: usize,
+    /// Tasks completed during the week.
+    pub completed: usize,
+}
+
+/// Projected finish dates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Estimate {
+    /// Finish at the mean throughput plus one standard deviation.
+    pub earliest: NaiveDate,
+    /// Finish at the mean throughput.
+    pub expected: NaiveDate,
+    /// Finish at the mean minus one standard deviation; `None` when that rate
+    /// is zero or less, i.e. the project might not finish at all.
+    pub latest: Option<NaiveDate>,
+}
+
+/// How the forecast compares with a due date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outlook {
+    /// Even the pessimistic estimate finishes by the due date.
+    OnTrack,
+    /// The expected finish is on time, but the pessimistic one is not.
+    AtRisk,
+    /// The expected finish is after the due date.
+    Late,
+}
+
+#[derive(Debug, Clone)]
+pub struct Forecast {
+    /// Oldest first; the last week ends today.
+    pub weeks: Vec<WeekPoint>,
+    /// Tasks open today.
+    pub remaining: usize,
+    /// Mean completions per week.
+    pub throughput: f64,
+    /// Standard deviation of the weekly completions.
+    pub spread: f64,
+    /// `None` when nothing is left, or nothing was completed recently.
+    pub estimate: Option<Estimate>,
+}
+
+impl Forecast {
+    /// Compares the forecast with `due`. `None` when there is no estimate
+    /// and work is still open.
+    pub fn outlook(&self, due: NaiveDate) -> Option<Outlook> {
+        if self.remaining == 0 {
+            return Some(Outlook::OnTrack);
+        }
+        let estimate = self.estimate.as_ref()?;
+        Some(if estimate.expected > due {
+            Outlook::Late
+        } else if estimate.latest.is_some_and(|d| d <= due) {
+            Outlook::OnTrack
+        } else {
+            Outlook::AtRisk
+        })
+    }
+}
+
+/// A change in a task's state on a given day.
+#[derive(Debug, Clone, Copy)]
+enum Transition {
+    Open,
+    Close { completed: bool },
+}
+
+/// Builds the burndown and forecast for `tasks` over the last `weeks` weeks.
+///
+/// `weeks` is clamped to `1..=`[`MAX_WEEKS`].
+///
+/// `tasks` should include deleted ones so that removals show up in the
+/// burndown; `events` may contain events for other tasks, which are ignored.
+pub fn forecast(tasks: &[&Task], events: &[Event], weeks: usize, today: NaiveDate) -> Forecast {
+    let timelines: Vec<Vec<(NaiveDate, Transition)>> =
+        tasks.iter().map(|t| timeline(t, events, today)).collect();
+
+    let open_at = |day: NaiveDate| {
+        timelines
+            .iter()
+            .filter(|tl| {
+                tl.iter()
+                    .take_while(|(d, _)| *d <= day)
+                    .last()
+                    .is_some_and(|(_, tr)| matches!(tr, Transition::Open))
+            })
+            .count()
+    };
+    let completed_between = |after: NaiveDate, until: NaiveDate| {
+        timelines
+            .iter()
+            .flatten()
+            .filter(|(d, tr)| {
+                *d > after && *d <= until && matches!(tr, Transition::Close { completed: true })
+            })
+            .count()
+    };
+
+    let weeks = weeks.clamp(1, MAX_WEEKS);
+    let points: Vec<WeekPoint> = (0..weeks)
+        .rev()
+        .map(|back| {
+            let end = today - Duration::days(7 * back as i64);
+            WeekPoint {
+                end,
+                remaining: open_at(end),
+                completed: completed_between(end - Duration::days(7), end),
+            }
+        })
+        .collect();
+
+    let remaining = open_at(today);
+    let counts: Vec<f64> = points.iter().map(|p| p.completed as f64).collect();
+    let throughput = counts.iter().sum::<f64>() / counts.len() as f64;
+    let spread =
+        (counts.iter().map(|c| (c - throughput).powi(2)).sum::<f64>() / counts.len() as f64).sqrt();
+
+    let finish = |rate: f64| today + Duration::days((remaining as f64 / rate * 7.0).ceil() as i64);
+    let estimate = (remaining > 0 && throughput > 0.0).then(|| Estimate {
+        earliest: finish(throughput + spread),
+        expected: finish(throughput),
+        latest: (throughput - spread > f64::EPSILON).then(|| finish(throughput - spread)),
+    });
+
+    Forecast {
+        weeks: points,
+        remaining,
+        throughput,
+        spread,
+        estimate,
+    }
+}
+
+/// Replays one task's events into dated open/close transitions.
+fn timeline(task: &Task, events: &[Event], today: NaiveDate) -> Vec<(NaiveDate, Transition)> {
+    let local = |at: chrono::DateTime<chrono::Utc>| at.with_timezone(&Local).date_naive();
+
+    let mut out = vec![(local(task.created_at), Transition::Open)];
+    for event in events.iter().filter(|e| e.entity_uuid == task.uuid) {
+        let transition = match event.event_type {
+            EventType::Completed => Transition::Close { completed: true },
+            EventType::Cancelled | EventType::Deleted | EventType::Purged => {
+                Transition::Close { completed: false }
+            }
+            EventType::Uncompleted | EventType::Restored => Transition::Open,
+            _ => continue,
+        };
+        out.push((local(event.occurred_at), transition));
+    }
+    out.sort_by_key(|(d, _)| *d);
+
+    // Reconcile with the task's current state for history the log missed.
+    let open_now = task.is_open() && !task.is_deleted();
+    let replayed_open = matches!(out.last(), Some((_, Transition::Open)));
+    if replayed_open && !open_now {
+        let (day, completed) = if task.is_deleted() {
+            (task.deleted_at.map(local), false)
+        } else if task.completed {
+            (task.completed_at, true)
+        } else {
+            (task.updated_at.map(local), false)
+        };
+        let day = day
+            .unwrap_or(today)
+            .max(out.last().map_or(today, |(d, _)| *d));
+        out.push((day, Transition::Close { completed }));
+    } else if !replayed_open && open_now {
+        let day = task.updated_at.map(local).unwrap_or(today);
+        out.push((
+            day.max(out.last().map_or(day, |(d, _)| *d)),
+            Transition::Open,
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use chrono::{TimeZone, Utc};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn task_created(d: u32) -> Task {
+        let mut t = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        t.created_at = Utc.from_utc_datetime(&day(d).and_hms_opt(12, 0, 0).unwrap());
+        t
+    }
+
+    fn completed(task: &mut Task, d: u32) -> Event {
+        task.mark_done();
+        task.completed_at = Some(day(d));
+        Event {
+            entity_uuid: task.uuid,
+            event_type: EventType::Completed,
+            occurred_at: Utc.from_utc_datetime(&day(d).and_hms_opt(12, 0, 0).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_burndown_counts_remaining_and_completions_per_week() {
+        let mut a = task_created(1);
+        let mut b = task_created(1);
+        let c = task_created(10);
+        let events = vec![completed(&mut a, 5), completed(&mut b, 12)];
+
+        let f = forecast(&[&a, &b, &c], &events, 3, day(15));
+
+        let weeks: Vec<(NaiveDate, usize, usize)> = f
+            .weeks
+            .iter()
+            .map(|w| (w.end, w.remaining, w.completed))
+            .collect();
+        assert_eq!(weeks, vec![(day(1), 2, 0), (day(8), 1, 1), (day(15), 1, 1)]);
+        assert_eq!(f.remaining, 1);
+    }
+
+    #[test]
+    fn test_estimate_uses_mean_throughput() {
+        let mut tasks: Vec<Task> = (0..6).map(|_| task_created(1)).collect();
+        // One completion in each of the last two weeks.
+        let events = vec![completed(&mut tasks[0], 5), completed(&mut tasks[1], 12)];
+        let refs: Vec<&Task> = tasks.iter().collect();
+
+        let f = forecast(&refs, &events, 2, day(15));
+
+        assert_eq!(f.remaining, 4);
+        assert!((f.throughput - 1.0).abs() < f64::EPSILON);
+        let estimate = f.estimate.as_ref().unwrap();
+        // Four tasks at one a week, with no spread.
+        assert_eq!(estimate.expected, day(15) + Duration::days(28));
+        assert_eq!(estimate.earliest, estimate.expected);
+        assert_eq!(estimate.latest, Some(estimate.expected));
+
+        assert_eq!(
+            f.outlook(day(15) + Duration::days(30)),
+            Some(Outlook::OnTrack)
+        );
+        assert_eq!(f.outlook(day(15) + Duration::days(20)), Some(Outlook::Late));
+    }
+
+    #[test]
+    fn test_uneven_throughput_is_at_risk_near_the_expected_date() {
+        let mut tasks: Vec<Task> = (0..5).map(|_| task_created(1)).collect();
+        let events = vec![completed(&mut tasks[0], 14), completed(&mut tasks[1], 15)];
+        let refs: Vec<&Task> = tasks.iter().collect();
+
+        let f = forecast(&refs, &events, 2, day(15));
+        let estimate = f.estimate.clone().unwrap();
+        // Weeks of 0 and 2 completions: the pessimistic rate is zero.
+        assert!(estimate.latest.is_none());
+        assert!(estimate.earliest < estimate.expected);
+        assert_eq!(f.outlook(estimate.expected), Some(Outlook::AtRisk));
+    }
+
+    #[test]
+    fn test_no_recent_completions_has_no_estimate() {
+        let a = task_created(1);
+        let f = forecast(&[&a], &[], 4, day(15));
+        assert!(f.estimate.is_none());
+        assert_eq!(f.outlook(day(20)), None);
+    }
+
+    #[test]
+    fn test_closing_without_an_event_falls_back_to_completed_at() {
+        let mut a = task_created(1);
+        a.mark_done();
+        a.completed_at = Some(day(3));
+        let f = forecast(&[&a], &[], 2, day(8));
+        assert_eq!(f.weeks[0].remaining, 1);
+        assert_eq!(f.weeks[1].remaining, 0);
+        assert_eq!(f.weeks[1].completed, 1);
+        assert_eq!(f.outlook(day(9)), Some(Outlook::OnTrack));
+    }
+
+    #[test]
+    fn test_huge_week_count_is_clamped() {
+        let a = task_created(1);
+        let f = forecast(&[&a], &[], usize::MAX, day(15));
+        assert_eq!(f.weeks.len(), MAX_WEEKS);
+    }
+}
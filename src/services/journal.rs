@@ -0,0 +1,215 @@
+//! Daily journal entries.
+//!
+//! A journal entry is a markdown note tagged `journal` and titled with its
+//! date (`2026-03-14`), so other notes can link to it with
+//! `[[2026-03-14]]`. New entries are prefilled from a template, where these
+//! placeholders are replaced:
+//!
+//! | Placeholder   | Replaced with |
+//! |---|---|
+//! | `{{date}}`    | the date, `2026-03-14` |
+//! | `{{weekday}}` | the day of the week, `Saturday` |
+//! | `{{done}}`    | the tasks completed that day, from the event log |
+//! | `{{due}}`     | the tasks due that day |
+//!
+//! Tasks are listed as markdown checklist items with a `[[task:N]]` link,
+//! which ties the entry to the task (see [`crate::services::links`]).
+
+use std::collections::BTreeMap;
+
+use chrono::{Local, NaiveDate};
+use uuid::Uuid;
+
+use crate::models::{Note, Task, TaskStatus, substitute};
+use crate::storage::{Event, EventType};
+
+/// The tag every journal entry carries.
+pub const TAG: &str = "journal";
+
+/// The template used when `[notes] journal_template` is unset.
+pub const DEFAULT_TEMPLATE: &str = "\
+# {{weekday}} {{date}}
+
+## Done
+
+{{done}}
+
+## Due
+
+{{due}}
+
+## Notes
+
+";
+
+/// The title of the entry for `date`.
+pub fn title(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// The date of a journal entry, or `None` if `note` is not one.
+pub fn entry_date(note: &Note) -> Option<NaiveDate> {
+    if !note.tags.iter().any(|t| t == TAG) {
+        return None;
+    }
+    NaiveDate::parse_from_str(note.title.as_deref()?.trim(), "%Y-%m-%d").ok()
+}
+
+/// The index of the entry for `date` among `notes`, ignoring the trash.
+pub fn find(notes: &[Note], date: NaiveDate) -> Option<usize> {
+    notes
+        .iter()
+        .position(|n| !n.is_deleted() && entry_date(n) == Some(date))
+}
+
+/// Tasks completed on `date` (local time) and still done, in the order
+/// they were completed.
+pub fn completed_on<'a>(date: NaiveDate, tasks: &'a [Task], events: &[Event]) -> Vec<&'a Task> {
+    let mut seen: Vec<Uuid> = Vec::new();
+    for event in events {
+        if event.event_type == EventType::Completed
+            && event.occurred_at.with_timezone(&Local).date_naive() == date
+            && !seen.contains(&event.entity_uuid)
+        {
+            seen.push(event.entity_uuid);
+        }
+    }
+    seen.iter()
+        .filter_map(|uuid| tasks.iter().find(|t| t.uuid == *uuid))
+        .filter(|t| !t.is_deleted() && t.status == TaskStatus::Done)
+        .collect()
+}
+
+/// Tasks due on `date`, in list order.
+pub fn due_on(date: NaiveDate, tasks: &[Task]) -> Vec<&Task> {
+    tasks
+        .iter()
+        .filter(|t| !t.is_deleted() && t.due_date == Some(date))
+        .collect()
+}
+
+/// Fills in `template` for `date`.
+///
+/// `tasks` is the full task list, used to number the `[[task:N]]` links.
+/// Fails with the name of an unknown placeholder.
+pub fn render(
+    template: &str,
+    date: NaiveDate,
+    tasks: &[Task],
+    done: &[&Task],
+    due: &[&Task],
+) -> Result<String, String> {
+    let vars = BTreeMap::from([
+        ("date".to_string(), title(date)),
+        ("weekday".to_string(), date.format("%A").to_string()),
+        ("done".to_string(), checklist(tasks, done)),
+        ("due".to_string(), checklist(tasks, due)),
+    ]);
+    substitute(template, &vars)
+}
+
+fn checklist(tasks: &[Task], items: &[&Task]) -> String {
+    if items.is_empty() {
+        return "_Nothing._".to_string();
+    }
+    items
+        .iter()
+        .map(|task| {
+            let id = tasks
+                .iter()
+                .filter(|t| !t.is_deleted())
+                .position(|t| t.uuid == task.uuid)
+                .map_or(0, |i| i + 1);
+            format!(
+                "- [{}] {} [[task:{}]]",
+                if task.status == TaskStatus::Done {
+                    "x"
+                } else {
+                    " "
+                },
+                task.text,
+                id
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use chrono::Utc;
+
+    fn task(text: &str) -> Task {
+        Task::new(text.into(), Priority::Medium, vec![], None, None, None)
+    }
+
+    fn day() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 14).unwrap()
+    }
+
+    #[test]
+    fn test_entry_date_requires_tag_and_date_title() {
+        let mut note = Note::new_markdown("body".into());
+        note.title = Some("2026-03-14".into());
+        assert_eq!(entry_date(&note), None);
+
+        note.tags.push(TAG.into());
+        assert_eq!(entry_date(&note), Some(day()));
+        assert_eq!(find(std::slice::from_ref(&note), day()), Some(0));
+
+        note.title = Some("Standup".into());
+        assert_eq!(entry_date(&note), None);
+    }
+
+    #[test]
+    fn test_completed_on_uses_events_of_that_day() {
+        let mut done = task("Ship it");
+        done.mark_done();
+        let mut reopened = task("Reopened");
+        reopened.mark_done();
+        reopened.mark_undone();
+        let tasks = vec![done.clone(), reopened.clone()];
+        let today = Local::now().date_naive();
+        let event = |uuid| Event {
+            entity_uuid: uuid,
+            event_type: EventType::Completed,
+            occurred_at: Utc::now(),
+        };
+        let events = vec![event(done.uuid), event(reopened.uuid), event(done.uuid)];
+
+        let found = completed_on(today, &tasks, &events);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].text, "Ship it");
+        assert!(completed_on(today.pred_opt().unwrap(), &tasks, &events).is_empty());
+    }
+
+    #[test]
+    fn test_render_fills_placeholders() {
+        let mut first = task("Write report");
+        first.due_date = Some(day());
+        let mut second = task("Fix bug");
+        second.mark_done();
+        let tasks = vec![first, second];
+
+        let body = render(
+            DEFAULT_TEMPLATE,
+            day(),
+            &tasks,
+            &[&tasks[1]],
+            &due_on(day(), &tasks),
+        )
+        .unwrap();
+        assert!(body.starts_with("# Saturday 2026-03-14\n"));
+        assert!(body.contains("- [x] Fix bug [[task:2]]"));
+        assert!(body.contains("- [ ] Write report [[task:1]]"));
+
+        let empty = render("{{done}}", day(), &[], &[], &[]).unwrap();
+        assert_eq!(empty, "_Nothing._");
+        assert_eq!(
+            render("{{mood}}", day(), &[], &[], &[]).unwrap_err(),
+            "mood"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! HTTP health checks for resource URLs, used by `todo resource check`.
+//!
+//! Each URL is requested with `HEAD` first. Servers that reject `HEAD` or
+//! answer it with an error get a `GET` as well, and the `GET` result is kept.
+//! Redirects are followed; the URL they end at is recorded as
+//! [`LinkCheck::final_url`].
+
+use std::time::Duration;
+
+use chrono::Utc;
+use ureq::ResponseExt;
+
+use crate::models::LinkCheck;
+
+/// HTTP timeout for a single link check.
+///
+/// Long enough for slow servers; a dead host should not stall a check of a
+/// whole library for more than a few seconds.
+pub const HTTP_TIMEOUT_SECS: u64 = 10;
+
+/// Redirects followed before a link is reported as failing.
+const MAX_REDIRECTS: u32 = 10;
+
+/// Checks URLs with one shared HTTP agent.
+pub struct LinkChecker {
+    agent: ureq::Agent,
+}
+
+impl LinkChecker {
+    pub fn new(timeout: Duration) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(timeout))
+            .http_status_as_error(false)
+            .max_redirects(MAX_REDIRECTS)
+            .save_redirect_history(true)
+            .user_agent(concat!("rustodo/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .into();
+        Self { agent }
+    }
+
+    /// Requests `url` and reports what came back. Never fails: errors are
+    /// recorded in [`LinkCheck::error`].
+    pub fn check(&self, url: &str) -> LinkCheck {
+        let outcome = match self.agent.head(url).call() {
+            Ok(response) if response.status().as_u16() < 400 => Ok(response),
+            _ => self.agent.get(url).call(),
+        };
+        let checked_at = Utc::now();
+        match outcome {
+            Ok(response) => {
+                let redirected = response
+                    .get_redirect_history()
+                    .is_some_and(|history| history.len() > 1);
+                LinkCheck {
+                    checked_at,
+                    status: Some(response.status().as_u16()),
+                    final_url: redirected.then(|| response.get_uri().to_string()),
+                    error: None,
+                }
+            }
+            Err(e) => LinkCheck {
+                checked_at,
+                status: None,
+                final_url: None,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+impl Default for LinkChecker {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(HTTP_TIMEOUT_SECS))
+    }
+}
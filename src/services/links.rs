@@ -0,0 +1,344 @@
+//! Wiki-links between notes, tasks and resources.
+//!
+//! A note body can reference other items with double brackets:
+//!
+//! | Syntax | Links to |
+//! |---|---|
+//! | `[[Design notes]]` | the note titled "Design notes" (case-insensitive) |
+//! | `[[note:4]]`       | note #4 |
+//! | `[[task:12]]`      | task #12 |
+//! | `[[resource:3]]`   | resource #3 |
+//!
+//! References are resolved when the note is saved and stored as
+//! [`NoteLink`]s holding the target's UUID, so a link keeps pointing at the
+//! same item when list IDs shift. References that resolve to nothing are not
+//! stored; title references are retried whenever another note is saved, so a
+//! note can link to one that does not exist yet.
+
+use uuid::Uuid;
+
+use crate::models::{LinkTarget, Note, NoteLink, Resource, Task};
+use crate::render::formatting::note_preview;
+use crate::utils::validation::resolve_visible;
+
+/// Returns the distinct `[[…]]` references in `body`, trimmed, in order.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::services::links::references;
+///
+/// assert_eq!(
+///     references("See [[Design]], [[task:3]] and [[Design]]. [[ ]] [not]"),
+///     vec!["Design", "task:3"]
+/// );
+/// ```
+pub fn references(body: &str) -> Vec<&str> {
+    let mut found: Vec<&str> = Vec::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("[[") {
+        rest = &rest[start..];
+        match reference_at(rest) {
+            Some(inner) => {
+                let reference = inner.trim();
+                if !found.contains(&reference) {
+                    found.push(reference);
+                }
+                rest = &rest[inner.len() + 4..];
+            }
+            None => rest = &rest[1..],
+        }
+    }
+    found
+}
+
+/// Returns the text between the brackets when `s` starts with a
+/// `[[reference]]`, untrimmed.
+pub fn reference_at(s: &str) -> Option<&str> {
+    let inner = s.strip_prefix("[[")?;
+    let end = inner.find("]]")?;
+    let inner = &inner[..end];
+    let valid = !inner.trim().is_empty() && !inner.contains(['[', ']', '\n']);
+    valid.then_some(inner)
+}
+
+/// Resolves one reference written in note `source`.
+///
+/// A title never resolves to the note it is written in.
+pub fn resolve(
+    reference: &str,
+    source: Uuid,
+    notes: &[Note],
+    tasks: &[Task],
+    resources: &[Resource],
+) -> Option<LinkTarget> {
+    if let Some((kind, id)) = reference.split_once(':')
+        && let Ok(id) = id.trim().parse::<usize>()
+    {
+        let target = match kind.trim().to_lowercase().as_str() {
+            "note" => LinkTarget::Note(resolve_visible(notes, id, |n| n.is_deleted()).ok()?.uuid),
+            "task" => LinkTarget::Task(resolve_visible(tasks, id, |t| t.is_deleted()).ok()?.uuid),
+            "resource" => LinkTarget::Resource(
+                resolve_visible(resources, id, |r| r.is_deleted())
+                    .ok()?
+                    .uuid,
+            ),
+            _ => return None,
+        };
+        return Some(target);
+    }
+
+    notes
+        .iter()
+        .filter(|n| !n.is_deleted() && n.uuid != source)
+        .find(|n| {
+            n.title
+                .as_deref()
+                .is_some_and(|t| t.trim().eq_ignore_ascii_case(reference))
+        })
+        .map(|n| LinkTarget::Note(n.uuid))
+}
+
+/// Re-resolves the links of the note at `index` after it was saved, and
+/// retries unresolved title references in every other note.
+///
+/// Links whose reference is still in the body keep their target. Returns
+/// the indices of the notes whose links changed, which need saving.
+pub fn refresh(
+    notes: &mut [Note],
+    index: usize,
+    tasks: &[Task],
+    resources: &[Resource],
+) -> Vec<usize> {
+    let mut changed = Vec::new();
+    for i in 0..notes.len() {
+        if notes[i].is_deleted() && i != index {
+            continue;
+        }
+        let note = &notes[i];
+        let mut links = Vec::new();
+        for reference in references(&note.body) {
+            if let Some(link) = note.links.iter().find(|l| l.reference == reference) {
+                links.push(link.clone());
+                continue;
+            }
+            // Only the saved note resolves numeric IDs: in other notes they
+            // would now point at whatever item took that number.
+            let is_title = !reference.contains(':');
+            if (i == index || is_title)
+                && let Some(target) = resolve(reference, note.uuid, notes, tasks, resources)
+            {
+                links.push(NoteLink {
+                    reference: reference.to_string(),
+                    target,
+                });
+            }
+        }
+        if links != notes[i].links {
+            notes[i].links = links;
+            changed.push(i);
+        }
+    }
+    changed
+}
+
+/// Rewrites `[[old]]` references to the note `target` as `[[new]]`, after
+/// that note was renamed.
+///
+/// Returns the indices of the notes whose body changed; they are touched.
+pub fn rename_note(notes: &mut [Note], target: Uuid, old: &str, new: &str) -> Vec<usize> {
+    let mut changed = Vec::new();
+    for (i, note) in notes.iter_mut().enumerate() {
+        let mut renamed = false;
+        for link in &mut note.links {
+            if link.target == LinkTarget::Note(target)
+                && !link.reference.contains(':')
+                && link.reference.eq_ignore_ascii_case(old.trim())
+            {
+                note.body = replace_reference(&note.body, &link.reference, new);
+                link.reference = new.to_string();
+                renamed = true;
+            }
+        }
+        if renamed {
+            note.touch();
+            changed.push(i);
+        }
+    }
+    changed
+}
+
+/// Replaces every `[[ reference ]]` in `body` (spacing inside the brackets
+/// included) with `[[new]]`.
+fn replace_reference(body: &str, reference: &str, new: &str) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut rest = body;
+    while let Some(start) = rest.find("[[") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match reference_at(rest) {
+            Some(inner) if inner.trim() == reference => {
+                out.push_str(&format!("[[{}]]", new));
+                rest = &rest[inner.len() + 4..];
+            }
+            _ => {
+                out.push('[');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Visible notes linking to `target`, as `(visible ID, note)`.
+pub fn backlinks(notes: &[Note], target: LinkTarget) -> Vec<(usize, &Note)> {
+    notes
+        .iter()
+        .filter(|n| !n.is_deleted())
+        .enumerate()
+        .filter(|(_, n)| n.links_to(target))
+        .map(|(i, n)| (i + 1, n))
+        .collect()
+}
+
+/// Describes a link target as `(kind, visible ID, label)`, or `None` when it
+/// was deleted.
+pub fn describe(
+    target: LinkTarget,
+    notes: &[Note],
+    tasks: &[Task],
+    resources: &[Resource],
+) -> Option<(&'static str, usize, String)> {
+    fn position<T>(
+        items: &[T],
+        deleted: impl Fn(&T) -> bool,
+        matches: impl Fn(&T) -> bool,
+    ) -> Option<(usize, &T)> {
+        items
+            .iter()
+            .filter(|x| !deleted(x))
+            .enumerate()
+            .find(|(_, x)| matches(x))
+            .map(|(i, x)| (i + 1, x))
+    }
+
+    let uuid = target.uuid();
+    let (id, label) = match target {
+        LinkTarget::Note(_) => position(notes, |n| n.is_deleted(), |n| n.uuid == uuid)
+            .map(|(id, n)| (id, note_preview(n)))?,
+        LinkTarget::Task(_) => position(tasks, |t| t.is_deleted(), |t| t.uuid == uuid)
+            .map(|(id, t)| (id, t.text.clone()))?,
+        LinkTarget::Resource(_) => position(resources, |r| r.is_deleted(), |r| r.uuid == uuid)
+            .map(|(id, r)| (id, r.title.clone()))?,
+    };
+    Some((target.kind(), id, label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn titled(title: &str, body: &str) -> Note {
+        let mut note = Note::new_markdown(body.to_string());
+        note.title = Some(title.to_string());
+        note
+    }
+
+    #[test]
+    fn test_references_skip_empty_and_nested_brackets() {
+        assert_eq!(
+            references("[[a]] [[ b ]] [[]] [[c\nd]] [x]]"),
+            vec!["a", "b"]
+        );
+        assert!(references("no links [here]").is_empty());
+    }
+
+    #[test]
+    fn test_resolve_titles_and_ids() {
+        let notes = vec![titled("Design", "x"), titled("Other", "y")];
+        let tasks = vec![Task::new(
+            "Ship".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            None,
+            None,
+        )];
+        let resources = vec![Resource::new("Docs".into())];
+        let me = notes[1].uuid;
+
+        assert_eq!(
+            resolve("design", me, &notes, &tasks, &resources),
+            Some(LinkTarget::Note(notes[0].uuid))
+        );
+        assert_eq!(resolve("Other", me, &notes, &tasks, &resources), None);
+        assert_eq!(
+            resolve("task:1", me, &notes, &tasks, &resources),
+            Some(LinkTarget::Task(tasks[0].uuid))
+        );
+        assert_eq!(
+            resolve("resource:1", me, &notes, &tasks, &resources),
+            Some(LinkTarget::Resource(resources[0].uuid))
+        );
+        assert_eq!(resolve("task:2", me, &notes, &tasks, &resources), None);
+    }
+
+    #[test]
+    fn test_refresh_keeps_targets_and_retries_titles() {
+        let tasks = vec![
+            Task::new("A".into(), Priority::Medium, vec![], None, None, None),
+            Task::new("B".into(), Priority::Medium, vec![], None, None, None),
+        ];
+        let mut notes = vec![titled("Index", "See [[task:1]] and [[Later]]")];
+        assert_eq!(refresh(&mut notes, 0, &tasks, &[]), vec![0]);
+        assert_eq!(notes[0].links.len(), 1);
+        assert_eq!(notes[0].links[0].target, LinkTarget::Task(tasks[0].uuid));
+
+        // Task #1 is now B, but the stored link still points at A.
+        let shifted = vec![tasks[1].clone()];
+        notes.push(titled("Later", "body"));
+        assert_eq!(refresh(&mut notes, 1, &shifted, &[]), vec![0]);
+        assert_eq!(notes[0].links[0].target, LinkTarget::Task(tasks[0].uuid));
+        assert!(notes[0].links_to(LinkTarget::Note(notes[1].uuid)));
+    }
+
+    #[test]
+    fn test_rename_rewrites_inbound_references() {
+        let mut notes = vec![
+            titled("Design", "x"),
+            titled("Index", "See [[design]] and [[ design ]], not [[Designs]]"),
+        ];
+        refresh(&mut notes, 1, &[], &[]);
+        let target = notes[0].uuid;
+
+        assert_eq!(
+            rename_note(&mut notes, target, "Design", "Architecture"),
+            vec![1]
+        );
+        assert_eq!(
+            notes[1].body,
+            "See [[Architecture]] and [[Architecture]], not [[Designs]]"
+        );
+        assert_eq!(notes[1].links[0].reference, "Architecture");
+        assert!(refresh(&mut notes, 0, &[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_backlinks_and_describe() {
+        let mut notes = vec![titled("Design", "x"), titled("Index", "[[Design]]")];
+        refresh(&mut notes, 1, &[], &[]);
+        let target = LinkTarget::Note(notes[0].uuid);
+
+        let back = backlinks(&notes, target);
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].0, 2);
+        assert_eq!(
+            describe(target, &notes, &[], &[]),
+            Some(("note", 1, "Design".to_string()))
+        );
+        notes[0].soft_delete();
+        assert_eq!(describe(target, &notes, &[], &[]), None);
+    }
+}
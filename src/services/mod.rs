@@ -1,2 +1,12 @@
+pub mod diff;
+pub mod duplicates;
+pub mod forecast;
 pub mod holidays;
+pub mod journal;
+pub mod link_check;
+pub mod links;
+pub mod note_template;
+pub mod query;
+pub mod schedule;
 pub mod tag_service;
+pub mod vault;
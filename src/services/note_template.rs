@@ -0,0 +1,319 @@
+//! Named templates for `todo note add --template NAME`.
+//!
+//! A template is a markdown file, `<config dir>/note-templates/<name>.md`,
+//! whose optional front matter (see [`crate::services::vault`]) sets the
+//! new note's `title`, `tags`, `language`, `format` and `project`(s). The body
+//! and the title may use these placeholders:
+//!
+//! | Placeholder     | Replaced with |
+//! |---|---|
+//! | `{{date}}`      | today, `2026-03-14` |
+//! | `{{time}}`      | the current time, `09:30` |
+//! | `{{weekday}}`   | the day of the week, `Saturday` |
+//! | `{{title}}`     | `--title`, or else the text of the `--task` |
+//! | `{{project}}`   | the note's project paths, comma-separated |
+//! | `{{task.id}}`   | the visible ID of the (first) `--task` |
+//! | `{{task.text}}` | its text |
+//! | `{{task.due}}`  | its due date |
+//! | `{{task.tags}}` | its tags, comma-separated |
+//!
+//! Values that do not apply, such as `{{task.text}}` without `--task`, are
+//! left empty. The built-in `meeting`, `adr` and `bug` templates are used
+//! unless a file of the same name replaces them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Local};
+
+use crate::models::{NoteFormat, Task, substitute};
+use crate::services::vault::{self, FrontMatter};
+
+/// Templates available without any file, as `(name, contents)`.
+pub const BUILTIN: &[(&str, &str)] = &[
+    (
+        "adr",
+        "\
+---
+title: \"{{title}}\"
+tags: [adr]
+---
+# {{title}}
+
+- **Status:** proposed
+- **Date:** {{date}}
+
+## Context
+
+## Decision
+
+## Consequences
+",
+    ),
+    (
+        "bug",
+        "\
+---
+title: \"{{title}}\"
+tags: [bug]
+---
+# {{title}}
+
+## Steps to reproduce
+
+1.
+
+## Expected
+
+## Actual
+
+## Environment
+
+- Reported: {{date}}
+",
+    ),
+    (
+        "meeting",
+        "\
+---
+title: Meeting {{date}}
+tags: [meeting]
+---
+# Meeting — {{weekday}} {{date}}, {{time}}
+
+**Attendees:**
+
+## Agenda
+
+## Notes
+
+## Action items
+
+- [ ]
+",
+    ),
+];
+
+/// A note template, from a file or built in.
+#[derive(Debug, Clone)]
+pub struct NoteTemplate {
+    pub name: String,
+    pub front: FrontMatter,
+    pub body: String,
+    /// The file it was read from; `None` for built-in templates.
+    pub path: Option<PathBuf>,
+}
+
+impl NoteTemplate {
+    fn parse(name: &str, content: &str, path: Option<PathBuf>) -> Self {
+        let (front, body) = vault::parse(content);
+        Self {
+            name: name.to_string(),
+            front,
+            body,
+            path,
+        }
+    }
+
+    /// The format of notes made from this template; markdown by default.
+    pub fn format(&self) -> NoteFormat {
+        self.front.format.unwrap_or(NoteFormat::Markdown)
+    }
+
+    /// Fills in the title and body. An empty title is `None`.
+    ///
+    /// Fails with the name of an unknown placeholder.
+    pub fn render(
+        &self,
+        vars: &BTreeMap<String, String>,
+    ) -> Result<(Option<String>, String), String> {
+        let title = match &self.front.title {
+            Some(title) => Some(substitute(title, vars)?.trim().to_string()),
+            None => None,
+        };
+        let body = substitute(&self.body, vars)?;
+        Ok((title.filter(|t| !t.is_empty()), body))
+    }
+}
+
+/// What a template is applied to.
+#[derive(Debug, Clone, Default)]
+pub struct Subject<'a> {
+    pub title: Option<&'a str>,
+    pub project: Option<&'a str>,
+    /// The first linked task and its visible ID.
+    pub task: Option<(usize, &'a Task)>,
+}
+
+/// The placeholder values for `subject` at `now`.
+pub fn variables(now: DateTime<Local>, subject: &Subject) -> BTreeMap<String, String> {
+    let task = subject.task.map(|(_, t)| t);
+    let title = subject
+        .title
+        .or(task.map(|t| t.text.as_str()))
+        .unwrap_or_default();
+    BTreeMap::from([
+        ("date".to_string(), now.format("%Y-%m-%d").to_string()),
+        ("time".to_string(), now.format("%H:%M").to_string()),
+        ("weekday".to_string(), now.format("%A").to_string()),
+        ("title".to_string(), title.to_string()),
+        (
+            "project".to_string(),
+            subject.project.unwrap_or_default().to_string(),
+        ),
+        (
+            "task.id".to_string(),
+            subject.task.map_or(String::new(), |(id, _)| id.to_string()),
+        ),
+        (
+            "task.text".to_string(),
+            task.map_or(String::new(), |t| t.text.clone()),
+        ),
+        (
+            "task.due".to_string(),
+            task.and_then(|t| t.due_date)
+                .map_or(String::new(), |d| d.format("%Y-%m-%d").to_string()),
+        ),
+        (
+            "task.tags".to_string(),
+            task.map_or(String::new(), |t| t.tags.join(", ")),
+        ),
+    ])
+}
+
+/// Every template, sorted by name. Files in `dir` replace built-in
+/// templates of the same name, ignoring case; a missing directory has none.
+pub fn load_all(dir: &Path) -> Result<Vec<NoteTemplate>> {
+    let mut templates: BTreeMap<String, NoteTemplate> = BUILTIN
+        .iter()
+        .map(|(name, content)| (name.to_string(), NoteTemplate::parse(name, content, None)))
+        .collect();
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Ok(templates.into_values().collect());
+        }
+        Err(e) => return Err(e).context(format!("Cannot read {}", dir.display())),
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_file() || path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let content =
+            fs::read_to_string(&path).with_context(|| format!("Cannot read {}", path.display()))?;
+        let template = NoteTemplate::parse(name, &content, Some(path.clone()));
+        templates.insert(name.to_lowercase(), template);
+    }
+    Ok(templates.into_values().collect())
+}
+
+/// The template called `name` (case-insensitive).
+pub fn find(dir: &Path, name: &str) -> Result<NoteTemplate> {
+    let templates = load_all(dir)?;
+    let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+    let available = names.join(", ");
+    templates
+        .iter()
+        .find(|t| t.name.eq_ignore_ascii_case(name.trim()))
+        .cloned()
+        .ok_or_else(|| {
+            anyhow!(
+                "Note template '{}' not found (available: {})",
+                name,
+                available
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+    use chrono::{NaiveDate, TimeZone};
+    use tempfile::TempDir;
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 3, 14, 9, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_templates_render_without_context() {
+        let dir = TempDir::new().unwrap();
+        let templates = load_all(dir.path()).unwrap();
+        let names: Vec<_> = templates.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["adr", "bug", "meeting"]);
+
+        let vars = variables(now(), &Subject::default());
+        for template in &templates {
+            assert!(template.path.is_none());
+            assert!(template.render(&vars).is_ok(), "{}", template.name);
+        }
+        let (title, body) = templates[2].render(&vars).unwrap();
+        assert_eq!(title.as_deref(), Some("Meeting 2026-03-14"));
+        assert!(body.starts_with("# Meeting — Saturday 2026-03-14, 09:30"));
+        assert_eq!(templates[0].render(&vars).unwrap().0, None);
+    }
+
+    #[test]
+    fn test_task_variables() {
+        let task = Task::new(
+            "Pick a database".into(),
+            Priority::Medium,
+            vec!["infra".into(), "db".into()],
+            None,
+            NaiveDate::from_ymd_opt(2026, 4, 1),
+            None,
+        );
+        let subject = Subject {
+            title: None,
+            project: Some("acme.api"),
+            task: Some((12, &task)),
+        };
+        let vars = variables(now(), &subject);
+        assert_eq!(vars["title"], "Pick a database");
+        assert_eq!(vars["task.id"], "12");
+        assert_eq!(vars["task.due"], "2026-04-01");
+        assert_eq!(vars["task.tags"], "infra, db");
+        assert_eq!(vars["project"], "acme.api");
+
+        let titled = Subject {
+            title: Some("Use SQLite"),
+            ..subject
+        };
+        assert_eq!(variables(now(), &titled)["title"], "Use SQLite");
+    }
+
+    #[test]
+    fn test_files_replace_builtins_and_set_metadata() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("ADR.md"),
+            "---\ntitle: ADR {{task.id}}\nformat: plain\nlanguage: en\n---\n{{task.text}}\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("snippet.md"), "{{nope}}").unwrap();
+        fs::write(dir.path().join("readme.txt"), "ignored").unwrap();
+
+        let adr = find(dir.path(), "adr").unwrap();
+        assert_eq!(adr.path, Some(dir.path().join("ADR.md")));
+        assert_eq!(adr.format(), NoteFormat::Plain);
+        assert_eq!(adr.front.language.as_deref(), Some("en"));
+        assert_eq!(load_all(dir.path()).unwrap().len(), 4);
+
+        let vars = variables(now(), &Subject::default());
+        let snippet = find(dir.path(), "snippet").unwrap();
+        assert_eq!(snippet.format(), NoteFormat::Markdown);
+        assert_eq!(snippet.render(&vars).unwrap_err(), "nope");
+
+        let err = find(dir.path(), "readme").unwrap_err().to_string();
+        assert!(err.contains("available: ADR, bug, meeting, snippet"));
+    }
+}
@@ -0,0 +1,617 @@
+//! Filter expression language for selecting tasks.
+//!
+//! A query combines field predicates with `and`, `or`, `not` and
+//! parentheses. Adjacent terms without an operator are ANDed, so
+//! `project:api tag:bug` and `project:api and tag:bug` are equivalent.
+//!
+//! ```text
+//! project:api and (tag:bug or priority:high) and due.before:friday and not blocked
+//! ```
+//!
+//! | Term | Matches |
+//! |---|---|
+//! | `project:NAME` / `project:none`  | tasks in a project or its sub-projects (case-insensitive) / without one |
+//! | `tag:NAME` / `tag:none`          | tasks carrying a tag / without tags |
+//! | `status:S`                       | `pending`, `active`, `waiting`, `done`, `cancelled`, `all` |
+//! | `priority:P`                     | `high`, `medium`, `low` |
+//! | `due:W`                          | `overdue`, `soon`, `with-due`, `no-due`, or a date |
+//! | `due.before:D` / `due.after:D` / `due.on:D` | due-date comparisons; `D` is any `--due` date |
+//! | `created.before:D` / `created.after:D` / `created.on:D` | same, on the creation date |
+//! | `recur:R`                        | `daily`, `weekly`, `monthly`, `recurring`, `none` |
+//! | `text:WORD`                      | substring of the task text |
+//! | `blocked`, `ready`, `overdue`, `recurring` | status keywords |
+//! | any other word or `"quoted phrase"` | substring of the task text |
+//!
+//! Parse errors carry the column of the offending token so callers can
+//! point at it (see [`TodoError::InvalidFilter`]).
+
+use chrono::NaiveDate;
+use clap::ValueEnum;
+
+use crate::error::TodoError;
+use crate::models::{DueFilter, Priority, Project, Recurrence, StatusFilter, Task};
+use crate::utils::date_parser::parse_date;
+
+/// A parsed filter expression, ready to be evaluated against tasks.
+#[derive(Debug, Clone)]
+pub struct Query {
+    expr: Expr,
+    source: String,
+}
+
+/// Data a query may need beyond the task itself.
+pub struct QueryContext<'a> {
+    /// Every task, used to decide whether a task is blocked.
+    pub tasks: &'a [Task],
+    /// Every project, used to resolve `project:` names.
+    pub projects: &'a [Project],
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Pred(Predicate),
+}
+
+#[derive(Debug, Clone)]
+enum Predicate {
+    Project(Option<String>),
+    Tag(Option<String>),
+    Status(StatusFilter),
+    Priority(Priority),
+    Due(DueFilter),
+    DueCmp(DateCmp, NaiveDate),
+    CreatedCmp(DateCmp, NaiveDate),
+    Recur(Option<Recurrence>),
+    Recurring,
+    Text(String),
+    Blocked,
+    Ready,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DateCmp {
+    Before,
+    After,
+    On,
+}
+
+impl DateCmp {
+    fn test(self, value: NaiveDate, bound: NaiveDate) -> bool {
+        match self {
+            DateCmp::Before => value < bound,
+            DateCmp::After => value > bound,
+            DateCmp::On => value == bound,
+        }
+    }
+}
+
+impl Query {
+    /// Parses a filter expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rustodo::services::query::Query;
+    ///
+    /// assert!(Query::parse("project:api and (tag:bug or priority:high)").is_ok());
+    /// assert!(Query::parse("tag:bug or").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, TodoError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            input,
+            tokens,
+            pos: 0,
+        };
+        let expr = parser.parse_or()?;
+        if let Some(tok) = parser.peek() {
+            let reason = match tok.kind {
+                TokenKind::Close => "unmatched ')'".to_string(),
+                _ => format!("unexpected '{}'", tok.text),
+            };
+            return Err(parser.error_at(tok.start, reason));
+        }
+        Ok(Self {
+            expr,
+            source: input.trim().to_string(),
+        })
+    }
+
+    /// The expression as typed (trimmed).
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns `true` when `task` satisfies the expression.
+    pub fn matches(&self, task: &Task, ctx: &QueryContext) -> bool {
+        eval(&self.expr, task, ctx)
+    }
+}
+
+fn eval(expr: &Expr, task: &Task, ctx: &QueryContext) -> bool {
+    match expr {
+        Expr::And(a, b) => eval(a, task, ctx) && eval(b, task, ctx),
+        Expr::Or(a, b) => eval(a, task, ctx) || eval(b, task, ctx),
+        Expr::Not(e) => !eval(e, task, ctx),
+        Expr::Pred(p) => p.matches(task, ctx),
+    }
+}
+
+impl Predicate {
+    fn matches(&self, task: &Task, ctx: &QueryContext) -> bool {
+        match self {
+            Predicate::Project(name) => {
+                let project = task
+                    .project_id
+                    .and_then(|pid| ctx.projects.iter().find(|p| p.uuid == pid))
+                    .filter(|p| !p.is_deleted());
+                match (name, project) {
+                    (None, p) => p.is_none(),
+                    // Matches the project itself or any ancestor, by name or
+                    // dotted path, so `project:acme` covers `acme.api` too.
+                    (Some(name), Some(p)) => p.ancestry(ctx.projects).iter().any(|a| {
+                        a.name.eq_ignore_ascii_case(name)
+                            || a.path(ctx.projects).eq_ignore_ascii_case(name)
+                    }),
+                    (Some(_), None) => false,
+                }
+            }
+            Predicate::Tag(None) => task.tags.is_empty(),
+            Predicate::Tag(Some(tag)) => task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            Predicate::Status(s) => task.matches_status(*s),
+            Predicate::Priority(p) => task.priority == *p,
+            Predicate::Due(d) => task.matches_due_filter(*d),
+            Predicate::DueCmp(cmp, date) => task.due_date.is_some_and(|d| cmp.test(d, *date)),
+            Predicate::CreatedCmp(cmp, date) => cmp.test(task.created_at.date_naive(), *date),
+            Predicate::Recur(r) => task.recurrence == *r,
+            Predicate::Recurring => task.recurrence.is_some(),
+            Predicate::Text(text) => task.text.to_lowercase().contains(text),
+            Predicate::Blocked => task.is_blocked(ctx.tasks),
+            Predicate::Ready => task.is_open() && !task.is_blocked(ctx.tasks),
+        }
+    }
+}
+
+// ── Tokenizer ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Term,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    /// The term with quotes removed.
+    text: String,
+    /// Byte offset of the token in the input.
+    start: usize,
+    /// `true` if the term starts with a quote: a text phrase, never a
+    /// keyword or `field:value`.
+    quoted: bool,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, TodoError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c == '(' || c == ')' {
+            chars.next();
+            tokens.push(Token {
+                kind: if c == '(' {
+                    TokenKind::Open
+                } else {
+                    TokenKind::Close
+                },
+                text: c.to_string(),
+                start,
+                quoted: false,
+            });
+            continue;
+        }
+
+        let mut text = String::new();
+        let quoted = c == '"';
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() || c == '(' || c == ')' {
+                break;
+            }
+            chars.next();
+            if c == '"' {
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, c)) => text.push(c),
+                        None => return Err(error_at(input, i, "unterminated quote")),
+                    }
+                }
+            } else {
+                text.push(c);
+            }
+        }
+
+        let kind = match text.to_lowercase().as_str() {
+            _ if quoted => TokenKind::Term,
+            "and" | "&&" => TokenKind::And,
+            "or" | "||" => TokenKind::Or,
+            "not" | "!" => TokenKind::Not,
+            _ => TokenKind::Term,
+        };
+        tokens.push(Token {
+            kind,
+            text,
+            start,
+            quoted,
+        });
+    }
+    Ok(tokens)
+}
+
+fn error_at(input: &str, byte: usize, reason: impl Into<String>) -> TodoError {
+    // Columns count characters past the leading whitespace that
+    // `InvalidFilter` trims off when echoing the input.
+    let leading = input.len() - input.trim_start().len();
+    TodoError::InvalidFilter {
+        input: input.trim().to_string(),
+        column: input[leading..byte.max(leading)].chars().count(),
+        reason: reason.into(),
+    }
+}
+
+// ── Parser ────────────────────────────────────────────────────────────────────
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn error_at(&self, byte: usize, reason: impl Into<String>) -> TodoError {
+        error_at(self.input, byte, reason)
+    }
+
+    fn end(&self) -> usize {
+        self.input.trim_end().len()
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, TodoError> {
+        let mut left = self.parse_and()?;
+        while self.peek().is_some_and(|t| t.kind == TokenKind::Or) {
+            self.next();
+            let right = self.parse_and()?;
+            left = Expr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, TodoError> {
+        let mut left = self.parse_unary()?;
+        loop {
+            match self.peek().map(|t| t.kind) {
+                Some(TokenKind::And) => {
+                    self.next();
+                }
+                // Juxtaposed terms are an implicit `and`.
+                Some(TokenKind::Term | TokenKind::Not | TokenKind::Open) => {}
+                _ => break,
+            }
+            let right = self.parse_unary()?;
+            left = Expr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, TodoError> {
+        let Some(tok) = self.next() else {
+            return Err(self.error_at(self.end(), self.expected_term()));
+        };
+        match tok.kind {
+            TokenKind::Not => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            TokenKind::Open => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(t) if t.kind == TokenKind::Close => Ok(inner),
+                    _ => Err(self.error_at(tok.start, "missing closing ')'")),
+                }
+            }
+            TokenKind::Term => self.parse_term(&tok).map(Expr::Pred),
+            TokenKind::Close | TokenKind::And | TokenKind::Or => Err(self.error_at(
+                tok.start,
+                format!("{} before '{}'", self.expected_term(), tok.text),
+            )),
+        }
+    }
+
+    fn expected_term(&self) -> String {
+        match self.pos.checked_sub(2).and_then(|i| self.tokens.get(i)) {
+            Some(prev) if prev.kind != TokenKind::Term && prev.kind != TokenKind::Close => {
+                format!("expected a filter term after '{}'", prev.text)
+            }
+            _ => "expected a filter term".to_string(),
+        }
+    }
+
+    fn parse_term(&self, tok: &Token) -> Result<Predicate, TodoError> {
+        let err = |reason: String| self.error_at(tok.start, reason);
+
+        let Some((field, value)) = tok.text.split_once(':').filter(|_| !tok.quoted) else {
+            let word = tok.text.to_lowercase();
+            return Ok(match word.as_str() {
+                _ if tok.quoted => Predicate::Text(word),
+                "blocked" => Predicate::Blocked,
+                "ready" => Predicate::Ready,
+                "overdue" => Predicate::Due(DueFilter::Overdue),
+                "recurring" => Predicate::Recurring,
+                _ => Predicate::Text(word),
+            });
+        };
+
+        let field = field.to_lowercase();
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(err(format!("missing value after '{}:'", field)));
+        }
+        let lower = value.to_lowercase();
+        let (name, modifier) = match field.split_once('.') {
+            Some((n, m)) => (n, Some(m)),
+            None => (field.as_str(), None),
+        };
+
+        let date = |v: &str| -> Result<NaiveDate, TodoError> {
+            parse_date(v).map_err(|_| {
+                err(format!(
+                    "invalid date '{}' (try today, friday, in 3 days or YYYY-MM-DD)",
+                    v
+                ))
+            })
+        };
+        let cmp = |m: Option<&str>| -> Result<DateCmp, TodoError> {
+            match m {
+                Some("before") => Ok(DateCmp::Before),
+                Some("after") => Ok(DateCmp::After),
+                Some("on") | None => Ok(DateCmp::On),
+                Some(other) => Err(err(format!(
+                    "unknown modifier '{}.{}' (expected before, after or on)",
+                    name, other
+                ))),
+            }
+        };
+        let no_modifier = || -> Result<(), TodoError> {
+            match modifier {
+                Some(m) => Err(err(format!("'{}' does not take a '.{}' modifier", name, m))),
+                None => Ok(()),
+            }
+        };
+
+        let pred = match name {
+            "project" | "proj" => {
+                no_modifier()?;
+                Predicate::Project((lower != "none").then(|| value.to_string()))
+            }
+            "tag" => {
+                no_modifier()?;
+                Predicate::Tag((lower != "none").then(|| value.to_string()))
+            }
+            "status" => {
+                no_modifier()?;
+                Predicate::Status(value_enum(&lower).ok_or_else(|| {
+                    err(format!(
+                        "unknown status '{}' (expected {})",
+                        value,
+                        variants::<StatusFilter>()
+                    ))
+                })?)
+            }
+            "priority" | "pri" => {
+                no_modifier()?;
+                Predicate::Priority(value_enum(&lower).ok_or_else(|| {
+                    err(format!(
+                        "unknown priority '{}' (expected {})",
+                        value,
+                        variants::<Priority>()
+                    ))
+                })?)
+            }
+            "due" => match (modifier, value_enum::<DueFilter>(&lower)) {
+                (None, Some(window)) => Predicate::Due(window),
+                _ => Predicate::DueCmp(cmp(modifier)?, date(value)?),
+            },
+            "created" => Predicate::CreatedCmp(cmp(modifier)?, date(value)?),
+            "recur" | "recurrence" => {
+                no_modifier()?;
+                match lower.as_str() {
+                    "none" | "non-recurring" => Predicate::Recur(None),
+                    "recurring" | "any" => Predicate::Recurring,
+                    _ => Predicate::Recur(Some(value_enum(&lower).ok_or_else(|| {
+                        err(format!(
+                            "unknown recurrence '{}' (expected daily, weekly, monthly, recurring or none)",
+                            value
+                        ))
+                    })?)),
+                }
+            }
+            "text" => {
+                no_modifier()?;
+                Predicate::Text(lower)
+            }
+            _ => {
+                return Err(err(format!(
+                    "unknown field '{}' (expected project, tag, status, priority, due, created, recur or text)",
+                    name
+                )));
+            }
+        };
+        Ok(pred)
+    }
+}
+
+fn value_enum<T: ValueEnum>(value: &str) -> Option<T> {
+    T::from_str(value, true).ok()
+}
+
+fn variants<T: ValueEnum>() -> String {
+    T::value_variants()
+        .iter()
+        .filter_map(|v| v.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Local};
+
+    fn task(text: &str, tags: &[&str], priority: Priority) -> Task {
+        Task::new(
+            text.into(),
+            priority,
+            tags.iter().map(|t| t.to_string()).collect(),
+            None,
+            None,
+            None,
+        )
+    }
+
+    fn matching(query: &str, tasks: &[Task], projects: &[Project]) -> Vec<String> {
+        let q = Query::parse(query).unwrap();
+        let ctx = QueryContext { tasks, projects };
+        tasks
+            .iter()
+            .filter(|t| q.matches(t, &ctx))
+            .map(|t| t.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_boolean_operators_and_precedence() {
+        let tasks = vec![
+            task("A", &["bug"], Priority::Low),
+            task("B", &["feature"], Priority::High),
+            task("C", &["feature"], Priority::Low),
+        ];
+        assert_eq!(
+            matching("tag:bug or priority:high", &tasks, &[]),
+            ["A", "B"]
+        );
+        assert_eq!(matching("tag:feature priority:low", &tasks, &[]), ["C"]);
+        assert_eq!(matching("not tag:bug and priority:low", &tasks, &[]), ["C"]);
+        // `and` binds tighter than `or`.
+        assert_eq!(
+            matching("tag:bug or tag:feature and priority:high", &tasks, &[]),
+            ["A", "B"]
+        );
+        assert_eq!(
+            matching("(tag:bug or tag:feature) and priority:low", &tasks, &[]),
+            ["A", "C"]
+        );
+    }
+
+    #[test]
+    fn test_project_blocked_and_dates() {
+        let project = Project::new("api".into());
+        let dep = task("Dep", &[], Priority::Medium);
+        let mut blocked = task("Blocked", &[], Priority::Medium);
+        blocked.depends_on = vec![dep.uuid];
+        blocked.project_id = Some(project.uuid);
+        blocked.due_date = Some(Local::now().date_naive() + Duration::days(2));
+        let tasks = vec![dep, blocked];
+        let projects = [project];
+
+        assert_eq!(matching("blocked", &tasks, &projects), ["Blocked"]);
+        assert_eq!(matching("not blocked", &tasks, &projects), ["Dep"]);
+        assert_eq!(matching("project:API", &tasks, &projects), ["Blocked"]);
+        assert_eq!(matching("project:none", &tasks, &projects), ["Dep"]);
+        assert_eq!(
+            matching("due.before:\"in 3 days\"", &tasks, &projects),
+            ["Blocked"]
+        );
+        assert!(matching("due.after:\"in 3 days\"", &tasks, &projects).is_empty());
+        assert_eq!(matching("due:with-due", &tasks, &projects), ["Blocked"]);
+    }
+
+    #[test]
+    fn test_project_matches_sub_projects() {
+        let acme = Project::new("acme".into());
+        let mut api = Project::new("api".into());
+        api.parent_id = Some(acme.uuid);
+        let mut top = task("Top", &[], Priority::Medium);
+        top.project_id = Some(acme.uuid);
+        let mut nested = task("Nested", &[], Priority::Medium);
+        nested.project_id = Some(api.uuid);
+        let tasks = vec![top, nested];
+        let projects = [acme, api];
+
+        assert_eq!(
+            matching("project:acme", &tasks, &projects),
+            ["Top", "Nested"]
+        );
+        assert_eq!(matching("project:acme.api", &tasks, &projects), ["Nested"]);
+        assert_eq!(matching("project:api", &tasks, &projects), ["Nested"]);
+    }
+
+    #[test]
+    fn test_text_terms_and_quotes() {
+        let tasks = vec![
+            task("Fix login page", &[], Priority::Medium),
+            task("Review blocked PRs", &[], Priority::Medium),
+        ];
+        assert_eq!(matching("\"login page\"", &tasks, &[]), ["Fix login page"]);
+        assert_eq!(
+            matching("text:\"blocked PRs\"", &tasks, &[]),
+            ["Review blocked PRs"]
+        );
+        assert_eq!(matching("fix LOGIN", &tasks, &[]), ["Fix login page"]);
+    }
+
+    #[test]
+    fn test_errors_point_at_offending_token() {
+        let column = |q: &str| match Query::parse(q).unwrap_err() {
+            TodoError::InvalidFilter { column, reason, .. } => (column, reason),
+            other => panic!("unexpected error {other}"),
+        };
+
+        let (col, reason) = column("tag:bug and colour:red");
+        assert_eq!(col, 12);
+        assert!(reason.contains("unknown field 'colour'"));
+
+        let (col, reason) = column("(tag:bug or tag:x");
+        assert_eq!(col, 0);
+        assert!(reason.contains("missing closing ')'"));
+
+        let (_, reason) = column("tag:bug or");
+        assert!(reason.contains("after 'or'"));
+
+        let (col, _) = column("status:bogus");
+        assert_eq!(col, 0);
+        assert!(column("tag:bug)").1.contains("unmatched"));
+        assert!(column("due.soon:x").1.contains("unknown modifier"));
+        assert!(column("text:\"open").1.contains("unterminated"));
+        assert!(column("tag:").1.contains("missing value"));
+    }
+}
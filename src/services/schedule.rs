@@ -0,0 +1,380 @@
+//! Critical path analysis for `todo plan`.
+//!
+//! Open tasks are scheduled as early as their dependencies allow, starting
+//! today, using each task's `estimate` in days ([`DEFAULT_ESTIMATE`] when
+//! unset). A backward pass from the overall finish gives every task its
+//! latest start; the difference is its slack. Tasks with zero slack form the
+//! critical path — delaying any of them delays the whole plan.
+//!
+//! Done and cancelled prerequisites are treated as satisfied. Dates are
+//! calendar days; weekends and holidays are not skipped.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, NaiveDate};
+use uuid::Uuid;
+
+use crate::error::TodoError;
+use crate::models::{Task, detect_cycle};
+
+/// Days assumed for a task without an estimate.
+pub const DEFAULT_ESTIMATE: u32 = 1;
+
+/// Schedule of a single task, in days counted from the plan start.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedTask {
+    /// Index into the task slice given to [`plan`].
+    pub index: usize,
+    pub duration: u32,
+    /// `false` when [`DEFAULT_ESTIMATE`] was assumed.
+    pub estimated: bool,
+    pub earliest_start: u32,
+    pub earliest_finish: u32,
+    pub latest_start: u32,
+    pub slack: u32,
+}
+
+impl PlannedTask {
+    pub fn is_critical(&self) -> bool {
+        self.slack == 0
+    }
+
+    /// First day of work when starting as early as possible.
+    ///
+    /// A zero-length task (a milestone) starts on the day it finishes.
+    pub fn start_date(&self, start: NaiveDate) -> NaiveDate {
+        if self.duration == 0 {
+            return self.finish_date(start);
+        }
+        add_days(start, self.earliest_start)
+    }
+
+    /// Last day of work when starting as early as possible.
+    ///
+    /// A zero-length task finishes on the day its prerequisites do.
+    pub fn finish_date(&self, start: NaiveDate) -> NaiveDate {
+        add_days(start, self.earliest_finish.max(1) - 1)
+    }
+}
+
+/// `start` plus `days`, capped at the last representable date.
+fn add_days(start: NaiveDate, days: u32) -> NaiveDate {
+    start
+        .checked_add_signed(Duration::days(days as i64))
+        .unwrap_or(NaiveDate::MAX)
+}
+
+/// A task due before one of its (direct or indirect) prerequisites.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DueConflict {
+    /// Index of the task with the earlier due date.
+    pub task: usize,
+    /// Index of the prerequisite due last.
+    pub prerequisite: usize,
+}
+
+/// Result of [`plan`].
+#[derive(Debug, Clone, Default)]
+pub struct Plan {
+    /// Scheduled tasks in dependency order.
+    pub tasks: Vec<PlannedTask>,
+    /// One chain of critical tasks from the first to the last, as indices.
+    pub critical_path: Vec<usize>,
+    /// Days needed to finish every task in the plan.
+    pub total_days: u32,
+    pub conflicts: Vec<DueConflict>,
+}
+
+impl Plan {
+    /// Earliest date by which every task can be finished.
+    pub fn finish_date(&self, start: NaiveDate) -> NaiveDate {
+        add_days(start, self.total_days.max(1) - 1)
+    }
+
+    /// Returns the schedule of the task at `index`, if it is in the plan.
+    pub fn get(&self, index: usize) -> Option<&PlannedTask> {
+        self.tasks.iter().find(|p| p.index == index)
+    }
+}
+
+/// Schedules the open tasks of `project` (all open tasks when `None`).
+///
+/// Open prerequisites from other projects are pulled into the plan, since
+/// the project cannot finish before they do.
+///
+/// # Errors
+///
+/// Returns `TodoError::DependencyCycle` if the tasks depend on each other in
+/// a loop, as no schedule exists then.
+pub fn plan(tasks: &[Task], project: Option<Uuid>) -> Result<Plan, TodoError> {
+    let visible: Vec<Task> = tasks.iter().filter(|t| !t.is_deleted()).cloned().collect();
+    let index_of: HashMap<Uuid, usize> = tasks
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.is_open() && !t.is_deleted())
+        .map(|(i, t)| (t.uuid, i))
+        .collect();
+    let deps = |i: usize| -> Vec<usize> {
+        tasks[i]
+            .depends_on
+            .iter()
+            .filter_map(|d| index_of.get(d).copied())
+            .collect()
+    };
+
+    // ── Scope: the project's open tasks plus their open prerequisites ─────────
+    let mut scope: HashSet<usize> = HashSet::new();
+    let mut stack: Vec<usize> = index_of
+        .values()
+        .copied()
+        .filter(|&i| project.is_none_or(|p| tasks[i].project_id == Some(p)))
+        .collect();
+    while let Some(i) = stack.pop() {
+        if scope.insert(i) {
+            stack.extend(deps(i));
+        }
+    }
+
+    for &i in &scope {
+        for d in deps(i) {
+            if detect_cycle(&visible, tasks[i].uuid, tasks[d].uuid).is_err() {
+                return Err(TodoError::DependencyCycle(format!(
+                    "task #{} → task #{} → ... → task #{}",
+                    visible_id(tasks, i),
+                    visible_id(tasks, d),
+                    visible_id(tasks, i)
+                )));
+            }
+        }
+    }
+
+    // ── Topological order (Kahn), lowest index first for stable output ────────
+    let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut pending: HashMap<usize, usize> = HashMap::new();
+    for &i in &scope {
+        let d = deps(i);
+        pending.insert(i, d.len());
+        for dep in d {
+            dependents.entry(dep).or_default().push(i);
+        }
+    }
+    let mut ready: Vec<usize> = pending
+        .iter()
+        .filter(|(_, n)| **n == 0)
+        .map(|(&i, _)| i)
+        .collect();
+    let mut order = Vec::with_capacity(scope.len());
+    while !ready.is_empty() {
+        ready.sort_unstable_by(|a, b| b.cmp(a));
+        let i = ready.pop().unwrap();
+        order.push(i);
+        for &next in dependents.get(&i).into_iter().flatten() {
+            let n = pending.get_mut(&next).unwrap();
+            *n -= 1;
+            if *n == 0 {
+                ready.push(next);
+            }
+        }
+    }
+
+    // ── Forward pass ──────────────────────────────────────────────────────────
+    let mut planned: HashMap<usize, PlannedTask> = HashMap::new();
+    for &i in &order {
+        let earliest_start = deps(i)
+            .iter()
+            .map(|d| planned[d].earliest_finish)
+            .max()
+            .unwrap_or(0);
+        let duration = tasks[i].estimate.unwrap_or(DEFAULT_ESTIMATE);
+        planned.insert(
+            i,
+            PlannedTask {
+                index: i,
+                duration,
+                estimated: tasks[i].estimate.is_some(),
+                earliest_start,
+                earliest_finish: earliest_start.saturating_add(duration),
+                latest_start: 0,
+                slack: 0,
+            },
+        );
+    }
+    let total_days = planned
+        .values()
+        .map(|p| p.earliest_finish)
+        .max()
+        .unwrap_or(0);
+
+    // ── Backward pass ─────────────────────────────────────────────────────────
+    for &i in order.iter().rev() {
+        let latest_finish = dependents
+            .get(&i)
+            .into_iter()
+            .flatten()
+            .map(|d| planned[d].latest_start)
+            .min()
+            .unwrap_or(total_days);
+        let p = planned.get_mut(&i).unwrap();
+        p.latest_start = latest_finish.saturating_sub(p.duration);
+        p.slack = p.latest_start.saturating_sub(p.earliest_start);
+    }
+
+    // ── Critical path: walk back from the last critical task to finish ────────
+    let mut critical_path = Vec::new();
+    let mut current = order
+        .iter()
+        .rev()
+        .copied()
+        .find(|i| planned[i].is_critical() && planned[i].earliest_finish == total_days);
+    while let Some(i) = current {
+        critical_path.push(i);
+        let start = planned[&i].earliest_start;
+        current = deps(i)
+            .into_iter()
+            .filter(|d| planned[d].is_critical() && planned[d].earliest_finish == start)
+            .min();
+    }
+    critical_path.reverse();
+
+    // ── Due dates earlier than a prerequisite's ───────────────────────────────
+    let mut conflicts = Vec::new();
+    for &i in &order {
+        let Some(due) = tasks[i].due_date else {
+            continue;
+        };
+        let mut seen = HashSet::new();
+        let mut stack = deps(i);
+        let mut latest: Option<usize> = None;
+        while let Some(d) = stack.pop() {
+            if !seen.insert(d) {
+                continue;
+            }
+            if tasks[d].due_date.is_some_and(|dd| dd > due)
+                && latest.is_none_or(|l| tasks[d].due_date > tasks[l].due_date)
+            {
+                latest = Some(d);
+            }
+            stack.extend(deps(d));
+        }
+        if let Some(prerequisite) = latest {
+            conflicts.push(DueConflict {
+                task: i,
+                prerequisite,
+            });
+        }
+    }
+
+    Ok(Plan {
+        tasks: order.iter().map(|i| planned[i].clone()).collect(),
+        critical_path,
+        total_days,
+        conflicts,
+    })
+}
+
+fn visible_id(tasks: &[Task], index: usize) -> usize {
+    tasks[..index].iter().filter(|t| !t.is_deleted()).count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn task(text: &str, estimate: u32) -> Task {
+        let mut t = Task::new(text.into(), Priority::Medium, vec![], None, None, None);
+        t.estimate = Some(estimate);
+        t
+    }
+
+    fn day(d: u32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(2099, 1, d)
+    }
+
+    /// Design(3) → Build(5) → Ship(1), with Docs(2) after Design and
+    /// before Ship.
+    fn project() -> Vec<Task> {
+        let design = task("Design", 3);
+        let mut build = task("Build", 5);
+        build.depends_on = vec![design.uuid];
+        let mut docs = task("Docs", 2);
+        docs.depends_on = vec![design.uuid];
+        let mut ship = task("Ship", 1);
+        ship.depends_on = vec![build.uuid, docs.uuid];
+        vec![design, build, docs, ship]
+    }
+
+    #[test]
+    fn test_critical_path_and_slack() {
+        let plan = plan(&project(), None).unwrap();
+        assert_eq!(plan.total_days, 9);
+        assert_eq!(plan.critical_path, vec![0, 1, 3]);
+        assert_eq!(plan.get(2).unwrap().slack, 3);
+        assert!(!plan.get(2).unwrap().is_critical());
+
+        let start = NaiveDate::from_ymd_opt(2099, 1, 1).unwrap();
+        assert_eq!(plan.get(3).unwrap().start_date(start), day(9).unwrap());
+        assert_eq!(plan.finish_date(start), day(9).unwrap());
+    }
+
+    #[test]
+    fn test_done_tasks_are_satisfied_and_default_estimate_applies() {
+        let mut tasks = project();
+        tasks[0].mark_done();
+        tasks[1].estimate = None;
+        let plan = plan(&tasks, None).unwrap();
+        assert!(plan.get(0).is_none());
+        assert!(!plan.get(1).unwrap().estimated);
+        assert_eq!(plan.critical_path, vec![2, 3]);
+        assert_eq!(plan.total_days, 3);
+    }
+
+    #[test]
+    fn test_project_scope_pulls_in_prerequisites() {
+        let p = Uuid::new_v4();
+        let mut tasks = project();
+        tasks[1].project_id = Some(p);
+        tasks.push(task("Unrelated", 10));
+        let plan = plan(&tasks, Some(p)).unwrap();
+        let scheduled: Vec<usize> = plan.tasks.iter().map(|t| t.index).collect();
+        assert_eq!(scheduled, vec![0, 1]);
+        assert_eq!(plan.total_days, 8);
+    }
+
+    #[test]
+    fn test_due_conflicts_follow_indirect_prerequisites() {
+        let mut tasks = project();
+        tasks[0].due_date = day(20);
+        tasks[3].due_date = day(10);
+        let plan = plan(&tasks, None).unwrap();
+        assert_eq!(
+            plan.conflicts,
+            vec![DueConflict {
+                task: 3,
+                prerequisite: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn test_cycle_is_an_error() {
+        let mut tasks = project();
+        tasks[0].depends_on = vec![tasks[3].uuid];
+        assert!(matches!(
+            plan(&tasks, None),
+            Err(TodoError::DependencyCycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_huge_estimates_saturate_instead_of_overflowing() {
+        let mut tasks = project();
+        tasks[0].estimate = Some(4_000_000_000);
+        tasks[1].estimate = Some(4_000_000_000);
+        let plan = plan(&tasks, None).unwrap();
+        assert_eq!(plan.total_days, u32::MAX);
+        let start = NaiveDate::from_ymd_opt(2099, 1, 1).unwrap();
+        assert_eq!(plan.finish_date(start), NaiveDate::MAX);
+        assert_eq!(plan.get(3).unwrap().start_date(start), NaiveDate::MAX);
+    }
+}
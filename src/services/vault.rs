@@ -0,0 +1,577 @@
+//! Markdown vault format for notes.
+//!
+//! Each note is one `.md` file, named after its title and UUID
+//! (`design-notes-6f1c….md`), with its metadata in YAML front matter:
+//!
+//! ```text
+//! ---
+//! id: 6f1c2a0e-…
+//! title: Design notes
+//! tags:
+//!   - rust
+//! language: rust
+//! project: acme.api
+//! task: 0b7d…  # Write spec
+//! resources:
+//!   - 9e21…  # Rust book
+//! created: 2026-01-05T09:30:00+00:00
+//! updated: 2026-01-06T17:02:11+00:00
+//! ---
+//!
+//! Body…
+//! ```
+//!
+//! A note linked to several projects or tasks gets `projects:` / `tasks:`
+//! lists instead; both spellings are read.
+//!
+//! Only flat keys with scalar or list values are read, which covers what
+//! editors such as Obsidian write; other keys are ignored. A file without
+//! `id` is a new note, titled after the file name when it has no `title`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use uuid::Uuid;
+
+use crate::models::{Note, NoteFormat, Project, Resource, Task};
+
+// ── FrontMatter ───────────────────────────────────────────────────────────────
+
+/// The metadata block of a vault file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrontMatter {
+    pub id: Option<Uuid>,
+    pub title: Option<String>,
+    pub tags: Vec<String>,
+    pub language: Option<String>,
+    pub format: Option<NoteFormat>,
+    /// Dotted project paths, created on import when missing.
+    pub projects: Vec<String>,
+    pub tasks: Vec<Uuid>,
+    pub resources: Vec<Uuid>,
+    pub created: Option<DateTime<Utc>>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+/// A `.md` file read from a vault directory.
+#[derive(Debug, Clone)]
+pub struct VaultFile {
+    pub path: PathBuf,
+    pub front: FrontMatter,
+    pub body: String,
+    /// Last modification time of the file.
+    pub modified: DateTime<Utc>,
+}
+
+impl VaultFile {
+    /// Title from the front matter, or else from the file name, without the
+    /// UUID suffix `file_name` adds.
+    pub fn title(&self) -> Option<String> {
+        if self.front.title.is_some() {
+            return self.front.title.clone();
+        }
+        let stem = self.path.file_stem()?.to_str()?;
+        let stem = match self.front.id {
+            Some(id) => stem
+                .strip_suffix(&id.to_string())
+                .unwrap_or(stem)
+                .trim_end_matches('-'),
+            None => stem,
+        };
+        (!stem.is_empty()).then(|| stem.to_string())
+    }
+}
+
+// ── Writing ───────────────────────────────────────────────────────────────────
+
+/// Lowercase ASCII words joined by `-`, at most 60 characters.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::services::vault::slug;
+///
+/// assert_eq!(slug("Design notes: v2 (draft)"), "design-notes-v2-draft");
+/// assert_eq!(slug("¿Qué?"), "qu");
+/// ```
+pub fn slug(text: &str) -> String {
+    let mut out = String::new();
+    for word in text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if out.len() + word.len() >= 60 {
+            break;
+        }
+        if !out.is_empty() {
+            out.push('-');
+        }
+        out.push_str(&word.to_ascii_lowercase());
+    }
+    out
+}
+
+/// File name for a note: the slug of its title (or first line) plus its
+/// UUID, so renaming a note never makes two files collide.
+pub fn file_name(note: &Note) -> String {
+    let label = note
+        .title
+        .as_deref()
+        .unwrap_or_else(|| note.body.lines().next().unwrap_or(""));
+    match slug(label) {
+        s if s.is_empty() => format!("{}.md", note.uuid),
+        s => format!("{}-{}.md", s, note.uuid),
+    }
+}
+
+/// Renders a note as a vault file: front matter, a blank line, the body.
+///
+/// Task and resource UUIDs are followed by a comment naming them.
+pub fn render(note: &Note, projects: &[Project], tasks: &[Task], resources: &[Resource]) -> String {
+    let mut out = String::from("---\n");
+    out.push_str(&format!("id: {}\n", note.uuid));
+    if let Some(ref title) = note.title {
+        out.push_str(&format!("title: {}\n", scalar(title)));
+    }
+    if !note.tags.is_empty() {
+        out.push_str("tags:\n");
+        for tag in &note.tags {
+            // Quoted with a space in it, so other tools keep it one tag.
+            let tag = match tag.contains(' ') {
+                true => serde_json::to_string(tag).unwrap_or_default(),
+                false => scalar(tag),
+            };
+            out.push_str(&format!("  - {}\n", tag));
+        }
+    }
+    if let Some(ref language) = note.language {
+        out.push_str(&format!("language: {}\n", scalar(language)));
+    }
+    if note.format == NoteFormat::Plain {
+        out.push_str("format: plain\n");
+    }
+    let paths: Vec<String> = note
+        .project_ids
+        .iter()
+        .filter_map(|id| projects.iter().find(|p| p.uuid == *id))
+        .map(|p| scalar(&p.path(projects)))
+        .collect();
+    match paths.as_slice() {
+        [] => {}
+        [path] => out.push_str(&format!("project: {}\n", path)),
+        paths => {
+            out.push_str("projects:\n");
+            for path in paths {
+                out.push_str(&format!("  - {}\n", path));
+            }
+        }
+    }
+    let task_text = |id: &Uuid| {
+        tasks
+            .iter()
+            .find(|t| t.uuid == *id)
+            .map(|t| t.text.as_str())
+    };
+    match note.task_ids.as_slice() {
+        [] => {}
+        [id] => out.push_str(&format!("task: {}{}\n", id, comment(task_text(id)))),
+        ids => {
+            out.push_str("tasks:\n");
+            for id in ids {
+                out.push_str(&format!("  - {}{}\n", id, comment(task_text(id))));
+            }
+        }
+    }
+    if !note.resource_ids.is_empty() {
+        out.push_str("resources:\n");
+        for id in &note.resource_ids {
+            let title = resources
+                .iter()
+                .find(|r| r.uuid == *id)
+                .map(|r| r.title.as_str());
+            out.push_str(&format!("  - {}{}\n", id, comment(title)));
+        }
+    }
+    out.push_str(&format!(
+        "created: {}\n",
+        note.created_at.to_rfc3339_opts(SecondsFormat::Secs, false)
+    ));
+    if let Some(updated) = note.updated_at {
+        out.push_str(&format!(
+            "updated: {}\n",
+            updated.to_rfc3339_opts(SecondsFormat::Secs, false)
+        ));
+    }
+    out.push_str("---\n\n");
+    out.push_str(note.body.trim_end_matches('\n'));
+    out.push('\n');
+    out
+}
+
+/// A YAML scalar, double-quoted when it would otherwise be misread.
+fn scalar(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value.trim() == value
+        && !value.starts_with(|c: char| "-?:,[]{}#&*!|>'\"%@`".contains(c))
+        && !value.contains(": ")
+        && !value.contains(" #")
+        && !value.contains('\n')
+        && !matches!(
+            value.to_ascii_lowercase().as_str(),
+            "true" | "false" | "yes" | "no" | "null" | "~"
+        )
+        && value.parse::<f64>().is_err();
+    if plain {
+        value.to_string()
+    } else {
+        serde_json::to_string(value).unwrap_or_default()
+    }
+}
+
+fn comment(label: Option<&str>) -> String {
+    label
+        .map(|l| format!("  # {}", l.replace('\n', " ")))
+        .unwrap_or_default()
+}
+
+/// Writes `content` to `path` and sets its modification time, so a later
+/// sync sees the file and the note as equally recent.
+pub fn write(path: &Path, content: &str, modified: DateTime<Utc>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    let file = fs::File::options().write(true).open(path)?;
+    file.set_modified(modified.into())?;
+    Ok(())
+}
+
+// ── Reading ───────────────────────────────────────────────────────────────────
+
+/// Splits a file into its front matter and body. A file without a leading
+/// `---` block is all body.
+pub fn parse(content: &str) -> (FrontMatter, String) {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return (FrontMatter::default(), clean_body(content));
+    };
+
+    let mut yaml = Vec::new();
+    let mut body = None;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed == "..." {
+            body = Some(&rest[offset..]);
+            break;
+        }
+        yaml.push(trimmed);
+    }
+    match body {
+        Some(body) => (front_matter(&yaml), clean_body(body)),
+        // No closing delimiter: not front matter after all.
+        None => (FrontMatter::default(), clean_body(content)),
+    }
+}
+
+/// Normalizes a body the way reading a file does: LF line endings, no
+/// leading blank lines and no trailing whitespace.
+pub fn clean_body(body: &str) -> String {
+    body.replace("\r\n", "\n")
+        .trim_start_matches('\n')
+        .trim_end()
+        .to_string()
+}
+
+/// Parses the `key: value` and `key:` + `- item` lines of a front matter.
+fn front_matter(lines: &[&str]) -> FrontMatter {
+    // (key, values, whether the value was a plain `key: value` scalar)
+    let mut entries: Vec<(String, Vec<String>, bool)> = Vec::new();
+    for line in lines {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or((trimmed == "-").then_some(""))
+        {
+            if line.starts_with([' ', '-'])
+                && let Some((_, values, plain)) = entries.last_mut()
+            {
+                values.extend(unquote(item));
+                *plain = false;
+            }
+            continue;
+        }
+        if line.starts_with(' ') {
+            continue; // nested mappings are not supported
+        }
+        if let Some((key, value)) = trimmed.split_once(':') {
+            let value = value.trim();
+            let (values, plain) = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                Some(items) => (
+                    split_flow(items)
+                        .iter()
+                        .filter_map(|i| unquote(i))
+                        .collect(),
+                    false,
+                ),
+                None => (
+                    unquote(value).into_iter().collect(),
+                    !value.starts_with(['"', '\'']),
+                ),
+            };
+            entries.push((key.trim().to_ascii_lowercase(), values, plain));
+        }
+    }
+
+    let mut front = FrontMatter::default();
+    for (key, values, plain) in entries {
+        let first = values.first().cloned();
+        match key.as_str() {
+            "id" | "uuid" => front.id = first.and_then(|v| Uuid::parse_str(&v).ok()),
+            "title" => front.title = first,
+            "tags" => {
+                // Only a plain `tags: a, b` scalar lists several tags in one
+                // value; list items are one tag each, spaces included.
+                front.tags = values
+                    .iter()
+                    .flat_map(|v| match plain {
+                        true => v.split([',', ' ']).collect(),
+                        false => vec![v.as_str()],
+                    })
+                    .map(|t| t.trim().trim_start_matches('#').to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect()
+            }
+            "language" | "lang" => front.language = first,
+            "format" => {
+                front.format = first.map(|f| match f.to_ascii_lowercase().as_str() {
+                    "plain" | "text" => NoteFormat::Plain,
+                    _ => NoteFormat::Markdown,
+                })
+            }
+            "project" | "projects" => front.projects = values,
+            "task" | "tasks" => {
+                front.tasks = values
+                    .iter()
+                    .filter_map(|v| Uuid::parse_str(v).ok())
+                    .collect()
+            }
+            "resources" => {
+                front.resources = values
+                    .iter()
+                    .filter_map(|v| Uuid::parse_str(v).ok())
+                    .collect()
+            }
+            "created" => front.created = first.as_deref().and_then(timestamp),
+            "updated" | "modified" => front.updated = first.as_deref().and_then(timestamp),
+            _ => {}
+        }
+    }
+    front
+}
+
+/// Splits the inside of a `[a, "b, c"]` flow list on commas outside quotes.
+fn split_flow(items: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let (mut start, mut quote) = (0, None);
+    for (i, c) in items.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(q)) if c == q => quote = None,
+            (',', None) => {
+                out.push(&items[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    out.push(&items[start..]);
+    out
+}
+
+/// The value of a scalar, or `None` when it is empty or `null`.
+fn unquote(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let value = if raw.starts_with('"') {
+        let end = raw.rfind('"').filter(|&e| e > 0)?;
+        serde_json::from_str::<String>(&raw[..=end]).ok()?
+    } else if let Some(inner) = raw.strip_prefix('\'') {
+        inner.rsplit_once('\'')?.0.replace("''", "'")
+    } else {
+        // A ` #` starts a comment in a plain scalar.
+        let value = raw.split(" #").next().unwrap_or("").trim();
+        if value.is_empty() || value == "~" || value.eq_ignore_ascii_case("null") {
+            return None;
+        }
+        value.to_string()
+    };
+    Some(value)
+}
+
+fn timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S"))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M"))
+    {
+        return Some(dt.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+/// Reads every `.md` file under `dir`, skipping hidden files and folders
+/// such as `.obsidian`.
+pub fn scan(dir: &Path) -> Result<Vec<VaultFile>> {
+    let mut files = Vec::new();
+    scan_into(dir, &mut files)?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+fn scan_into(dir: &Path, files: &mut Vec<VaultFile>) -> Result<()> {
+    let entries = fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            scan_into(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("md"))
+        {
+            let content = fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            let (front, body) = parse(&content);
+            let modified = entry.metadata()?.modified()?.into();
+            files.push(VaultFile {
+                path,
+                front,
+                body,
+                modified,
+            });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    #[test]
+    fn test_render_and_parse_round_trip() {
+        let project = Project::new("api".into());
+        let task = Task::new(
+            "Write spec".into(),
+            Priority::Medium,
+            vec![],
+            None,
+            None,
+            None,
+        );
+        let mut note = Note::new_markdown("# Plan\n\nSteps: one, two".into());
+        note.title = Some("Plan: v2".into());
+        note.tags = vec!["rust".into(), "true".into(), "foo bar".into()];
+        note.language = Some("rust".into());
+        note.project_ids = vec![project.uuid];
+        note.task_ids = vec![task.uuid];
+
+        let text = render(&note, &[project], std::slice::from_ref(&task), &[]);
+        assert!(text.contains("title: \"Plan: v2\"\n"));
+        assert!(text.contains("  - \"foo bar\"\n"));
+        assert!(text.contains("task: ") && text.contains("  # Write spec\n"));
+
+        let (front, body) = parse(&text);
+        assert_eq!(front.id, Some(note.uuid));
+        assert_eq!(front.title.as_deref(), Some("Plan: v2"));
+        assert_eq!(front.tags, vec!["rust", "true", "foo bar"]);
+        assert_eq!(front.language.as_deref(), Some("rust"));
+        assert_eq!(front.projects, vec!["api"]);
+        assert_eq!(front.tasks, vec![task.uuid]);
+        assert_eq!(front.format, None);
+        assert_eq!(
+            front.created.map(|c| c.timestamp()),
+            Some(note.created_at.timestamp())
+        );
+        assert_eq!(body, note.body);
+    }
+
+    #[test]
+    fn test_several_projects_and_tasks_render_as_lists() {
+        let projects = vec![Project::new("api".into()), Project::new("web".into())];
+        let tasks: Vec<Task> = ["Spec", "Review"]
+            .into_iter()
+            .map(|text| Task::new(text.into(), Priority::Medium, vec![], None, None, None))
+            .collect();
+        let mut note = Note::new("Meeting".into());
+        note.project_ids = projects.iter().map(|p| p.uuid).collect();
+        note.task_ids = tasks.iter().map(|t| t.uuid).collect();
+
+        let text = render(&note, &projects, &tasks, &[]);
+        assert!(text.contains("projects:\n  - api\n  - web\n"));
+        assert!(text.contains("tasks:\n"));
+        assert!(!text.contains("task: "));
+
+        let (front, _) = parse(&text);
+        assert_eq!(front.projects, vec!["api", "web"]);
+        assert_eq!(front.tasks, note.task_ids);
+    }
+
+    #[test]
+    fn test_parse_obsidian_style_front_matter() {
+        let (front, body) = parse(
+            "---\ntags: [work, \"#ideas\", 'a, b']\naliases:\n  - Other\ncreated: 2024-03-01\n---\nHello\n",
+        );
+        assert_eq!(front.id, None);
+        assert_eq!(front.tags, vec!["work", "ideas", "a, b"]);
+        assert_eq!(
+            front.created.unwrap().to_rfc3339(),
+            "2024-03-01T00:00:00+00:00"
+        );
+        assert_eq!(body, "Hello");
+
+        let (front, _) = parse("---\ntags: work,ideas later\n---\n");
+        assert_eq!(front.tags, vec!["work", "ideas", "later"]);
+
+        let (front, body) = parse("No front matter\n---\nstill body");
+        assert_eq!(front, FrontMatter::default());
+        assert_eq!(body, "No front matter\n---\nstill body");
+    }
+
+    #[test]
+    fn test_file_name_and_title_from_path() {
+        let mut note = Note::new("body".into());
+        note.title = Some("Design Notes!".into());
+        let name = file_name(&note);
+        assert_eq!(name, format!("design-notes-{}.md", note.uuid));
+
+        let file = VaultFile {
+            path: PathBuf::from(format!("vault/{}", name)),
+            front: FrontMatter {
+                id: Some(note.uuid),
+                ..Default::default()
+            },
+            body: String::new(),
+            modified: Utc::now(),
+        };
+        assert_eq!(file.title().as_deref(), Some("design-notes"));
+    }
+}
@@ -0,0 +1,246 @@
+//! Content-addressed blob store for attachment contents.
+//!
+//! Each file is stored once, under the SHA-256 of its contents:
+//! `<dir>/<first two hex digits>/<hash>`. Attachments only refer to blobs
+//! by hash, so attaching the same file twice costs no extra space, and a
+//! blob can be removed once no attachment refers to it
+//! ([`collect_garbage`]).
+//!
+//! Blobs are never modified after they are written, which lets backups
+//! share them with the live store through hard links ([`copy_all`]).
+
+use anyhow::{Context, Result, bail};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The blob store that belongs to the database at `db_path`.
+pub fn dir_for(db_path: &Path) -> PathBuf {
+    db_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("attachments")
+}
+
+/// Whether `hash` is a SHA-256 in lowercase hex, the only form blobs are
+/// stored under.
+pub fn is_valid_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+/// Where the blob with `hash` is (or would be) stored.
+///
+/// Fails for anything but a valid hash, so a hash read from an import file
+/// cannot point outside the store.
+pub fn blob_path(dir: &Path, hash: &str) -> Result<PathBuf> {
+    if !is_valid_hash(hash) {
+        bail!("Invalid attachment hash '{}'", hash);
+    }
+    Ok(dir.join(&hash[..2]).join(hash))
+}
+
+/// Returns the SHA-256 (lowercase hex) and size of a file.
+pub fn hash_file(path: &Path) -> Result<(String, u64)> {
+    let mut file = File::open(path).with_context(|| format!("Cannot read {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    let mut size = 0u64;
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        size += n as u64;
+    }
+    let hash = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Ok((hash, size))
+}
+
+/// Copies `source` into the store and returns its hash and size.
+///
+/// Does nothing but hash the file if the same contents are already stored.
+pub fn store(dir: &Path, source: &Path) -> Result<(String, u64)> {
+    let (hash, size) = hash_file(source)?;
+    let dest = blob_path(dir, &hash)?;
+    if !dest.exists() {
+        copy_blob(source, &dest)?;
+    }
+    Ok((hash, size))
+}
+
+/// Copies every blob in `from` that is missing in `to`, hard-linking when
+/// `link` is set and the file system allows it.
+///
+/// With `only`, blobs whose hash is not in the set are left out. Returns
+/// the number of blobs copied.
+pub fn copy_all(from: &Path, to: &Path, only: Option<&HashSet<&str>>, link: bool) -> Result<usize> {
+    let mut count = 0;
+    for (hash, path) in blobs(from)? {
+        if only.is_some_and(|only| !only.contains(hash.as_str())) {
+            continue;
+        }
+        let dest = blob_path(to, &hash)?;
+        if dest.exists() {
+            continue;
+        }
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if !(link && fs::hard_link(&path, &dest).is_ok()) {
+            copy_blob(&path, &dest)?;
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Deletes every blob whose hash is not in `referenced`.
+///
+/// Returns the number of blobs removed and the bytes freed.
+pub fn collect_garbage(dir: &Path, referenced: &HashSet<&str>) -> Result<(usize, u64)> {
+    let mut count = 0;
+    let mut bytes = 0;
+    for (hash, path) in blobs(dir)? {
+        if referenced.contains(hash.as_str()) {
+            continue;
+        }
+        bytes += fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        fs::remove_file(&path).with_context(|| format!("Cannot remove {}", path.display()))?;
+        count += 1;
+        if let Some(shard) = path.parent() {
+            // Only succeeds once the shard is empty.
+            let _ = fs::remove_dir(shard);
+        }
+    }
+    Ok((count, bytes))
+}
+
+/// Every stored blob as `(hash, path)`. A missing store is empty.
+fn blobs(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let shards = match fs::read_dir(dir) {
+        Ok(shards) => shards,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e).context(format!("Cannot read {}", dir.display())),
+    };
+    let mut blobs = Vec::new();
+    for shard in shards.filter_map(|e| e.ok()) {
+        if !shard.path().is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(shard.path())?.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if path.is_file() && is_valid_hash(name) {
+                blobs.push((name.to_string(), path));
+            }
+        }
+    }
+    blobs.sort();
+    Ok(blobs)
+}
+
+/// Copies through a temporary file so a blob is never seen half-written.
+fn copy_blob(source: &Path, dest: &Path) -> Result<()> {
+    let parent = dest.parent().unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)
+        .with_context(|| format!("Cannot create directory {}", parent.display()))?;
+    let tmp = dest.with_extension("tmp");
+    fs::copy(source, &tmp).with_context(|| format!("Cannot copy {}", source.display()))?;
+    fs::rename(&tmp, dest).with_context(|| format!("Cannot write {}", dest.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_store_is_content_addressed() {
+        let tmp = TempDir::new().unwrap();
+        let store_dir = tmp.path().join("attachments");
+        let a = write(tmp.path(), "a.txt", "hello");
+        let b = write(tmp.path(), "b.txt", "hello");
+
+        let (hash, size) = store(&store_dir, &a).unwrap();
+        assert_eq!(
+            hash,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(size, 5);
+        assert_eq!(store(&store_dir, &b).unwrap().0, hash);
+        assert_eq!(blobs(&store_dir).unwrap().len(), 1);
+        assert_eq!(
+            fs::read_to_string(blob_path(&store_dir, &hash).unwrap()).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn test_collect_garbage_keeps_referenced_blobs() {
+        let tmp = TempDir::new().unwrap();
+        let store_dir = tmp.path().join("attachments");
+        let (keep, _) = store(&store_dir, &write(tmp.path(), "a", "keep")).unwrap();
+        let (drop, _) = store(&store_dir, &write(tmp.path(), "b", "drop me")).unwrap();
+
+        let referenced = HashSet::from([keep.as_str()]);
+        assert_eq!(collect_garbage(&store_dir, &referenced).unwrap(), (1, 7));
+        assert!(blob_path(&store_dir, &keep).unwrap().exists());
+        assert!(!blob_path(&store_dir, &drop).unwrap().exists());
+        assert_eq!(collect_garbage(&store_dir, &referenced).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn test_copy_all_skips_existing_and_filters() {
+        let tmp = TempDir::new().unwrap();
+        let from = tmp.path().join("from");
+        let to = tmp.path().join("to");
+        let (a, _) = store(&from, &write(tmp.path(), "a", "one")).unwrap();
+        store(&from, &write(tmp.path(), "b", "two")).unwrap();
+
+        let only = HashSet::from([a.as_str()]);
+        assert_eq!(copy_all(&from, &to, Some(&only), false).unwrap(), 1);
+        assert_eq!(copy_all(&from, &to, None, true).unwrap(), 1);
+        assert_eq!(copy_all(&from, &to, None, true).unwrap(), 0);
+        assert_eq!(blobs(&to).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_blob_path_rejects_invalid_hashes() {
+        let dir = Path::new("store");
+        let hash = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+        assert_eq!(blob_path(dir, hash).unwrap(), dir.join("2c").join(hash));
+        for bad in [
+            "../..",
+            "",
+            "é",
+            &hash.to_uppercase(),
+            &format!("{}0", hash),
+        ] {
+            assert!(blob_path(dir, bad).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_missing_store_is_empty() {
+        let tmp = TempDir::new().unwrap();
+        let missing = tmp.path().join("nope");
+        assert!(blobs(&missing).unwrap().is_empty());
+        assert_eq!(collect_garbage(&missing, &HashSet::new()).unwrap(), (0, 0));
+    }
+}
@@ -3,6 +3,10 @@
 //! Uses `VACUUM INTO` to create a consistent snapshot without closing the
 //! main connection. Backups are stored in `<data_dir>/backups/`.
 //!
+//! Attachment contents are copied next to each snapshot, in
+//! `<timestamp>.attachments/`, hard-linked to the live blob store where the
+//! file system allows it (see [`attachments`]).
+//!
 //! Rotation policy: keep the last `max_backups` files (default 10).
 //! Backup is skipped if the last backup is newer than `min_interval_minutes`.
 //!
@@ -15,6 +19,8 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
+use super::attachments;
+
 /// Checks whether a backup is needed and creates one if so.
 ///
 /// Called after every write operation.
@@ -67,9 +73,22 @@ pub fn create_backup(db_path: &Path, backup_dir: &Path) -> Result<PathBuf> {
     conn.execute("VACUUM INTO ?1", rusqlite::params![backup_path_str])
         .context("VACUUM INTO failed")?;
 
+    attachments::copy_all(
+        &attachments::dir_for(db_path),
+        &attachments_of(&backup_path),
+        None,
+        true,
+    )
+    .context("Failed to back up attachments")?;
+
     Ok(backup_path)
 }
 
+/// The directory holding the attachment contents saved with a backup.
+pub fn attachments_of(backup_path: &Path) -> PathBuf {
+    backup_path.with_extension("attachments")
+}
+
 /// Removes oldest backups keeping only `max_backups`.
 fn rotate_backups(backup_dir: &Path, max_backups: usize) -> Result<()> {
     let mut backups: Vec<PathBuf> = std::fs::read_dir(backup_dir)?
@@ -83,6 +102,10 @@ fn rotate_backups(backup_dir: &Path, max_backups: usize) -> Result<()> {
     while backups.len() > max_backups {
         if let Some(oldest) = backups.first() {
             std::fs::remove_file(oldest).context("Failed to remove old backup")?;
+            let blobs = attachments_of(oldest);
+            if blobs.is_dir() {
+                std::fs::remove_dir_all(blobs).context("Failed to remove old backup")?;
+            }
             backups.remove(0);
         }
     }
@@ -3,15 +3,18 @@
 use anyhow::Result;
 use chrono::TimeZone;
 use std::cell::RefCell;
+use std::path::PathBuf;
+use tempfile::TempDir;
 use uuid::Uuid;
 
-use super::{EntityType, EventStat, EventType, Storage};
-use crate::models::{Note, Project, Resource, Task};
+use super::{EntityType, Event, EventStat, EventType, Storage};
+use crate::models::{
+    ArchiveEntry, Attachment, Note, NoteRevision, Project, Resource, Task, Template, View,
+};
 
 #[derive(Debug, Default)]
 struct EventRow {
     entity_type: String,
-    #[allow(dead_code)]
     entity_uuid: Uuid,
     event_type: String,
     occurred_at: i64,
@@ -23,8 +26,16 @@ pub struct InMemoryStorage {
     tasks: RefCell<Vec<Task>>,
     projects: RefCell<Vec<Project>>,
     notes: RefCell<Vec<Note>>,
+    note_revisions: RefCell<Vec<NoteRevision>>,
     resources: RefCell<Vec<Resource>>,
+    templates: RefCell<Vec<Template>>,
+    views: RefCell<Vec<View>>,
+    context: RefCell<Option<String>>,
+    archive: RefCell<Vec<ArchiveEntry>>,
     events: RefCell<Vec<EventRow>>,
+    attachments: RefCell<Vec<Attachment>>,
+    /// Blob store for attachments, created on first use.
+    attachments_dir: RefCell<Option<TempDir>>,
 }
 
 #[allow(dead_code)]
@@ -96,6 +107,12 @@ impl Storage for InMemoryStorage {
 
     fn delete_tasks(&self, uuids: &[Uuid]) -> Result<()> {
         self.tasks.borrow_mut().retain(|t| !uuids.contains(&t.uuid));
+        for note in self.notes.borrow_mut().iter_mut() {
+            note.task_ids.retain(|t| !uuids.contains(t));
+        }
+        self.attachments
+            .borrow_mut()
+            .retain(|a| !uuids.contains(&a.owner.uuid()));
         Ok(())
     }
 
@@ -109,9 +126,16 @@ impl Storage for InMemoryStorage {
     }
 
     fn delete_projects(&self, uuids: &[Uuid]) -> Result<()> {
-        self.projects
-            .borrow_mut()
-            .retain(|p| !uuids.contains(&p.uuid));
+        let mut projects = self.projects.borrow_mut();
+        projects.retain(|p| !uuids.contains(&p.uuid));
+        for project in projects.iter_mut() {
+            if project.parent_id.is_some_and(|u| uuids.contains(&u)) {
+                project.parent_id = None;
+            }
+        }
+        for note in self.notes.borrow_mut().iter_mut() {
+            note.project_ids.retain(|p| !uuids.contains(p));
+        }
         Ok(())
     }
 
@@ -126,9 +150,49 @@ impl Storage for InMemoryStorage {
 
     fn delete_notes(&self, uuids: &[Uuid]) -> Result<()> {
         self.notes.borrow_mut().retain(|n| !uuids.contains(&n.uuid));
+        self.note_revisions
+            .borrow_mut()
+            .retain(|r| !uuids.contains(&r.note_uuid));
+        self.attachments
+            .borrow_mut()
+            .retain(|a| !uuids.contains(&a.owner.uuid()));
         Ok(())
     }
 
+    fn load_note_revisions(&self, note_uuid: Uuid) -> Result<Vec<NoteRevision>> {
+        let mut revisions: Vec<NoteRevision> = self
+            .note_revisions
+            .borrow()
+            .iter()
+            .filter(|r| r.note_uuid == note_uuid)
+            .cloned()
+            .collect();
+        revisions.sort_by_key(|r| r.number);
+        Ok(revisions)
+    }
+
+    fn save_note_revision(&self, revision: &NoteRevision) -> Result<()> {
+        let mut revisions = self.note_revisions.borrow_mut();
+        revisions.retain(|r| !(r.note_uuid == revision.note_uuid && r.number == revision.number));
+        revisions.push(revision.clone());
+        Ok(())
+    }
+
+    fn prune_note_revisions(&self, keep: usize) -> Result<usize> {
+        let notes = self.notes.borrow();
+        let mut revisions = self.note_revisions.borrow_mut();
+        let before = revisions.len();
+        let snapshot = revisions.clone();
+        revisions.retain(|r| {
+            let newer = snapshot
+                .iter()
+                .filter(|o| o.note_uuid == r.note_uuid && o.number > r.number)
+                .count();
+            newer < keep && notes.iter().any(|n| n.uuid == r.note_uuid)
+        });
+        Ok(before - revisions.len())
+    }
+
     fn load_resources(&self) -> Result<Vec<Resource>> {
         Ok(self.resources.borrow().clone())
     }
@@ -145,6 +209,115 @@ impl Storage for InMemoryStorage {
         Ok(())
     }
 
+    fn load_templates(&self) -> Result<Vec<Template>> {
+        let mut templates = self.templates.borrow().clone();
+        templates.sort_by_key(|t| t.name.to_lowercase());
+        Ok(templates)
+    }
+
+    fn save_template(&self, template: &Template) -> Result<()> {
+        let mut templates = self.templates.borrow_mut();
+        templates.retain(|t| !t.name.eq_ignore_ascii_case(&template.name));
+        templates.push(template.clone());
+        Ok(())
+    }
+
+    fn delete_template(&self, name: &str) -> Result<bool> {
+        let mut templates = self.templates.borrow_mut();
+        let before = templates.len();
+        templates.retain(|t| !t.name.eq_ignore_ascii_case(name));
+        Ok(templates.len() != before)
+    }
+
+    fn load_views(&self) -> Result<Vec<View>> {
+        let mut views = self.views.borrow().clone();
+        views.sort_by_key(|v| v.name.to_lowercase());
+        Ok(views)
+    }
+
+    fn save_view(&self, view: &View) -> Result<()> {
+        let mut views = self.views.borrow_mut();
+        views.retain(|v| !v.name.eq_ignore_ascii_case(&view.name));
+        views.push(view.clone());
+        Ok(())
+    }
+
+    fn delete_view(&self, name: &str) -> Result<bool> {
+        let mut views = self.views.borrow_mut();
+        let before = views.len();
+        views.retain(|v| !v.name.eq_ignore_ascii_case(name));
+        Ok(views.len() != before)
+    }
+
+    fn load_context(&self) -> Result<Option<String>> {
+        Ok(self.context.borrow().clone())
+    }
+
+    fn save_context(&self, name: Option<&str>) -> Result<()> {
+        *self.context.borrow_mut() = name.map(String::from);
+        Ok(())
+    }
+
+    fn load_archive(&self) -> Result<Vec<ArchiveEntry>> {
+        Ok(self.archive.borrow().clone())
+    }
+
+    fn archive(&self, entries: &[ArchiveEntry]) -> Result<()> {
+        let uuids: Vec<Uuid> = entries.iter().map(|e| e.uuid()).collect();
+        let gone = |id: &Option<Uuid>| id.is_some_and(|u| uuids.contains(&u));
+
+        self.tasks.borrow_mut().retain(|t| !uuids.contains(&t.uuid));
+        self.projects
+            .borrow_mut()
+            .retain(|p| !uuids.contains(&p.uuid));
+        self.notes.borrow_mut().retain(|n| !uuids.contains(&n.uuid));
+        let (archived_revisions, live_revisions): (Vec<_>, Vec<_>) = self
+            .note_revisions
+            .take()
+            .into_iter()
+            .partition(|r| uuids.contains(&r.note_uuid));
+        self.note_revisions.replace(live_revisions);
+
+        for project in self.projects.borrow_mut().iter_mut() {
+            if gone(&project.parent_id) {
+                project.parent_id = None;
+            }
+        }
+        for task in self.tasks.borrow_mut().iter_mut() {
+            task.depends_on.retain(|d| !uuids.contains(d));
+            if gone(&task.parent_id) {
+                task.parent_id = None;
+            }
+            if gone(&task.project_id) {
+                task.project_id = None;
+            }
+        }
+        for note in self.notes.borrow_mut().iter_mut() {
+            note.task_ids.retain(|t| !uuids.contains(t));
+            note.project_ids.retain(|p| !uuids.contains(p));
+        }
+
+        let mut archive = self.archive.borrow_mut();
+        archive.retain(|e| !uuids.contains(&e.uuid()));
+        archive.extend(entries.iter().cloned().map(|mut entry| {
+            let uuid = entry.uuid();
+            entry.revisions = archived_revisions
+                .iter()
+                .filter(|r| r.note_uuid == uuid)
+                .cloned()
+                .collect();
+            entry
+        }));
+        Ok(())
+    }
+
+    fn delete_archived(&self, uuids: &[Uuid]) -> Result<()> {
+        self.archive
+            .borrow_mut()
+            .retain(|e| !uuids.contains(&e.uuid()));
+        Ok(())
+    }
+
     fn record_event(
         &self,
         entity_type: EntityType,
@@ -227,6 +400,7 @@ impl Storage for InMemoryStorage {
             match ev.event_type.as_str() {
                 "created" => stat.created += 1,
                 "completed" => stat.completed += 1,
+                "cancelled" => stat.cancelled += 1,
                 "deleted" | "purged" => stat.deleted += 1,
                 _ => {}
             }
@@ -235,6 +409,52 @@ impl Storage for InMemoryStorage {
         Ok(map.into_values().collect())
     }
 
+    fn load_events(&self, entity_type: EntityType, uuids: &[Uuid]) -> Result<Vec<Event>> {
+        let mut events: Vec<Event> = self
+            .events
+            .borrow()
+            .iter()
+            .filter(|e| e.entity_type == entity_type.as_str() && uuids.contains(&e.entity_uuid))
+            .filter_map(|e| {
+                Some(Event {
+                    entity_uuid: e.entity_uuid,
+                    event_type: EventType::parse(&e.event_type)?,
+                    occurred_at: chrono::DateTime::from_timestamp(e.occurred_at, 0)?,
+                })
+            })
+            .collect();
+        events.sort_by_key(|e| e.occurred_at);
+        Ok(events)
+    }
+
+    fn load_attachments(&self) -> Result<Vec<Attachment>> {
+        Ok(self.attachments.borrow().clone())
+    }
+
+    fn save_attachment(&self, attachment: &Attachment) -> Result<()> {
+        let mut attachments = self.attachments.borrow_mut();
+        if let Some(existing) = attachments.iter_mut().find(|a| a.uuid == attachment.uuid) {
+            *existing = attachment.clone();
+        } else {
+            attachments.push(attachment.clone());
+        }
+        Ok(())
+    }
+
+    fn attachments_dir(&self) -> Result<PathBuf> {
+        let mut dir = self.attachments_dir.borrow_mut();
+        let root = match dir.as_ref() {
+            Some(root) => root.path().to_path_buf(),
+            None => {
+                let root = TempDir::new()?;
+                let path = root.path().to_path_buf();
+                *dir = Some(root);
+                path
+            }
+        };
+        Ok(root.join("attachments"))
+    }
+
     fn location(&self) -> String {
         "memory".to_string()
     }
@@ -6,8 +6,12 @@
 //! | [`InMemoryStorage`] | Stores in memory — ideal for tests |
 
 use crate::models::StatusFilter;
-use crate::models::{Note, Project, Resource, Task};
+use crate::models::{
+    ArchiveEntry, Attachment, Note, NoteRevision, Project, Resource, Task, Template, View,
+};
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::path::PathBuf;
 use uuid::Uuid;
 
 // ── EntityType / EventType ────────────────────────────────────────────────────
@@ -32,6 +36,7 @@ impl EntityType {
 }
 
 /// The kind of action that occurred on an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EventType {
     Created,
     Completed,
@@ -39,9 +44,27 @@ pub enum EventType {
     Edited,
     Deleted,
     Purged,
+    Started,
+    Waiting,
+    Cancelled,
+    Restored,
 }
 
 impl EventType {
+    /// Every event type, in the order used by the SQLite `CHECK` constraint.
+    pub const ALL: [EventType; 10] = [
+        EventType::Created,
+        EventType::Completed,
+        EventType::Uncompleted,
+        EventType::Edited,
+        EventType::Deleted,
+        EventType::Purged,
+        EventType::Started,
+        EventType::Waiting,
+        EventType::Cancelled,
+        EventType::Restored,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             EventType::Created => "created",
@@ -50,8 +73,27 @@ impl EventType {
             EventType::Edited => "edited",
             EventType::Deleted => "deleted",
             EventType::Purged => "purged",
+            EventType::Started => "started",
+            EventType::Waiting => "waiting",
+            EventType::Cancelled => "cancelled",
+            EventType::Restored => "restored",
         }
     }
+
+    /// Parses the value stored in the `event_type` column.
+    pub fn parse(s: &str) -> Option<EventType> {
+        Self::ALL.into_iter().find(|e| e.as_str() == s)
+    }
+}
+
+// ── Event ─────────────────────────────────────────────────────────────────────
+
+/// A single row of the event log.
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub entity_uuid: Uuid,
+    pub event_type: EventType,
+    pub occurred_at: DateTime<Utc>,
 }
 
 // ── EventStat ─────────────────────────────────────────────────────────────────
@@ -63,6 +105,7 @@ pub struct EventStat {
     pub month: u32,
     pub created: usize,
     pub completed: usize,
+    pub cancelled: usize,
     pub deleted: usize,
 }
 
@@ -76,7 +119,7 @@ pub trait Storage {
     /// Persist all tasks (upsert by UUID).
     fn save(&self, tasks: &[Task]) -> Result<()>;
 
-    /// Permanently delete tasks by UUID.
+    /// Permanently delete tasks by UUID, with their attachments.
     fn delete_tasks(&self, uuids: &[Uuid]) -> Result<()>;
 
     // ── projects ──────────────────────────────────────────────────────────────
@@ -98,7 +141,7 @@ pub trait Storage {
     /// Persist all notes (upsert by UUID).
     fn save_notes(&self, notes: &[Note]) -> Result<()>;
 
-    /// Permanently delete notes by UUID.
+    /// Permanently delete notes by UUID, with their revisions and attachments.
     fn delete_notes(&self, uuids: &[Uuid]) -> Result<()>;
 
     // ── resources ─────────────────────────────────────────────────────────────
@@ -112,6 +155,87 @@ pub trait Storage {
     /// Permanently delete resources by UUID.
     fn delete_resources(&self, uuids: &[Uuid]) -> Result<()>;
 
+    // ── note revisions ────────────────────────────────────────────────────────
+
+    /// Load the saved revisions of a note, oldest first.
+    fn load_note_revisions(&self, note_uuid: Uuid) -> Result<Vec<NoteRevision>>;
+
+    /// Persist a revision, replacing any with the same note and number.
+    fn save_note_revision(&self, revision: &NoteRevision) -> Result<()>;
+
+    /// Delete all but the newest `keep` revisions of each note, and the
+    /// revisions of notes that no longer exist.
+    ///
+    /// Returns the number of revisions deleted.
+    fn prune_note_revisions(&self, keep: usize) -> Result<usize>;
+
+    // ── attachments ───────────────────────────────────────────────────────────
+
+    /// Load every attachment, oldest first.
+    ///
+    /// Attachments of tasks and notes that are permanently deleted are
+    /// removed with them; those of trashed or archived items are kept.
+    fn load_attachments(&self) -> Result<Vec<Attachment>>;
+
+    /// Persist an attachment (upsert by UUID).
+    fn save_attachment(&self, attachment: &Attachment) -> Result<()>;
+
+    /// The directory holding attachment contents (see
+    /// [`attachments`](crate::storage::attachments)). May not exist yet.
+    fn attachments_dir(&self) -> Result<PathBuf>;
+
+    // ── templates ─────────────────────────────────────────────────────────────
+
+    /// Load all task templates saved in storage, sorted by name.
+    ///
+    /// Templates defined in `config.toml` are not included.
+    fn load_templates(&self) -> Result<Vec<Template>>;
+
+    /// Persist a template, replacing any existing one with the same name
+    /// (case-insensitive).
+    fn save_template(&self, template: &Template) -> Result<()>;
+
+    /// Delete a template by name (case-insensitive).
+    ///
+    /// Returns `false` when no template had that name.
+    fn delete_template(&self, name: &str) -> Result<bool>;
+
+    // ── views & context ───────────────────────────────────────────────────────
+
+    /// Load all saved views, sorted by name.
+    fn load_views(&self) -> Result<Vec<View>>;
+
+    /// Persist a view, replacing any existing one with the same name
+    /// (case-insensitive).
+    fn save_view(&self, view: &View) -> Result<()>;
+
+    /// Delete a view by name (case-insensitive).
+    ///
+    /// Returns `false` when no view had that name.
+    fn delete_view(&self, name: &str) -> Result<bool>;
+
+    /// Name of the view currently set as the context, if any.
+    fn load_context(&self) -> Result<Option<String>>;
+
+    /// Set (`Some`) or clear (`None`) the active context.
+    fn save_context(&self, name: Option<&str>) -> Result<()>;
+
+    // ── archive ───────────────────────────────────────────────────────────────
+
+    /// Load every archived entry, in the order it was archived.
+    fn load_archive(&self) -> Result<Vec<ArchiveEntry>>;
+
+    /// Move entries into the archive and physically remove them from the
+    /// live tables.
+    ///
+    /// Live rows that still reference an archived entity (dependencies,
+    /// recurrence parents, tombstones) are unlinked so nothing dangles. A
+    /// note's revisions are moved into its entry's `revisions`.
+    fn archive(&self, entries: &[ArchiveEntry]) -> Result<()>;
+
+    /// Permanently delete archived entries by UUID.
+    fn delete_archived(&self, uuids: &[Uuid]) -> Result<()>;
+
     // ── events ────────────────────────────────────────────────────────────────
 
     /// Record a domain event (created, completed, deleted, etc.).
@@ -139,6 +263,16 @@ pub trait Storage {
     /// `EventStat` per month, oldest first, covering only `task` events.
     fn load_event_stats(&self, months: usize) -> Result<Vec<EventStat>>;
 
+    /// Load the events recorded for the given entities, oldest first.
+    fn load_events(&self, entity_type: EntityType, uuids: &[Uuid]) -> Result<Vec<Event>>;
+
+    /// Load the events recorded for the given tasks, oldest first.
+    ///
+    /// Used by `todo project forecast` to replay a project's history.
+    fn load_task_events(&self, task_uuids: &[Uuid]) -> Result<Vec<Event>> {
+        self.load_events(EntityType::Task, task_uuids)
+    }
+
     // ── search ────────────────────────────────────────────────────────────────
 
     /// Search tasks by substring query with optional tag and project filters.
@@ -188,7 +322,7 @@ pub trait Storage {
                         .contains(&q_lower)
             })
             .filter(|n| tags.is_empty() || tags.iter().all(|tag| n.tags.contains(tag)))
-            .filter(|n| project_id.is_none_or(|uuid| n.project_id == Some(uuid)))
+            .filter(|n| project_id.is_none_or(|uuid| n.belongs_to_project(uuid)))
             .collect())
     }
 
@@ -202,6 +336,9 @@ pub trait Storage {
             .filter(|p| {
                 p.name.to_lowercase().contains(&q_lower)
                     || p.tech.iter().any(|t| t.to_lowercase().contains(&q_lower))
+                    || p.description
+                        .as_ref()
+                        .is_some_and(|d| d.to_lowercase().contains(&q_lower))
             })
             .collect())
     }
@@ -285,6 +422,7 @@ pub trait Storage {
     fn location(&self) -> String;
 }
 
+pub mod attachments;
 pub mod backup;
 pub mod memory;
 pub mod sqlite;
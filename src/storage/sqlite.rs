@@ -29,16 +29,18 @@ use anyhow::{Context, Result};
 use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
 use directories::ProjectDirs;
 use rusqlite::{
-    Connection, Row, params,
+    Connection, OptionalExtension, Row, params,
     types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef},
 };
 use serde::{Serialize, de::DeserializeOwned};
 use uuid::Uuid;
 
-use super::{EntityType, EventStat, EventType, Storage};
+use super::{EntityType, Event, EventStat, EventType, Storage};
 use crate::models::StatusFilter;
 use crate::models::{
-    Difficulty, Note, NoteFormat, Priority, Project, Recurrence, Resource, ResourceType, Task,
+    ArchiveEntry, Archived, Attachment, AttachmentOwner, Difficulty, LinkCheck, LinkTarget, Note,
+    NoteFormat, NoteLink, NoteRevision, Priority, Project, ProjectStatus, Recurrence, Resource,
+    ResourceType, Task, TaskStatus, Template, View,
 };
 
 // ── JsonVec<T> ────────────────────────────────────────────────────────────────
@@ -114,21 +116,142 @@ impl SqliteStorage {
     }
 
     fn initialize(&self) -> Result<()> {
-        self.conn
-            .borrow()
-            .execute_batch(SCHEMA)
+        let conn = self.conn.borrow();
+        conn.execute_batch(SCHEMA)
             .context("Failed to initialize schema")?;
+        conn.execute_batch(EVENTS_SCHEMA)
+            .context("Failed to initialize events schema")?;
+        migrate(&conn).context("Failed to migrate schema")?;
         Ok(())
     }
 }
 
+// ── migrations ────────────────────────────────────────────────────────────────
+
+/// Brings databases created by older versions up to the current schema.
+///
+/// `CREATE TABLE IF NOT EXISTS` never alters an existing table, so columns
+/// added after the first release are appended here. Every step is idempotent.
+fn migrate(conn: &Connection) -> Result<()> {
+    if add_column_if_missing(conn, "tasks", "status", "TEXT NOT NULL DEFAULT 'pending'")? {
+        conn.execute("UPDATE tasks SET status = 'done' WHERE completed = 1", [])?;
+    }
+    add_column_if_missing(conn, "tasks", "estimate", "INTEGER")?;
+    add_column_if_missing(
+        conn,
+        "projects",
+        "parent_id",
+        "TEXT REFERENCES projects(uuid)",
+    )?;
+    if add_column_if_missing(conn, "projects", "status", "TEXT NOT NULL DEFAULT 'active'")? {
+        conn.execute(
+            "UPDATE projects SET status = 'done' WHERE completed = 1",
+            [],
+        )?;
+    }
+    for column in ["description", "start_date", "owner", "url", "color"] {
+        add_column_if_missing(conn, "projects", column, "TEXT")?;
+    }
+    for (column, decl) in [
+        ("checked_at", "INTEGER"),
+        ("http_status", "INTEGER"),
+        ("final_url", "TEXT"),
+        ("check_error", "TEXT"),
+    ] {
+        add_column_if_missing(conn, "resources", column, decl)?;
+    }
+    add_column_if_missing(conn, "archive", "revisions", "TEXT NOT NULL DEFAULT '[]'")?;
+    move_note_links_to_tables(conn)?;
+    rebuild_events_if_outdated(conn)?;
+    Ok(())
+}
+
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({table})"))?;
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>("name"))?
+        .filter_map(|r| r.ok())
+        .any(|name| name == column);
+    Ok(exists)
+}
+
+/// Adds `column` to `table` unless it already exists.
+///
+/// Returns `true` when the column was added.
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, decl: &str) -> Result<bool> {
+    if has_column(conn, table, column)? {
+        return Ok(false);
+    }
+    conn.execute_batch(&format!("ALTER TABLE {table} ADD COLUMN {column} {decl}"))?;
+    Ok(true)
+}
+
+/// Moves the single `notes.task_id` / `notes.project_id` links of older
+/// databases into the `note_tasks` / `note_projects` tables.
+fn move_note_links_to_tables(conn: &Connection) -> Result<()> {
+    if !has_column(conn, "notes", "task_id")? {
+        return Ok(());
+    }
+    conn.execute_batch(
+        "BEGIN;
+         INSERT OR IGNORE INTO note_tasks (note_uuid, task_uuid)
+             SELECT uuid, task_id FROM notes WHERE task_id IS NOT NULL;
+         INSERT OR IGNORE INTO note_projects (note_uuid, project_uuid)
+             SELECT uuid, project_id FROM notes WHERE project_id IS NOT NULL;
+         DROP INDEX IF EXISTS idx_notes_project;
+         DROP INDEX IF EXISTS idx_notes_task;
+         ALTER TABLE notes DROP COLUMN task_id;
+         ALTER TABLE notes DROP COLUMN project_id;
+         COMMIT;",
+    )?;
+    Ok(())
+}
+
+/// Recreates the `events` table when its `CHECK` constraint predates one of
+/// the current [`EventType`] values.
+///
+/// SQLite cannot alter a `CHECK` constraint in place, so the table is renamed,
+/// recreated from [`EVENTS_SCHEMA`] and the rows are copied across.
+fn rebuild_events_if_outdated(conn: &Connection) -> Result<()> {
+    let sql: String = conn.query_row(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'events'",
+        [],
+        |row| row.get(0),
+    )?;
+    let outdated = EventType::ALL
+        .iter()
+        .any(|e| !sql.contains(&format!("'{}'", e.as_str())));
+    if !outdated {
+        return Ok(());
+    }
+    conn.execute_batch(&format!(
+        "BEGIN;
+         DROP INDEX IF EXISTS idx_events_occurred;
+         DROP INDEX IF EXISTS idx_events_entity;
+         ALTER TABLE events RENAME TO events_old;
+         {EVENTS_SCHEMA}
+         INSERT INTO events (id, entity_type, entity_uuid, event_type, occurred_at)
+             SELECT id, entity_type, entity_uuid, event_type, occurred_at FROM events_old;
+         DROP TABLE events_old;
+         COMMIT;"
+    ))?;
+    Ok(())
+}
+
 // ── schema ────────────────────────────────────────────────────────────────────
 
 const SCHEMA: &str = "
 CREATE TABLE IF NOT EXISTS projects (
     uuid        TEXT PRIMARY KEY NOT NULL,
     name        TEXT NOT NULL,
+    parent_id   TEXT REFERENCES projects(uuid),
     completed   INTEGER NOT NULL DEFAULT 0,
+    status      TEXT NOT NULL DEFAULT 'active',
+    description TEXT,
+    start_date  TEXT,
+    owner       TEXT,
+    url         TEXT,
+    color       TEXT,
     difficulty  TEXT NOT NULL DEFAULT 'medium',
     tech        TEXT NOT NULL DEFAULT '[]',
     due_date    TEXT,
@@ -142,6 +265,7 @@ CREATE TABLE IF NOT EXISTS tasks (
     uuid        TEXT PRIMARY KEY NOT NULL,
     text        TEXT NOT NULL,
     completed   INTEGER NOT NULL DEFAULT 0,
+    status      TEXT NOT NULL DEFAULT 'pending',
     priority    TEXT NOT NULL DEFAULT 'medium'
                     CHECK(priority IN ('low','medium','high')),
     due_date    TEXT,
@@ -149,6 +273,7 @@ CREATE TABLE IF NOT EXISTS tasks (
     project_id  TEXT REFERENCES projects(uuid),
     parent_id   TEXT REFERENCES tasks(uuid),
     tags        TEXT NOT NULL DEFAULT '[]',
+    estimate    INTEGER,
     completed_at INTEGER,
     created_at  INTEGER NOT NULL,
     updated_at  INTEGER,
@@ -167,8 +292,6 @@ CREATE TABLE IF NOT EXISTS notes (
     body        TEXT NOT NULL,
     format      TEXT NOT NULL DEFAULT 'plain',
     language    TEXT,
-    project_id  TEXT REFERENCES projects(uuid),
-    task_id     TEXT REFERENCES tasks(uuid),
     tags        TEXT NOT NULL DEFAULT '[]',
     created_at  INTEGER NOT NULL,
     updated_at  INTEGER,
@@ -184,7 +307,11 @@ CREATE TABLE IF NOT EXISTS resources (
     tags          TEXT NOT NULL DEFAULT '[]',
     created_at    INTEGER NOT NULL,
     updated_at    INTEGER,
-    deleted_at    INTEGER
+    deleted_at    INTEGER,
+    checked_at    INTEGER,
+    http_status   INTEGER,
+    final_url     TEXT,
+    check_error   TEXT
 );
 
 CREATE TABLE IF NOT EXISTS note_resources (
@@ -193,15 +320,80 @@ CREATE TABLE IF NOT EXISTS note_resources (
     PRIMARY KEY (note_uuid, resource_uuid)
 );
 
--- Event log: append-only, never purged automatically.
--- Records every domain action so stats_history stays accurate
--- even after tombstones are physically removed by 'todo purge'.
-CREATE TABLE IF NOT EXISTS events (
-    id          INTEGER PRIMARY KEY AUTOINCREMENT,
-    entity_type TEXT NOT NULL CHECK(entity_type IN ('task','project','note','resource')),
-    entity_uuid TEXT NOT NULL,
-    event_type  TEXT NOT NULL CHECK(event_type IN ('created','completed','uncompleted','edited','deleted','purged')),
-    occurred_at INTEGER NOT NULL
+CREATE TABLE IF NOT EXISTS note_tasks (
+    note_uuid   TEXT NOT NULL REFERENCES notes(uuid),
+    task_uuid   TEXT NOT NULL REFERENCES tasks(uuid),
+    PRIMARY KEY (note_uuid, task_uuid)
+);
+
+CREATE TABLE IF NOT EXISTS note_projects (
+    note_uuid    TEXT NOT NULL REFERENCES notes(uuid),
+    project_uuid TEXT NOT NULL REFERENCES projects(uuid),
+    PRIMARY KEY (note_uuid, project_uuid)
+);
+
+-- Wiki-links from note bodies. Targets may be notes, tasks or resources,
+-- so target_uuid carries no foreign key.
+CREATE TABLE IF NOT EXISTS note_links (
+    note_uuid   TEXT NOT NULL REFERENCES notes(uuid),
+    reference   TEXT NOT NULL,
+    target_type TEXT NOT NULL CHECK (target_type IN ('note', 'task', 'resource')),
+    target_uuid TEXT NOT NULL,
+    PRIMARY KEY (note_uuid, reference)
+);
+
+-- Previous titles and bodies of notes, numbered per note from 1.
+CREATE TABLE IF NOT EXISTS note_revisions (
+    note_uuid   TEXT NOT NULL REFERENCES notes(uuid),
+    number      INTEGER NOT NULL,
+    title       TEXT,
+    body        TEXT NOT NULL,
+    created_at  INTEGER NOT NULL,
+    PRIMARY KEY (note_uuid, number)
+);
+
+-- Files attached to tasks and notes. The contents live in the blob store
+-- next to the database, addressed by hash; several rows may share a blob.
+CREATE TABLE IF NOT EXISTS attachments (
+    uuid        TEXT PRIMARY KEY NOT NULL,
+    owner_type  TEXT NOT NULL CHECK (owner_type IN ('task', 'note')),
+    owner_uuid  TEXT NOT NULL,
+    name        TEXT NOT NULL,
+    hash        TEXT NOT NULL,
+    size        INTEGER NOT NULL,
+    created_at  INTEGER NOT NULL
+);
+
+-- Task templates: the step list is stored as JSON (see models::Template).
+CREATE TABLE IF NOT EXISTS templates (
+    name        TEXT PRIMARY KEY NOT NULL COLLATE NOCASE,
+    body        TEXT NOT NULL,
+    created_at  INTEGER NOT NULL,
+    updated_at  INTEGER
+);
+
+-- Saved views: filter expression + sort, stored as JSON (see models::View).
+CREATE TABLE IF NOT EXISTS views (
+    name        TEXT PRIMARY KEY NOT NULL COLLATE NOCASE,
+    body        TEXT NOT NULL,
+    created_at  INTEGER NOT NULL,
+    updated_at  INTEGER
+);
+
+-- Archived tasks, projects and notes, stored as JSON (see models::ArchiveEntry).
+-- Rows here are invisible to normal commands; see `todo archive`.
+CREATE TABLE IF NOT EXISTS archive (
+    uuid        TEXT PRIMARY KEY NOT NULL,
+    kind        TEXT NOT NULL CHECK(kind IN ('task','project','note')),
+    body        TEXT NOT NULL,
+    archived_at INTEGER NOT NULL,
+    revisions   TEXT NOT NULL DEFAULT '[]'
+);
+
+-- Small key/value store for per-database state such as the active context.
+CREATE TABLE IF NOT EXISTS settings (
+    key   TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
 );
 
 CREATE INDEX IF NOT EXISTS idx_tasks_active
@@ -212,16 +404,35 @@ CREATE INDEX IF NOT EXISTS idx_tasks_completed
     ON tasks(completed_at);
 CREATE INDEX IF NOT EXISTS idx_notes_active
     ON notes(created_at) WHERE deleted_at IS NULL;
-CREATE INDEX IF NOT EXISTS idx_notes_project
-    ON notes(project_id, created_at) WHERE deleted_at IS NULL;
-CREATE INDEX IF NOT EXISTS idx_notes_task
-    ON notes(task_id) WHERE deleted_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_note_tasks_task
+    ON note_tasks(task_uuid);
+CREATE INDEX IF NOT EXISTS idx_note_projects_project
+    ON note_projects(project_uuid);
 CREATE INDEX IF NOT EXISTS idx_resources_active
     ON resources(created_at) WHERE deleted_at IS NULL;
 CREATE INDEX IF NOT EXISTS idx_projects_active
     ON projects(created_at) WHERE deleted_at IS NULL;
 CREATE INDEX IF NOT EXISTS idx_task_deps
     ON task_dependencies(depends_on_uuid);
+";
+
+/// Event log: append-only, never purged automatically.
+///
+/// Kept separate from [`SCHEMA`] so that [`rebuild_events_if_outdated`] can
+/// recreate it when new event types are added to the `CHECK` constraint.
+const EVENTS_SCHEMA: &str = "
+-- Records every domain action so stats_history stays accurate
+-- even after tombstones are physically removed by 'todo purge'.
+CREATE TABLE IF NOT EXISTS events (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type TEXT NOT NULL CHECK(entity_type IN ('task','project','note','resource')),
+    entity_uuid TEXT NOT NULL,
+    event_type  TEXT NOT NULL CHECK(event_type IN (
+                    'created','completed','uncompleted','edited','deleted','purged',
+                    'started','waiting','cancelled','restored')),
+    occurred_at INTEGER NOT NULL
+);
+
 CREATE INDEX IF NOT EXISTS idx_events_occurred
     ON events(occurred_at);
 CREATE INDEX IF NOT EXISTS idx_events_entity
@@ -286,6 +497,7 @@ fn row_to_task(row: &Row, conn: &Connection, uuid_str: &str) -> rusqlite::Result
         uuid,
         text: row.get("text")?,
         completed: row.get::<_, i64>("completed")? != 0,
+        status: TaskStatus::from_str_lossy(&row.get::<_, String>("status")?),
         priority,
         due_date,
         recurrence,
@@ -294,6 +506,7 @@ fn row_to_task(row: &Row, conn: &Connection, uuid_str: &str) -> rusqlite::Result
         parent_id,
         tags: tags.0,
         depends_on,
+        estimate: row.get("estimate")?,
         created_at,
         updated_at,
         deleted_at,
@@ -324,10 +537,24 @@ fn row_to_project(row: &Row) -> rusqlite::Result<Project> {
         .as_deref()
         .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
 
+    let parent_id = row
+        .get::<_, Option<String>>("parent_id")?
+        .and_then(|s| Uuid::parse_str(&s).ok());
+    let start_date = row
+        .get::<_, Option<String>>("start_date")?
+        .and_then(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok());
+
     Ok(Project {
         uuid,
         name: row.get("name")?,
+        parent_id,
         completed: row.get::<_, i64>("completed")? != 0,
+        status: ProjectStatus::from_str_lossy(&row.get::<_, String>("status")?),
+        description: row.get("description")?,
+        start_date,
+        owner: row.get("owner")?,
+        url: row.get("url")?,
+        color: row.get("color")?,
         difficulty,
         tech: tech.0,
         due_date,
@@ -350,20 +577,41 @@ fn row_to_note(row: &Row, conn: &Connection) -> rusqlite::Result<Note> {
         _ => NoteFormat::Plain,
     };
 
-    let project_id_str: Option<String> = row.get("project_id")?;
-    let project_id = project_id_str
-        .as_deref()
-        .and_then(|s| Uuid::parse_str(s).ok());
-    let task_id_str: Option<String> = row.get("task_id")?;
-    let task_id = task_id_str.as_deref().and_then(|s| Uuid::parse_str(s).ok());
     let tags: JsonVec<String> = row.get("tags")?;
 
-    let mut res_stmt =
-        conn.prepare_cached("SELECT resource_uuid FROM note_resources WHERE note_uuid = ?1")?;
-    let resource_ids: Vec<Uuid> = res_stmt
-        .query_map(params![uuid_str], |r| r.get::<_, String>(0))?
+    let project_ids = load_uuids(
+        conn,
+        "SELECT project_uuid FROM note_projects WHERE note_uuid = ?1 ORDER BY rowid",
+        &uuid_str,
+    )?;
+    let task_ids = load_uuids(
+        conn,
+        "SELECT task_uuid FROM note_tasks WHERE note_uuid = ?1 ORDER BY rowid",
+        &uuid_str,
+    )?;
+    let resource_ids = load_uuids(
+        conn,
+        "SELECT resource_uuid FROM note_resources WHERE note_uuid = ?1",
+        &uuid_str,
+    )?;
+
+    let mut link_stmt = conn.prepare_cached(
+        "SELECT reference, target_type, target_uuid FROM note_links
+         WHERE note_uuid = ?1 ORDER BY rowid",
+    )?;
+    let links: Vec<NoteLink> = link_stmt
+        .query_map(params![uuid_str], |r| {
+            Ok((
+                r.get::<_, String>(0)?,
+                r.get::<_, String>(1)?,
+                r.get::<_, String>(2)?,
+            ))
+        })?
         .filter_map(|r| r.ok())
-        .filter_map(|s| Uuid::parse_str(&s).ok())
+        .filter_map(|(reference, kind, target)| {
+            let target = LinkTarget::from_parts(&kind, Uuid::parse_str(&target).ok()?)?;
+            Some(NoteLink { reference, target })
+        })
         .collect();
 
     Ok(Note {
@@ -372,10 +620,11 @@ fn row_to_note(row: &Row, conn: &Connection) -> rusqlite::Result<Note> {
         body: row.get("body")?,
         format,
         language: row.get("language")?,
-        project_id,
-        task_id,
+        project_ids,
+        task_ids,
         tags: tags.0,
         resource_ids,
+        links,
         created_at: from_unix(row.get("created_at")?),
         updated_at: opt_from_unix(row.get("updated_at")?),
         deleted_at: opt_from_unix(row.get("deleted_at")?),
@@ -413,6 +662,15 @@ fn row_to_resource(row: &Row) -> rusqlite::Result<Resource> {
         created_at: from_unix(row.get("created_at")?),
         updated_at: opt_from_unix(row.get("updated_at")?),
         deleted_at: opt_from_unix(row.get("deleted_at")?),
+        last_check: match opt_from_unix(row.get("checked_at")?) {
+            Some(checked_at) => Some(LinkCheck {
+                checked_at,
+                status: row.get("http_status")?,
+                final_url: row.get("final_url")?,
+                error: row.get("check_error")?,
+            }),
+            None => None,
+        },
     })
 }
 
@@ -439,27 +697,29 @@ impl Storage for SqliteStorage {
             .context("Failed to begin upsert_task transaction")?;
         let uuid_str = task.uuid.to_string();
         tx.execute(
-            "INSERT INTO tasks (uuid, text, completed, priority, due_date, recurrence,
-                      project_id, parent_id, tags, completed_at, created_at,
+            "INSERT INTO tasks (uuid, text, completed, status, priority, due_date, recurrence,
+                      project_id, parent_id, tags, estimate, completed_at, created_at,
                       updated_at, deleted_at)
-             VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13)
+             VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15)
              ON CONFLICT(uuid) DO UPDATE SET
-               text=excluded.text, completed=excluded.completed,
+               text=excluded.text, completed=excluded.completed, status=excluded.status,
                priority=excluded.priority, due_date=excluded.due_date,
                recurrence=excluded.recurrence, project_id=excluded.project_id,
-               parent_id=excluded.parent_id, tags=excluded.tags,
+               parent_id=excluded.parent_id, tags=excluded.tags, estimate=excluded.estimate,
                completed_at=excluded.completed_at, updated_at=excluded.updated_at,
                deleted_at=excluded.deleted_at",
             params![
                 uuid_str,
                 task.text,
                 task.completed as i64,
+                task.status.as_str(),
                 priority_to_str(task.priority),
                 task.due_date.map(|d| d.format("%Y-%m-%d").to_string()),
                 task.recurrence.map(recurrence_to_str),
                 task.project_id.map(|u| u.to_string()),
                 task.parent_id.map(|u| u.to_string()),
                 JsonVec(task.tags.clone()),
+                task.estimate,
                 task.completed_at.map(|d| {
                     let dt: DateTime<Utc> = Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap());
                     to_unix(dt)
@@ -492,13 +752,17 @@ impl Storage for SqliteStorage {
             .borrow()
             .execute(
                 "INSERT INTO projects (uuid, name, completed, difficulty, tech, due_date,
-                          completed_at, created_at, updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10)
+                          completed_at, created_at, updated_at, deleted_at, parent_id,
+                          status, description, start_date, owner, url, color)
+                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15,?16,?17)
                  ON CONFLICT(uuid) DO UPDATE SET
-                   name=excluded.name, completed=excluded.completed,
+                   name=excluded.name, parent_id=excluded.parent_id,
+                   completed=excluded.completed, status=excluded.status,
                    difficulty=excluded.difficulty, tech=excluded.tech,
                    due_date=excluded.due_date, completed_at=excluded.completed_at,
-                   updated_at=excluded.updated_at, deleted_at=excluded.deleted_at",
+                   updated_at=excluded.updated_at, deleted_at=excluded.deleted_at,
+                   description=excluded.description, start_date=excluded.start_date,
+                   owner=excluded.owner, url=excluded.url, color=excluded.color",
                 params![
                     project.uuid.to_string(),
                     project.name,
@@ -512,6 +776,13 @@ impl Storage for SqliteStorage {
                     to_unix(project.created_at),
                     opt_to_unix(project.updated_at),
                     opt_to_unix(project.deleted_at),
+                    project.parent_id.map(|u| u.to_string()),
+                    project.status.as_str(),
+                    project.description,
+                    project.start_date.map(|d| d.format("%Y-%m-%d").to_string()),
+                    project.owner,
+                    project.url,
+                    project.color,
                 ],
             )
             .context("Failed to upsert project")?;
@@ -525,13 +796,12 @@ impl Storage for SqliteStorage {
             .context("Failed to begin upsert_note transaction")?;
         let uuid_str = note.uuid.to_string();
         tx.execute(
-            "INSERT INTO notes (uuid, title, body, format, language, project_id,
-                      task_id, tags, created_at, updated_at, deleted_at)
-             VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11)
+            "INSERT INTO notes (uuid, title, body, format, language, tags,
+                      created_at, updated_at, deleted_at)
+             VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9)
              ON CONFLICT(uuid) DO UPDATE SET
                title=excluded.title, body=excluded.body, format=excluded.format,
-               language=excluded.language, project_id=excluded.project_id,
-               task_id=excluded.task_id, tags=excluded.tags,
+               language=excluded.language, tags=excluded.tags,
                updated_at=excluded.updated_at, deleted_at=excluded.deleted_at",
             params![
                 uuid_str,
@@ -539,8 +809,6 @@ impl Storage for SqliteStorage {
                 note.body,
                 format_to_str(note.format),
                 note.language,
-                note.project_id.map(|u| u.to_string()),
-                note.task_id.map(|u| u.to_string()),
                 JsonVec(note.tags.clone()),
                 to_unix(note.created_at),
                 opt_to_unix(note.updated_at),
@@ -548,47 +816,15 @@ impl Storage for SqliteStorage {
             ],
         )
         .context("Failed to upsert note")?;
-        tx.execute(
-            "DELETE FROM note_resources WHERE note_uuid = ?1",
-            params![uuid_str],
-        )?;
-        for resource_id in &note.resource_ids {
-            tx.execute(
-                "INSERT OR IGNORE INTO note_resources
-                 (note_uuid, resource_uuid) VALUES (?1, ?2)",
-                params![uuid_str, resource_id.to_string()],
-            )?;
-        }
+        save_note_relations(&tx, note)?;
+        save_note_links(&tx, note)?;
         tx.commit()
             .context("Failed to commit upsert_note transaction")?;
         Ok(())
     }
 
     fn upsert_resource(&self, resource: &Resource) -> Result<()> {
-        self.conn
-            .borrow()
-            .execute(
-                "INSERT INTO resources (uuid, title, resource_type, url, description,
-                          tags, created_at, updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9)
-                 ON CONFLICT(uuid) DO UPDATE SET
-                   title=excluded.title, resource_type=excluded.resource_type,
-                   url=excluded.url, description=excluded.description,
-                   tags=excluded.tags, updated_at=excluded.updated_at,
-                   deleted_at=excluded.deleted_at",
-                params![
-                    resource.uuid.to_string(),
-                    resource.title.clone(),
-                    resource.resource_type.map(resource_type_to_str),
-                    resource.url,
-                    resource.description,
-                    JsonVec(resource.tags.clone()),
-                    to_unix(resource.created_at),
-                    opt_to_unix(resource.updated_at),
-                    opt_to_unix(resource.deleted_at),
-                ],
-            )
-            .context("Failed to upsert resource")?;
+        upsert_resource_row(&self.conn.borrow(), resource).context("Failed to upsert resource")?;
         Ok(())
     }
 
@@ -603,8 +839,11 @@ impl Storage for SqliteStorage {
         let pattern = format!("%{}%", q.to_lowercase());
 
         let status_clause = match status {
-            StatusFilter::Pending => " AND completed = 0",
-            StatusFilter::Done => " AND completed = 1",
+            StatusFilter::Pending => " AND status IN ('pending','active','waiting')",
+            StatusFilter::Active => " AND status = 'active'",
+            StatusFilter::Waiting => " AND status = 'waiting'",
+            StatusFilter::Done => " AND status = 'done'",
+            StatusFilter::Cancelled => " AND status = 'cancelled'",
             StatusFilter::All => "",
         };
         let proj_clause = if project_id.is_some() {
@@ -650,7 +889,7 @@ impl Storage for SqliteStorage {
         let conn = self.conn.borrow();
         let pattern = format!("%{}%", q.to_lowercase());
         let proj_clause = if project_id.is_some() {
-            " AND project_id = ?2"
+            " AND uuid IN (SELECT note_uuid FROM note_projects WHERE project_uuid = ?2)"
         } else {
             ""
         };
@@ -690,6 +929,7 @@ impl Storage for SqliteStorage {
         let mut stmt = conn.prepare(
             "SELECT * FROM projects WHERE deleted_at IS NULL AND (
                LOWER(name) LIKE ?1 OR LOWER(tech) LIKE ?1
+               OR LOWER(COALESCE(description, '')) LIKE ?1
              ) ORDER BY created_at",
         )?;
 
@@ -732,27 +972,29 @@ impl Storage for SqliteStorage {
         for task in tasks {
             let uuid_str = task.uuid.to_string();
             tx.execute(
-                "INSERT INTO tasks (uuid, text, completed, priority, due_date, recurrence,
-                          project_id, parent_id, tags, completed_at, created_at,
+                "INSERT INTO tasks (uuid, text, completed, status, priority, due_date, recurrence,
+                          project_id, parent_id, tags, estimate, completed_at, created_at,
                           updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13)
+                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15)
                  ON CONFLICT(uuid) DO UPDATE SET
-                   text=excluded.text, completed=excluded.completed,
+                   text=excluded.text, completed=excluded.completed, status=excluded.status,
                    priority=excluded.priority, due_date=excluded.due_date,
                    recurrence=excluded.recurrence, project_id=excluded.project_id,
-                   parent_id=excluded.parent_id, tags=excluded.tags,
+                   parent_id=excluded.parent_id, tags=excluded.tags, estimate=excluded.estimate,
                    completed_at=excluded.completed_at, updated_at=excluded.updated_at,
                    deleted_at=excluded.deleted_at",
                 params![
                     uuid_str,
                     task.text,
                     task.completed as i64,
+                    task.status.as_str(),
                     priority_to_str(task.priority),
                     task.due_date.map(|d| d.format("%Y-%m-%d").to_string()),
                     task.recurrence.map(recurrence_to_str),
                     task.project_id.map(|u| u.to_string()),
                     task.parent_id.map(|u| u.to_string()),
                     JsonVec(task.tags.clone()),
+                    task.estimate,
                     task.completed_at.map(|d| {
                         let dt: DateTime<Utc> =
                             Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap());
@@ -794,17 +1036,23 @@ impl Storage for SqliteStorage {
     fn save_projects(&self, projects: &[Project]) -> Result<()> {
         let mut conn = self.conn.borrow_mut();
         let tx = conn.transaction().context("Failed to begin transaction")?;
+        // A child may be saved before the parent it references.
+        tx.execute_batch("PRAGMA defer_foreign_keys = ON")?;
 
         for project in projects {
             tx.execute(
                 "INSERT INTO projects (uuid, name, completed, difficulty, tech, due_date,
-                          completed_at, created_at, updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10)
+                          completed_at, created_at, updated_at, deleted_at, parent_id,
+                          status, description, start_date, owner, url, color)
+                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15,?16,?17)
                  ON CONFLICT(uuid) DO UPDATE SET
-                   name=excluded.name, completed=excluded.completed,
+                   name=excluded.name, parent_id=excluded.parent_id,
+                   completed=excluded.completed, status=excluded.status,
                    difficulty=excluded.difficulty, tech=excluded.tech,
                    due_date=excluded.due_date, completed_at=excluded.completed_at,
-                   updated_at=excluded.updated_at, deleted_at=excluded.deleted_at",
+                   updated_at=excluded.updated_at, deleted_at=excluded.deleted_at,
+                   description=excluded.description, start_date=excluded.start_date,
+                   owner=excluded.owner, url=excluded.url, color=excluded.color",
                 params![
                     project.uuid.to_string(),
                     project.name,
@@ -818,6 +1066,13 @@ impl Storage for SqliteStorage {
                     to_unix(project.created_at),
                     opt_to_unix(project.updated_at),
                     opt_to_unix(project.deleted_at),
+                    project.parent_id.map(|u| u.to_string()),
+                    project.status.as_str(),
+                    project.description,
+                    project.start_date.map(|d| d.format("%Y-%m-%d").to_string()),
+                    project.owner,
+                    project.url,
+                    project.color,
                 ],
             )?;
         }
@@ -844,13 +1099,12 @@ impl Storage for SqliteStorage {
         for note in notes {
             let uuid_str = note.uuid.to_string();
             tx.execute(
-                "INSERT INTO notes (uuid, title, body, format, language, project_id,
-                          task_id, tags, created_at, updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11)
+                "INSERT INTO notes (uuid, title, body, format, language, tags,
+                          created_at, updated_at, deleted_at)
+                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9)
                  ON CONFLICT(uuid) DO UPDATE SET
                    title=excluded.title, body=excluded.body, format=excluded.format,
-                   language=excluded.language, project_id=excluded.project_id,
-                   task_id=excluded.task_id, tags=excluded.tags,
+                   language=excluded.language, tags=excluded.tags,
                    updated_at=excluded.updated_at, deleted_at=excluded.deleted_at",
                 params![
                     uuid_str,
@@ -858,26 +1112,14 @@ impl Storage for SqliteStorage {
                     note.body,
                     format_to_str(note.format),
                     note.language,
-                    note.project_id.map(|u| u.to_string()),
-                    note.task_id.map(|u| u.to_string()),
                     JsonVec(note.tags.clone()),
                     to_unix(note.created_at),
                     opt_to_unix(note.updated_at),
                     opt_to_unix(note.deleted_at),
                 ],
             )?;
-
-            tx.execute(
-                "DELETE FROM note_resources WHERE note_uuid = ?1",
-                params![uuid_str],
-            )?;
-            for resource_id in &note.resource_ids {
-                tx.execute(
-                    "INSERT OR IGNORE INTO note_resources
-                     (note_uuid, resource_uuid) VALUES (?1, ?2)",
-                    params![uuid_str, resource_id.to_string()],
-                )?;
-            }
+            save_note_relations(&tx, note)?;
+            save_note_links(&tx, note)?;
         }
 
         tx.commit().context("Failed to commit notes transaction")?;
@@ -899,30 +1141,374 @@ impl Storage for SqliteStorage {
         let tx = conn.transaction().context("Failed to begin transaction")?;
 
         for resource in resources {
+            upsert_resource_row(&tx, resource)?;
+        }
+
+        tx.commit()
+            .context("Failed to commit resources transaction")?;
+        Ok(())
+    }
+
+    fn load_note_revisions(&self, note_uuid: Uuid) -> Result<Vec<NoteRevision>> {
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare(
+            "SELECT number, title, body, created_at FROM note_revisions
+             WHERE note_uuid = ?1 ORDER BY number",
+        )?;
+        let revisions = stmt
+            .query_map(params![note_uuid.to_string()], |row| {
+                Ok(NoteRevision {
+                    note_uuid,
+                    number: row.get(0)?,
+                    title: row.get(1)?,
+                    body: row.get(2)?,
+                    created_at: from_unix(row.get(3)?),
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load note revisions")?;
+        Ok(revisions)
+    }
+
+    fn save_note_revision(&self, revision: &NoteRevision) -> Result<()> {
+        let conn = self.conn.borrow();
+        conn.execute(
+            "INSERT OR REPLACE INTO note_revisions (note_uuid, number, title, body, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                revision.note_uuid.to_string(),
+                revision.number,
+                revision.title,
+                revision.body,
+                to_unix(revision.created_at),
+            ],
+        )
+        .context("Failed to save note revision")?;
+        Ok(())
+    }
+
+    fn prune_note_revisions(&self, keep: usize) -> Result<usize> {
+        let conn = self.conn.borrow();
+        let n = conn
+            .execute(
+                "DELETE FROM note_revisions AS r
+                 WHERE r.note_uuid NOT IN (SELECT uuid FROM notes)
+                    OR (SELECT COUNT(*) FROM note_revisions AS newer
+                        WHERE newer.note_uuid = r.note_uuid
+                          AND newer.number > r.number) >= ?1",
+                params![keep as i64],
+            )
+            .context("Failed to prune note revisions")?;
+        Ok(n)
+    }
+
+    fn load_attachments(&self) -> Result<Vec<Attachment>> {
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare(
+            "SELECT uuid, owner_type, owner_uuid, name, hash, size, created_at
+             FROM attachments ORDER BY created_at, rowid",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, i64>(5)?,
+                    row.get::<_, i64>(6)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load attachments")?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|(uuid, kind, owner, name, hash, size, created)| {
+                Some(Attachment {
+                    uuid: Uuid::parse_str(&uuid).ok()?,
+                    owner: AttachmentOwner::from_parts(&kind, Uuid::parse_str(&owner).ok()?)?,
+                    name,
+                    hash,
+                    size: size as u64,
+                    created_at: from_unix(created),
+                })
+            })
+            .collect())
+    }
+
+    fn save_attachment(&self, attachment: &Attachment) -> Result<()> {
+        let conn = self.conn.borrow();
+        conn.execute(
+            "INSERT OR REPLACE INTO attachments
+                 (uuid, owner_type, owner_uuid, name, hash, size, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                attachment.uuid.to_string(),
+                attachment.owner.kind(),
+                attachment.owner.uuid().to_string(),
+                attachment.name,
+                attachment.hash,
+                attachment.size as i64,
+                to_unix(attachment.created_at),
+            ],
+        )
+        .context("Failed to save attachment")?;
+        Ok(())
+    }
+
+    fn attachments_dir(&self) -> Result<PathBuf> {
+        Ok(super::attachments::dir_for(&self.path))
+    }
+
+    fn load_templates(&self) -> Result<Vec<Template>> {
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare("SELECT name, body FROM templates ORDER BY name")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load templates")?;
+        rows.into_iter()
+            .map(|(name, body)| {
+                let mut template: Template = serde_json::from_str(&body)
+                    .with_context(|| format!("Corrupt template '{}'", name))?;
+                template.name = name;
+                Ok(template)
+            })
+            .collect()
+    }
+
+    fn save_template(&self, template: &Template) -> Result<()> {
+        let conn = self.conn.borrow();
+        let now = to_unix(Utc::now());
+        conn.execute(
+            "INSERT INTO templates (name, body, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?3)
+             ON CONFLICT(name) DO UPDATE SET
+               name=excluded.name, body=excluded.body, updated_at=excluded.updated_at",
+            params![template.name, serde_json::to_string(template)?, now],
+        )
+        .context("Failed to save template")?;
+        Ok(())
+    }
+
+    fn delete_template(&self, name: &str) -> Result<bool> {
+        let conn = self.conn.borrow();
+        let n = conn.execute("DELETE FROM templates WHERE name = ?1", params![name])?;
+        Ok(n > 0)
+    }
+
+    fn load_views(&self) -> Result<Vec<View>> {
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare("SELECT name, body FROM views ORDER BY name")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load views")?;
+        rows.into_iter()
+            .map(|(name, body)| {
+                let mut view: View = serde_json::from_str(&body)
+                    .with_context(|| format!("Corrupt view '{}'", name))?;
+                view.name = name;
+                Ok(view)
+            })
+            .collect()
+    }
+
+    fn save_view(&self, view: &View) -> Result<()> {
+        let conn = self.conn.borrow();
+        let now = to_unix(Utc::now());
+        conn.execute(
+            "INSERT INTO views (name, body, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?3)
+             ON CONFLICT(name) DO UPDATE SET
+               name=excluded.name, body=excluded.body, updated_at=excluded.updated_at",
+            params![view.name, serde_json::to_string(view)?, now],
+        )
+        .context("Failed to save view")?;
+        Ok(())
+    }
+
+    fn delete_view(&self, name: &str) -> Result<bool> {
+        let conn = self.conn.borrow();
+        let n = conn.execute("DELETE FROM views WHERE name = ?1", params![name])?;
+        Ok(n > 0)
+    }
+
+    fn load_context(&self) -> Result<Option<String>> {
+        let conn = self.conn.borrow();
+        conn.query_row(
+            "SELECT value FROM settings WHERE key = 'context'",
+            [],
+            |row| row.get(0),
+        )
+        .optional()
+        .context("Failed to load context")
+    }
+
+    fn save_context(&self, name: Option<&str>) -> Result<()> {
+        let conn = self.conn.borrow();
+        match name {
+            Some(name) => conn.execute(
+                "INSERT INTO settings (key, value) VALUES ('context', ?1)
+                 ON CONFLICT(key) DO UPDATE SET value=excluded.value",
+                params![name],
+            ),
+            None => conn.execute("DELETE FROM settings WHERE key = 'context'", []),
+        }
+        .context("Failed to save context")?;
+        Ok(())
+    }
+
+    fn load_archive(&self) -> Result<Vec<ArchiveEntry>> {
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare(
+            "SELECT uuid, kind, body, archived_at, revisions FROM archive ORDER BY rowid",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load archive")?;
+        rows.into_iter()
+            .map(|(uuid, kind, body, archived_at, revisions)| {
+                let corrupt = || format!("Corrupt archived {} '{}'", kind, uuid);
+                let item = match kind.as_str() {
+                    "task" => Archived::Task(serde_json::from_str(&body).with_context(corrupt)?),
+                    "project" => {
+                        Archived::Project(serde_json::from_str(&body).with_context(corrupt)?)
+                    }
+                    _ => Archived::Note(serde_json::from_str(&body).with_context(corrupt)?),
+                };
+                Ok(ArchiveEntry {
+                    item,
+                    archived_at: from_unix(archived_at),
+                    revisions: serde_json::from_str(&revisions).with_context(corrupt)?,
+                })
+            })
+            .collect()
+    }
+
+    fn archive(&self, entries: &[ArchiveEntry]) -> Result<()> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().context("Failed to begin transaction")?;
+        for entry in entries {
+            let body = match &entry.item {
+                Archived::Task(t) => serde_json::to_string(t)?,
+                Archived::Project(p) => serde_json::to_string(p)?,
+                Archived::Note(n) => serde_json::to_string(n)?,
+            };
+            // Revisions of an archived note travel with it.
+            let revisions: Vec<NoteRevision> = tx
+                .prepare(
+                    "SELECT number, title, body, created_at FROM note_revisions
+                     WHERE note_uuid = ?1 ORDER BY number",
+                )?
+                .query_map(params![entry.uuid().to_string()], |row| {
+                    Ok(NoteRevision {
+                        note_uuid: entry.uuid(),
+                        number: row.get(0)?,
+                        title: row.get(1)?,
+                        body: row.get(2)?,
+                        created_at: from_unix(row.get(3)?),
+                    })
+                })?
+                .collect::<rusqlite::Result<_>>()?;
             tx.execute(
-                "INSERT INTO resources (uuid, title, resource_type, url, description,
-                          tags, created_at, updated_at, deleted_at)
-                 VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9)
-                 ON CONFLICT(uuid) DO UPDATE SET
-                   title=excluded.title, resource_type=excluded.resource_type,
-                   url=excluded.url, description=excluded.description,
-                   tags=excluded.tags, updated_at=excluded.updated_at,
-                   deleted_at=excluded.deleted_at",
+                "INSERT OR REPLACE INTO archive (uuid, kind, body, archived_at, revisions)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
                 params![
-                    resource.uuid.to_string(),
-                    resource.resource_type.map(resource_type_to_str),
-                    resource.url,
-                    resource.description,
-                    JsonVec(resource.tags.clone()),
-                    to_unix(resource.created_at),
-                    opt_to_unix(resource.updated_at),
-                    opt_to_unix(resource.deleted_at),
+                    entry.uuid().to_string(),
+                    entry.kind(),
+                    body,
+                    to_unix(entry.archived_at),
+                    serde_json::to_string(&revisions)?
                 ],
             )?;
         }
 
+        // Notes reference tasks and projects, and tasks reference projects,
+        // so rows are removed in that order.
+        for entry in entries {
+            let s = entry.uuid().to_string();
+            match entry.item {
+                Archived::Note(_) => {
+                    tx.execute(
+                        "DELETE FROM note_resources WHERE note_uuid = ?1",
+                        params![s],
+                    )?;
+                    tx.execute("DELETE FROM note_tasks WHERE note_uuid = ?1", params![s])?;
+                    tx.execute("DELETE FROM note_projects WHERE note_uuid = ?1", params![s])?;
+                    tx.execute("DELETE FROM note_links WHERE note_uuid = ?1", params![s])?;
+                    tx.execute(
+                        "DELETE FROM note_revisions WHERE note_uuid = ?1",
+                        params![s],
+                    )?;
+                    tx.execute("DELETE FROM notes WHERE uuid = ?1", params![s])?;
+                }
+                Archived::Task(_) | Archived::Project(_) => {}
+            }
+        }
+        for entry in entries {
+            let s = entry.uuid().to_string();
+            if let Archived::Task(_) = entry.item {
+                tx.execute(
+                    "DELETE FROM task_dependencies WHERE task_uuid = ?1 OR depends_on_uuid = ?1",
+                    params![s],
+                )?;
+                tx.execute(
+                    "UPDATE tasks SET parent_id = NULL WHERE parent_id = ?1",
+                    params![s],
+                )?;
+                tx.execute("DELETE FROM note_tasks WHERE task_uuid = ?1", params![s])?;
+                tx.execute("DELETE FROM tasks WHERE uuid = ?1", params![s])?;
+            }
+        }
+        for entry in entries {
+            let s = entry.uuid().to_string();
+            if let Archived::Project(_) = entry.item {
+                tx.execute(
+                    "UPDATE projects SET parent_id = NULL WHERE parent_id = ?1",
+                    params![s],
+                )?;
+                tx.execute(
+                    "UPDATE tasks SET project_id = NULL WHERE project_id = ?1",
+                    params![s],
+                )?;
+                tx.execute(
+                    "DELETE FROM note_projects WHERE project_uuid = ?1",
+                    params![s],
+                )?;
+                tx.execute("DELETE FROM projects WHERE uuid = ?1", params![s])?;
+            }
+        }
+        tx.commit()
+            .context("Failed to commit archive transaction")?;
+        Ok(())
+    }
+
+    fn delete_archived(&self, uuids: &[Uuid]) -> Result<()> {
+        let mut conn = self.conn.borrow_mut();
+        let tx = conn.transaction().context("Failed to begin transaction")?;
+        for uuid in uuids {
+            tx.execute(
+                "DELETE FROM archive WHERE uuid = ?1",
+                params![uuid.to_string()],
+            )?;
+        }
         tx.commit()
-            .context("Failed to commit resources transaction")?;
+            .context("Failed to commit delete_archived transaction")?;
         Ok(())
     }
 
@@ -1029,6 +1615,7 @@ impl Storage for SqliteStorage {
             match event_type.as_str() {
                 "created" => stat.created += 1,
                 "completed" => stat.completed += 1,
+                "cancelled" => stat.cancelled += 1,
                 "deleted" | "purged" => stat.deleted += 1,
                 _ => {}
             }
@@ -1037,6 +1624,40 @@ impl Storage for SqliteStorage {
         Ok(map.into_values().collect())
     }
 
+    fn load_events(&self, entity_type: EntityType, uuids: &[Uuid]) -> Result<Vec<Event>> {
+        let wanted: std::collections::HashSet<String> =
+            uuids.iter().map(|u| u.to_string()).collect();
+        let conn = self.conn.borrow();
+        let mut stmt = conn.prepare(
+            "SELECT entity_uuid, event_type, occurred_at
+             FROM events
+             WHERE entity_type = ?1
+             ORDER BY occurred_at, id",
+        )?;
+        let rows = stmt
+            .query_map(params![entity_type.as_str()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                ))
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to load events")?;
+
+        Ok(rows
+            .into_iter()
+            .filter(|(uuid, _, _)| wanted.contains(uuid))
+            .filter_map(|(uuid, event_type, occurred_at)| {
+                Some(Event {
+                    entity_uuid: Uuid::parse_str(&uuid).ok()?,
+                    event_type: EventType::parse(&event_type)?,
+                    occurred_at: from_unix(occurred_at),
+                })
+            })
+            .collect())
+    }
+
     fn location(&self) -> String {
         self.path.display().to_string()
     }
@@ -1050,6 +1671,8 @@ impl Storage for SqliteStorage {
                 "DELETE FROM task_dependencies WHERE task_uuid = ?1 OR depends_on_uuid = ?1",
                 params![s],
             )?;
+            tx.execute("DELETE FROM note_tasks WHERE task_uuid = ?1", params![s])?;
+            tx.execute("DELETE FROM attachments WHERE owner_uuid = ?1", params![s])?;
             tx.execute("DELETE FROM tasks WHERE uuid = ?1", params![s])?;
         }
         tx.commit()
@@ -1061,6 +1684,14 @@ impl Storage for SqliteStorage {
         let mut conn = self.conn.borrow_mut();
         let tx = conn.transaction().context("Failed to begin transaction")?;
         for uuid in uuids {
+            tx.execute(
+                "UPDATE projects SET parent_id = NULL WHERE parent_id = ?1",
+                params![uuid.to_string()],
+            )?;
+            tx.execute(
+                "DELETE FROM note_projects WHERE project_uuid = ?1",
+                params![uuid.to_string()],
+            )?;
             tx.execute(
                 "DELETE FROM projects WHERE uuid = ?1",
                 params![uuid.to_string()],
@@ -1080,6 +1711,14 @@ impl Storage for SqliteStorage {
                 "DELETE FROM note_resources WHERE note_uuid = ?1",
                 params![s],
             )?;
+            tx.execute("DELETE FROM note_tasks WHERE note_uuid = ?1", params![s])?;
+            tx.execute("DELETE FROM note_projects WHERE note_uuid = ?1", params![s])?;
+            tx.execute("DELETE FROM note_links WHERE note_uuid = ?1", params![s])?;
+            tx.execute(
+                "DELETE FROM note_revisions WHERE note_uuid = ?1",
+                params![s],
+            )?;
+            tx.execute("DELETE FROM attachments WHERE owner_uuid = ?1", params![s])?;
             tx.execute("DELETE FROM notes WHERE uuid = ?1", params![s])?;
         }
         tx.commit()
@@ -1130,6 +1769,95 @@ fn difficulty_to_str(d: Difficulty) -> &'static str {
     }
 }
 
+/// The UUIDs returned by a single-column `sql` query for `note_uuid`.
+fn load_uuids(conn: &Connection, sql: &str, note_uuid: &str) -> rusqlite::Result<Vec<Uuid>> {
+    let mut stmt = conn.prepare_cached(sql)?;
+    let uuids = stmt
+        .query_map(params![note_uuid], |r| r.get::<_, String>(0))?
+        .filter_map(|r| r.ok())
+        .filter_map(|s| Uuid::parse_str(&s).ok())
+        .collect();
+    Ok(uuids)
+}
+
+/// Replaces the stored resource, task and project links of `note`.
+fn save_note_relations(tx: &Connection, note: &Note) -> rusqlite::Result<()> {
+    let uuid_str = note.uuid.to_string();
+    let relations = [
+        ("note_resources", "resource_uuid", &note.resource_ids),
+        ("note_tasks", "task_uuid", &note.task_ids),
+        ("note_projects", "project_uuid", &note.project_ids),
+    ];
+    for (table, column, ids) in relations {
+        tx.execute(
+            &format!("DELETE FROM {table} WHERE note_uuid = ?1"),
+            params![uuid_str],
+        )?;
+        for id in ids {
+            tx.execute(
+                &format!("INSERT OR IGNORE INTO {table} (note_uuid, {column}) VALUES (?1, ?2)"),
+                params![uuid_str, id.to_string()],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Replaces the stored wiki-links of `note` with `note.links`.
+fn save_note_links(tx: &Connection, note: &Note) -> rusqlite::Result<()> {
+    let uuid_str = note.uuid.to_string();
+    tx.execute(
+        "DELETE FROM note_links WHERE note_uuid = ?1",
+        params![uuid_str],
+    )?;
+    for link in &note.links {
+        tx.execute(
+            "INSERT OR IGNORE INTO note_links
+             (note_uuid, reference, target_type, target_uuid) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                uuid_str,
+                link.reference,
+                link.target.kind(),
+                link.target.uuid().to_string()
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+fn upsert_resource_row(conn: &Connection, resource: &Resource) -> rusqlite::Result<()> {
+    let check = resource.last_check.as_ref();
+    conn.execute(
+        "INSERT INTO resources (uuid, title, resource_type, url, description, tags,
+                  created_at, updated_at, deleted_at,
+                  checked_at, http_status, final_url, check_error)
+         VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13)
+         ON CONFLICT(uuid) DO UPDATE SET
+           title=excluded.title, resource_type=excluded.resource_type,
+           url=excluded.url, description=excluded.description,
+           tags=excluded.tags, updated_at=excluded.updated_at,
+           deleted_at=excluded.deleted_at, checked_at=excluded.checked_at,
+           http_status=excluded.http_status, final_url=excluded.final_url,
+           check_error=excluded.check_error",
+        params![
+            resource.uuid.to_string(),
+            resource.title,
+            resource.resource_type.map(resource_type_to_str),
+            resource.url,
+            resource.description,
+            JsonVec(resource.tags.clone()),
+            to_unix(resource.created_at),
+            opt_to_unix(resource.updated_at),
+            opt_to_unix(resource.deleted_at),
+            check.map(|c| to_unix(c.checked_at)),
+            check.and_then(|c| c.status),
+            check.and_then(|c| c.final_url.as_deref()),
+            check.and_then(|c| c.error.as_deref()),
+        ],
+    )?;
+    Ok(())
+}
+
 fn format_to_str(f: NoteFormat) -> &'static str {
     match f {
         NoteFormat::Plain => "plain",
@@ -1257,4 +1985,349 @@ mod tests {
         storage.save(&[task]).unwrap();
         assert!(storage.load().unwrap()[0].is_deleted());
     }
+
+    #[test]
+    fn test_views_and_context_round_trip() {
+        use crate::models::{SortBy, View};
+
+        let (storage, _tmp) = make_storage();
+        let view = View {
+            name: "Work".into(),
+            filter: "project:work status:pending".into(),
+            sort: Some(SortBy::Urgency),
+        };
+        storage.save_view(&view).unwrap();
+        assert_eq!(storage.load_views().unwrap(), vec![view]);
+
+        assert_eq!(storage.load_context().unwrap(), None);
+        storage.save_context(Some("Work")).unwrap();
+        storage.save_context(Some("work")).unwrap();
+        assert_eq!(storage.load_context().unwrap().as_deref(), Some("work"));
+        storage.save_context(None).unwrap();
+        assert_eq!(storage.load_context().unwrap(), None);
+
+        assert!(storage.delete_view("WORK").unwrap());
+        assert!(storage.load_views().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_template_round_trip_is_case_insensitive() {
+        use crate::models::{Template, TemplateTask};
+
+        let (storage, _tmp) = make_storage();
+        let step = TemplateTask {
+            text: "Tag v{{version}}".into(),
+            priority: Priority::High,
+            tags: vec!["release".into()],
+            due: Some("+2d".into()),
+            depends_on: vec![],
+        };
+        let mut template = Template::new("Release".into(), vec![step]);
+        storage.save_template(&template).unwrap();
+
+        template.name = "release".into();
+        template.description = Some("updated".into());
+        storage.save_template(&template).unwrap();
+
+        let loaded = storage.load_templates().unwrap();
+        assert_eq!(loaded, vec![template]);
+
+        assert!(storage.delete_template("RELEASE").unwrap());
+        assert!(!storage.delete_template("release").unwrap());
+    }
+
+    #[test]
+    fn test_note_links_round_trip() {
+        let (storage, _tmp) = make_storage();
+        let task = Task::new("Spec".into(), Priority::Medium, vec![], None, None, None);
+        let mut note = Note::new("See [[task:1]]".into());
+        note.links = vec![NoteLink {
+            reference: "task:1".into(),
+            target: LinkTarget::Task(task.uuid),
+        }];
+        storage.upsert_note(&note).unwrap();
+        assert_eq!(storage.load_notes().unwrap()[0].links, note.links);
+
+        note.links.clear();
+        storage.save_notes(std::slice::from_ref(&note)).unwrap();
+        assert!(storage.load_notes().unwrap()[0].links.is_empty());
+    }
+
+    #[test]
+    fn test_resource_link_check_round_trip() {
+        let (storage, _tmp) = make_storage();
+        let mut resource = Resource::new("Rust book".into());
+        resource.url = Some("https://doc.rust-lang.org/book".into());
+        storage.upsert_resource(&resource).unwrap();
+        assert_eq!(storage.load_resources().unwrap()[0].last_check, None);
+
+        resource.last_check = Some(LinkCheck {
+            checked_at: from_unix(to_unix(Utc::now())),
+            status: Some(200),
+            final_url: Some("https://doc.rust-lang.org/book/".into()),
+            error: None,
+        });
+        storage
+            .save_resources(std::slice::from_ref(&resource))
+            .unwrap();
+        let loaded = &storage.load_resources().unwrap()[0];
+        assert_eq!(loaded.title, "Rust book");
+        assert_eq!(loaded.last_check, resource.last_check);
+    }
+
+    #[test]
+    fn test_note_revisions_prune_and_cascade() {
+        let (storage, _tmp) = make_storage();
+        let mut note = Note::new("v1".into());
+        storage.upsert_note(&note).unwrap();
+        for number in 1..=3 {
+            storage
+                .save_note_revision(&NoteRevision::of(&note, number))
+                .unwrap();
+            note.body = format!("v{}", number + 1);
+        }
+
+        assert_eq!(storage.prune_note_revisions(2).unwrap(), 1);
+        let numbers: Vec<u32> = storage
+            .load_note_revisions(note.uuid)
+            .unwrap()
+            .iter()
+            .map(|r| r.number)
+            .collect();
+        assert_eq!(numbers, vec![2, 3]);
+
+        storage.delete_notes(&[note.uuid]).unwrap();
+        assert!(storage.load_note_revisions(note.uuid).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_attachments_round_trip_and_cascade() {
+        let (storage, tmp) = make_storage();
+        let note = Note::new("Design".into());
+        storage.upsert_note(&note).unwrap();
+        let attachment = Attachment::new(
+            AttachmentOwner::Note(note.uuid),
+            "spec.pdf".into(),
+            "ab".repeat(32),
+            1234,
+        );
+        storage.save_attachment(&attachment).unwrap();
+
+        let loaded = storage.load_attachments().unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].owner, attachment.owner);
+        assert_eq!(loaded[0].name, "spec.pdf");
+        assert_eq!(loaded[0].size, 1234);
+        assert_eq!(
+            storage.attachments_dir().unwrap(),
+            tmp.path().join("attachments")
+        );
+
+        storage.delete_notes(&[note.uuid]).unwrap();
+        assert!(storage.load_attachments().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_archive_round_trip_unlinks_live_rows() {
+        use crate::models::{ArchiveEntry, Archived};
+
+        let (storage, _tmp) = make_storage();
+        let mut project = Project::new("Old".into());
+        project.mark_done();
+        let mut done = Task::new(
+            "Done".into(),
+            Priority::Medium,
+            vec![],
+            Some(project.uuid),
+            None,
+            None,
+        );
+        done.mark_done();
+        let mut live = Task::new("Live".into(), Priority::Medium, vec![], None, None, None);
+        live.depends_on = vec![done.uuid];
+        let mut note = Note::new("Retro".into());
+        note.task_ids = vec![done.uuid];
+        storage
+            .save_projects(std::slice::from_ref(&project))
+            .unwrap();
+        storage.save(&[done.clone(), live]).unwrap();
+        storage.save_notes(std::slice::from_ref(&note)).unwrap();
+        storage
+            .save_note_revision(&NoteRevision::of(&note, 1))
+            .unwrap();
+        let note_uuid = note.uuid;
+
+        storage
+            .archive(&[
+                ArchiveEntry::new(Archived::Project(project)),
+                ArchiveEntry::new(Archived::Task(done.clone())),
+                ArchiveEntry::new(Archived::Note(note)),
+            ])
+            .unwrap();
+
+        let tasks = storage.load().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert!(tasks[0].depends_on.is_empty());
+        assert!(storage.load_projects().unwrap().is_empty());
+        assert!(storage.load_notes().unwrap().is_empty());
+
+        let archive = storage.load_archive().unwrap();
+        assert_eq!(
+            archive.iter().map(|e| e.kind()).collect::<Vec<_>>(),
+            vec!["project", "task", "note"]
+        );
+        assert!(matches!(&archive[1].item, Archived::Task(t) if t.text == "Done"));
+        // The note's revisions move into the archive with it.
+        assert!(storage.load_note_revisions(note_uuid).unwrap().is_empty());
+        assert_eq!(archive[2].revisions.len(), 1);
+        assert_eq!(archive[2].revisions[0].body, "Retro");
+
+        storage.delete_archived(&[done.uuid]).unwrap();
+        assert_eq!(storage.load_archive().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_project_status_and_details_round_trip() {
+        let (storage, _tmp) = make_storage();
+        let mut project = Project::new("Lab".into());
+        project.set_status(ProjectStatus::OnHold);
+        project.description = Some("Self-hosted services".into());
+        project.start_date = NaiveDate::from_ymd_opt(2026, 11, 1);
+        project.owner = Some("sam".into());
+        project.url = Some("https://lab.example.com".into());
+        project.color = Some("#ff8800".into());
+        storage.upsert_project(&project).unwrap();
+
+        let loaded = &storage.load_projects().unwrap()[0];
+        assert_eq!(loaded.status, ProjectStatus::OnHold);
+        assert_eq!(loaded.description, project.description);
+        assert_eq!(loaded.start_date, project.start_date);
+        assert_eq!(loaded.owner, project.owner);
+        assert_eq!(loaded.url, project.url);
+        assert_eq!(loaded.color, project.color);
+        assert_eq!(storage.search_projects("self-hosted").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_project_parent_round_trip_and_delete_unlinks_children() {
+        let (storage, _tmp) = make_storage();
+        let parent = Project::new("Acme".into());
+        let mut child = Project::new("Api".into());
+        child.parent_id = Some(parent.uuid);
+        // The child comes first; foreign keys are only checked on commit.
+        storage
+            .save_projects(&[child.clone(), parent.clone()])
+            .unwrap();
+
+        let projects = storage.load_projects().unwrap();
+        let loaded = projects.iter().find(|p| p.uuid == child.uuid).unwrap();
+        assert_eq!(loaded.parent_id, Some(parent.uuid));
+
+        storage.delete_projects(&[parent.uuid]).unwrap();
+        let projects = storage.load_projects().unwrap();
+        assert_eq!(projects.len(), 1);
+        assert!(projects[0].parent_id.is_none());
+    }
+
+    #[test]
+    fn test_status_round_trip_and_cancelled_stats() {
+        let (storage, _tmp) = make_storage();
+        let mut task = Task::new("T".into(), Priority::Medium, vec![], None, None, None);
+        task.set_status(TaskStatus::Cancelled);
+        let uuid = task.uuid;
+        storage.save(&[task]).unwrap();
+        assert_eq!(storage.load().unwrap()[0].status, TaskStatus::Cancelled);
+
+        storage
+            .record_event(EntityType::Task, uuid, EventType::Cancelled)
+            .unwrap();
+        let stats = storage.load_event_stats(1).unwrap();
+        assert_eq!(stats.last().unwrap().cancelled, 1);
+    }
+
+    #[test]
+    fn test_migrates_single_note_links_to_tables() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("notes.db");
+        let task = Task::new("Spec".into(), Priority::Medium, vec![], None, None, None);
+        let project = Project::new("Backend".into());
+        let note = Note::new("Body".into());
+        {
+            let storage = SqliteStorage::with_path(path.clone()).unwrap();
+            storage.save(std::slice::from_ref(&task)).unwrap();
+            storage
+                .save_projects(std::slice::from_ref(&project))
+                .unwrap();
+            storage.upsert_note(&note).unwrap();
+            // Put the notes table back the way older versions left it.
+            storage
+                .conn
+                .borrow()
+                .execute_batch(&format!(
+                    "ALTER TABLE notes ADD COLUMN project_id TEXT REFERENCES projects(uuid);
+                     ALTER TABLE notes ADD COLUMN task_id TEXT REFERENCES tasks(uuid);
+                     CREATE INDEX idx_notes_task ON notes(task_id);
+                     UPDATE notes SET project_id = '{}', task_id = '{}';",
+                    project.uuid, task.uuid
+                ))
+                .unwrap();
+        }
+
+        let storage = SqliteStorage::with_path(path).unwrap();
+        let notes = storage.load_notes().unwrap();
+        assert_eq!(notes[0].task_ids, vec![task.uuid]);
+        assert_eq!(notes[0].project_ids, vec![project.uuid]);
+        let conn = storage.conn.borrow();
+        assert!(!has_column(&conn, "notes", "task_id").unwrap());
+        assert!(!has_column(&conn, "notes", "project_id").unwrap());
+    }
+
+    #[test]
+    fn test_migrates_legacy_schema() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("legacy.db");
+        {
+            let conn = Connection::open(&path).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE tasks (
+                    uuid TEXT PRIMARY KEY NOT NULL, text TEXT NOT NULL,
+                    completed INTEGER NOT NULL DEFAULT 0,
+                    priority TEXT NOT NULL DEFAULT 'medium',
+                    due_date TEXT, recurrence TEXT, project_id TEXT, parent_id TEXT,
+                    tags TEXT NOT NULL DEFAULT '[]', completed_at INTEGER,
+                    created_at INTEGER NOT NULL, updated_at INTEGER, deleted_at INTEGER);
+                 INSERT INTO tasks (uuid, text, completed, created_at)
+                    VALUES ('6f1c2b7e-8a9d-4e3f-9b0a-1c2d3e4f5a6b', 'Old', 1, 0);
+                 CREATE TABLE events (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    entity_type TEXT NOT NULL, entity_uuid TEXT NOT NULL,
+                    event_type TEXT NOT NULL CHECK(event_type IN ('created','completed')),
+                    occurred_at INTEGER NOT NULL);
+                 INSERT INTO events (entity_type, entity_uuid, event_type, occurred_at)
+                    VALUES ('task', '6f1c2b7e-8a9d-4e3f-9b0a-1c2d3e4f5a6b', 'created', 0);",
+            )
+            .unwrap();
+        }
+
+        let storage = SqliteStorage::with_path(path).unwrap();
+        let mut tasks = storage.load().unwrap();
+        assert_eq!(tasks[0].status, TaskStatus::Done);
+        assert_eq!(tasks[0].estimate, None);
+
+        // The added estimate column is writable.
+        tasks[0].estimate = Some(3);
+        storage.save(&tasks).unwrap();
+        assert_eq!(storage.load().unwrap()[0].estimate, Some(3));
+
+        // The rebuilt events table keeps old rows and accepts new event types.
+        storage
+            .record_event(EntityType::Task, tasks[0].uuid, EventType::Started)
+            .unwrap();
+        let count: i64 = storage
+            .conn
+            .borrow()
+            .query_row("SELECT COUNT(*) FROM events", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
 }
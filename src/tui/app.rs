@@ -1,10 +1,14 @@
 //! Application state for the TUI.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 
-use crate::models::{Priority, Project, Recurrence, StatusFilter, Task};
+use crate::commands::view::{ActiveContext, active_context};
+use crate::error::TodoError;
+use crate::models::{Note, Priority, Project, Recurrence, StatusFilter, Task, View};
+use crate::services::query::{Query, QueryContext};
 use crate::storage::Storage;
 use anyhow::Result;
+use uuid::Uuid;
 
 // ── Mode ──────────────────────────────────────────────────────────────────────
 
@@ -43,6 +47,8 @@ pub enum LeftPanel {
     Tasks,
     Projects,
     Tags,
+    Views,
+    Notes,
 }
 
 impl LeftPanel {
@@ -50,14 +56,18 @@ impl LeftPanel {
         match self {
             LeftPanel::Tasks => LeftPanel::Projects,
             LeftPanel::Projects => LeftPanel::Tags,
-            LeftPanel::Tags => LeftPanel::Tasks,
+            LeftPanel::Tags => LeftPanel::Views,
+            LeftPanel::Views => LeftPanel::Notes,
+            LeftPanel::Notes => LeftPanel::Tasks,
         }
     }
     pub fn prev(self) -> Self {
         match self {
-            LeftPanel::Tasks => LeftPanel::Tags,
+            LeftPanel::Tasks => LeftPanel::Notes,
             LeftPanel::Projects => LeftPanel::Tasks,
             LeftPanel::Tags => LeftPanel::Projects,
+            LeftPanel::Views => LeftPanel::Tags,
+            LeftPanel::Notes => LeftPanel::Views,
         }
     }
     pub fn label(self) -> &'static str {
@@ -65,6 +75,8 @@ impl LeftPanel {
             LeftPanel::Tasks => "Tasks",
             LeftPanel::Projects => "Projects",
             LeftPanel::Tags => "Tags",
+            LeftPanel::Views => "Views",
+            LeftPanel::Notes => "Notes",
         }
     }
 }
@@ -332,12 +344,16 @@ impl PriorityFilter {
 pub enum TreeItem {
     /// A project header (expandable).
     Project {
+        uuid: Option<Uuid>,
         name: Option<String>,
+        /// Tasks in the project and all of its sub-projects.
         task_count: usize,
         expanded: bool,
+        /// Nesting level; top-level projects are 0.
+        depth: usize,
     },
     /// A task row nested under its project.
-    Task { task_idx: usize },
+    Task { task_idx: usize, depth: usize },
 }
 
 impl TreeItem {
@@ -349,7 +365,7 @@ impl TreeItem {
     }
     pub fn task_idx(&self) -> Option<usize> {
         match self {
-            TreeItem::Task { task_idx } => Some(*task_idx),
+            TreeItem::Task { task_idx, .. } => Some(*task_idx),
             _ => None,
         }
     }
@@ -374,6 +390,8 @@ pub struct App {
     pub list_filter: ListFilter,
     pub priority_filter: PriorityFilter,
     pub input: String,
+    /// Parse error for a filter expression typed into the search bar.
+    pub search_error: Option<String>,
     pub edit_form: Option<EditFormState>,
     pub help_selected: usize,
     pub right_panel: RightPanel,
@@ -382,22 +400,21 @@ pub struct App {
     pub focused_panel: FocusedPanel,
     pub project_tree: Vec<TreeItem>,
     pub tree_selected: usize,
+    pub views: Vec<View>,
+    /// The view set with `todo ctx set`; narrows the task list while set.
+    pub context: Option<ActiveContext>,
+    /// Notes that are not in the trash, in `todo note list` order.
+    pub notes: Vec<Note>,
 }
 
 impl App {
     pub fn new(storage: &impl Storage) -> Result<Self> {
         let tasks = Self::load_visible(storage)?;
         let projects = storage.load_projects()?;
-        let filtered_indices = tasks
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| !t.completed)
-            .map(|(i, _)| i)
-            .collect();
         let mut app = Self {
             tasks,
             projects,
-            filtered_indices,
+            filtered_indices: vec![],
             selected: 0,
             mode: Mode::Normal,
             status_msg: None,
@@ -405,6 +422,7 @@ impl App {
             list_filter: ListFilter::Pending,
             priority_filter: PriorityFilter::All,
             input: String::new(),
+            search_error: None,
             edit_form: None,
             help_selected: 0,
             right_panel: RightPanel::Details,
@@ -413,7 +431,11 @@ impl App {
             focused_panel: FocusedPanel::Left,
             project_tree: vec![],
             tree_selected: 0,
+            views: storage.load_views()?,
+            context: active_context(storage)?,
+            notes: Self::load_visible_notes(storage)?,
         };
+        app.refilter();
         app.build_project_tree();
         Ok(app)
     }
@@ -421,6 +443,9 @@ impl App {
     pub fn reload(&mut self, storage: &impl Storage) -> Result<()> {
         self.tasks = Self::load_visible(storage)?;
         self.projects = storage.load_projects()?;
+        self.views = storage.load_views()?;
+        self.context = active_context(storage)?;
+        self.notes = Self::load_visible_notes(storage)?;
         self.refilter();
         if self.selected >= self.filtered_indices.len() {
             self.selected = self.filtered_indices.len().saturating_sub(1);
@@ -441,11 +466,37 @@ impl App {
         let raw = self.input.to_lowercase();
         let status = self.list_filter.as_status_filter();
 
+        // Inputs using the filter language (`tag:bug or priority:high`) go
+        // through the query parser; plain words, `@project` and `#tag` keep
+        // the quick substring search below.
+        self.search_error = None;
+        let query = if self.mode == Mode::Search && looks_like_query(&raw) {
+            match Query::parse(&self.input) {
+                Ok(q) => Some(q),
+                Err(e) => {
+                    // Keep the last good result while the user is mid-expression.
+                    self.search_error = Some(match e {
+                        TodoError::InvalidFilter { reason, column, .. } => {
+                            format!("{} (col {})", reason, column + 1)
+                        }
+                        other => other.to_string(),
+                    });
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+        let ctx = QueryContext {
+            tasks: &self.tasks,
+            projects: &self.projects,
+        };
+
         let mut project_filter: Option<String> = None;
         let mut tag_filters: Vec<String> = Vec::new();
         let mut text_tokens: Vec<String> = Vec::new();
 
-        if self.mode == Mode::Search && !raw.is_empty() {
+        if self.mode == Mode::Search && !raw.is_empty() && query.is_none() {
             for token in raw.split_whitespace() {
                 if let Some(proj) = token.strip_prefix('@') {
                     project_filter = Some(proj.to_string());
@@ -462,6 +513,11 @@ impl App {
             .iter()
             .enumerate()
             .filter(|(_, t)| t.matches_status(status))
+            .filter(|(_, t)| {
+                self.context
+                    .as_ref()
+                    .is_none_or(|c| c.matches(t, &self.tasks, &self.projects))
+            })
             .filter(|(_, t)| match self.priority_filter {
                 PriorityFilter::All => true,
                 PriorityFilter::High => t.priority == Priority::High,
@@ -472,6 +528,9 @@ impl App {
                 if self.mode != Mode::Search || raw.is_empty() {
                     return true;
                 }
+                if let Some(ref q) = query {
+                    return q.matches(t, &ctx);
+                }
                 if let Some(ref pf) = project_filter {
                     let proj_name = t
                         .project_id
@@ -480,7 +539,7 @@ impl App {
                                 .iter()
                                 .find(|p| p.uuid == pid && !p.is_deleted())
                         })
-                        .map(|p| p.name.to_lowercase());
+                        .map(|p| p.path(&self.projects).to_lowercase());
                     match proj_name {
                         Some(ref name) if name.contains(pf.as_str()) => {}
                         _ => return false,
@@ -510,72 +569,61 @@ impl App {
 
     /// Build (or rebuild) the flat navigable project tree.
     ///
-    /// Uses a `BTreeSet<Option<String>>` to collect and deduplicate project
-    /// names in a single pass, eliminating the previous `HashSet` + manual
-    /// `.sort()` step.
+    /// Projects are walked depth-first with siblings sorted by name; each
+    /// expanded project lists its own tasks, then its sub-projects one level
+    /// deeper. Expanded state is keyed by project UUID so it survives reloads.
     pub fn build_project_tree(&mut self) {
         // Preserve existing expanded states
-        let prev_expanded: std::collections::HashMap<String, bool> = self
+        let prev_expanded: HashMap<Option<Uuid>, bool> = self
             .project_tree
             .iter()
             .filter_map(|item| match item {
-                TreeItem::Project { name, expanded, .. } => {
-                    Some((name.clone().unwrap_or_default(), *expanded))
-                }
+                TreeItem::Project { uuid, expanded, .. } => Some((*uuid, *expanded)),
                 _ => None,
             })
             .collect();
 
-        // BTreeSet gives us deduplication + sorted order in one step.
-        let project_names: BTreeSet<Option<String>> = self
-            .projects
-            .iter()
-            .filter(|p| !p.is_deleted())
-            .map(|p| Some(p.name.clone()))
-            .collect();
-
         let mut tree: Vec<TreeItem> = Vec::new();
+        // Children of a collapsed project stay hidden until it is expanded.
+        let mut hidden_below: Option<usize> = None;
+
+        for project in Project::tree_order(&self.projects) {
+            let depth = project.depth(&self.projects);
+            match hidden_below {
+                Some(d) if depth > d => continue,
+                _ => hidden_below = None,
+            }
 
-        for proj_name in &project_names {
-            let key = proj_name.clone().unwrap_or_default();
-            let expanded = prev_expanded.get(&key).copied().unwrap_or(true);
-
+            let expanded = prev_expanded
+                .get(&Some(project.uuid))
+                .copied()
+                .unwrap_or(true);
+            let subtree = Project::subtree(&self.projects, project.uuid);
             let task_count = self
                 .tasks
                 .iter()
-                .filter(|t| {
-                    let name = t
-                        .project_id
-                        .and_then(|pid| {
-                            self.projects
-                                .iter()
-                                .find(|p| p.uuid == pid && !p.is_deleted())
-                        })
-                        .map(|p| p.name.as_str());
-                    name == proj_name.as_deref()
-                })
+                .filter(|t| t.project_id.is_some_and(|pid| subtree.contains(&pid)))
                 .count();
 
             tree.push(TreeItem::Project {
-                name: proj_name.clone(),
+                uuid: Some(project.uuid),
+                name: Some(project.name.clone()),
                 task_count,
                 expanded,
+                depth,
             });
 
             if expanded {
                 for (idx, task) in self.tasks.iter().enumerate() {
-                    let name = task
-                        .project_id
-                        .and_then(|pid| {
-                            self.projects
-                                .iter()
-                                .find(|p| p.uuid == pid && !p.is_deleted())
-                        })
-                        .map(|p| p.name.as_str());
-                    if name == proj_name.as_deref() {
-                        tree.push(TreeItem::Task { task_idx: idx });
+                    if task.project_id == Some(project.uuid) {
+                        tree.push(TreeItem::Task {
+                            task_idx: idx,
+                            depth: depth + 1,
+                        });
                     }
                 }
+            } else {
+                hidden_below = Some(depth);
             }
         }
 
@@ -608,14 +656,14 @@ impl App {
 
     pub fn tree_selected_task(&self) -> Option<&Task> {
         match self.project_tree.get(self.tree_selected)? {
-            TreeItem::Task { task_idx } => self.tasks.get(*task_idx),
+            TreeItem::Task { task_idx, .. } => self.tasks.get(*task_idx),
             _ => None,
         }
     }
 
     pub fn tree_selected_task_visible_id(&self) -> Option<usize> {
         let task_idx = match self.project_tree.get(self.tree_selected)? {
-            TreeItem::Task { task_idx } => *task_idx,
+            TreeItem::Task { task_idx, .. } => *task_idx,
             _ => return None,
         };
         let visible: Vec<&Task> = self.tasks.iter().filter(|t| !t.is_deleted()).collect();
@@ -699,23 +747,22 @@ impl App {
         let len = self.left_list_len();
         if len > 0 {
             self.left_selected = (self.left_selected + 1).min(len - 1);
+            self.details_scroll = 0;
         }
     }
 
     pub fn move_left_up(&mut self) {
         self.left_selected = self.left_selected.saturating_sub(1);
+        self.details_scroll = 0;
     }
 
     // ── lists ─────────────────────────────────────────────────────────────────
 
     pub fn projects_list(&self) -> Vec<String> {
-        // BTreeSet gives sorted + deduplicated names in one step
-        self.projects
-            .iter()
-            .filter(|p| !p.is_deleted())
-            .map(|p| p.name.clone())
-            .collect::<BTreeSet<_>>()
+        // Full paths, so sub-projects with the same name stay apart
+        Project::tree_order(&self.projects)
             .into_iter()
+            .map(|p| p.path(&self.projects))
             .collect()
     }
 
@@ -730,15 +777,13 @@ impl App {
 
     pub fn tasks_for_selected_project(&self) -> Vec<&Task> {
         let projects = self.projects_list();
-        if let Some(proj_name) = projects.get(self.left_selected) {
-            let proj_uuid = self
-                .projects
-                .iter()
-                .find(|p| &p.name == proj_name && !p.is_deleted())
-                .map(|p| p.uuid);
+        if let Some(proj_path) = projects.get(self.left_selected) {
+            let subtree = Project::find_by_path(&self.projects, proj_path)
+                .map(|p| Project::subtree(&self.projects, p.uuid))
+                .unwrap_or_default();
             self.tasks
                 .iter()
-                .filter(|t| proj_uuid.is_some() && t.project_id == proj_uuid)
+                .filter(|t| t.project_id.is_some_and(|pid| subtree.contains(&pid)))
                 .collect()
         } else {
             vec![]
@@ -754,16 +799,45 @@ impl App {
         }
     }
 
+    /// Tasks matched by the view highlighted in the Views tab.
+    pub fn tasks_for_selected_view(&self) -> Vec<&Task> {
+        let Some(view) = self.views.get(self.left_selected) else {
+            return vec![];
+        };
+        match ActiveContext::from_view(view) {
+            Ok(ctx) => self
+                .tasks
+                .iter()
+                .filter(|t| ctx.matches(t, &self.tasks, &self.projects))
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    /// The note highlighted in the Notes tab.
+    pub fn selected_note(&self) -> Option<&Note> {
+        self.notes.get(self.left_selected)
+    }
+
+    /// Returns `true` when `view` is the active context.
+    pub fn is_context(&self, view: &View) -> bool {
+        self.context
+            .as_ref()
+            .is_some_and(|c| c.name.eq_ignore_ascii_case(&view.name))
+    }
+
     pub fn left_list_len(&self) -> usize {
         match self.left_panel {
             LeftPanel::Tasks => self.filtered_indices.len(),
             LeftPanel::Projects => self.project_tree.len(),
             LeftPanel::Tags => self.tags_list().len(),
+            LeftPanel::Views => self.views.len(),
+            LeftPanel::Notes => self.notes.len(),
         }
     }
 
     pub fn pending_count(&self) -> usize {
-        self.tasks.iter().filter(|t| !t.completed).count()
+        self.tasks.iter().filter(|t| t.is_open()).count()
     }
     pub fn total_count(&self) -> usize {
         self.tasks.len()
@@ -776,4 +850,21 @@ impl App {
             .filter(|t| !t.is_deleted())
             .collect())
     }
+
+    fn load_visible_notes(storage: &impl Storage) -> Result<Vec<Note>> {
+        Ok(storage
+            .load_notes()?
+            .into_iter()
+            .filter(|n| !n.is_deleted())
+            .collect())
+    }
+}
+
+/// `true` when the search input uses the filter language rather than plain
+/// words, `@project` and `#tag`.
+fn looks_like_query(input: &str) -> bool {
+    input.contains([':', '(', ')', '"'])
+        || input
+            .split_whitespace()
+            .any(|w| matches!(w, "and" | "or" | "not"))
 }
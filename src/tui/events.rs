@@ -5,8 +5,12 @@
 //! |----------|-----------------------------------------|
 //! | `j/k`    | Navigate active tab list                |
 //! | `g/G`    | Jump to first/last                      |
-//! | `[/]`    | Cycle left panel tabs (Tasks/Projects/Tags) |
+//! | `[/]`    | Cycle left panel tabs (Tasks/Projects/Tags/Views/Notes) |
+//! | `Enter`  | Set / clear context (Views tab only)    |
 //! | `d`      | Toggle done / undone (Tasks tab only)   |
+//! | `s`      | Toggle active / pending (Tasks tab only) |
+//! | `w`      | Toggle waiting / pending (Tasks tab only) |
+//! | `c`      | Toggle cancelled / pending (Tasks tab only) |
 //! | `e`      | Open edit form (Tasks tab only)         |
 //! | `a`      | Open add form                           |
 //! | `/`      | Enter search mode                       |
@@ -39,7 +43,7 @@
 use anyhow::Result;
 use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
 
-use crate::models::Task;
+use crate::models::{Task, TaskStatus};
 use crate::storage::Storage;
 use crate::utils::tag_normalizer::{collect_existing_tags, normalize_tags};
 
@@ -127,6 +131,7 @@ fn handle_left(
             app.tree_toggle_expand();
             app.status_msg = None;
         }
+        KeyCode::Enter if app.left_panel == LeftPanel::Views => toggle_context(app, storage)?,
 
         KeyCode::Char('?') => {
             app.help_selected = 0;
@@ -135,6 +140,15 @@ fn handle_left(
         }
 
         KeyCode::Char('d') if app.left_panel == LeftPanel::Tasks => toggle_done(app, storage)?,
+        KeyCode::Char('s') if app.left_panel == LeftPanel::Tasks => {
+            toggle_status(app, storage, TaskStatus::Active)?
+        }
+        KeyCode::Char('w') if app.left_panel == LeftPanel::Tasks => {
+            toggle_status(app, storage, TaskStatus::Waiting)?
+        }
+        KeyCode::Char('c') if app.left_panel == LeftPanel::Tasks => {
+            toggle_status(app, storage, TaskStatus::Cancelled)?
+        }
         KeyCode::Char('e') if app.left_panel == LeftPanel::Tasks => app.open_edit_form(),
         KeyCode::Char('a') => app.open_add_form(),
 
@@ -174,15 +188,13 @@ fn handle_left(
                 app.status_msg = Some(format!("Clear all {} tasks? [y/n]", count));
             }
         }
-        KeyCode::Char('x') if app.left_panel == LeftPanel::Tasks => {
-            if !app.tasks.is_empty() {
-                app.mode = Mode::ConfirmDelete;
-                let preview = app
-                    .selected_task()
-                    .map(|t| truncate_str(&t.text, 30))
-                    .unwrap_or_default();
-                app.status_msg = Some(format!("Delete \"{}\"? [y/n]", preview));
-            }
+        KeyCode::Char('x') if app.left_panel == LeftPanel::Tasks && !app.tasks.is_empty() => {
+            app.mode = Mode::ConfirmDelete;
+            let preview = app
+                .selected_task()
+                .map(|t| truncate_str(&t.text, 30))
+                .unwrap_or_default();
+            app.status_msg = Some(format!("Delete \"{}\"? [y/n]", preview));
         }
 
         KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
@@ -433,21 +445,36 @@ fn commit_add_form(app: &mut App, storage: &impl Storage) -> Result<()> {
         due,
         recurrence: form.recurrence,
         depends_on: deps,
+        estimate: None,
     };
 
     match crate::commands::task::add::execute_silent(storage, args) {
         Ok(_) => {
-            let count = storage
-                .load()
-                .map(|t| t.iter().filter(|t| !t.is_deleted()).count())
-                .unwrap_or(0);
-            app.status_msg = Some(format!("Task #{} added.", count));
+            let visible: Vec<_> = storage
+                .load()?
+                .into_iter()
+                .filter(|t| !t.is_deleted())
+                .collect();
+            let count = visible.len();
+            app.status_msg = Some(
+                match visible.last().and_then(|new| {
+                    crate::services::duplicates::find_similar(&new.text, &visible, &[new.uuid])
+                }) {
+                    Some((idx, _)) => format!(
+                        "Task #{} added — looks like #{} (merge with 'todo dedupe').",
+                        count,
+                        idx + 1
+                    ),
+                    None => format!("Task #{} added.", count),
+                },
+            );
             app.mode = Mode::Normal;
             app.reload(storage)?;
         }
         Err(e) => {
+            // Keep the form open so a typo in the inline syntax can be fixed.
             app.status_msg = Some(format!("Error: {}", e));
-            app.mode = Mode::Normal;
+            app.edit_form = Some(form);
         }
     }
     Ok(())
@@ -529,7 +556,7 @@ fn toggle_done(app: &mut App, storage: &impl Storage) -> Result<()> {
         Some(id) => id,
         None => return Ok(()),
     };
-    let completed = app.selected_task().map(|t| t.completed).unwrap_or(false);
+    let completed = app.selected_task().map(|t| !t.is_open()).unwrap_or(false);
     let msg = if completed {
         crate::commands::task::undone::execute_silent(storage, vis_id)?
     } else {
@@ -540,6 +567,43 @@ fn toggle_done(app: &mut App, storage: &impl Storage) -> Result<()> {
     Ok(())
 }
 
+/// Makes the highlighted view the context, or clears it if it already is.
+fn toggle_context(app: &mut App, storage: &impl Storage) -> Result<()> {
+    let Some(view) = app.views.get(app.left_selected).cloned() else {
+        return Ok(());
+    };
+    if app.is_context(&view) {
+        storage.save_context(None)?;
+        app.status_msg = Some(format!("Context '{}' cleared", view.name));
+    } else {
+        storage.save_context(Some(&view.name))?;
+        app.status_msg = Some(format!("Context set to '{}'", view.name));
+    }
+    app.selected = 0;
+    app.reload(storage)?;
+    Ok(())
+}
+
+/// Moves the selected task to `target`, or back to pending if it is already there.
+fn toggle_status(app: &mut App, storage: &impl Storage, target: TaskStatus) -> Result<()> {
+    let vis_id = match app.selected_visible_id() {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+    let current = app.selected_task().map(|t| t.status);
+    let result = if current == Some(target) {
+        crate::commands::task::undone::execute_silent(storage, vis_id)
+    } else {
+        crate::commands::task::status::execute_silent(storage, vis_id, target)
+    };
+    app.status_msg = Some(match result {
+        Ok(msg) => msg,
+        Err(e) => e.to_string(),
+    });
+    app.reload(storage)?;
+    Ok(())
+}
+
 fn commit_edit_form(app: &mut App, storage: &impl Storage) -> Result<()> {
     let vis_id = match app.selected_visible_id() {
         Some(id) => id,
@@ -634,6 +698,8 @@ fn commit_edit_form(app: &mut App, storage: &impl Storage) -> Result<()> {
         add_dep,
         remove_dep,
         clear_deps,
+        estimate: None,
+        clear_estimate: false,
     };
 
     match crate::commands::task::edit::execute_silent(storage, args) {
@@ -11,13 +11,25 @@
 //! medium         = "#ffff55"
 //! low            = "#55ff55"
 //! done           = "#555555"
+//! active         = "#55aaff"
+//! waiting        = "#c678dd"
+//! cancelled      = "#444444"
 //! blocked        = "#aaaaaa"
 //! selected_bg    = "#282840"
 //! search_bg      = "#1e1e32"
 //! focused_bg     = "#1e2840"
 //! focused_border = "#00ffff"
+//! syntax_keyword  = "#c678dd"
+//! syntax_type     = "#e5c07b"
+//! syntax_function = "#61afef"
+//! syntax_string   = "#98c379"
+//! syntax_number   = "#d19a66"
+//! syntax_comment  = "#5c6370"
 //! ```
 //!
+//! The `syntax_*` colors highlight code notes and fenced code blocks, in the
+//! TUI and in `todo note show` / `todo note preview`.
+//!
 //! Colors accept:
 //!   - Hex:         `"#rrggbb"`
 //!   - Named:       `"red"`, `"blue"`, `"cyan"`, `"white"`, etc.
@@ -26,6 +38,9 @@
 use ratatui::style::{Color, Modifier, Style};
 use serde::{Deserialize, Serialize};
 
+use crate::models::TaskStatus;
+use crate::render::highlight::Syntax;
+
 // ── Theme ─────────────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +56,12 @@ pub struct Theme {
     pub low: String,
     /// Completed tasks
     pub done: String,
+    /// Tasks in progress (`todo start`)
+    pub active: String,
+    /// Tasks waiting on something external (`todo wait`)
+    pub waiting: String,
+    /// Cancelled tasks
+    pub cancelled: String,
     /// Blocked tasks
     pub blocked: String,
     /// Selected item background
@@ -51,6 +72,18 @@ pub struct Theme {
     pub focused_bg: String,
     /// Focused input field border
     pub focused_border: String,
+    /// Code: keywords
+    pub syntax_keyword: String,
+    /// Code: type names
+    pub syntax_type: String,
+    /// Code: function and macro calls
+    pub syntax_function: String,
+    /// Code: string and character literals
+    pub syntax_string: String,
+    /// Code: numbers
+    pub syntax_number: String,
+    /// Code: comments
+    pub syntax_comment: String,
 }
 
 impl Default for Theme {
@@ -61,11 +94,20 @@ impl Default for Theme {
             medium: "#ffff55".into(),  // yellow
             low: "#55ff55".into(),     // green
             done: "#555555".into(),    // dark gray
+            active: "#55aaff".into(),  // blue
+            waiting: "#c678dd".into(), // purple
+            cancelled: "#444444".into(),
             blocked: "#969696".into(), // gray
             selected_bg: "#282840".into(),
             search_bg: "#1e1e32".into(),
             focused_bg: "#1e2840".into(),
-            focused_border: "#00ffff".into(), // cyan
+            focused_border: "#00ffff".into(),  // cyan
+            syntax_keyword: "#c678dd".into(),  // purple
+            syntax_type: "#e5c07b".into(),     // yellow
+            syntax_function: "#61afef".into(), // blue
+            syntax_string: "#98c379".into(),   // green
+            syntax_number: "#d19a66".into(),   // orange
+            syntax_comment: "#5c6370".into(),  // gray
         }
     }
 }
@@ -79,11 +121,32 @@ impl Theme {
             medium: parse_color(&self.medium),
             low: parse_color(&self.low),
             done: parse_color(&self.done),
+            active: parse_color(&self.active),
+            waiting: parse_color(&self.waiting),
+            cancelled: parse_color(&self.cancelled),
             blocked: parse_color(&self.blocked),
             selected_bg: parse_color(&self.selected_bg),
             search_bg: parse_color(&self.search_bg),
             focused_bg: parse_color(&self.focused_bg),
             focused_border: parse_color(&self.focused_border),
+            syntax_keyword: parse_color(&self.syntax_keyword),
+            syntax_type: parse_color(&self.syntax_type),
+            syntax_function: parse_color(&self.syntax_function),
+            syntax_string: parse_color(&self.syntax_string),
+            syntax_number: parse_color(&self.syntax_number),
+            syntax_comment: parse_color(&self.syntax_comment),
+        }
+    }
+
+    /// Configured color string for a kind of code token.
+    pub fn syntax(&self, kind: Syntax) -> &str {
+        match kind {
+            Syntax::Keyword => &self.syntax_keyword,
+            Syntax::Type => &self.syntax_type,
+            Syntax::Function => &self.syntax_function,
+            Syntax::String => &self.syntax_string,
+            Syntax::Number => &self.syntax_number,
+            Syntax::Comment => &self.syntax_comment,
         }
     }
 }
@@ -99,11 +162,20 @@ pub struct ResolvedTheme {
     pub medium: Color,
     pub low: Color,
     pub done: Color,
+    pub active: Color,
+    pub waiting: Color,
+    pub cancelled: Color,
     pub blocked: Color,
     pub selected_bg: Color,
     pub search_bg: Color,
     pub focused_bg: Color,
     pub focused_border: Color,
+    pub syntax_keyword: Color,
+    pub syntax_type: Color,
+    pub syntax_function: Color,
+    pub syntax_string: Color,
+    pub syntax_number: Color,
+    pub syntax_comment: Color,
 }
 
 impl ResolvedTheme {
@@ -145,6 +217,48 @@ impl ResolvedTheme {
     pub fn search_bg(&self) -> Style {
         Style::default().bg(self.search_bg)
     }
+
+    /// Foreground color for a kind of code token.
+    pub fn syntax(&self, kind: Syntax) -> Color {
+        match kind {
+            Syntax::Keyword => self.syntax_keyword,
+            Syntax::Type => self.syntax_type,
+            Syntax::Function => self.syntax_function,
+            Syntax::String => self.syntax_string,
+            Syntax::Number => self.syntax_number,
+            Syntax::Comment => self.syntax_comment,
+        }
+    }
+
+    /// Status letter and its color for list rows; `blocked` wins over open states.
+    pub fn status_letter(&self, status: TaskStatus, blocked: bool) -> (&'static str, Color) {
+        if blocked && status.is_open() {
+            return ("B", Color::Red);
+        }
+        match status {
+            TaskStatus::Pending => ("P", Color::Blue),
+            TaskStatus::Active => ("A", self.active),
+            TaskStatus::Waiting => ("W", self.waiting),
+            TaskStatus::Done => ("D", Color::Green),
+            TaskStatus::Cancelled => ("C", self.cancelled),
+        }
+    }
+
+    /// `Style` for a task's text, based on its status.
+    pub fn task_text(&self, status: TaskStatus, blocked: bool) -> Style {
+        if blocked && status.is_open() {
+            return Style::default().fg(self.blocked);
+        }
+        match status {
+            TaskStatus::Pending => Style::default().fg(Color::White),
+            TaskStatus::Active => Style::default().fg(self.active),
+            TaskStatus::Waiting => Style::default().fg(self.waiting),
+            TaskStatus::Done => Style::default().fg(self.done),
+            TaskStatus::Cancelled => Style::default()
+                .fg(self.cancelled)
+                .add_modifier(Modifier::CROSSED_OUT),
+        }
+    }
 }
 
 // ── Color parser ──────────────────────────────────────────────────────────────
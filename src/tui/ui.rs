@@ -1,12 +1,14 @@
 //! Ratatui rendering — lazygit-style layout.
 //!
-//! Left panel  `[1]`: single box with tab bar in the title — Tasks / Projects / Tags.
+//! Left panel  `[1]`: single box with tab bar in the title — Tasks / Projects / Tags / Views / Notes.
 //!   `[`/`]` cycles tabs, `j`/`k` navigates within the active tab.
 //!
 //! Right panel `[0]`: single full-height box, content is contextual:
 //!   • Tasks active   → rich task details (metadata + deps + history)
 //!   • Projects active → list of tasks for selected project
 //!   • Tags active    → list of tasks for selected tag
+//!   • Views active   → list of tasks matched by selected view
+//!   • Notes active   → selected note, markdown rendered, code highlighted
 //!
 //! Edit/Add form replaces the right panel content when active.
 
@@ -21,8 +23,11 @@ use ratatui::{
         ScrollbarOrientation, ScrollbarState, Table, Wrap,
     },
 };
+use uuid::Uuid;
 
-use crate::models::Task;
+use crate::models::{Project, Task, TaskStatus};
+use crate::render::formatting::note_preview;
+use crate::render::markdown::{self, Role as MdRole, StyledLine};
 
 use super::app::{App, EditField, FocusedPanel, LeftPanel, Mode, PriorityFilter, TreeItem};
 
@@ -66,8 +71,14 @@ fn draw_left_panel(f: &mut Frame, app: &mut App, area: Rect, theme: &ResolvedThe
         Style::default().fg(Color::DarkGray)
     };
 
-    // Title: [1]-Tasks - Projects - Tags  (active tab highlighted)
-    let tabs = [LeftPanel::Tasks, LeftPanel::Projects, LeftPanel::Tags];
+    // Title: [1]-Tasks - Projects - Tags - Views  (active tab highlighted)
+    let tabs = [
+        LeftPanel::Tasks,
+        LeftPanel::Projects,
+        LeftPanel::Tags,
+        LeftPanel::Views,
+        LeftPanel::Notes,
+    ];
     let mut title_spans: Vec<Span> = vec![
         Span::styled("[1]", Style::default().fg(Color::DarkGray)),
         Span::styled("─", Style::default().fg(Color::DarkGray)),
@@ -101,6 +112,8 @@ fn draw_left_panel(f: &mut Frame, app: &mut App, area: Rect, theme: &ResolvedThe
         LeftPanel::Tasks => draw_tasks_tab(f, app, block, area, theme),
         LeftPanel::Projects => draw_projects_tree(f, app, block, area, theme),
         LeftPanel::Tags => draw_tags_list(f, app, block, area, theme),
+        LeftPanel::Views => draw_views_list(f, app, block, area, theme),
+        LeftPanel::Notes => draw_notes_list(f, app, block, area, theme),
     }
 }
 
@@ -113,7 +126,7 @@ fn draw_tasks_tab(f: &mut Frame, app: &mut App, block: Block, area: Rect, theme:
         app.selected + 1
     };
     let total = app.filtered_indices.len();
-    let counter = if app.priority_filter == PriorityFilter::All {
+    let mut counter = if app.priority_filter == PriorityFilter::All {
         format!(" ({}/{}) [{}] ", current, total, app.list_filter.label())
     } else {
         format!(
@@ -124,6 +137,9 @@ fn draw_tasks_tab(f: &mut Frame, app: &mut App, block: Block, area: Rect, theme:
             app.priority_filter.label()
         )
     };
+    if let Some(ref ctx) = app.context {
+        counter.push_str(&format!("ctx:{} ", ctx.name));
+    }
 
     let lines: Vec<Line> = app
         .filtered_indices
@@ -148,23 +164,10 @@ fn draw_tasks_tab(f: &mut Frame, app: &mut App, block: Block, area: Rect, theme:
 }
 
 fn task_line<'a>(task: &'a Task, all_tasks: &'a [Task], theme: &ResolvedTheme) -> Line<'a> {
-    let blocked = !task.completed && task.is_blocked(all_tasks);
+    let blocked = task.is_open() && task.is_blocked(all_tasks);
 
-    let (status_text, status_color) = if task.completed {
-        ("D", Color::Green)
-    } else if blocked {
-        ("B", Color::Red)
-    } else {
-        ("P", Color::Blue)
-    };
-
-    let text_style = if task.completed {
-        Style::default().fg(theme.done)
-    } else if blocked {
-        Style::default().fg(theme.blocked)
-    } else {
-        Style::default().fg(Color::White)
-    };
+    let (status_text, status_color) = theme.status_letter(task.status, blocked);
+    let text_style = theme.task_text(task.status, blocked);
 
     Line::from(vec![
         Span::raw(" "),
@@ -198,6 +201,8 @@ fn draw_projects_tree(f: &mut Frame, app: &App, block: Block, area: Rect, theme:
                     name,
                     task_count,
                     expanded,
+                    depth,
+                    ..
                 } => {
                     let arrow = if *expanded { "▼ " } else { "▶ " };
                     let label = name.as_deref().unwrap_or_default();
@@ -212,7 +217,7 @@ fn draw_projects_tree(f: &mut Frame, app: &App, block: Block, area: Rect, theme:
                         (Color::Magenta, Color::DarkGray)
                     };
                     Line::from(vec![
-                        Span::raw(" "),
+                        Span::raw(" ".repeat(1 + 2 * depth)),
                         Span::styled(arrow, Style::default().fg(arrow_color)),
                         Span::styled(
                             label.to_string(),
@@ -221,18 +226,12 @@ fn draw_projects_tree(f: &mut Frame, app: &App, block: Block, area: Rect, theme:
                         Span::styled(count_str, Style::default().fg(Color::DarkGray)),
                     ])
                 }
-                TreeItem::Task { task_idx } => {
+                TreeItem::Task { task_idx, depth } => {
                     let task = &app.tasks[*task_idx];
-                    let blocked = !task.completed && task.is_blocked(&app.tasks);
-                    let text_style = if task.completed {
-                        Style::default().fg(theme.done)
-                    } else if blocked {
-                        Style::default().fg(theme.blocked)
-                    } else {
-                        Style::default().fg(Color::White)
-                    };
+                    let blocked = task.is_open() && task.is_blocked(&app.tasks);
+                    let text_style = theme.task_text(task.status, blocked);
                     Line::from(vec![
-                        Span::raw("    "),
+                        Span::raw(" ".repeat(2 + 2 * depth)),
                         Span::styled(task.text.clone(), text_style),
                     ])
                 }
@@ -315,6 +314,116 @@ fn draw_tags_list(f: &mut Frame, app: &App, block: Block, area: Rect, theme: &Re
     f.render_stateful_widget(list, area, &mut state);
 }
 
+// ── Views tab ─────────────────────────────────────────────────────────────────
+
+fn draw_views_list(f: &mut Frame, app: &App, block: Block, area: Rect, theme: &ResolvedTheme) {
+    if app.views.is_empty() {
+        f.render_widget(
+            Paragraph::new("No saved views  (todo view save <NAME> …)")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let lines: Vec<Line> = app
+        .views
+        .iter()
+        .map(|view| {
+            let marker = if app.is_context(view) { "● " } else { "  " };
+            Line::from(vec![
+                Span::styled(marker, Style::default().fg(Color::Green)),
+                Span::styled(
+                    view.name.clone(),
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        "  {}",
+                        if view.filter.is_empty() {
+                            "(all tasks)"
+                        } else {
+                            &view.filter
+                        }
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        })
+        .collect();
+
+    let counter = format!(
+        " {}/{}  Enter: set/clear context ",
+        app.left_selected + 1,
+        app.views.len()
+    );
+    let block = block.title_bottom(Span::styled(counter, Style::default().fg(Color::DarkGray)));
+
+    let mut state = ListState::default();
+    state.select(Some(app.left_selected));
+
+    let list = List::new(lines).block(block).highlight_style(
+        Style::default()
+            .bg(theme.selected_bg)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+// ── Notes tab ─────────────────────────────────────────────────────────────────
+
+fn draw_notes_list(f: &mut Frame, app: &App, block: Block, area: Rect, theme: &ResolvedTheme) {
+    if app.notes.is_empty() {
+        f.render_widget(
+            Paragraph::new("No notes  (todo note add …)")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let lines: Vec<Line> = app
+        .notes
+        .iter()
+        .enumerate()
+        .map(|(i, note)| {
+            let tags: String = note.tags.iter().map(|t| format!("  #{}", t)).collect();
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>3} ", i + 1),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    note_preview(note),
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(tags, Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    let counter = format!(" {}/{} ", app.left_selected + 1, app.notes.len());
+    let block = block.title_bottom(Span::styled(counter, Style::default().fg(Color::DarkGray)));
+
+    let mut state = ListState::default();
+    state.select(Some(app.left_selected));
+
+    let list = List::new(lines).block(block).highlight_style(
+        Style::default()
+            .bg(theme.selected_bg)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
 // ── right panel — contextual ──────────────────────────────────────────────────
 
 fn draw_right_panel(f: &mut Frame, app: &mut App, area: Rect, theme: &ResolvedTheme) {
@@ -339,7 +448,10 @@ fn draw_right_panel(f: &mut Frame, app: &mut App, area: Rect, theme: &ResolvedTh
     match app.left_panel {
         LeftPanel::Tasks => draw_task_details(f, app, area, border_style, theme),
         LeftPanel::Projects => draw_tree_details(f, app, area, border_style, theme),
-        LeftPanel::Tags => draw_context_panel(f, app, area, border_style, true, theme),
+        LeftPanel::Tags | LeftPanel::Views => {
+            draw_context_panel(f, app, area, border_style, app.left_panel, theme)
+        }
+        LeftPanel::Notes => draw_note_panel(f, app, area, border_style, theme),
     }
 }
 
@@ -448,15 +560,21 @@ fn build_task_details(
             Span::styled(format!("{:<14}", "Low"), Style::default().fg(theme.low))
         }
     };
-    let status_span = if task.completed {
-        Span::styled("Done", Style::default().fg(theme.done))
-    } else if task.is_blocked(all_tasks) {
-        Span::styled(
+    let status_span = match task.status {
+        TaskStatus::Done => Span::styled("Done", Style::default().fg(theme.done)),
+        TaskStatus::Cancelled => Span::styled("Cancelled", Style::default().fg(theme.cancelled)),
+        _ if task.is_blocked(all_tasks) => Span::styled(
             "Blocked",
             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-        )
-    } else {
-        Span::styled("Pending", Style::default().fg(Color::Green))
+        ),
+        TaskStatus::Active => Span::styled(
+            "Active",
+            Style::default()
+                .fg(theme.active)
+                .add_modifier(Modifier::BOLD),
+        ),
+        TaskStatus::Waiting => Span::styled("Waiting", Style::default().fg(theme.waiting)),
+        TaskStatus::Pending => Span::styled("Pending", Style::default().fg(Color::Green)),
     };
     lines.push(Line::from(vec![
         lbl("Priority"),
@@ -540,12 +658,16 @@ fn build_task_details(
         ]));
     }
 
-    // Completed at
-    if task.completed
+    // Completed / cancelled at
+    if !task.is_open()
         && let Some(done_at) = task.completed_at
     {
         lines.push(Line::from(vec![
-            lbl("Completed"),
+            lbl(if task.completed {
+                "Completed"
+            } else {
+                "Cancelled"
+            }),
             Span::styled(
                 done_at.format("%Y-%m-%d").to_string(),
                 Style::default().fg(theme.done),
@@ -568,7 +690,7 @@ fn build_task_details(
         for dep_uuid in &task.depends_on {
             if let Some(pos) = visible.iter().position(|t| t.uuid == *dep_uuid) {
                 let dep = visible[pos];
-                let done = dep.completed;
+                let done = !dep.is_open();
                 lines.push(Line::from(vec![
                     Span::styled(
                         if done {
@@ -612,7 +734,7 @@ fn build_task_details(
                 .add_modifier(Modifier::BOLD),
         )));
         for (pos, dep) in &downstream {
-            let done = dep.completed;
+            let done = !dep.is_open();
             lines.push(Line::from(vec![
                 Span::styled(
                     if done {
@@ -649,25 +771,25 @@ fn draw_tree_details(
     border_style: Style,
     theme: &ResolvedTheme,
 ) {
-    // Find the project name for whatever is currently selected
+    // Find the project for whatever is currently selected
     // (walk backwards from tree_selected to find the parent project header)
-    let project_name: Option<Option<&str>> = {
-        let mut name = None;
+    let project_uuid: Option<Option<Uuid>> = {
+        let mut found = None;
         for item in app.project_tree[..=app
             .tree_selected
             .min(app.project_tree.len().saturating_sub(1))]
             .iter()
             .rev()
         {
-            if let TreeItem::Project { name: n, .. } = item {
-                name = Some(n.as_deref());
+            if let TreeItem::Project { uuid, .. } = item {
+                found = Some(*uuid);
                 break;
             }
         }
-        name
+        found
     };
 
-    let Some(proj_key) = project_name else {
+    let Some(proj_uuid) = project_uuid else {
         f.render_widget(
             Paragraph::new("").block(
                 Block::default()
@@ -680,26 +802,26 @@ fn draw_tree_details(
         return;
     };
 
-    let label = proj_key.unwrap_or("");
-    let proj_uuid = app
-        .projects
-        .iter()
-        .find(|p| Some(p.name.as_str()) == proj_key && !p.is_deleted())
-        .map(|p| p.uuid);
+    // Totals cover sub-projects too, matching the count in the tree.
+    let project = proj_uuid.and_then(|uuid| app.projects.iter().find(|p| p.uuid == uuid));
+    let label = project.map(|p| p.path(&app.projects)).unwrap_or_default();
+    let subtree = project
+        .map(|p| Project::subtree(&app.projects, p.uuid))
+        .unwrap_or_default();
     let tasks: Vec<&Task> = app
         .tasks
         .iter()
-        .filter(|t| proj_uuid.is_some() && t.project_id == proj_uuid)
+        .filter(|t| t.project_id.is_some_and(|pid| subtree.contains(&pid)))
         .collect();
-    let pending = tasks.iter().filter(|t| !t.completed).count();
+    let pending = tasks.iter().filter(|t| t.is_open()).count();
     let done = tasks.iter().filter(|t| t.completed).count();
     let blocked = tasks
         .iter()
         .filter(|t| {
-            !t.completed
+            t.is_open()
                 && t.depends_on
                     .iter()
-                    .any(|dep| app.tasks.iter().any(|t2| t2.uuid == *dep && !t2.completed))
+                    .any(|dep| app.tasks.iter().any(|t2| t2.uuid == *dep && t2.is_open()))
         })
         .count();
 
@@ -733,18 +855,12 @@ fn draw_tree_details(
     ];
 
     for task in &tasks {
-        let is_blocked = !task.completed
+        let is_blocked = task.is_open()
             && task
                 .depends_on
                 .iter()
-                .any(|dep| app.tasks.iter().any(|t2| t2.uuid == *dep && !t2.completed));
-        let text_style = if task.completed {
-            Style::default().fg(theme.done)
-        } else if is_blocked {
-            Style::default().fg(theme.blocked)
-        } else {
-            Style::default().fg(Color::White)
-        };
+                .any(|dep| app.tasks.iter().any(|t2| t2.uuid == *dep && t2.is_open()));
+        let text_style = theme.task_text(task.status, is_blocked);
         let pri = match task.priority {
             crate::models::Priority::High => Span::styled("H  ", Style::default().fg(theme.high)),
             crate::models::Priority::Medium => {
@@ -776,10 +892,31 @@ fn draw_context_panel(
     app: &App,
     area: Rect,
     border_style: Style,
-    is_tags: bool,
+    panel: LeftPanel,
     theme: &ResolvedTheme,
 ) {
-    let (label, tasks): (String, Vec<&Task>) = if is_tags {
+    // Tag and view listings show each task's project; project listings show tags.
+    let show_project = panel != LeftPanel::Projects;
+    let (label, tasks): (String, Vec<&Task>) = if panel == LeftPanel::Views {
+        match app.views.get(app.left_selected) {
+            Some(view) => (
+                format!("View: {}", view.name),
+                app.tasks_for_selected_view(),
+            ),
+            None => {
+                f.render_widget(
+                    Paragraph::new("No view selected").block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .title(right_title("View"))
+                            .border_style(border_style),
+                    ),
+                    area,
+                );
+                return;
+            }
+        }
+    } else if panel == LeftPanel::Tags {
         let tags = app.tags_list();
         match tags.get(app.left_selected) {
             Some(tag) => (format!("Tag: {}", tag), app.tasks_for_selected_tag()),
@@ -818,16 +955,16 @@ fn draw_context_panel(
         }
     };
 
-    let pending = tasks.iter().filter(|t| !t.completed).count();
+    let pending = tasks.iter().filter(|t| t.is_open()).count();
     let done = tasks.iter().filter(|t| t.completed).count();
     let blocked = tasks
         .iter()
         .filter(|t| {
-            !t.completed
+            t.is_open()
                 && t.depends_on.iter().any(|dep_uuid| {
                     app.tasks
                         .iter()
-                        .any(|t2| t2.uuid == *dep_uuid && !t2.completed)
+                        .any(|t2| t2.uuid == *dep_uuid && t2.is_open())
                 })
         })
         .count();
@@ -862,20 +999,14 @@ fn draw_context_panel(
     lines.push(Line::from(""));
 
     for task in &tasks {
-        let is_blocked = !task.completed
+        let is_blocked = task.is_open()
             && task.depends_on.iter().any(|dep_uuid| {
                 app.tasks
                     .iter()
-                    .any(|t2| t2.uuid == *dep_uuid && !t2.completed)
+                    .any(|t2| t2.uuid == *dep_uuid && t2.is_open())
             });
-        let (s, s_color) = if task.completed {
-            ("D", Color::Green)
-        } else if is_blocked {
-            ("B", Color::Red)
-        } else {
-            ("P", Color::Blue)
-        };
-        let extra = if is_tags {
+        let (s, s_color) = theme.status_letter(task.status, is_blocked);
+        let extra = if show_project {
             app.project_name_for(task).unwrap_or("").to_string()
         } else {
             task.tags.join(", ")
@@ -900,7 +1031,7 @@ fn draw_context_panel(
             Span::styled(
                 truncate(&extra, 12),
                 Style::default()
-                    .fg(if is_tags {
+                    .fg(if show_project {
                         Color::Magenta
                     } else {
                         theme.accent
@@ -929,6 +1060,112 @@ fn draw_context_panel(
     );
 }
 
+// ── [0] note — markdown rendered ─────────────────────────────────────────────
+
+fn draw_note_panel(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+    border_style: Style,
+    theme: &ResolvedTheme,
+) {
+    let Some(note) = app.selected_note() else {
+        f.render_widget(
+            Paragraph::new("No note selected").block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(right_title("Note"))
+                    .border_style(border_style),
+            ),
+            area,
+        );
+        return;
+    };
+
+    // Leave a column for the scrollbar.
+    let width = (area.width as usize).saturating_sub(4);
+    let mut content: Vec<Line> = Vec::new();
+    if !note.tags.is_empty() {
+        let tags: Vec<String> = note.tags.iter().map(|t| format!("#{}", t)).collect();
+        content.push(Line::from(Span::styled(
+            tags.join("  "),
+            Style::default().fg(theme.accent),
+        )));
+        content.push(sep());
+    }
+    content.extend(
+        markdown::render_note(note, width)
+            .iter()
+            .map(|line| markdown_line(line, theme)),
+    );
+
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let content_len = content.len();
+    let max_scroll = content_len.saturating_sub(inner_height);
+    let scroll = app.details_scroll.min(max_scroll);
+
+    let title = right_title(&format!(
+        "Note #{}: {}",
+        app.left_selected + 1,
+        note_preview(note)
+    ));
+    let para = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(border_style),
+        )
+        .scroll((scroll as u16, 0));
+    f.render_widget(para, area);
+
+    if content_len > inner_height {
+        let mut ss = ScrollbarState::new(max_scroll).position(scroll);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None),
+            area,
+            &mut ss,
+        );
+    }
+}
+
+/// Maps a rendered markdown line onto ratatui styles.
+fn markdown_line(line: &StyledLine, theme: &ResolvedTheme) -> Line<'static> {
+    let spans: Vec<Span> = line
+        .segments
+        .iter()
+        .map(|segment| {
+            let mut style = match segment.role {
+                MdRole::Text | MdRole::CodeBlock => Style::default().fg(Color::White),
+                MdRole::Syntax(kind) => Style::default().fg(theme.syntax(kind)),
+                MdRole::Heading(1) => Style::default().fg(Color::LightMagenta),
+                MdRole::Heading(2) => Style::default().fg(theme.accent),
+                MdRole::Heading(_) => Style::default().fg(Color::Blue),
+                MdRole::Code => Style::default().fg(Color::Yellow),
+                MdRole::Link => Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::UNDERLINED),
+                MdRole::Url | MdRole::Decoration => Style::default().fg(Color::DarkGray),
+                MdRole::Marker => Style::default().fg(theme.accent),
+                MdRole::Checked => Style::default().fg(Color::Green),
+            };
+            if segment.bold {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            if segment.italic {
+                style = style.add_modifier(Modifier::ITALIC);
+            }
+            if segment.strike {
+                style = style.add_modifier(Modifier::CROSSED_OUT);
+            }
+            Span::styled(segment.text.clone(), style)
+        })
+        .collect();
+    Line::from(spans)
+}
+
 fn right_title(label: &str) -> Line<'static> {
     Line::from(vec![
         Span::styled("[0]", Style::default().fg(Color::DarkGray)),
@@ -995,7 +1232,13 @@ fn draw_edit_form(f: &mut Frame, app: &App, area: Rect, is_add: bool, theme: &Re
         } else {
             Color::Reset
         };
-        let label_line = Line::from(Span::styled(format!(" {} ", field.label()), label_style));
+        let mut label_line = Line::from(Span::styled(format!(" {} ", field.label()), label_style));
+        if is_add && field == EditField::Text {
+            label_line.push_span(Span::styled(
+                " +project #tag !high due:fri every:week after:3",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
 
         let input_line = if field == EditField::Priority {
             let (h_s, m_s, l_s) = match form.priority {
@@ -1103,6 +1346,17 @@ fn draw_input_overlay(f: &mut Frame, app: &App, area: Rect, theme: &ResolvedThem
         Span::styled(app.input.clone(), Style::default().fg(Color::White)),
         Span::styled("█", Style::default().fg(theme.accent)),
     ]);
+    let content = match app.search_error {
+        Some(ref err) => {
+            let mut spans = content.spans;
+            spans.push(Span::styled(
+                format!("  ✗ {}", err),
+                Style::default().fg(Color::Red),
+            ));
+            Line::from(spans)
+        }
+        None => content,
+    };
     f.render_widget(
         Paragraph::new(content).style(Style::default().bg(theme.search_bg)),
         area,
@@ -1216,7 +1470,16 @@ fn help_entries() -> Vec<HelpEntry> {
         HelpEntry {
             key: "[ / ]",
             action: "Cycle left panel tabs",
-            description: Some("Switches the left panel [1] between Tasks, Projects, and Tags."),
+            description: Some(
+                "Switches the left panel [1] between Tasks, Projects, Tags, Views and Notes.",
+            ),
+        },
+        HelpEntry {
+            key: "Enter",
+            action: "Set / clear context (Views tab)",
+            description: Some(
+                "Makes the highlighted saved view the context, narrowing the task list until cleared.",
+            ),
         },
         HelpEntry {
             key: "──── Actions",
@@ -1238,6 +1501,21 @@ fn help_entries() -> Vec<HelpEntry> {
             action: "Toggle done / undone",
             description: Some("Marks task completed. Recurring tasks spawn a new occurrence."),
         },
+        HelpEntry {
+            key: "s",
+            action: "Toggle active / pending",
+            description: Some("Starts work on the task. Blocked tasks cannot be started."),
+        },
+        HelpEntry {
+            key: "w",
+            action: "Toggle waiting / pending",
+            description: Some("Parks the task. Waiting tasks are hidden from 'todo next'."),
+        },
+        HelpEntry {
+            key: "c",
+            action: "Toggle cancelled / pending",
+            description: Some("Closes the task without completing it."),
+        },
         HelpEntry {
             key: "x",
             action: "Delete task",
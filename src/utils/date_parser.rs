@@ -109,6 +109,71 @@ pub fn parse_date_not_in_past(input: &str) -> Result<NaiveDate> {
     Ok(date)
 }
 
+/// Parses a span of time into whole days, for `--older-than` and (through
+/// [`parse_estimate`]) `--estimate`.
+///
+/// Accepts a bare number of days (`3`), or a number followed by `d` or `w`
+/// (`3d`, `2w`). `0` is allowed.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::utils::date_parser::parse_days;
+///
+/// assert_eq!(parse_days("3").unwrap(), 3);
+/// assert_eq!(parse_days("3d").unwrap(), 3);
+/// assert_eq!(parse_days("2w").unwrap(), 14);
+/// assert!(parse_days("soon").is_err());
+/// ```
+pub fn parse_days(input: &str) -> Result<u32> {
+    let trimmed = input.trim().to_lowercase();
+    let (number, factor) = if let Some(n) = trimmed.strip_suffix('w') {
+        (n, 7)
+    } else {
+        (trimmed.strip_suffix('d').unwrap_or(&trimmed), 1)
+    };
+    number
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .and_then(|n| n.checked_mul(factor))
+        .with_context(|| {
+            format!(
+                "Could not parse duration: '{}'\n\n\
+                Use a number of days or weeks, e.g. 3, 3d or 2w",
+                input.trim()
+            )
+        })
+}
+
+/// Longest `--estimate` accepted, in days (about ten years).
+///
+/// Plans add estimates up along dependency chains and turn them into dates,
+/// so an unbounded estimate would overflow the calendar.
+pub const MAX_ESTIMATE_DAYS: u32 = 3650;
+
+/// Parses an `--estimate` like [`parse_days`], up to [`MAX_ESTIMATE_DAYS`].
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::utils::date_parser::parse_estimate;
+///
+/// assert_eq!(parse_estimate("2w").unwrap(), 14);
+/// assert!(parse_estimate("4000000000d").is_err());
+/// ```
+pub fn parse_estimate(input: &str) -> Result<u32> {
+    let days = parse_days(input)?;
+    if days > MAX_ESTIMATE_DAYS {
+        bail!(
+            "Estimate too long: '{}' (at most {} days)",
+            input.trim(),
+            MAX_ESTIMATE_DAYS
+        );
+    }
+    Ok(days)
+}
+
 /// Tries custom date patterns that chrono-english does not handle well.
 fn try_parse_custom_patterns(input: &str) -> Option<NaiveDate> {
     let today = Local::now().date_naive();
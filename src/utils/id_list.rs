@@ -0,0 +1,82 @@
+//! Parsing of ID lists and ranges such as `1-5,8,12`.
+
+use crate::error::TodoError;
+
+/// Upper bound on how many IDs a single range may expand to.
+const MAX_RANGE: usize = 10_000;
+
+/// Parses a comma-separated list of 1-based IDs and inclusive ranges.
+///
+/// Whitespace around items is ignored, duplicates are removed, and the
+/// result keeps first-seen order.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::utils::id_list::parse_id_list;
+///
+/// assert_eq!(parse_id_list("1-3,8, 2").unwrap(), vec![1, 2, 3, 8]);
+/// assert!(parse_id_list("5-2").is_err());
+/// ```
+pub fn parse_id_list(input: &str) -> Result<Vec<usize>, TodoError> {
+    let err = |reason: String| TodoError::InvalidIdList {
+        input: input.trim().to_string(),
+        reason,
+    };
+    let parse_one = |s: &str| -> Result<usize, TodoError> {
+        match s.trim().parse::<usize>() {
+            Ok(0) => Err(err("IDs start at 1".into())),
+            Ok(n) => Ok(n),
+            Err(_) => Err(err(format!("'{}' is not a number", s.trim()))),
+        }
+    };
+
+    let mut ids = Vec::new();
+    for item in input.split(',') {
+        let item = item.trim();
+        if item.is_empty() {
+            return Err(err("empty item".into()));
+        }
+        let (start, end) = match item.split_once('-') {
+            Some((a, b)) => (parse_one(a)?, parse_one(b)?),
+            None => {
+                let n = parse_one(item)?;
+                (n, n)
+            }
+        };
+        if start > end {
+            return Err(err(format!("range {}-{} is reversed", start, end)));
+        }
+        if end - start >= MAX_RANGE {
+            return Err(err(format!("range {}-{} is too large", start, end)));
+        }
+        for id in start..=end {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_list_and_ranges() {
+        assert_eq!(parse_id_list("4").unwrap(), vec![4]);
+        assert_eq!(
+            parse_id_list("1-5,8,12").unwrap(),
+            vec![1, 2, 3, 4, 5, 8, 12]
+        );
+        assert_eq!(parse_id_list(" 3 - 4 , 3 ").unwrap(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        for bad in ["", "0", "1,,2", "a", "3-1", "1-", "-2", "1-2-3"] {
+            assert!(parse_id_list(bad).is_err(), "{bad:?} should be rejected");
+        }
+    }
+}
@@ -5,10 +5,14 @@
 //! | [`confirm`] | Yes/no prompt for destructive operations |
 //! | [`tag_normalizer`] | Fuzzy tag normalization with Levenshtein distance |
 //! | [`date_parser`] |
+//! | [`id_list`] | Parsing of ID lists and ranges (`1-5,8,12`) |
+//! | [`quick_capture`] | Inline `+project #tag !high due:…` syntax for `todo add` |
 //! | [`validation`] | Input validation for task fields |
 
 pub mod confirm;
 pub mod date_parser;
+pub mod id_list;
+pub mod quick_capture;
 pub mod tag_normalizer;
 pub mod validation;
 
@@ -0,0 +1,239 @@
+//! Inline quick-capture syntax for `todo add`.
+//!
+//! Lets a whole task be typed as one line:
+//!
+//! ```text
+//! todo add "Fix login bug +api #bug !high due:fri every:week after:12"
+//! ```
+//!
+//! | Token | Field |
+//! |---|---|
+//! | `+project`, `+"Day job"` | project |
+//! | `#tag` | tag (repeatable) |
+//! | `!high`, `!medium`, `!low` (or `!h`, `!m`, `!l`) | priority |
+//! | `due:fri`, `due:"next friday"` | due date — anything `--due` accepts |
+//! | `every:day`, `every:week`, `every:month` | recurrence |
+//! | `after:12`, `after:3-5,9` | dependencies (visible task IDs) |
+//!
+//! Everything else is task text. A token starting with `\` is kept as
+//! literal text without the backslash (`\#1`, `\+1`, `\due:later`); a lone
+//! `+`, `#` or `!` is always text. Values are not validated here beyond their
+//! shape — `todo add` runs them through [`crate::utils::validation`] like the
+//! corresponding flags.
+
+use crate::error::TodoError;
+use crate::models::{Priority, Recurrence};
+use crate::utils::id_list::parse_id_list;
+
+/// Fields extracted from a quick-capture line.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuickCapture {
+    /// The remaining words, joined by single spaces.
+    pub text: String,
+    pub priority: Option<Priority>,
+    pub tags: Vec<String>,
+    pub project: Option<String>,
+    /// Unparsed due expression, e.g. `"fri"` or `"in 3 days"`.
+    pub due: Option<String>,
+    pub recurrence: Option<Recurrence>,
+    pub depends_on: Vec<usize>,
+}
+
+/// Splits `input` into task text and inline fields.
+///
+/// # Errors
+///
+/// Returns `TodoError::InvalidQuickCapture` for an unknown priority or
+/// recurrence, a malformed `after:` list, an unterminated quote, or a field
+/// (other than tags and dependencies) given twice.
+///
+/// # Examples
+///
+/// ```
+/// use rustodo::models::Priority;
+/// use rustodo::utils::quick_capture::parse;
+///
+/// let qc = parse("Fix login +api #bug !high due:fri").unwrap();
+/// assert_eq!(qc.text, "Fix login");
+/// assert_eq!(qc.project.as_deref(), Some("api"));
+/// assert_eq!(qc.tags, vec!["bug"]);
+/// assert_eq!(qc.priority, Some(Priority::High));
+/// assert_eq!(qc.due.as_deref(), Some("fri"));
+///
+/// assert_eq!(parse(r"Reply to \#42").unwrap().text, "Reply to #42");
+/// ```
+pub fn parse(input: &str) -> Result<QuickCapture, TodoError> {
+    let mut qc = QuickCapture::default();
+    let mut words: Vec<String> = Vec::new();
+
+    for token in tokenize(input)? {
+        if let Some(literal) = token.strip_prefix('\\') {
+            words.push(literal.to_string());
+            continue;
+        }
+        if let Some(project) = value_after(&token, "+") {
+            set_once(&mut qc.project, unquote(project), &token)?;
+        } else if let Some(tag) = value_after(&token, "#") {
+            qc.tags.push(tag.to_string());
+        } else if let Some(priority) = value_after(&token, "!") {
+            set_once(&mut qc.priority, parse_priority(priority, &token)?, &token)?;
+        } else if let Some(due) = value_after(&token, "due:") {
+            set_once(&mut qc.due, unquote(due), &token)?;
+        } else if let Some(every) = value_after(&token, "every:") {
+            set_once(&mut qc.recurrence, parse_every(every, &token)?, &token)?;
+        } else if let Some(ids) = value_after(&token, "after:") {
+            let ids = parse_id_list(ids).map_err(|e| invalid(&token, e.to_string()))?;
+            qc.depends_on.extend(ids);
+        } else {
+            words.push(token);
+        }
+    }
+
+    qc.text = words.join(" ");
+    Ok(qc)
+}
+
+/// Splits on whitespace, keeping `"…"` together when it follows a prefix
+/// (`+"Day job"`, `due:"next friday"`).
+fn tokenize(input: &str) -> Result<Vec<String>, TodoError> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' if in_quotes => {
+                current.push(c);
+                in_quotes = false;
+            }
+            '"' if current.ends_with(['+', ':']) => {
+                current.push(c);
+                in_quotes = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(invalid(&current, "unterminated quote".into()));
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+/// Returns the non-empty value after `prefix` (case-insensitive for keys).
+fn value_after<'a>(token: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = token.get(..prefix.len())?;
+    if !head.eq_ignore_ascii_case(prefix) {
+        return None;
+    }
+    Some(&token[prefix.len()..]).filter(|v| !v.is_empty() && *v != "\"\"")
+}
+
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, token: &str) -> Result<(), TodoError> {
+    if slot.is_some() {
+        return Err(invalid(token, "given more than once".into()));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn parse_priority(value: &str, token: &str) -> Result<Priority, TodoError> {
+    match value.to_lowercase().as_str() {
+        "h" | "high" => Ok(Priority::High),
+        "m" | "med" | "medium" => Ok(Priority::Medium),
+        "l" | "low" => Ok(Priority::Low),
+        _ => Err(invalid(
+            token,
+            "priority must be !high, !medium or !low".into(),
+        )),
+    }
+}
+
+fn parse_every(value: &str, token: &str) -> Result<Recurrence, TodoError> {
+    match value.to_lowercase().as_str() {
+        "day" | "daily" => Ok(Recurrence::Daily),
+        "week" | "weekly" => Ok(Recurrence::Weekly),
+        "month" | "monthly" => Ok(Recurrence::Monthly),
+        _ => Err(invalid(
+            token,
+            "recurrence must be every:day, every:week or every:month".into(),
+        )),
+    }
+}
+
+fn invalid(token: &str, reason: String) -> TodoError {
+    TodoError::InvalidQuickCapture {
+        token: token.to_string(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_every_field() {
+        let qc = parse("Fix login bug +api #bug !high due:fri every:week after:12").unwrap();
+        assert_eq!(
+            qc,
+            QuickCapture {
+                text: "Fix login bug".into(),
+                priority: Some(Priority::High),
+                tags: vec!["bug".into()],
+                project: Some("api".into()),
+                due: Some("fri".into()),
+                recurrence: Some(Recurrence::Weekly),
+                depends_on: vec![12],
+            }
+        );
+    }
+
+    #[test]
+    fn test_quoted_values_and_multiple_tags() {
+        let qc =
+            parse(r#"Call Bob +"Day job" due:"next friday" #calls #work after:1,3-4"#).unwrap();
+        assert_eq!(qc.text, "Call Bob");
+        assert_eq!(qc.project.as_deref(), Some("Day job"));
+        assert_eq!(qc.due.as_deref(), Some("next friday"));
+        assert_eq!(qc.tags, vec!["calls", "work"]);
+        assert_eq!(qc.depends_on, vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn test_escapes_and_plain_text_are_kept() {
+        let qc = parse(r"Vote \+1 on A + B, see \#12 at https://x.io/a:b !").unwrap();
+        assert_eq!(qc.text, "Vote +1 on A + B, see #12 at https://x.io/a:b !");
+        assert_eq!(
+            qc,
+            QuickCapture {
+                text: qc.text.clone(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse("Task !urgent").is_err());
+        assert!(parse("Task every:year").is_err());
+        assert!(parse("Task after:x").is_err());
+        assert!(parse("Task +a +b").is_err());
+        assert!(parse(r#"Task due:"next friday"#).is_err());
+    }
+}
@@ -6,6 +6,7 @@ use helpers::{TestEnv, days_from_now};
 use rustodo::cli::AddArgs;
 use rustodo::commands;
 use rustodo::models::{Priority, Recurrence};
+use rustodo::storage::Storage;
 
 #[test]
 fn test_add_simple_task() {
@@ -21,6 +22,7 @@ fn test_add_simple_task() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -52,6 +54,7 @@ fn test_add_task_with_all_metadata() {
             due: Some(due_date.to_string()),
             recurrence: Some(Recurrence::Weekly),
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -82,6 +85,7 @@ fn test_add_multiple_tasks_preserves_order() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -95,6 +99,7 @@ fn test_add_multiple_tasks_preserves_order() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -108,6 +113,7 @@ fn test_add_multiple_tasks_preserves_order() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -133,6 +139,7 @@ fn test_add_recurring_task_requires_due_date() {
             due: None, // No due date
             recurrence: Some(Recurrence::Daily),
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -156,6 +163,7 @@ fn test_add_empty_text_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -177,6 +185,7 @@ fn test_add_whitespace_only_text_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -198,6 +207,7 @@ fn test_add_with_invalid_tags_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -224,9 +234,39 @@ fn test_add_with_duplicate_tags_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
     assert!(result.is_err());
     assert!(result.unwrap_err().to_string().contains("Duplicate tag"));
 }
+
+#[test]
+fn test_add_quick_capture_syntax() {
+    let env = TestEnv::new();
+
+    commands::task::add::execute_silent(
+        env.storage(),
+        AddArgs {
+            text: r#"Call Bob +"Day job" #calls !low due:"in 3 days" every:week"#.to_string(),
+            priority: Priority::Medium,
+            tag: vec![],
+            project: None,
+            due: None,
+            recurrence: None,
+            depends_on: vec![],
+            estimate: None,
+        },
+    )
+    .unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks[0].text, "Call Bob");
+    assert_eq!(tasks[0].priority, Priority::Low);
+    assert_eq!(tasks[0].tags, vec!["calls"]);
+    assert_eq!(tasks[0].due_date, Some(days_from_now(3)));
+    assert_eq!(tasks[0].recurrence, Some(Recurrence::Weekly));
+    let projects = env.storage().load_projects().unwrap();
+    assert_eq!(projects[0].name, "Day job");
+}
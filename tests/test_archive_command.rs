@@ -0,0 +1,131 @@
+//! Integration tests for `todo archive`, `todo unarchive` and
+//! `todo search --archived`
+//!
+//! Covers:
+//! - Old completed tasks move to the archive with their notes
+//! - Open and recently closed tasks stay live
+//! - Projects are only archived once all their tasks are
+//! - Unarchiving restores an item with what it owns and belongs to
+//! - Searching the archive
+
+mod helpers;
+
+use chrono::NaiveDate;
+use helpers::{TestEnv, simple_task};
+use rustodo::commands::archive;
+use rustodo::models::{Archived, Note, Project, StatusFilter, Task};
+use rustodo::storage::Storage;
+
+fn old_done(text: &str, project: Option<&Project>) -> Task {
+    let mut task = simple_task(text);
+    task.project_id = project.map(|p| p.uuid);
+    task.mark_done();
+    task.completed_at = NaiveDate::from_ymd_opt(2020, 3, 1);
+    task
+}
+
+fn old_project(name: &str) -> Project {
+    let mut project = Project::new(name.into());
+    project.mark_done();
+    project.completed_at = NaiveDate::from_ymd_opt(2020, 3, 1);
+    project
+}
+
+#[test]
+fn test_archive_moves_old_done_task_with_its_note() {
+    let done = old_done("Write report", None);
+    let mut recent = simple_task("Recent");
+    recent.mark_done();
+    let env = TestEnv::with_tasks(vec![done.clone(), recent, simple_task("Open")]);
+    let mut note = Note::new("Report outline".into());
+    note.task_ids = vec![done.uuid];
+    env.storage().save_notes(&[note]).unwrap();
+
+    archive::execute(env.storage(), "90d", false, true).unwrap();
+
+    let texts: Vec<String> = env.load_tasks().into_iter().map(|t| t.text).collect();
+    assert_eq!(texts, vec!["Recent", "Open"]);
+    assert!(env.storage().load_notes().unwrap().is_empty());
+
+    let archived = env.storage().load_archive().unwrap();
+    assert_eq!(archived.len(), 2);
+    assert_eq!(archived[0].uuid(), done.uuid);
+    assert_eq!(archived[1].kind(), "note");
+}
+
+#[test]
+fn test_archive_dry_run_changes_nothing() {
+    let env = TestEnv::with_tasks(vec![old_done("Old", None)]);
+    archive::execute(env.storage(), "90d", true, true).unwrap();
+    assert_eq!(env.load_tasks().len(), 1);
+    assert!(env.storage().load_archive().unwrap().is_empty());
+}
+
+#[test]
+fn test_archive_keeps_project_with_live_tasks() {
+    let project = old_project("Website");
+    let mut open = simple_task("Follow-up");
+    open.project_id = Some(project.uuid);
+    let env = TestEnv::with_tasks(vec![old_done("Launch", Some(&project)), open]);
+    env.storage()
+        .save_projects(std::slice::from_ref(&project))
+        .unwrap();
+
+    archive::execute(env.storage(), "90d", false, true).unwrap();
+
+    assert_eq!(env.storage().load_projects().unwrap().len(), 1);
+    let tasks = env.load_tasks();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].project_id, Some(project.uuid));
+}
+
+#[test]
+fn test_unarchive_task_restores_its_project() {
+    let project = old_project("Website");
+    let env = TestEnv::with_tasks(vec![old_done("Launch", Some(&project))]);
+    env.storage()
+        .save_projects(std::slice::from_ref(&project))
+        .unwrap();
+    archive::execute(env.storage(), "30d", false, true).unwrap();
+    assert!(env.is_empty());
+    assert!(env.storage().load_projects().unwrap().is_empty());
+
+    // #1 is the project, #2 the task.
+    archive::unarchive(env.storage(), 2).unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0].project_id, Some(project.uuid));
+    assert_eq!(env.storage().load_projects().unwrap().len(), 1);
+    assert!(env.storage().load_archive().unwrap().is_empty());
+}
+
+#[test]
+fn test_unarchive_unknown_id_fails() {
+    let env = TestEnv::new();
+    assert!(archive::unarchive(env.storage(), 3).is_err());
+}
+
+#[test]
+fn test_search_archived() {
+    let env = TestEnv::with_tasks(vec![old_done("Quarterly report", None)]);
+    archive::execute(env.storage(), "90d", false, true).unwrap();
+
+    assert!(archive::search(env.storage(), "report", &[], None, StatusFilter::All).is_ok());
+    assert!(archive::search(env.storage(), "report", &[], None, StatusFilter::Done).is_ok());
+    assert!(archive::search(env.storage(), "invoice", &[], None, StatusFilter::All).is_err());
+    assert!(
+        archive::search(
+            env.storage(),
+            "report",
+            &[],
+            Some("nope"),
+            StatusFilter::All
+        )
+        .is_err()
+    );
+    assert!(matches!(
+        env.storage().load_archive().unwrap()[0].item,
+        Archived::Task(_)
+    ));
+}
@@ -0,0 +1,145 @@
+//! Integration tests for `attach`, `attachments` and `open-attachment`
+//!
+//! Covers:
+//! - Attaching files to tasks and notes, by visible ID
+//! - Identical contents are stored once
+//! - Opening by number copies the file out under its original name
+//! - Purge keeps blobs of live attachments and removes unreferenced ones
+
+mod helpers;
+
+use std::path::PathBuf;
+
+use helpers::TestEnv;
+use rustodo::cli::AddArgs;
+use rustodo::commands::{attachment, purge, task};
+use rustodo::models::{AttachmentOwner, Note, Priority};
+use rustodo::storage::{Storage, attachments};
+use tempfile::TempDir;
+
+// ─── helpers ─────────────────────────────────────────────────────────────────
+
+fn add_simple(env: &TestEnv, text: &str) {
+    task::add::execute(
+        env.storage(),
+        AddArgs {
+            text: text.to_string(),
+            priority: Priority::Medium,
+            tag: vec![],
+            project: None,
+            due: None,
+            recurrence: None,
+            depends_on: vec![],
+            estimate: None,
+        },
+    )
+    .unwrap();
+}
+
+fn write(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn blob_count(env: &TestEnv) -> usize {
+    let dir = env.storage().attachments_dir().unwrap();
+    match std::fs::read_dir(&dir) {
+        Ok(shards) => shards
+            .filter_map(|s| s.ok())
+            .map(|s| std::fs::read_dir(s.path()).unwrap().count())
+            .sum(),
+        Err(_) => 0,
+    }
+}
+
+// ─── attach ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_attach_uses_visible_task_ids() {
+    let env = TestEnv::new();
+    let tmp = TempDir::new().unwrap();
+    add_simple(&env, "First");
+    add_simple(&env, "Second");
+    add_simple(&env, "Third");
+    task::remove::execute(env.storage(), 1, true).unwrap();
+
+    // #2 is now "Third".
+    attachment::execute_attach(env.storage(), 2, write(&tmp, "a.txt", "a"), false).unwrap();
+
+    let third = env.load_tasks()[1].uuid;
+    let files = env.storage().load_attachments().unwrap();
+    assert_eq!(files[0].owner, AttachmentOwner::Task(third));
+}
+
+#[test]
+fn test_identical_contents_are_stored_once() {
+    let env = TestEnv::new();
+    let tmp = TempDir::new().unwrap();
+    add_simple(&env, "Task");
+    env.storage()
+        .save_notes(&[Note::new("Note".into())])
+        .unwrap();
+
+    attachment::execute_attach(env.storage(), 1, write(&tmp, "a.txt", "same"), false).unwrap();
+    attachment::execute_attach(env.storage(), 1, write(&tmp, "b.txt", "same"), true).unwrap();
+
+    let files = env.storage().load_attachments().unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].hash, files[1].hash);
+    assert_eq!(blob_count(&env), 1);
+}
+
+#[test]
+fn test_attach_missing_file_fails() {
+    let env = TestEnv::new();
+    let tmp = TempDir::new().unwrap();
+    add_simple(&env, "Task");
+
+    let result = attachment::execute_attach(env.storage(), 1, tmp.path().join("nope"), false);
+    assert!(result.is_err());
+    assert!(env.storage().load_attachments().unwrap().is_empty());
+}
+
+// ─── list / open ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_list_and_open_attachments() {
+    let env = TestEnv::new();
+    let tmp = TempDir::new().unwrap();
+    add_simple(&env, "Task");
+
+    assert!(attachment::execute_list(env.storage(), 1, false).is_ok());
+    assert!(attachment::execute_open(env.storage(), 1, None, false, true).is_err());
+
+    attachment::execute_attach(env.storage(), 1, write(&tmp, "notes.md", "# hi"), false).unwrap();
+    assert!(attachment::execute_list(env.storage(), 1, false).is_ok());
+    assert!(attachment::execute_open(env.storage(), 1, Some(1), false, true).is_ok());
+    assert!(attachment::execute_list(env.storage(), 1, true).is_err());
+}
+
+// ─── purge ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_purge_removes_unreferenced_blobs() {
+    let env = TestEnv::new();
+    let tmp = TempDir::new().unwrap();
+    add_simple(&env, "Kept");
+    add_simple(&env, "Gone");
+
+    attachment::execute_attach(env.storage(), 1, write(&tmp, "a.txt", "kept"), false).unwrap();
+    attachment::execute_attach(env.storage(), 2, write(&tmp, "b.txt", "gone"), false).unwrap();
+    let kept = env.storage().load_attachments().unwrap()[0].hash.clone();
+
+    // A trashed task keeps its attachments until it is purged.
+    task::remove::execute(env.storage(), 2, true).unwrap();
+    assert_eq!(env.storage().load_attachments().unwrap().len(), 2);
+
+    purge::execute(env.storage(), 0, false, true).unwrap();
+
+    let files = env.storage().load_attachments().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(blob_count(&env), 1);
+    let dir = env.storage().attachments_dir().unwrap();
+    assert!(attachments::blob_path(&dir, &kept).unwrap().exists());
+}
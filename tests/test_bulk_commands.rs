@@ -0,0 +1,147 @@
+//! Integration tests for bulk task operations (ID lists, ranges and `--filter`)
+//!
+//! Covers:
+//! - Ranges and lists select the right tasks and skip the confirmation with `--yes`
+//! - `--filter` combined with IDs selects the intersection
+//! - Bulk edit and recur apply the same change to every selected task
+//! - A single plain ID still goes through the regular handler
+
+mod helpers;
+
+use helpers::{TestEnv, simple_task, task_with_due, task_with_tags, today};
+use rustodo::cli::{EditArgs, TaskTarget};
+use rustodo::commands::task::{self, bulk};
+use rustodo::models::{Priority, Recurrence, TaskStatus};
+use rustodo::storage::Storage;
+
+fn target(ids: Option<&str>, filter: Option<&str>) -> TaskTarget {
+    TaskTarget {
+        ids: ids.map(String::from),
+        filter: filter.map(String::from),
+        yes: true,
+    }
+}
+
+#[test]
+fn test_done_with_range_and_list() {
+    let env = TestEnv::with_tasks((1..=6).map(|i| simple_task(&format!("T{i}"))).collect());
+
+    bulk::run(
+        env.storage(),
+        &target(Some("1-3,5"), None),
+        "complete",
+        task::done::execute_silent,
+    )
+    .unwrap();
+
+    let done: Vec<bool> = env.load_tasks().iter().map(|t| t.completed).collect();
+    assert_eq!(done, vec![true, true, true, false, true, false]);
+}
+
+#[test]
+fn test_remove_with_filter_and_ids_intersects() {
+    let env = TestEnv::with_tasks(vec![
+        task_with_tags("A", vec!["bug"]),
+        task_with_tags("B", vec!["feature"]),
+        task_with_tags("C", vec!["bug"]),
+        task_with_tags("D", vec!["bug"]),
+    ]);
+
+    bulk::run(
+        env.storage(),
+        &target(Some("1-3"), Some("tag:bug")),
+        "remove",
+        task::remove::execute_silent,
+    )
+    .unwrap();
+
+    let left: Vec<String> = env.load_tasks().into_iter().map(|t| t.text).collect();
+    assert_eq!(left, vec!["B", "D"]);
+}
+
+#[test]
+fn test_bulk_edit_and_status() {
+    let env = TestEnv::with_tasks(vec![
+        task_with_tags("A", vec!["bug"]),
+        task_with_tags("B", vec!["bug"]),
+    ]);
+
+    let args = EditArgs {
+        id: 0,
+        text: None,
+        priority: Some(Priority::High),
+        add_tag: vec![],
+        remove_tag: vec![],
+        project: Some("api".into()),
+        clear_project: false,
+        due: None,
+        clear_due: false,
+        clear_tags: false,
+        add_dep: vec![],
+        remove_dep: vec![],
+        clear_deps: false,
+        estimate: None,
+        clear_estimate: false,
+    };
+    bulk::run(
+        env.storage(),
+        &target(None, Some("tag:bug")),
+        "edit",
+        |s, id| task::edit::execute_silent(s, EditArgs { id, ..args.clone() }),
+    )
+    .unwrap();
+    bulk::run(
+        env.storage(),
+        &target(Some("all"), None),
+        "start",
+        |s, id| task::status::execute_silent(s, id, TaskStatus::Active),
+    )
+    .unwrap();
+
+    let tasks = env.load_tasks();
+    assert!(tasks.iter().all(|t| t.priority == Priority::High));
+    assert!(tasks.iter().all(|t| t.project_id.is_some()));
+    assert!(tasks.iter().all(|t| t.status == TaskStatus::Active));
+    assert_eq!(env.storage().load_projects().unwrap().len(), 1);
+}
+
+#[test]
+fn test_bulk_recur_reports_failures() {
+    let env = TestEnv::with_tasks(vec![
+        task_with_due("Has due", today()),
+        simple_task("No due"),
+    ]);
+
+    let result = bulk::run(
+        env.storage(),
+        &target(Some("1,2"), None),
+        "recur",
+        |s, id| task::recur::execute_silent(s, id, Recurrence::Weekly),
+    );
+
+    assert!(result.is_err());
+    let tasks = env.load_tasks();
+    assert_eq!(tasks[0].recurrence, Some(Recurrence::Weekly));
+    assert_eq!(tasks[1].recurrence, None);
+}
+
+#[test]
+fn test_single_id_uses_regular_handler() {
+    let env = TestEnv::with_tasks(vec![simple_task("A")]);
+    let mut called = false;
+
+    bulk::dispatch(
+        env.storage(),
+        &target(Some("1"), None),
+        "complete",
+        |s, id| {
+            called = true;
+            task::done::execute(s, id)
+        },
+        task::done::execute_silent,
+    )
+    .unwrap();
+
+    assert!(called);
+    assert!(env.load_tasks()[0].completed);
+}
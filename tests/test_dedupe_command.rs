@@ -0,0 +1,46 @@
+//! Integration tests for duplicate detection and merging
+//!
+//! Covers:
+//! - `dedupe --yes` merges each group of similar pending tasks into the oldest
+//! - `merge` combines tags, dependencies and the earliest due date
+//! - Dependents of a merged task are rewired to the kept task
+
+mod helpers;
+
+use helpers::{TestEnv, days_from_now, simple_task, task_with_tags};
+use rustodo::commands::task;
+
+#[test]
+fn test_dedupe_merges_similar_pending_tasks() {
+    let env = TestEnv::with_tasks(vec![
+        task_with_tags("Fix login bug", vec!["bug"]),
+        simple_task("Write report"),
+        task_with_tags("Fix the login bug", vec!["auth"]),
+    ]);
+
+    task::dedupe::execute(env.storage(), true).unwrap();
+
+    let open = env.load_tasks();
+    assert_eq!(open.len(), 2);
+    assert_eq!(open[0].text, "Fix login bug");
+    assert_eq!(open[0].tags, vec!["bug", "auth"]);
+}
+
+#[test]
+fn test_merge_keeps_earliest_due_and_rewires_dependents() {
+    let mut keep = simple_task("Email Alice about invoice");
+    keep.due_date = Some(days_from_now(7));
+    let mut dup = simple_task("Email Alice re invoice");
+    dup.due_date = Some(days_from_now(2));
+    let mut dependent = simple_task("Close the books");
+    dependent.depends_on = vec![dup.uuid];
+    let keep_uuid = keep.uuid;
+    let env = TestEnv::with_tasks(vec![keep, dup, dependent]);
+
+    task::merge::execute(env.storage(), 1, "2", true).unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].due_date, Some(days_from_now(2)));
+    assert_eq!(tasks[1].depends_on, vec![keep_uuid]);
+}
@@ -9,6 +9,7 @@
 //! - Cycle detection
 //! - Dependency not found on remove
 //! - Duplicate dependency on add
+//! - Whole-graph export with `--graph`
 
 mod helpers;
 
@@ -16,6 +17,7 @@ use helpers::TestEnv;
 use rustodo::cli::{AddArgs, EditArgs};
 use rustodo::commands::task;
 use rustodo::models::Priority;
+use rustodo::render::dep_graph::GraphFormat;
 
 // ─── helpers ────────────────────────────────────────────────────────────────
 
@@ -30,6 +32,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -47,6 +50,7 @@ fn add_with_deps(env: &TestEnv, text: &str, depends_on: Vec<usize>) -> usize {
             due: None,
             recurrence: None,
             depends_on,
+            estimate: None,
         },
     )
     .unwrap();
@@ -201,6 +205,7 @@ fn test_add_self_dependency_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![1],
+            estimate: None,
         },
     );
 
@@ -234,6 +239,8 @@ fn test_edit_add_self_dependency_fails() {
             add_dep: vec![1],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -271,6 +278,8 @@ fn test_edit_direct_cycle_fails() {
             add_dep: vec![2], // add_dep: A depends on B
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -303,6 +312,8 @@ fn test_edit_transitive_cycle_fails() {
             add_dep: vec![3],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -335,6 +346,8 @@ fn test_edit_no_cycle_on_valid_dep() {
             add_dep: vec![3], // A depends on C
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -366,6 +379,8 @@ fn test_edit_duplicate_dependency_fails() {
             add_dep: vec![1], // already a dep
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -398,6 +413,8 @@ fn test_edit_remove_existing_dependency() {
             add_dep: vec![],
             remove_dep: vec![1],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -429,6 +446,8 @@ fn test_edit_remove_nonexistent_dependency_fails() {
             add_dep: vec![],
             remove_dep: vec![1],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -460,6 +479,8 @@ fn test_edit_clear_all_dependencies() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: true, // clear_deps
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -522,6 +543,7 @@ fn test_recurrence_does_not_inherit_deps() {
             due: Some(due_str),
             recurrence: Some(Recurrence::Daily),
             depends_on: vec![1],
+            estimate: None,
         },
     )
     .unwrap();
@@ -538,3 +560,39 @@ fn test_recurrence_does_not_inherit_deps() {
         "recurrence should not inherit dependencies"
     );
 }
+
+// ─── graph export ─────────────────────────────────────────────────────────────
+
+#[test]
+fn test_deps_graph_writes_mermaid_file() {
+    let env = TestEnv::new();
+    add_simple(&env, "Design");
+    add_with_deps(&env, "Build", vec![1]);
+    add_simple(&env, "Unrelated");
+    task::done::execute(env.storage(), 1).unwrap();
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("deps.mmd");
+    task::deps::execute_graph(
+        env.storage(),
+        None,
+        GraphFormat::Mermaid,
+        Some(path.clone()),
+        false,
+    )
+    .unwrap();
+
+    let graph = std::fs::read_to_string(&path).unwrap();
+    assert!(graph.starts_with("flowchart LR"));
+    assert!(graph.contains("t1 --> t2"));
+    assert!(graph.contains("class t1 done"));
+    assert!(!graph.contains("Unrelated"));
+}
+
+#[test]
+fn test_deps_graph_unknown_project_fails() {
+    let env = TestEnv::new();
+    let result =
+        task::deps::execute_graph(env.storage(), Some("nope"), GraphFormat::Dot, None, false);
+    assert!(result.is_err());
+}
@@ -19,6 +19,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -36,6 +37,7 @@ fn add_with_deps(env: &TestEnv, text: &str, depends_on: Vec<usize>) -> usize {
             due: None,
             recurrence: None,
             depends_on,
+            estimate: None,
         },
     )
     .unwrap();
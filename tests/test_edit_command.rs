@@ -22,6 +22,7 @@ fn test_edit_text() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -43,6 +44,8 @@ fn test_edit_text() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -67,6 +70,7 @@ fn test_edit_priority() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -87,6 +91,8 @@ fn test_edit_priority() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -110,6 +116,7 @@ fn test_edit_add_invalid_tag_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -130,6 +137,8 @@ fn test_edit_add_invalid_tag_fails() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -157,6 +166,7 @@ fn test_edit_add_tags_preserves_existing() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -178,6 +188,8 @@ fn test_edit_add_tags_preserves_existing() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -209,6 +221,7 @@ fn test_edit_remove_specific_tag() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -229,6 +242,8 @@ fn test_edit_remove_specific_tag() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -257,6 +272,7 @@ fn test_edit_add_and_remove_tags_simultaneously() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -277,6 +293,8 @@ fn test_edit_add_and_remove_tags_simultaneously() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -305,6 +323,7 @@ fn test_edit_clear_all_tags() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -325,6 +344,8 @@ fn test_edit_clear_all_tags() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -349,6 +370,7 @@ fn test_edit_remove_nonexistent_tag_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -370,6 +392,8 @@ fn test_edit_remove_nonexistent_tag_fails() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -404,6 +428,8 @@ fn test_edit_invalid_id() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -425,6 +451,7 @@ fn test_edit_due_date() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -447,6 +474,8 @@ fn test_edit_due_date() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -471,6 +500,7 @@ fn test_edit_clear_due_date() {
             due: Some(due_date.to_string()),
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -491,6 +521,8 @@ fn test_edit_clear_due_date() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -0,0 +1,99 @@
+//! Integration tests for the `journal` command
+//!
+//! Covers:
+//! - One entry per day, tagged `journal` and titled with the date
+//! - The template lists tasks completed today (from the event log) and due today
+//! - Listed tasks are linked, so the task's context shows the entry
+//! - Listing by month
+
+mod helpers;
+
+use chrono::Local;
+use helpers::TestEnv;
+use rustodo::cli::AddArgs;
+use rustodo::commands::{journal, task};
+use rustodo::models::{LinkTarget, Priority};
+use rustodo::storage::Storage;
+
+// ─── helpers ─────────────────────────────────────────────────────────────────
+
+fn add(env: &TestEnv, text: &str, due: Option<&str>) {
+    task::add::execute(
+        env.storage(),
+        AddArgs {
+            text: text.to_string(),
+            priority: Priority::Medium,
+            tag: vec![],
+            project: None,
+            due: due.map(str::to_string),
+            recurrence: None,
+            depends_on: vec![],
+            estimate: None,
+        },
+    )
+    .unwrap();
+}
+
+// ─── open ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_journal_prefills_done_and_due_tasks() {
+    let env = TestEnv::new();
+    add(&env, "Ship release", None);
+    add(&env, "Write standup", Some("today"));
+    add(&env, "Someday", None);
+    task::done::execute(env.storage(), 1).unwrap();
+
+    journal::execute_open(env.storage(), None, true).unwrap();
+
+    let notes = env.storage().load_notes().unwrap();
+    assert_eq!(notes.len(), 1);
+    let entry = &notes[0];
+    let today = Local::now().date_naive().format("%Y-%m-%d").to_string();
+    assert_eq!(entry.title.as_deref(), Some(today.as_str()));
+    assert!(entry.tags.contains(&"journal".to_string()));
+    assert!(entry.body.contains("- [x] Ship release [[task:1]]"));
+    assert!(entry.body.contains("- [ ] Write standup [[task:2]]"));
+    assert!(!entry.body.contains("Someday"));
+
+    let tasks = env.load_tasks();
+    assert!(entry.links_to(LinkTarget::Task(tasks[0].uuid)));
+    assert!(entry.links_to(LinkTarget::Task(tasks[1].uuid)));
+}
+
+#[test]
+fn test_journal_reuses_the_days_entry() {
+    let env = TestEnv::new();
+    journal::execute_open(env.storage(), Some("2027-03-14".into()), true).unwrap();
+    add(&env, "Added later", Some("2027-03-14"));
+    journal::execute_open(env.storage(), Some("2027-03-14".into()), true).unwrap();
+
+    let notes = env.storage().load_notes().unwrap();
+    assert_eq!(notes.len(), 1, "the entry is created once");
+    assert!(
+        !notes[0].body.contains("Added later"),
+        "an existing entry is not regenerated"
+    );
+}
+
+#[test]
+fn test_journal_rejects_bad_date() {
+    let env = TestEnv::new();
+    assert!(journal::execute_open(env.storage(), Some("not a date".into()), true).is_err());
+    assert!(env.storage().load_notes().unwrap().is_empty());
+}
+
+// ─── list ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_journal_list_by_month() {
+    let env = TestEnv::new();
+    for date in ["2026-03-14", "2026-03-15", "2026-04-01"] {
+        journal::execute_open(env.storage(), Some(date.into()), true).unwrap();
+    }
+
+    assert!(journal::execute_list(env.storage(), None).is_ok());
+    assert!(journal::execute_list(env.storage(), Some("2026-03".into())).is_ok());
+    assert!(journal::execute_list(env.storage(), Some("".into())).is_ok());
+    assert!(journal::execute_list(env.storage(), Some("March".into())).is_err());
+}
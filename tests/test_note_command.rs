@@ -3,12 +3,14 @@
 //! Covers:
 //! - note add (body inline, empty body fails)
 //! - note list (all, filter by project, tag, language)
-//! - note show
+//! - note show, note preview (markdown rendering)
 //! - note edit (body, title, language, tags, project, task link, resource links)
+//! - wiki-links: resolution on save, backlinks, rename updates references
+//! - note history, note diff, note revert (revisions kept by edit)
 //! - note remove
 //! - note clear
 //! - soft-delete: removed notes don't appear in list
-//! - task_id is cleared when linked task is removed
+//! - task links are cleared when the linked task is removed
 //! - resource_ids are cleaned when resource is removed
 
 mod helpers;
@@ -16,7 +18,7 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::{AddArgs, NoteAddArgs, NoteEditArgs, NoteListArgs, ResourceAddArgs};
 use rustodo::commands::{note, resource, task};
-use rustodo::models::Priority;
+use rustodo::models::{LinkTarget, Note, Priority};
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -32,6 +34,7 @@ fn add_task(env: &TestEnv, text: &str) {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -44,11 +47,12 @@ fn add_note(env: &TestEnv, body: &str) {
             body: Some(body.to_string()),
             editor: false,
             file: None,
+            template: None,
             title: None,
             tag: vec![],
             language: None,
-            project: None,
-            task: None,
+            project: vec![],
+            task: vec![],
         },
     )
     .unwrap();
@@ -69,16 +73,31 @@ fn add_note_full(
             body: Some(body.to_string()),
             editor: false,
             file: None,
+            template: None,
             title: title.map(|s| s.to_string()),
             tag: tags.into_iter().map(|s| s.to_string()).collect(),
             language: language.map(|s| s.to_string()),
-            project: project.map(|s| s.to_string()),
-            task: task_num,
+            project: project.map(|s| s.to_string()).into_iter().collect(),
+            task: task_num.into_iter().collect(),
         },
     )
     .unwrap();
 }
 
+fn blank_add() -> NoteAddArgs {
+    NoteAddArgs {
+        body: None,
+        editor: false,
+        file: None,
+        template: None,
+        title: None,
+        tag: vec![],
+        language: None,
+        project: vec![],
+        task: vec![],
+    }
+}
+
 fn add_resource(env: &TestEnv, title: &str) {
     resource::add::execute(
         env.storage(),
@@ -137,8 +156,8 @@ fn test_note_add_with_all_metadata() {
     assert!(n.tags.contains(&"rust".to_string()));
     assert!(n.tags.contains(&"docs".to_string()));
     assert_eq!(n.language.as_deref(), Some("rust"));
-    assert!(n.project_id.is_some());
-    assert!(n.task_id.is_some());
+    assert!(!n.project_ids.is_empty());
+    assert!(!n.task_ids.is_empty());
 }
 
 #[test]
@@ -150,11 +169,12 @@ fn test_note_add_no_body_fails() {
             body: None,
             editor: false,
             file: None,
+            template: None,
             title: None,
             tag: vec![],
             language: None,
-            project: None,
-            task: None,
+            project: vec![],
+            task: vec![],
         },
     );
     assert!(result.is_err());
@@ -305,6 +325,26 @@ fn test_note_show_out_of_range_fails() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_note_show_and_preview_render_markdown() {
+    let env = TestEnv::new();
+    let body = "# Plan\n\n- [x] **draft**\n- [ ] review `spec`\n\n| a | b |\n|---|--:|\n| 1 | 2 |\n\n```sh\ncargo test\n```";
+    env.storage()
+        .upsert_note(&Note::new_markdown(body.to_string()))
+        .unwrap();
+
+    assert!(note::show::execute(env.storage(), 1).is_ok());
+    assert!(note::preview::execute(env.storage(), 1).is_ok());
+}
+
+#[test]
+fn test_note_preview_plain_note_is_ok() {
+    let env = TestEnv::new();
+    add_note(&env, "Just text");
+
+    assert!(note::preview::execute(env.storage(), 1).is_ok());
+}
+
 // ─── edit ─────────────────────────────────────────────────────────────────────
 
 fn blank_edit(id: usize) -> NoteEditArgs {
@@ -319,10 +359,12 @@ fn blank_edit(id: usize) -> NoteEditArgs {
         add_tag: vec![],
         remove_tag: vec![],
         clear_tags: false,
-        project: None,
+        project: vec![],
         clear_project: false,
-        task: None,
+        task: vec![],
         clear_task: false,
+        add_task: vec![],
+        remove_task: vec![],
         add_resource: vec![],
         remove_resource: vec![],
         clear_resources: false,
@@ -483,14 +525,14 @@ fn test_note_edit_assign_project() {
     note::edit::execute(
         env.storage(),
         NoteEditArgs {
-            project: Some("Backend".to_string()),
+            project: vec!["Backend".to_string()],
             ..blank_edit(1)
         },
     )
     .unwrap();
 
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes[0].project_id.is_some());
+    assert!(!notes[0].project_ids.is_empty());
 }
 
 #[test]
@@ -508,7 +550,7 @@ fn test_note_edit_clear_project() {
     .unwrap();
 
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes[0].project_id.is_none());
+    assert!(notes[0].project_ids.is_empty());
 }
 
 #[test]
@@ -520,14 +562,14 @@ fn test_note_edit_link_task() {
     note::edit::execute(
         env.storage(),
         NoteEditArgs {
-            task: Some(1),
+            task: vec![1],
             ..blank_edit(1)
         },
     )
     .unwrap();
 
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes[0].task_id.is_some());
+    assert!(!notes[0].task_ids.is_empty());
 }
 
 #[test]
@@ -546,7 +588,75 @@ fn test_note_edit_clear_task() {
     .unwrap();
 
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes[0].task_id.is_none());
+    assert!(notes[0].task_ids.is_empty());
+}
+
+#[test]
+fn test_note_add_links_several_tasks_and_projects() {
+    let env = TestEnv::new();
+    for text in ["Pick a database", "Write the schema", "Benchmark"] {
+        add_task(&env, text);
+    }
+
+    note::add::execute(
+        env.storage(),
+        NoteAddArgs {
+            body: Some("Meeting notes".to_string()),
+            project: vec!["Backend".to_string(), "Infra".to_string()],
+            task: vec![1, 3, 1],
+            ..blank_add()
+        },
+    )
+    .unwrap();
+
+    let tasks = env.load_tasks();
+    let note = &env.storage().load_notes().unwrap()[0];
+    assert_eq!(note.task_ids, vec![tasks[0].uuid, tasks[2].uuid]);
+    assert_eq!(note.project_ids.len(), 2);
+    assert!(note.belongs_to_task(tasks[2].uuid));
+    assert!(!note.belongs_to_task(tasks[1].uuid));
+    assert!(rustodo::commands::context::execute(env.storage(), 3).is_ok());
+}
+
+#[test]
+fn test_note_edit_add_and_remove_tasks() {
+    let env = TestEnv::new();
+    for text in ["One", "Two", "Three"] {
+        add_task(&env, text);
+    }
+    add_note_full(&env, "Body", None, vec![], None, None, Some(1));
+
+    note::edit::execute(
+        env.storage(),
+        NoteEditArgs {
+            add_task: vec![2, 3],
+            ..blank_edit(1)
+        },
+    )
+    .unwrap();
+    note::edit::execute(
+        env.storage(),
+        NoteEditArgs {
+            remove_task: vec![1],
+            ..blank_edit(1)
+        },
+    )
+    .unwrap();
+
+    let tasks = env.load_tasks();
+    let notes = env.storage().load_notes().unwrap();
+    assert_eq!(notes[0].task_ids, vec![tasks[1].uuid, tasks[2].uuid]);
+
+    assert!(
+        note::edit::execute(
+            env.storage(),
+            NoteEditArgs {
+                add_task: vec![9],
+                ..blank_edit(1)
+            },
+        )
+        .is_err()
+    );
 }
 
 #[test]
@@ -572,6 +682,148 @@ fn test_note_edit_no_changes_is_ok() {
     assert!(result.is_ok());
 }
 
+// ─── wiki-links ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_note_links_resolve_on_save() {
+    let env = TestEnv::new();
+    add_task(&env, "Write spec");
+    add_resource(&env, "Rust book");
+    add_note_full(
+        &env,
+        "See [[Design]], [[task:1]], [[resource:1]] and [[task:9]]",
+        Some("Index"),
+        vec![],
+        None,
+        None,
+        None,
+    );
+
+    // [[Design]] does not exist yet; [[task:9]] never resolves.
+    let notes = env.storage().load_notes().unwrap();
+    let tasks = env.storage().load().unwrap();
+    let resources = env.storage().load_resources().unwrap();
+    assert_eq!(notes[0].links.len(), 2);
+    assert!(notes[0].links_to(LinkTarget::Task(tasks[0].uuid)));
+    assert!(notes[0].links_to(LinkTarget::Resource(resources[0].uuid)));
+
+    add_note_full(&env, "Body", Some("Design"), vec![], None, None, None);
+    let notes = env.storage().load_notes().unwrap();
+    assert!(notes[0].links_to(LinkTarget::Note(notes[1].uuid)));
+
+    assert!(note::show::execute(env.storage(), 1).is_ok());
+    assert!(note::show::execute(env.storage(), 2).is_ok());
+    assert!(rustodo::commands::context::execute(env.storage(), 1).is_ok());
+}
+
+#[test]
+fn test_note_rename_updates_inbound_links() {
+    let env = TestEnv::new();
+    add_note_full(&env, "Body", Some("Design"), vec![], None, None, None);
+    add_note(&env, "Read [[design]] first");
+
+    note::edit::execute(
+        env.storage(),
+        NoteEditArgs {
+            title: Some("Architecture".to_string()),
+            ..blank_edit(1)
+        },
+    )
+    .unwrap();
+
+    let notes = env.storage().load_notes().unwrap();
+    assert_eq!(notes[1].body, "Read [[Architecture]] first");
+    assert_eq!(notes[1].links[0].reference, "Architecture");
+    assert!(notes[1].links_to(LinkTarget::Note(notes[0].uuid)));
+
+    // The rewritten note keeps its old body as a revision.
+    let revisions = env.storage().load_note_revisions(notes[1].uuid).unwrap();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].body, "Read [[design]] first");
+}
+
+#[test]
+fn test_note_edit_body_drops_removed_links() {
+    let env = TestEnv::new();
+    add_task(&env, "Write spec");
+    add_note(&env, "See [[task:1]]");
+
+    note::edit::execute(
+        env.storage(),
+        NoteEditArgs {
+            body: Some("No links any more".to_string()),
+            ..blank_edit(1)
+        },
+    )
+    .unwrap();
+
+    assert!(env.storage().load_notes().unwrap()[0].links.is_empty());
+}
+
+// ─── revisions ───────────────────────────────────────────────────────────────
+
+fn edit_body(env: &TestEnv, id: usize, body: &str) {
+    note::edit::execute(
+        env.storage(),
+        NoteEditArgs {
+            body: Some(body.to_string()),
+            ..blank_edit(id)
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_note_edit_keeps_previous_revision() {
+    let env = TestEnv::new();
+    add_note_full(&env, "First", Some("Draft"), vec![], None, None, None);
+    edit_body(&env, 1, "Second");
+    edit_body(&env, 1, "Second");
+
+    let note = &env.storage().load_notes().unwrap()[0];
+    let revisions = env.storage().load_note_revisions(note.uuid).unwrap();
+    assert_eq!(
+        revisions.len(),
+        1,
+        "an edit without changes keeps no revision"
+    );
+    assert_eq!(revisions[0].number, 1);
+    assert_eq!(revisions[0].body, "First");
+    assert_eq!(revisions[0].title.as_deref(), Some("Draft"));
+
+    assert!(note::history::execute(env.storage(), 1).is_ok());
+    assert!(note::diff::execute(env.storage(), 1, None).is_ok());
+    assert!(note::diff::execute(env.storage(), 1, Some(1)).is_ok());
+    assert!(note::diff::execute(env.storage(), 1, Some(2)).is_err());
+}
+
+#[test]
+fn test_note_revert_restores_and_keeps_current() {
+    let env = TestEnv::new();
+    add_note(&env, "First");
+    edit_body(&env, 1, "Second");
+
+    note::revert::execute(env.storage(), 1, 1).unwrap();
+
+    let note = &env.storage().load_notes().unwrap()[0];
+    assert_eq!(note.body, "First");
+    let revisions = env.storage().load_note_revisions(note.uuid).unwrap();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[1].number, 2);
+    assert_eq!(revisions[1].body, "Second");
+
+    assert!(note::revert::execute(env.storage(), 1, 9).is_err());
+}
+
+#[test]
+fn test_note_history_without_revisions_is_ok() {
+    let env = TestEnv::new();
+    add_note(&env, "Body");
+    assert!(note::history::execute(env.storage(), 1).is_ok());
+    assert!(note::diff::execute(env.storage(), 1, None).is_ok());
+    assert!(note::history::execute(env.storage(), 2).is_err());
+}
+
 // ─── remove ───────────────────────────────────────────────────────────────────
 
 #[test]
@@ -654,13 +906,13 @@ fn test_remove_task_clears_note_task_id() {
 
     // Verificar que o link foi criado
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes[0].task_id.is_some());
+    assert!(!notes[0].task_ids.is_empty());
 
     task::remove::execute(env.storage(), 1, true).unwrap();
 
     let notes = env.storage().load_notes().unwrap();
     assert!(
-        notes[0].task_id.is_none(),
+        notes[0].task_ids.is_empty(),
         "task_id deve ser limpo quando a task é removida"
     );
 }
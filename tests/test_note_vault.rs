@@ -0,0 +1,181 @@
+//! Integration tests for markdown vault export, import and sync
+//!
+//! Covers:
+//! - note export writes one front-matter file per visible note
+//! - note import updates notes by UUID and creates notes from new files
+//! - re-importing an unchanged export is a no-op (body whitespace, multi-word tags)
+//! - note import fails for a missing directory
+//! - note sync exports new notes, imports newer files and writes ids back
+
+mod helpers;
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use helpers::TestEnv;
+use rustodo::cli::NoteAddArgs;
+use rustodo::commands::note;
+use rustodo::storage::Storage;
+use tempfile::TempDir;
+
+// ─── helpers ─────────────────────────────────────────────────────────────────
+
+fn add_note(env: &TestEnv, body: &str, title: &str, tags: &[&str]) {
+    note::add::execute(
+        env.storage(),
+        NoteAddArgs {
+            body: Some(body.to_string()),
+            editor: false,
+            file: None,
+            template: None,
+            title: Some(title.to_string()),
+            tag: tags.iter().map(|t| t.to_string()).collect(),
+            language: None,
+            project: vec!["docs".to_string()],
+            task: vec![],
+        },
+    )
+    .unwrap();
+}
+
+fn md_files(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|n| n.ends_with(".md"))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Rewrites a file and pushes its modification time into the future, so
+/// sync sees it as newer than the note.
+fn edit_file(path: &Path, content: &str) {
+    fs::write(path, content).unwrap();
+    let file = fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(60))
+        .unwrap();
+}
+
+// ─── export / import ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_note_export_writes_front_matter_files() {
+    let env = TestEnv::new();
+    add_note(&env, "First body", "Design notes", &["rust"]);
+    add_note(&env, "Second body", "Gone", &[]);
+    note::remove::execute(env.storage(), 2, true).unwrap();
+    let tmp = TempDir::new().unwrap();
+
+    note::vault::execute_export(env.storage(), tmp.path().to_path_buf()).unwrap();
+
+    let uuid = env.storage().load_notes().unwrap()[0].uuid;
+    assert_eq!(
+        md_files(tmp.path()),
+        vec![format!("design-notes-{}.md", uuid)]
+    );
+    let content = fs::read_to_string(tmp.path().join(&md_files(tmp.path())[0])).unwrap();
+    assert!(content.starts_with(&format!("---\nid: {}\ntitle: Design notes\n", uuid)));
+    assert!(content.contains("tags:\n  - rust\n"));
+    assert!(content.contains("project: docs\n"));
+    assert!(content.ends_with("---\n\nFirst body\n"));
+}
+
+#[test]
+fn test_note_import_updates_by_uuid_and_creates_new() {
+    let env = TestEnv::new();
+    add_note(&env, "Old body", "Design", &[]);
+    let tmp = TempDir::new().unwrap();
+    note::vault::execute_export(env.storage(), tmp.path().to_path_buf()).unwrap();
+
+    let exported = tmp.path().join(&md_files(tmp.path())[0]);
+    let content = fs::read_to_string(&exported).unwrap();
+    fs::write(&exported, content.replace("Old body", "New body")).unwrap();
+    fs::create_dir(tmp.path().join("ideas")).unwrap();
+    fs::write(
+        tmp.path().join("ideas/Fresh idea.md"),
+        "---\ntags: [idea]\nproject: lab\n---\nWritten elsewhere\n",
+    )
+    .unwrap();
+
+    note::vault::execute_import(env.storage(), tmp.path().to_path_buf()).unwrap();
+
+    let notes = env.storage().load_notes().unwrap();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].body, "New body");
+    assert_eq!(notes[0].title.as_deref(), Some("Design"));
+    assert_eq!(notes[1].body, "Written elsewhere");
+    assert_eq!(notes[1].title.as_deref(), Some("Fresh idea"));
+    assert_eq!(notes[1].tags, vec!["idea"]);
+    let projects = env.storage().load_projects().unwrap();
+    assert!(projects.iter().any(|p| p.name == "lab"));
+
+    // The overwritten body is kept as a revision.
+    let revisions = env.storage().load_note_revisions(notes[0].uuid).unwrap();
+    assert_eq!(revisions[0].body, "Old body");
+}
+
+#[test]
+fn test_note_reimport_of_unchanged_export_is_a_noop() {
+    let env = TestEnv::new();
+    add_note(&env, "Trailing blank lines\n\n", "Padded", &["foo bar"]);
+    add_note(&env, "\n  Indented\r\nbody  \n", "Messy", &[]);
+    let before = env.storage().load_notes().unwrap();
+    let tmp = TempDir::new().unwrap();
+    note::vault::execute_export(env.storage(), tmp.path().to_path_buf()).unwrap();
+
+    note::vault::execute_import(env.storage(), tmp.path().to_path_buf()).unwrap();
+
+    let after = env.storage().load_notes().unwrap();
+    for (old, new) in before.iter().zip(&after) {
+        assert_eq!(new.body, old.body);
+        assert_eq!(new.tags, old.tags);
+        assert_eq!(new.updated_at, old.updated_at);
+        let revisions = env.storage().load_note_revisions(old.uuid).unwrap();
+        assert!(revisions.is_empty(), "no revision for an unchanged file");
+    }
+}
+
+#[test]
+fn test_note_import_missing_dir_fails() {
+    let env = TestEnv::new();
+    let tmp = TempDir::new().unwrap();
+    let result = note::vault::execute_import(env.storage(), tmp.path().join("nope"));
+    assert!(result.is_err());
+}
+
+// ─── sync ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_note_sync_both_ways() {
+    let env = TestEnv::new();
+    add_note(&env, "Body", "Design", &[]);
+    let tmp = TempDir::new().unwrap();
+    let dir = tmp.path().to_path_buf();
+
+    // First sync exports the note.
+    note::vault::execute_sync(env.storage(), dir.clone()).unwrap();
+    let files = md_files(&dir);
+    assert_eq!(files.len(), 1);
+
+    // A newer file wins; a new file becomes a note and gets its id written.
+    let exported = dir.join(&files[0]);
+    let content = fs::read_to_string(&exported).unwrap();
+    edit_file(&exported, &content.replace("Body", "Edited in the vault"));
+    fs::write(dir.join("Inbox.md"), "Captured on the phone").unwrap();
+
+    note::vault::execute_sync(env.storage(), dir.clone()).unwrap();
+
+    let notes = env.storage().load_notes().unwrap();
+    assert_eq!(notes.len(), 2);
+    assert_eq!(notes[0].body, "Edited in the vault");
+    assert_eq!(notes[1].title.as_deref(), Some("Inbox"));
+    let inbox = fs::read_to_string(dir.join("Inbox.md")).unwrap();
+    assert!(inbox.starts_with(&format!("---\nid: {}\n", notes[1].uuid)));
+
+    // Nothing changed since: a third sync neither duplicates nor rewrites.
+    note::vault::execute_sync(env.storage(), dir.clone()).unwrap();
+    assert_eq!(env.storage().load_notes().unwrap().len(), 2);
+    assert_eq!(md_files(&dir).len(), 2);
+}
@@ -0,0 +1,140 @@
+//! Integration tests for `todo plan` and task estimates
+//!
+//! Covers:
+//! - `--estimate` on add and edit, and `--clear-estimate`
+//! - Planning a project, including prerequisites from other projects
+//! - Unknown projects and dependency cycles are errors
+
+mod helpers;
+
+use helpers::{TestEnv, simple_task};
+use rustodo::cli::{AddArgs, EditArgs};
+use rustodo::commands::{plan, task};
+use rustodo::models::Priority;
+use rustodo::services::schedule;
+use rustodo::storage::Storage;
+
+fn add(env: &TestEnv, text: &str, project: Option<&str>, estimate: &str, deps: Vec<usize>) {
+    task::add::execute_silent(
+        env.storage(),
+        AddArgs {
+            text: text.into(),
+            priority: Priority::Medium,
+            tag: vec![],
+            project: project.map(Into::into),
+            due: None,
+            recurrence: None,
+            depends_on: deps,
+            estimate: Some(estimate.into()),
+        },
+    )
+    .unwrap();
+}
+
+fn edit_args(id: usize) -> EditArgs {
+    EditArgs {
+        id,
+        text: None,
+        priority: None,
+        add_tag: vec![],
+        remove_tag: vec![],
+        project: None,
+        clear_project: false,
+        due: None,
+        clear_due: false,
+        clear_tags: false,
+        add_dep: vec![],
+        remove_dep: vec![],
+        clear_deps: false,
+        estimate: None,
+        clear_estimate: false,
+    }
+}
+
+#[test]
+fn test_estimate_on_add_and_edit() {
+    let env = TestEnv::new();
+    add(&env, "Design", None, "2w", vec![]);
+    assert_eq!(env.load_tasks()[0].estimate, Some(14));
+
+    let args = EditArgs {
+        estimate: Some("3d".into()),
+        ..edit_args(1)
+    };
+    task::edit::execute_silent(env.storage(), args).unwrap();
+    assert_eq!(env.load_tasks()[0].estimate, Some(3));
+
+    let args = EditArgs {
+        clear_estimate: true,
+        ..edit_args(1)
+    };
+    task::edit::execute_silent(env.storage(), args).unwrap();
+    assert_eq!(env.load_tasks()[0].estimate, None);
+}
+
+#[test]
+fn test_invalid_estimate_is_rejected() {
+    let env = TestEnv::new();
+    let args = AddArgs {
+        text: "Design".into(),
+        priority: Priority::Medium,
+        tag: vec![],
+        project: None,
+        due: None,
+        recurrence: None,
+        depends_on: vec![],
+        estimate: Some("a while".into()),
+    };
+    assert!(task::add::execute_silent(env.storage(), args).is_err());
+    assert!(env.is_empty());
+}
+
+#[test]
+fn test_huge_estimate_is_rejected() {
+    let env = TestEnv::new();
+    let args = AddArgs {
+        text: "Design".into(),
+        priority: Priority::Medium,
+        tag: vec![],
+        project: None,
+        due: None,
+        recurrence: None,
+        depends_on: vec![],
+        estimate: Some("4000000000d".into()),
+    };
+    assert!(task::add::execute_silent(env.storage(), args).is_err());
+    assert!(env.is_empty());
+}
+
+#[test]
+fn test_plan_project_includes_outside_prerequisites() {
+    let env = TestEnv::new();
+    add(&env, "Order hardware", None, "5d", vec![]);
+    add(&env, "Install", Some("Office"), "2d", vec![1]);
+    add(&env, "Signage", Some("Office"), "1d", vec![]);
+    add(&env, "Holiday party", None, "1d", vec![]);
+
+    let tasks = env.load_tasks();
+    let office = env.storage().load_projects().unwrap()[0].uuid;
+    let plan = schedule::plan(&tasks, Some(office)).unwrap();
+
+    assert_eq!(plan.total_days, 7);
+    assert_eq!(plan.critical_path, vec![0, 1]);
+    assert_eq!(plan.get(2).unwrap().slack, 6);
+    assert!(plan.get(3).is_none());
+
+    plan::execute(env.storage(), Some("office")).unwrap();
+}
+
+#[test]
+fn test_plan_errors() {
+    let env = TestEnv::with_tasks(vec![simple_task("A"), simple_task("B")]);
+    assert!(plan::execute(env.storage(), Some("Nowhere")).is_err());
+
+    let mut tasks = env.load_tasks();
+    tasks[0].depends_on = vec![tasks[1].uuid];
+    tasks[1].depends_on = vec![tasks[0].uuid];
+    env.save_tasks(&tasks);
+    let err = plan::execute(env.storage(), None).unwrap_err();
+    assert!(err.to_string().contains("cycle"));
+}
@@ -3,7 +3,7 @@
 //! Complementa test_projects_command.rs cobrindo:
 //! - project add (simples, duplicado, validação)
 //! - project show
-//! - project edit (name, difficulty, tech, due, done/undone)
+//! - project edit (name, difficulty, tech, due, done/undone, status)
 //! - project done / undone
 //! - project remove (soft delete, cascata em tasks e notes)
 //! - project clear
@@ -13,7 +13,7 @@ mod helpers;
 use helpers::TestEnv;
 use rustodo::cli::{AddArgs, NoteAddArgs, ProjectAddArgs, ProjectEditArgs};
 use rustodo::commands::{note, project, task};
-use rustodo::models::{Difficulty, Priority};
+use rustodo::models::{Difficulty, Priority, ProjectStatus};
 use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
@@ -26,6 +26,12 @@ fn add_proj(env: &TestEnv, name: &str) {
             difficulty: None,
             tech: vec![],
             due: None,
+            status: None,
+            description: None,
+            start: None,
+            owner: None,
+            url: None,
+            color: None,
         },
     )
     .unwrap();
@@ -42,6 +48,7 @@ fn add_task_in_project(env: &TestEnv, text: &str, proj: &str) {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -54,11 +61,12 @@ fn add_note_in_project(env: &TestEnv, body: &str, proj: &str) {
             body: Some(body.to_string()),
             editor: false,
             file: None,
+            template: None,
             title: None,
             tag: vec![],
             language: None,
-            project: Some(proj.to_string()),
-            task: None,
+            project: vec![proj.to_string()],
+            task: vec![],
         },
     )
     .unwrap();
@@ -85,6 +93,19 @@ fn blank_edit(id: usize) -> ProjectEditArgs {
         clear_tech: false,
         due: None,
         clear_due: false,
+        parent: None,
+        clear_parent: false,
+        status: None,
+        description: None,
+        clear_description: false,
+        start: None,
+        clear_start: false,
+        owner: None,
+        clear_owner: false,
+        url: None,
+        clear_url: false,
+        color: None,
+        clear_color: false,
     }
 }
 
@@ -121,6 +142,12 @@ fn test_project_add_duplicate_fails() {
             difficulty: None,
             tech: vec![],
             due: None,
+            status: None,
+            description: None,
+            start: None,
+            owner: None,
+            url: None,
+            color: None,
         },
     );
     assert!(result.is_err());
@@ -137,6 +164,12 @@ fn test_project_add_with_metadata() {
             difficulty: Some(Difficulty::Hard),
             tech: vec!["rust".to_string(), "axum".to_string()],
             due: None,
+            status: None,
+            description: None,
+            start: None,
+            owner: None,
+            url: None,
+            color: None,
         },
     )
     .unwrap();
@@ -241,6 +274,12 @@ fn test_project_edit_remove_tech() {
             difficulty: None,
             tech: vec!["rust".to_string(), "postgres".to_string()],
             due: None,
+            status: None,
+            description: None,
+            start: None,
+            owner: None,
+            url: None,
+            color: None,
         },
     )
     .unwrap();
@@ -269,6 +308,12 @@ fn test_project_edit_clear_tech() {
             difficulty: None,
             tech: vec!["rust".to_string(), "postgres".to_string()],
             due: None,
+            status: None,
+            description: None,
+            start: None,
+            owner: None,
+            url: None,
+            color: None,
         },
     )
     .unwrap();
@@ -424,13 +469,13 @@ fn test_project_remove_clears_note_project_id() {
     add_note_in_project(&env, "Note B", "Backend");
 
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes.iter().all(|n| n.project_id.is_some()));
+    assert!(notes.iter().all(|n| !n.project_ids.is_empty()));
 
     project::remove::execute(env.storage(), 1, true).unwrap();
 
     let notes = env.storage().load_notes().unwrap();
     assert!(
-        notes.iter().all(|n| n.project_id.is_none()),
+        notes.iter().all(|n| n.project_ids.is_empty()),
         "project_id deve ser limpo nas notes quando o projeto é removido"
     );
 }
@@ -486,5 +531,30 @@ fn test_project_clear_cleans_task_and_note_links() {
     assert!(tasks[0].project_id.is_none());
 
     let notes = env.storage().load_notes().unwrap();
-    assert!(notes[0].project_id.is_none());
+    assert!(notes[0].project_ids.is_empty());
+}
+
+// ─── status ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_project_status_filter_and_undone() {
+    let env = TestEnv::new();
+    add_proj(&env, "Alpha");
+    add_proj(&env, "Beta");
+    project::edit::execute(
+        env.storage(),
+        ProjectEditArgs {
+            status: Some(ProjectStatus::Abandoned),
+            ..blank_edit(2)
+        },
+    )
+    .unwrap();
+
+    assert!(project::list::execute(env.storage(), &[ProjectStatus::Abandoned], true).is_ok());
+    assert!(project::list::execute(env.storage(), &[ProjectStatus::Idea], false).is_err());
+
+    project::undone::execute(env.storage(), 2).unwrap();
+    let projects = env.storage().load_projects().unwrap();
+    assert_eq!(projects[1].status, ProjectStatus::Active);
+    assert!(projects[1].completed_at.is_none());
 }
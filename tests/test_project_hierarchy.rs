@@ -0,0 +1,149 @@
+//! Integration tests for nested projects
+//!
+//! Covers:
+//! - `--project a.b.c` creates the missing projects along the path
+//! - `--project a` in list and search also matches sub-projects
+//! - Removing a project moves its sub-projects up a level
+//! - Archiving keeps a parent while a sub-project is still live
+//! - Restoring a project whose parent was deleted brings it back at the top
+
+mod helpers;
+
+use chrono::NaiveDate;
+use helpers::{TestEnv, simple_task};
+use rustodo::cli::AddArgs;
+use rustodo::commands::{archive, project, search, task, trash};
+use rustodo::models::{Priority, Project, StatusFilter, count_by_project_tree};
+use rustodo::storage::Storage;
+
+fn add_task(env: &TestEnv, text: &str, project: &str) {
+    task::add::execute(
+        env.storage(),
+        AddArgs {
+            text: text.to_string(),
+            priority: Priority::Medium,
+            tag: vec![],
+            project: Some(project.to_string()),
+            due: None,
+            recurrence: None,
+            depends_on: vec![],
+            estimate: None,
+        },
+    )
+    .unwrap();
+}
+
+fn list(env: &TestEnv, project: &str) -> anyhow::Result<()> {
+    task::list::execute(
+        env.storage(),
+        StatusFilter::All,
+        None,
+        None,
+        None,
+        vec![],
+        Some(project.to_string()),
+        None,
+        None,
+    )
+}
+
+#[test]
+fn test_dotted_project_creates_path() {
+    let env = TestEnv::new();
+    add_task(&env, "Login", "acme.api.auth");
+    add_task(&env, "Docs", "acme.api");
+
+    let projects = env.storage().load_projects().unwrap();
+    assert_eq!(projects.len(), 3);
+    let tasks = env.load_tasks();
+    let auth = projects.iter().find(|p| p.name == "auth").unwrap();
+    assert_eq!(auth.path(&projects), "acme.api.auth");
+    assert_eq!(tasks[0].project_id, Some(auth.uuid));
+    assert_eq!(tasks[1].project_id, auth.parent_id);
+}
+
+#[test]
+fn test_parent_progress_includes_sub_projects() {
+    let env = TestEnv::new();
+    add_task(&env, "Login", "acme.api");
+    add_task(&env, "Landing page", "acme.web");
+    task::done::execute(env.storage(), 1).unwrap();
+
+    let projects = env.storage().load_projects().unwrap();
+    let acme = Project::find(&projects, "acme").unwrap();
+    assert_eq!(
+        count_by_project_tree(&env.load_tasks(), &projects, acme.uuid),
+        (2, 1)
+    );
+    assert!(project::show::execute(env.storage(), 1).is_ok());
+    assert!(project::list::execute(env.storage(), &[], false).is_ok());
+}
+
+#[test]
+fn test_list_and_search_match_descendants() {
+    let env = TestEnv::new();
+    add_task(&env, "Login", "acme.api.auth");
+    add_task(&env, "Unrelated", "other");
+
+    assert!(list(&env, "acme").is_ok());
+    assert!(list(&env, "acme.api").is_ok());
+    assert!(list(&env, "other.api").is_err());
+
+    let search = |q: &str, project: &str| {
+        search::execute(
+            env.storage(),
+            q.into(),
+            vec![],
+            Some(project.into()),
+            StatusFilter::All,
+            None,
+        )
+    };
+    assert!(search("login", "acme").is_ok());
+    assert!(search("login", "other").is_err());
+}
+
+#[test]
+fn test_remove_moves_sub_projects_up() {
+    let env = TestEnv::new();
+    add_task(&env, "Login", "acme.api.auth");
+
+    // Storage order: acme, api, auth.
+    project::remove::execute_silent(env.storage(), 2).unwrap();
+
+    let projects = env.storage().load_projects().unwrap();
+    let auth = Project::find(&projects, "auth").unwrap();
+    assert_eq!(auth.path(&projects), "acme.auth");
+}
+
+#[test]
+fn test_archive_keeps_parent_with_live_sub_project() {
+    let mut parent = Project::new("acme".into());
+    parent.mark_done();
+    parent.completed_at = NaiveDate::from_ymd_opt(2020, 1, 1);
+    let mut child = Project::new("api".into());
+    child.parent_id = Some(parent.uuid);
+    let env = TestEnv::with_tasks(vec![simple_task("Open")]);
+    env.storage().save_projects(&[parent, child]).unwrap();
+
+    archive::execute(env.storage(), "30d", false, true).unwrap();
+
+    assert_eq!(env.storage().load_projects().unwrap().len(), 2);
+    assert!(env.storage().load_archive().unwrap().is_empty());
+}
+
+#[test]
+fn test_trash_restore_under_deleted_parent_goes_top_level() {
+    let env = TestEnv::new();
+    add_task(&env, "Login", "acme.api");
+    project::remove::execute_silent(env.storage(), 2).unwrap();
+    project::remove::execute_silent(env.storage(), 1).unwrap();
+
+    // The parent was deleted last, so the sub-project is #2.
+    trash::restore(env.storage(), 2).unwrap();
+
+    let projects = env.storage().load_projects().unwrap();
+    let api = Project::find(&projects, "api").unwrap();
+    assert!(api.parent_id.is_none());
+    assert_eq!(api.path(&projects), "api");
+}
@@ -29,6 +29,7 @@ fn add_task(env: &TestEnv, text: &str, project: Option<&str>) -> usize {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -42,7 +43,7 @@ fn test_projects_no_projects_fails() {
     let env = TestEnv::new();
     add_task(&env, "Task without project", None);
 
-    let result = project::list::execute(env.storage());
+    let result = project::list::execute(env.storage(), &[], false);
     assert!(result.is_err());
     let msg = result.unwrap_err().to_string();
     assert!(msg.to_lowercase().contains("project"), "got: {}", msg);
@@ -52,7 +53,7 @@ fn test_projects_no_projects_fails() {
 fn test_projects_empty_storage_fails() {
     let env = TestEnv::new();
 
-    let result = project::list::execute(env.storage());
+    let result = project::list::execute(env.storage(), &[], false);
     assert!(result.is_err());
 }
 
@@ -62,7 +63,7 @@ fn test_projects_single_project() {
     add_task(&env, "Task A", Some("Backend"));
     add_task(&env, "Task B", Some("Backend"));
 
-    let result = project::list::execute(env.storage());
+    let result = project::list::execute(env.storage(), &[], false);
     assert!(result.is_ok());
 
     // Verify counts via storage
@@ -86,7 +87,7 @@ fn test_projects_multiple_projects() {
     add_task(&env, "Button component", Some("Frontend"));
     add_task(&env, "Write docs", Some("Docs"));
 
-    let result = project::list::execute(env.storage());
+    let result = project::list::execute(env.storage(), &[], false);
     assert!(result.is_ok());
 
     let projects = env.storage().load_projects().unwrap();
@@ -129,7 +130,7 @@ fn test_projects_mixed_with_and_without_project() {
     add_task(&env, "Task without project", None);
     add_task(&env, "Another without project", None);
 
-    let result = project::list::execute(env.storage());
+    let result = project::list::execute(env.storage(), &[], false);
     assert!(result.is_ok());
 
     // Only 1 project should exist
@@ -149,7 +150,7 @@ fn test_projects_all_tasks_completed() {
     task::done::execute(env.storage(), 1).unwrap();
     task::done::execute(env.storage(), 2).unwrap();
 
-    let result = project::list::execute(env.storage());
+    let result = project::list::execute(env.storage(), &[], false);
     assert!(result.is_ok());
 
     let tasks = env.load_tasks();
@@ -184,6 +185,7 @@ fn test_list_filter_by_project() {
         vec![],
         Some("Backend".to_string()),
         None,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -203,6 +205,7 @@ fn test_list_filter_by_project_case_insensitive() {
         vec![],
         Some("backend".to_string()),
         None,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -221,6 +224,7 @@ fn test_list_filter_by_nonexistent_project_fails() {
         vec![],
         Some("Nonexistent".to_string()),
         None,
+        None,
     );
     assert!(result.is_err());
 }
@@ -242,6 +246,7 @@ fn test_list_filter_project_with_status() {
         vec![],
         Some("Backend".to_string()),
         None,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -262,6 +267,7 @@ fn test_list_filter_project_with_sort() {
             due: Some(days_from_now(10).to_string()),
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -276,6 +282,7 @@ fn test_list_filter_project_with_sort() {
             due: Some(days_from_now(2).to_string()),
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -289,6 +296,7 @@ fn test_list_filter_project_with_sort() {
         vec![],
         Some("Backend".to_string()),
         None,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -316,6 +324,8 @@ fn test_edit_assign_project() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -351,6 +361,8 @@ fn test_edit_change_project() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -386,6 +398,8 @@ fn test_edit_clear_project() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -417,6 +431,8 @@ fn test_edit_no_change_when_same_project() {
             add_dep: vec![],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     );
 
@@ -439,6 +455,7 @@ fn test_add_empty_project_name_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -459,6 +476,7 @@ fn test_add_project_name_too_long_fails() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -479,6 +497,7 @@ fn test_add_project_name_exactly_max_length_ok() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     );
 
@@ -8,13 +8,15 @@
 //! - Purge only removes deleted tasks, not active ones
 //! - Purge with multiple tombstones
 //! - Raw storage still has correct count after purge
+//! - Purging a note removes its revisions
 
 mod helpers;
 
 use helpers::TestEnv;
 use rustodo::cli::AddArgs;
 use rustodo::commands::{purge, task};
-use rustodo::models::Priority;
+use rustodo::models::{Note, NoteRevision, Priority};
+use rustodo::storage::Storage;
 
 // ─── helpers ─────────────────────────────────────────────────────────────────
 
@@ -29,6 +31,7 @@ fn add_simple(env: &TestEnv, text: &str) {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -197,3 +200,34 @@ fn test_purge_mixed_active_and_tombstones() {
     assert!(tasks.iter().any(|t| t.text == "Active A"));
     assert!(tasks.iter().any(|t| t.text == "Active C"));
 }
+
+// ─── note revisions ──────────────────────────────────────────────────────────
+
+#[test]
+fn test_purge_removes_revisions_of_purged_notes() {
+    let env = TestEnv::new();
+    let kept = Note::new("kept".into());
+    let mut gone = Note::new("gone".into());
+    gone.soft_delete();
+    env.storage()
+        .save_notes(&[kept.clone(), gone.clone()])
+        .unwrap();
+    for note in [&kept, &gone] {
+        env.storage()
+            .save_note_revision(&NoteRevision::of(note, 1))
+            .unwrap();
+    }
+
+    purge::execute(env.storage(), 0, false, true).unwrap();
+
+    assert_eq!(
+        env.storage().load_note_revisions(kept.uuid).unwrap().len(),
+        1
+    );
+    assert!(
+        env.storage()
+            .load_note_revisions(gone.uuid)
+            .unwrap()
+            .is_empty()
+    );
+}
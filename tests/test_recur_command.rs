@@ -33,6 +33,7 @@ fn add_simple(env: &TestEnv, text: &str) {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -50,6 +51,7 @@ fn add_with_due(env: &TestEnv, text: &str, due_offset_days: i64) -> usize {
             due: Some(due),
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -68,6 +70,7 @@ fn add_recurring(env: &TestEnv, text: &str, due_offset_days: i64, pattern: Recur
             due: Some(due),
             recurrence: Some(pattern),
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -239,8 +242,7 @@ fn test_done_recurring_weekly_creates_next() {
     let all = env.storage().load().unwrap();
     let next = all
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
-        .next()
+        .find(|t| !t.is_deleted() && !t.completed)
         .unwrap();
     let expected_due = due + chrono::Duration::days(7);
     assert_eq!(next.due_date, Some(expected_due));
@@ -263,6 +265,7 @@ fn test_done_recurring_monthly_creates_next() {
             due: Some(due_str.to_string()),
             recurrence: Some(Recurrence::Monthly),
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -272,8 +275,7 @@ fn test_done_recurring_monthly_creates_next() {
     let all = env.storage().load().unwrap();
     let next = all
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
-        .next()
+        .find(|t| !t.is_deleted() && !t.completed)
         .unwrap();
     assert_eq!(
         next.due_date,
@@ -321,6 +323,8 @@ fn test_done_recurring_next_does_not_inherit_deps() {
             add_dep: vec![1],
             remove_dep: vec![],
             clear_deps: false,
+            estimate: None,
+            clear_estimate: false,
         },
     )
     .unwrap();
@@ -332,8 +336,7 @@ fn test_done_recurring_next_does_not_inherit_deps() {
     let all = env.storage().load().unwrap();
     let next = all
         .iter()
-        .filter(|t| !t.is_deleted() && !t.completed)
-        .next()
+        .find(|t| !t.is_deleted() && !t.completed)
         .unwrap();
     assert!(
         next.depends_on.is_empty(),
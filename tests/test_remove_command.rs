@@ -27,6 +27,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -188,6 +189,7 @@ fn test_remove_preserves_task_metadata() {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
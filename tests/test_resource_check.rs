@@ -0,0 +1,203 @@
+//! Integration tests for `todo resource check`
+//!
+//! Covers:
+//! - status, redirect target and check time are stored per resource
+//! - servers that reject HEAD are retried with GET
+//! - unreachable hosts are recorded as dead with the error
+//! - --update-urls replaces redirected URLs with their target
+//! - recently checked links are skipped without --all; non-web URLs never checked
+//!
+//! Requests go to a local HTTP stand-in, never to the network.
+
+mod helpers;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use helpers::TestEnv;
+use rustodo::cli::{ResourceAddArgs, ResourceCheckArgs};
+use rustodo::commands::resource;
+use rustodo::models::Resource;
+use rustodo::storage::Storage;
+
+// ─── helpers ─────────────────────────────────────────────────────────────────
+
+/// Serves canned responses on 127.0.0.1 and returns the base URL.
+///
+/// `/ok` answers 200, `/gone` 404, `/moved` redirects to `/ok`, and `/no-head`
+/// answers 405 to HEAD but 200 to GET.
+fn serve() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let location = format!("{}/ok", base);
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            respond(stream, &location);
+        }
+    });
+    base
+}
+
+fn respond(mut stream: TcpStream, location: &str) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    let mut header = String::new();
+    while reader.read_line(&mut header).is_ok_and(|n| n > 0) && header.trim() != "" {
+        header.clear();
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let (status, extra) = match (method, path) {
+        (_, "/ok") => ("200 OK", String::new()),
+        (_, "/moved") => (
+            "301 Moved Permanently",
+            format!("Location: {}\r\n", location),
+        ),
+        ("HEAD", "/no-head") => ("405 Method Not Allowed", String::new()),
+        ("GET", "/no-head") => ("200 OK", String::new()),
+        _ => ("404 Not Found", String::new()),
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {}\r\n{}Content-Length: 0\r\nConnection: close\r\n\r\n",
+        status, extra
+    );
+}
+
+fn add_resource(env: &TestEnv, title: &str, url: &str) {
+    resource::add::execute(
+        env.storage(),
+        ResourceAddArgs {
+            title: title.to_string(),
+            r#type: None,
+            url: Some(url.to_string()),
+            description: None,
+            tag: vec![],
+        },
+    )
+    .unwrap();
+}
+
+fn check(env: &TestEnv, id: Option<usize>, all: bool, update_urls: bool) {
+    resource::check::execute(
+        env.storage(),
+        ResourceCheckArgs {
+            id,
+            all,
+            update_urls,
+        },
+    )
+    .unwrap();
+}
+
+fn resources(env: &TestEnv) -> Vec<Resource> {
+    env.storage().load_resources().unwrap()
+}
+
+// ─── check ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_check_records_status_and_redirects() {
+    let base = serve();
+    let env = TestEnv::new();
+    add_resource(&env, "Working", &format!("{}/ok", base));
+    add_resource(&env, "Gone", &format!("{}/gone", base));
+    add_resource(&env, "Moved", &format!("{}/moved", base));
+    add_resource(&env, "HEAD-shy", &format!("{}/no-head", base));
+
+    check(&env, None, false, false);
+
+    let resources = resources(&env);
+    let checks: Vec<_> = resources
+        .iter()
+        .map(|r| r.last_check.clone().unwrap())
+        .collect();
+    assert_eq!(checks[0].status, Some(200));
+    assert!(!checks[0].is_dead());
+    assert_eq!(checks[0].redirect(), None);
+
+    assert_eq!(checks[1].status, Some(404));
+    assert!(checks[1].is_dead());
+
+    assert_eq!(checks[2].status, Some(200));
+    assert_eq!(checks[2].redirect(), Some(format!("{}/ok", base).as_str()));
+    assert_eq!(
+        resources[2].url.as_deref(),
+        Some(format!("{}/moved", base).as_str()),
+        "URLs are only changed with --update-urls"
+    );
+
+    assert_eq!(checks[3].status, Some(200), "GET is tried when HEAD fails");
+}
+
+#[test]
+fn test_check_update_urls_follows_redirect() {
+    let base = serve();
+    let env = TestEnv::new();
+    add_resource(&env, "Moved", &format!("{}/moved", base));
+
+    check(&env, Some(1), false, true);
+
+    let resource = &resources(&env)[0];
+    assert_eq!(
+        resource.url.as_deref(),
+        Some(format!("{}/ok", base).as_str())
+    );
+    assert_eq!(resource.last_check.as_ref().unwrap().redirect(), None);
+}
+
+#[test]
+fn test_check_unreachable_host_is_dead() {
+    // Bind and drop a listener to get a port nothing is listening on.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let env = TestEnv::new();
+    add_resource(&env, "Offline", &format!("http://127.0.0.1:{}/", port));
+
+    check(&env, None, false, false);
+
+    let check = resources(&env)[0].last_check.clone().unwrap();
+    assert!(check.is_dead());
+    assert_eq!(check.status, None);
+    assert!(check.error.is_some());
+}
+
+#[test]
+fn test_check_skips_recent_and_non_web_links() {
+    let base = serve();
+    let env = TestEnv::new();
+    add_resource(&env, "Working", &format!("{}/ok", base));
+    add_resource(&env, "Local book", "~/books/rust.pdf");
+
+    check(&env, None, false, false);
+    let first = resources(&env)[0].last_check.clone().unwrap();
+    assert!(resources(&env)[1].last_check.is_none());
+
+    check(&env, None, false, false);
+    assert_eq!(
+        resources(&env)[0].last_check.as_ref().unwrap().checked_at,
+        first.checked_at,
+        "a recent check is not repeated"
+    );
+
+    check(&env, None, true, false);
+    assert!(resources(&env)[0].last_check.as_ref().unwrap().checked_at > first.checked_at);
+
+    let result = resource::check::execute(
+        env.storage(),
+        ResourceCheckArgs {
+            id: Some(2),
+            all: false,
+            update_urls: false,
+        },
+    );
+    assert!(result.is_err(), "a file path cannot be checked");
+}
@@ -513,11 +513,12 @@ fn test_resource_remove_clears_note_resource_ids() {
             body: Some("Note body".to_string()),
             editor: false,
             file: None,
+            template: None,
             title: None,
             tag: vec![],
             language: None,
-            project: None,
-            task: None,
+            project: vec![],
+            task: vec![],
         },
     )
     .unwrap();
@@ -535,10 +536,12 @@ fn test_resource_remove_clears_note_resource_ids() {
             add_tag: vec![],
             remove_tag: vec![],
             clear_tags: false,
-            project: None,
+            project: vec![],
             clear_project: false,
-            task: None,
+            task: vec![],
             clear_task: false,
+            add_task: vec![],
+            remove_task: vec![],
             add_resource: vec![1],
             remove_resource: vec![],
             clear_resources: false,
@@ -587,11 +590,12 @@ fn test_resource_clear_cleans_note_links() {
             body: Some("Note".to_string()),
             editor: false,
             file: None,
+            template: None,
             title: None,
             tag: vec![],
             language: None,
-            project: None,
-            task: None,
+            project: vec![],
+            task: vec![],
         },
     )
     .unwrap();
@@ -609,10 +613,12 @@ fn test_resource_clear_cleans_note_links() {
             add_tag: vec![],
             remove_tag: vec![],
             clear_tags: false,
-            project: None,
+            project: vec![],
             clear_project: false,
-            task: None,
+            task: vec![],
             clear_task: false,
+            add_task: vec![],
+            remove_task: vec![],
             add_resource: vec![1, 2],
             remove_resource: vec![],
             clear_resources: false,
@@ -30,6 +30,7 @@ fn add_task(env: &TestEnv, text: &str, tags: Vec<&str>, project: Option<&str>) -
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -54,6 +55,7 @@ fn test_search_finds_matching_task() {
         vec![],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -70,6 +72,7 @@ fn test_search_partial_match() {
         vec![],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -85,6 +88,7 @@ fn test_search_case_insensitive() {
         vec![],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -100,6 +104,7 @@ fn test_search_no_results_fails() {
         vec![],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_err());
 }
@@ -114,6 +119,7 @@ fn test_search_empty_storage_fails() {
         vec![],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_err());
 }
@@ -134,6 +140,7 @@ fn test_search_status_all_returns_both() {
         vec![],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 
@@ -160,6 +167,7 @@ fn test_search_status_pending_excludes_done() {
         vec![],
         None,
         StatusFilter::Pending,
+        None,
     );
     assert!(result.is_ok());
 
@@ -187,6 +195,7 @@ fn test_search_status_done_excludes_pending() {
         vec![],
         None,
         StatusFilter::Done,
+        None,
     );
     assert!(result.is_ok());
 
@@ -213,6 +222,7 @@ fn test_search_status_pending_no_results_fails() {
         vec![],
         None,
         StatusFilter::Pending,
+        None,
     );
     assert!(result.is_err());
 }
@@ -229,6 +239,7 @@ fn test_search_status_done_no_results_fails() {
         vec![],
         None,
         StatusFilter::Done,
+        None,
     );
     assert!(result.is_err());
 }
@@ -247,6 +258,7 @@ fn test_search_with_tag_filter() {
         vec!["work".to_string()],
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 
@@ -272,6 +284,7 @@ fn test_search_tag_filter_no_match_fails() {
         vec!["personal".to_string()], // tag doesn't exist on matching task
         None,
         StatusFilter::All,
+        None,
     );
     assert!(result.is_err());
 }
@@ -290,6 +303,7 @@ fn test_search_with_project_filter() {
         vec![],
         Some("Backend".to_string()),
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 
@@ -317,6 +331,7 @@ fn test_search_project_filter_case_insensitive() {
         vec![],
         Some("backend".to_string()), // lowercase
         StatusFilter::All,
+        None,
     );
     assert!(result.is_ok());
 }
@@ -339,7 +354,53 @@ fn test_search_tag_and_project_and_status() {
         vec!["urgent".to_string()],
         Some("Backend".to_string()),
         StatusFilter::Pending,
+        None,
     );
     // Task 1 matches but is done, Task 3 matches urgent but is Frontend
     assert!(result.is_err(), "no pending urgent Backend fix tasks");
 }
+
+#[test]
+fn test_search_filter_expression_or_across_tags() {
+    let env = TestEnv::new();
+    add_task(&env, "Crash on login", vec!["bug"], None);
+    add_task(&env, "Renew certificate", vec!["urgent"], None);
+    add_simple(&env, "Read book");
+
+    let result = search::execute(
+        env.storage(),
+        String::new(),
+        vec![],
+        None,
+        StatusFilter::All,
+        Some("tag:bug or tag:urgent".to_string()),
+    );
+    assert!(result.is_ok());
+
+    let result = search::execute(
+        env.storage(),
+        String::new(),
+        vec![],
+        None,
+        StatusFilter::All,
+        Some("tag:bug and tag:urgent".to_string()),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_search_invalid_filter_fails() {
+    let env = TestEnv::new();
+    add_simple(&env, "Buy milk");
+
+    let result = search::execute(
+        env.storage(),
+        "milk".to_string(),
+        vec![],
+        None,
+        StatusFilter::All,
+        Some("(tag:bug".to_string()),
+    );
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("missing closing ')'"));
+}
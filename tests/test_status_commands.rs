@@ -0,0 +1,73 @@
+//! Integration tests for the `start`, `wait` and `cancel` commands
+//!
+//! Covers:
+//! - Full lifecycle: pending → active → waiting → active → done
+//! - Cancelled dependencies unblock dependents
+//! - Cancelled tasks are excluded from pending/done filters
+//! - `undone` reopens cancelled tasks
+
+mod helpers;
+
+use helpers::{TestEnv, simple_task};
+use rustodo::commands::task;
+use rustodo::models::{StatusFilter, TaskStatus};
+
+#[test]
+fn test_full_lifecycle() {
+    let env = TestEnv::with_tasks(vec![simple_task("Write report")]);
+
+    task::status::execute(env.storage(), 1, TaskStatus::Active).unwrap();
+    task::status::execute(env.storage(), 1, TaskStatus::Waiting).unwrap();
+    task::status::execute(env.storage(), 1, TaskStatus::Active).unwrap();
+    task::done::execute(env.storage(), 1).unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks[0].status, TaskStatus::Done);
+    assert!(tasks[0].completed);
+}
+
+#[test]
+fn test_cancelled_dependency_unblocks_dependent() {
+    let dep = simple_task("Vendor API");
+    let mut dependent = simple_task("Integrate API");
+    dependent.depends_on = vec![dep.uuid];
+    let env = TestEnv::with_tasks(vec![dep, dependent]);
+
+    assert!(task::status::execute(env.storage(), 2, TaskStatus::Active).is_err());
+
+    task::status::execute(env.storage(), 1, TaskStatus::Cancelled).unwrap();
+
+    assert!(task::status::execute(env.storage(), 2, TaskStatus::Active).is_ok());
+}
+
+#[test]
+fn test_cancelled_excluded_from_pending_and_done_filters() {
+    let env = TestEnv::with_tasks(vec![simple_task("Keep"), simple_task("Drop")]);
+    task::status::execute(env.storage(), 2, TaskStatus::Cancelled).unwrap();
+
+    let tasks = env.load_tasks();
+    let pending = tasks
+        .iter()
+        .filter(|t| t.matches_status(StatusFilter::Pending))
+        .count();
+    let done = tasks
+        .iter()
+        .filter(|t| t.matches_status(StatusFilter::Done))
+        .count();
+    let cancelled = tasks
+        .iter()
+        .filter(|t| t.matches_status(StatusFilter::Cancelled))
+        .count();
+    assert_eq!((pending, done, cancelled), (1, 0, 1));
+}
+
+#[test]
+fn test_undone_reopens_cancelled_task() {
+    let env = TestEnv::with_tasks(vec![simple_task("Maybe later")]);
+    task::status::execute(env.storage(), 1, TaskStatus::Cancelled).unwrap();
+
+    assert!(task::status::execute(env.storage(), 1, TaskStatus::Active).is_err());
+
+    task::undone::execute(env.storage(), 1).unwrap();
+    assert_eq!(env.load_tasks()[0].status, TaskStatus::Pending);
+}
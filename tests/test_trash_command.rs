@@ -0,0 +1,84 @@
+//! Integration tests for `todo trash`
+//!
+//! Covers:
+//! - Listing an empty trash
+//! - Removing then restoring a task brings its dependencies back
+//! - Restoring a note drops links to entities that are still deleted
+//! - Emptying the trash, with and without `--older-than`
+//! - Unknown trash IDs are errors
+
+mod helpers;
+
+use chrono::{Duration, Utc};
+use helpers::{TestEnv, simple_task};
+use rustodo::commands::{note, task, trash};
+use rustodo::models::{Note, Resource, TrashType};
+use rustodo::storage::Storage;
+
+#[test]
+fn test_trash_list_empty_is_ok() {
+    let env = TestEnv::new();
+    assert!(trash::list(env.storage(), None).is_ok());
+    assert!(trash::list(env.storage(), Some(TrashType::Note)).is_ok());
+}
+
+#[test]
+fn test_remove_then_restore_task() {
+    let first = simple_task("Design");
+    let mut second = simple_task("Build");
+    second.depends_on = vec![first.uuid];
+    let env = TestEnv::with_tasks(vec![first.clone(), second]);
+
+    task::remove::execute_silent(env.storage(), 1).unwrap();
+    assert_eq!(env.load_tasks().len(), 1);
+
+    trash::restore(env.storage(), 1).unwrap();
+
+    let tasks = env.load_tasks();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].text, "Design");
+    assert_eq!(tasks[1].depends_on, vec![first.uuid]);
+    assert!(tasks[1].is_blocked(&tasks));
+}
+
+#[test]
+fn test_restore_note_drops_deleted_resource() {
+    let env = TestEnv::new();
+    let mut resource = Resource::new("Docs".into());
+    resource.soft_delete();
+    let mut n = Note::new("Reading list".into());
+    n.resource_ids = vec![resource.uuid];
+    env.storage().save_resources(&[resource]).unwrap();
+    env.storage().save_notes(&[n]).unwrap();
+    note::remove::execute(env.storage(), 1, true).unwrap();
+
+    // The note was deleted last, so it is #1.
+    trash::restore(env.storage(), 1).unwrap();
+
+    let notes = env.storage().load_notes().unwrap();
+    assert!(!notes[0].is_deleted());
+    assert!(notes[0].resource_ids.is_empty());
+}
+
+#[test]
+fn test_empty_respects_older_than() {
+    let mut old = simple_task("Old");
+    old.deleted_at = Some(Utc::now() - Duration::days(40));
+    let mut recent = simple_task("Recent");
+    recent.soft_delete();
+    let env = TestEnv::with_tasks(vec![old, recent, simple_task("Live")]);
+
+    trash::empty(env.storage(), Some("30d"), None, false, true).unwrap();
+    assert_eq!(env.load_all_tasks().len(), 2);
+
+    trash::empty(env.storage(), None, None, false, true).unwrap();
+    let all = env.load_all_tasks();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].text, "Live");
+}
+
+#[test]
+fn test_restore_unknown_id_fails() {
+    let env = TestEnv::with_tasks(vec![simple_task("Live")]);
+    assert!(trash::restore(env.storage(), 1).is_err());
+}
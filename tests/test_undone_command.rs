@@ -27,6 +27,7 @@ fn add_simple(env: &TestEnv, text: &str) -> usize {
             due: None,
             recurrence: None,
             depends_on: vec![],
+            estimate: None,
         },
     )
     .unwrap();
@@ -0,0 +1,118 @@
+//! Integration tests for saved views and the persistent context
+//!
+//! Covers:
+//! - `view save` turns flags into a filter expression and refuses duplicates
+//! - The context narrows tasks until cleared
+//! - Removing the view that is the context also clears the context
+
+mod helpers;
+
+use helpers::{TestEnv, simple_task, task_with_due, task_with_tags, today};
+use rustodo::cli::ViewSaveArgs;
+use rustodo::commands::view::{self, active_context};
+use rustodo::models::{DueFilter, SortBy, StatusFilter};
+use rustodo::storage::Storage;
+
+fn save_args(name: &str) -> ViewSaveArgs {
+    ViewSaveArgs {
+        name: name.into(),
+        status: None,
+        priority: None,
+        due: None,
+        tag: vec![],
+        project: None,
+        recurrence: None,
+        filter: None,
+        sort: None,
+        force: false,
+    }
+}
+
+#[test]
+fn test_save_builds_expression_from_flags() {
+    let env = TestEnv::new();
+    let view = view::save::execute_silent(
+        env.storage(),
+        ViewSaveArgs {
+            status: Some(StatusFilter::Pending),
+            due: Some(DueFilter::Overdue),
+            tag: vec!["work".into()],
+            sort: Some(SortBy::Urgency),
+            ..save_args("overdue-work")
+        },
+    )
+    .unwrap();
+
+    assert_eq!(view.filter, "status:pending due:overdue tag:work");
+    assert_eq!(view.sort, Some(SortBy::Urgency));
+    assert_eq!(env.storage().load_views().unwrap().len(), 1);
+}
+
+#[test]
+fn test_save_rejects_duplicate_without_force() {
+    let env = TestEnv::new();
+    view::save::execute_silent(env.storage(), save_args("work")).unwrap();
+
+    assert!(view::save::execute_silent(env.storage(), save_args("WORK")).is_err());
+
+    let forced = ViewSaveArgs {
+        force: true,
+        filter: Some("tag:work".into()),
+        ..save_args("work")
+    };
+    view::save::execute_silent(env.storage(), forced).unwrap();
+    let views = env.storage().load_views().unwrap();
+    assert_eq!(views.len(), 1);
+    assert_eq!(views[0].filter, "tag:work");
+}
+
+#[test]
+fn test_save_rejects_invalid_filter() {
+    let env = TestEnv::new();
+    let args = ViewSaveArgs {
+        filter: Some("colour:red".into()),
+        ..save_args("bad")
+    };
+    assert!(view::save::execute_silent(env.storage(), args).is_err());
+    assert!(env.storage().load_views().unwrap().is_empty());
+}
+
+#[test]
+fn test_context_narrows_tasks_until_cleared() {
+    let env = TestEnv::with_tasks(vec![
+        task_with_tags("Deploy", vec!["work"]),
+        simple_task("Groceries"),
+        task_with_due("Report", today()),
+    ]);
+    let args = ViewSaveArgs {
+        tag: vec!["work".into()],
+        ..save_args("work")
+    };
+    view::save::execute_silent(env.storage(), args).unwrap();
+
+    assert!(active_context(env.storage()).unwrap().is_none());
+
+    view::context::set(env.storage(), "Work").unwrap();
+    let ctx = active_context(env.storage()).unwrap().unwrap();
+    let tasks = env.load_tasks();
+    let matched: Vec<&str> = tasks
+        .iter()
+        .filter(|t| ctx.matches(t, &tasks, &[]))
+        .map(|t| t.text.as_str())
+        .collect();
+    assert_eq!(matched, vec!["Deploy"]);
+
+    view::context::clear(env.storage()).unwrap();
+    assert!(active_context(env.storage()).unwrap().is_none());
+}
+
+#[test]
+fn test_removing_context_view_clears_context() {
+    let env = TestEnv::new();
+    view::save::execute_silent(env.storage(), save_args("work")).unwrap();
+    view::context::set(env.storage(), "work").unwrap();
+
+    assert!(view::remove::execute_silent(env.storage(), "work").unwrap());
+    assert_eq!(env.storage().load_context().unwrap(), None);
+    assert!(view::context::set(env.storage(), "work").is_err());
+}